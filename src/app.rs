@@ -3,38 +3,68 @@ use crossterm::event::Event;
 use ratatui::layout::{Constraint, Direction, Layout};
 use tokio::sync::mpsc;
 
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use crate::external::{
-    ActivityWatcher, AssistantCli, BranchPrInfo, ClaudeActivityTracker, ClaudePlanReader,
-    LinearClient, LinearIssue, LinearIssueStatus, WorktreeInfo, ZellijSession,
-    count_active_sessions, edit_markdown, get_all_open_prs, get_pr_for_branch,
-    launch_prime_session, launch_zellij_claude_in_worktree,
-    launch_zellij_claude_in_worktree_with_context, list_sessions_with_status, list_worktrees,
-    prime_session_name,
+    ActivityWatcher, AssistantCli, BranchPrInfo, ClaudeActivityTracker, ClaudeConfig,
+    ClaudePlanReader, CreatedIssue, GitWatcher, LinearAttachment, LinearClient, LinearIssue,
+    LinearIssueStatus, LinearWorkflowState, PrReview, RateLimit, WorktreeInfo, ZellijSession,
+    attach_zellij_foreground, count_active_sessions, dump_session_scrollback, edit_markdown,
+    get_graphql_rate_limit, get_pr_batch, get_pr_diff, get_pr_for_branch, get_pr_for_branch_or_url,
+    is_rate_limit_error, launch_prime_session, launch_zellij_claude_executing_plan,
+    launch_zellij_claude_in_worktree, launch_zellij_claude_in_worktree_with_context,
+    list_sessions_with_status, list_worktrees, log_poll_timings, my_github_login, open_url,
+    prime_session_name, send_compact, session_name_for_branch, submit_pr_review,
 };
 use crate::input::{Action, EventStream, extract_key_event, key_to_action};
 use crate::state::{
-    AppState, Modal, View, check_linear_api_key, linear_env_var_name, task_title_to_branch,
+    AppState, AssistantPicker, BoardLayout, BranchPicker, FocusPoll, LinearStatePicker, Modal,
+    PendingApprove, PendingCancel, PendingCompact, PendingDelete, PlanPhase, PrDiff,
+    RefreshGeneration, SessionScrollback, TaskStatus, View, WipLimits, archive_candidates,
+    auto_archive_days, board_statuses, branch_to_task_title, check_linear_api_key,
+    compact_threshold, current_worktree_branch, linear_env_var_name, pin_current_enabled,
+    push_status_change, task_pr_and_worktree, watch_git_enabled,
+};
+use crate::storage::{DeletedTask, TaskStorage, TaskStore, read_secret};
+use crate::task_prompt::{
+    PullRequestContext, TaskPromptOptions, build_task_prompt, load_standing_context,
 };
-use crate::storage::TaskStorage;
-use crate::task_prompt::{PullRequestContext, TaskPromptOptions, build_task_prompt};
 use crate::terminal::Terminal;
 use crate::ui::{
-    render_footer, render_header, render_help_modal, render_kanban_board, render_logs,
-    render_logs_overlay, render_search, render_task_detail_with_actions, render_worktrees,
+    Theme, is_too_small, render_assistant_picker_modal, render_branch_picker_modal,
+    render_confirm_approve_modal, render_confirm_cancel_modal, render_confirm_compact_modal,
+    render_confirm_delete_modal, render_footer, render_header, render_help_modal,
+    render_kanban_board, render_logs, render_logs_overlay, render_pr_diff_modal,
+    render_scrollback_modal, render_search, render_sessions, render_state_picker_modal,
+    render_tab_bar, render_task_detail_with_actions, render_too_small, render_worktrees,
 };
 
 type WorktreeResult = Result<Vec<WorktreeInfo>, String>;
-type SessionResult = Result<Vec<ZellijSession>, String>;
-type BatchPrResult = Result<std::collections::HashMap<String, BranchPrInfo>, String>;
+type SessionResult = (u64, Result<Vec<ZellijSession>, String>); // (generation, sessions)
+type BatchPrResult = (
+    u64,
+    Result<std::collections::HashMap<String, BranchPrInfo>, String>,
+    Option<RateLimit>,
+    Option<String>,
+    Option<String>,
+); // (generation, PRs, GraphQL budget left, default branch, my GitHub login)
 type LinearResult = Result<Vec<LinearIssue>, String>;
 type LinearStatusResult = Result<Vec<LinearIssueStatus>, String>;
+type LinearStatesResult = (String, Result<Vec<LinearWorkflowState>, String>); // (identifier, team states)
+type LinearAttachmentsResult = (String, Result<Vec<LinearAttachment>, String>); // (identifier, attachments)
+type PromoteResult = (String, Result<CreatedIssue, String>); // (task_id, created issue)
+type PrRefreshResult = (String, Result<Option<BranchPrInfo>, String>); // (branch, live PR)
+type ScrollbackResult = (String, Result<String, String>); // (session_name, scrollback)
+type PrDiffResult = (String, Result<String, String>); // (PR URL, diff)
+
+/// Lines moved per page in the scrollback popup (actual height is only known at render)
+const SCROLLBACK_PAGE: usize = 20;
 type PlanPresenceResult = (String, bool); // (task_id, has_plan)
 
 pub struct App {
     state: AppState,
-    storage: TaskStorage,
+    storage: Box<dyn TaskStore>,
     assistant: AssistantCli,
     events: EventStream,
     last_session_poll: std::time::Instant,
@@ -49,26 +79,73 @@ pub struct App {
     session_sender: mpsc::Sender<SessionResult>,
     pr_info_receiver: mpsc::Receiver<BatchPrResult>,
     pr_info_sender: mpsc::Sender<BatchPrResult>,
+    /// Batch PR fetches and session polls can overlap; only the newest result is applied
+    pr_generation: RefreshGeneration,
+    session_generation: RefreshGeneration,
     // Linear sync channels
     linear_receiver: mpsc::Receiver<LinearResult>,
     linear_sender: mpsc::Sender<LinearResult>,
     // Linear status sync channels (for issue status updates)
     linear_status_receiver: mpsc::Receiver<LinearStatusResult>,
-    #[allow(dead_code)] // Sender kept to prevent channel closure
     linear_status_sender: mpsc::Sender<LinearStatusResult>,
+    // Linear workflow states for the state picker
+    linear_states_receiver: mpsc::Receiver<LinearStatesResult>,
+    linear_states_sender: mpsc::Sender<LinearStatesResult>,
+    // Linear attachments for the task detail view
+    linear_attachments_receiver: mpsc::Receiver<LinearAttachmentsResult>,
+    linear_attachments_sender: mpsc::Sender<LinearAttachmentsResult>,
+    // Linear issues created for local tasks (promote)
+    promote_receiver: mpsc::Receiver<PromoteResult>,
+    promote_sender: mpsc::Sender<PromoteResult>,
+    // Live PR for a selected card that only had stored PR data
+    pr_refresh_receiver: mpsc::Receiver<PrRefreshResult>,
+    pr_refresh_sender: mpsc::Sender<PrRefreshResult>,
+    /// Branches looked up on demand; once per run, since the next batch poll drops
+    /// closed PRs again and would otherwise trigger a lookup per poll. A failed
+    /// lookup leaves the set so selecting the card again retries it.
+    pr_refreshing: std::collections::HashSet<String>,
+    /// Faster single-card polling for the focused task
+    focus_poll: FocusPoll,
+    /// Tasks outside the proactive refreshes whose Linear status and plan were
+    /// fetched on selection; once per run
+    lazily_refreshed: std::collections::HashSet<String>,
+    /// Tasks with a promote in flight, so a second press doesn't create a second issue
+    promoting: std::collections::HashSet<String>,
     // Activity file watcher (event-driven instead of polling)
     activity_receiver: mpsc::Receiver<PathBuf>,
     #[allow(dead_code)] // Watcher must stay alive
     _activity_watcher: Option<ActivityWatcher>,
+    // Git ref watcher (opt-in via VIBE_WATCH_GIT)
+    git_receiver: mpsc::Receiver<()>,
+    #[allow(dead_code)] // Watcher must stay alive
+    _git_watcher: Option<GitWatcher>,
+    /// Last git ref change not yet acted on; refreshes once changes settle
+    last_git_change: Option<std::time::Instant>,
+    // Session scrollback channel
+    scrollback_receiver: mpsc::Receiver<ScrollbackResult>,
+    scrollback_sender: mpsc::Sender<ScrollbackResult>,
+    // PR diff channel
+    pr_diff_receiver: mpsc::Receiver<PrDiffResult>,
+    pr_diff_sender: mpsc::Sender<PrDiffResult>,
     // Plan presence channel
     plan_presence_receiver: mpsc::Receiver<PlanPresenceResult>,
     plan_presence_sender: mpsc::Sender<PlanPresenceResult>,
+    /// Age in days for the one-shot startup auto-archive; taken once it has run
+    auto_archive_days: Option<i64>,
+    /// Most recently deleted task and its label, restorable with `u` until exit
+    last_deleted: Option<(String, DeletedTask)>,
+    /// Colors for every view, from `~/.vibe/theme.toml`
+    theme: Theme,
 }
 
 impl App {
     pub fn new(assistant: AssistantCli) -> Result<Self> {
         // Create storage from current directory
-        let storage = TaskStorage::from_cwd()?;
+        Self::with_store(Box::new(TaskStorage::from_cwd()?), assistant)
+    }
+
+    /// Build the app on top of any task store (file-backed in `new`)
+    pub fn with_store(storage: Box<dyn TaskStore>, assistant: AssistantCli) -> Result<Self> {
         let project_name = storage.project_name().to_string();
 
         let mut state = AppState::new();
@@ -79,6 +156,15 @@ impl App {
         // Load tasks from files
         let tasks = storage.list_tasks()?;
         state.tasks.set_tasks(tasks);
+        state.tasks.wip_limits = WipLimits::from_env();
+        state.tasks.layout = BoardLayout::from_env();
+
+        // A broken theme file falls back to the default colors
+        let theme = Theme::load().unwrap_or_else(|e| {
+            tracing::error!("{:#}", e);
+            state.set_notice(format!("{:#}", e));
+            Theme::default()
+        });
 
         // No project selection - we're already in the project
         state.selected_project_id = Some(project_name.clone());
@@ -91,8 +177,15 @@ impl App {
         let (pr_info_sender, pr_info_receiver) = mpsc::channel(4); // Reduced: batch results instead of per-branch
         let (linear_sender, linear_receiver) = mpsc::channel(4);
         let (linear_status_sender, linear_status_receiver) = mpsc::channel(4);
+        let (linear_states_sender, linear_states_receiver) = mpsc::channel(4);
+        let (linear_attachments_sender, linear_attachments_receiver) = mpsc::channel(4);
+        let (promote_sender, promote_receiver) = mpsc::channel(4);
+        let (pr_refresh_sender, pr_refresh_receiver) = mpsc::channel(4);
         let (activity_sender, activity_receiver) = mpsc::channel(32);
+        let (git_sender, git_receiver) = mpsc::channel(1);
         let (plan_presence_sender, plan_presence_receiver) = mpsc::channel(64);
+        let (scrollback_sender, scrollback_receiver) = mpsc::channel(4);
+        let (pr_diff_sender, pr_diff_receiver) = mpsc::channel(4);
 
         // Mark as loading immediately so UI shows loading state
         state.worktrees.loading = true;
@@ -106,17 +199,19 @@ impl App {
         });
 
         // Spawn immediate background load for sessions
+        let mut session_generation = RefreshGeneration::default();
+        let generation = session_generation.start();
         let sess_sender = session_sender.clone();
         tokio::task::spawn_blocking(move || {
             let result = list_sessions_with_status().map_err(|e| e.to_string());
-            let _ = sess_sender.blocking_send(result);
+            let _ = sess_sender.blocking_send((generation, result));
         });
 
         // Set initial Claude count from activity files (instant, no ps command)
         state.claude_process_count = count_active_sessions();
 
         // Create activity file watcher for instant activity detection
-        let activity_watcher = match ActivityWatcher::new(activity_sender) {
+        let activity_watcher = match ActivityWatcher::new(activity_sender, Some(&project_name)) {
             Ok(watcher) => {
                 tracing::info!("Activity file watcher started successfully");
                 Some(watcher)
@@ -127,6 +222,22 @@ impl App {
             }
         };
 
+        // Watch HEAD and refs so commits and checkouts elsewhere refresh the board
+        let git_watcher = if watch_git_enabled() {
+            match GitWatcher::new(git_sender) {
+                Ok(watcher) => {
+                    tracing::info!("Git ref watcher started");
+                    Some(watcher)
+                }
+                Err(e) => {
+                    tracing::error!("Failed to create git watcher: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
         // Spawn initial Linear fetch if API key is available
         if state.linear_api_key_available {
             let lin_sender = linear_sender.clone();
@@ -134,7 +245,7 @@ impl App {
             let env_var_clone = env_var.clone();
             tracing::info!("Starting Linear fetch with env var: {}", env_var);
             tokio::spawn(async move {
-                match std::env::var(&env_var) {
+                match read_secret(&env_var) {
                     Ok(api_key) => {
                         tracing::info!("Linear API key found, fetching backlog issues...");
                         let client = LinearClient::new(api_key);
@@ -155,45 +266,22 @@ impl App {
                 }
             });
 
-            // Fetch status for tasks that have linear_issue_id
+            // Fetch status for the board's linked tasks; Done ones wait until selected
+            let targets = state
+                .tasks
+                .refresh_targets(&HashMap::new(), &[], &HashMap::new());
             let identifiers: Vec<String> = state
                 .tasks
                 .tasks
                 .iter()
+                .filter(|t| targets.contains(&t.id))
                 .filter_map(|t| t.linear_issue_id.clone())
                 .collect();
-
-            if !identifiers.is_empty() {
-                let status_sender = linear_status_sender.clone();
-                tracing::info!("Fetching Linear statuses for {} tasks", identifiers.len());
-                tokio::spawn(async move {
-                    match std::env::var(&env_var_clone) {
-                        Ok(api_key) => {
-                            let client = LinearClient::new(api_key);
-                            match client.fetch_issue_statuses(&identifiers).await {
-                                Ok(statuses) => {
-                                    tracing::info!(
-                                        "Linear status fetch succeeded: {} statuses",
-                                        statuses.len()
-                                    );
-                                    let _ = status_sender.send(Ok(statuses)).await;
-                                }
-                                Err(e) => {
-                                    tracing::error!("Linear status fetch failed: {}", e);
-                                    let _ = status_sender.send(Err(e)).await;
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            tracing::error!(
-                                "Failed to read Linear API key {}: {}",
-                                env_var_clone,
-                                e
-                            );
-                        }
-                    }
-                });
-            }
+            Self::spawn_linear_status_fetch(
+                env_var_clone,
+                identifiers,
+                linear_status_sender.clone(),
+            );
         }
 
         // Use a time in the past to trigger immediate polling on startup
@@ -207,7 +295,7 @@ impl App {
             last_session_poll: startup_instant,
             last_animation_tick: std::time::Instant::now(),
             last_pr_poll: startup_instant,
-            claude_activity_tracker: ClaudeActivityTracker::new(),
+            claude_activity_tracker: ClaudeActivityTracker::for_project(&project_name),
             plan_reader: ClaudePlanReader::new(),
             worktree_receiver,
             worktree_sender,
@@ -215,14 +303,38 @@ impl App {
             session_sender,
             pr_info_receiver,
             pr_info_sender,
+            pr_generation: RefreshGeneration::default(),
+            session_generation,
             linear_receiver,
             linear_sender,
             linear_status_receiver,
             linear_status_sender,
+            linear_states_receiver,
+            linear_states_sender,
+            linear_attachments_receiver,
+            linear_attachments_sender,
+            promote_receiver,
+            promote_sender,
+            promoting: Default::default(),
+            pr_refresh_receiver,
+            pr_refresh_sender,
+            pr_refreshing: Default::default(),
+            focus_poll: FocusPoll::default(),
+            lazily_refreshed: Default::default(),
             activity_receiver,
+            git_receiver,
+            _git_watcher: git_watcher,
+            last_git_change: None,
             _activity_watcher: activity_watcher,
+            scrollback_receiver,
+            scrollback_sender,
+            pr_diff_receiver,
+            pr_diff_sender,
             plan_presence_receiver,
             plan_presence_sender,
+            auto_archive_days: auto_archive_days(),
+            last_deleted: None,
+            theme,
         })
     }
 
@@ -232,6 +344,8 @@ impl App {
         // Poll PR status every 15 seconds (batch query + limited targeted lookups)
         // Reduced frequency to avoid rate limits with many tasks
         const PR_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15);
+        // Wait for git to go quiet before refreshing, so a rebase triggers one refresh
+        const GIT_CHANGE_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(750);
         // Tick animation every 200ms for smooth spinner
         const ANIMATION_TICK_INTERVAL: std::time::Duration = std::time::Duration::from_millis(200);
 
@@ -245,13 +359,29 @@ impl App {
                 self.last_session_poll = std::time::Instant::now();
             }
 
-            // Poll PR status periodically
-            if self.last_pr_poll.elapsed() >= PR_POLL_INTERVAL {
+            // Poll PR status periodically, backing off as the GitHub budget runs low
+            let pr_poll_interval = self.state.gh_rate_limit.map_or(PR_POLL_INTERVAL, |limit| {
+                limit.poll_delay(PR_POLL_INTERVAL, chrono::Utc::now())
+            });
+            if self.last_pr_poll.elapsed() >= pr_poll_interval {
                 tracing::debug!("Periodic PR poll triggered");
                 self.poll_pr_info_async();
                 self.last_pr_poll = std::time::Instant::now();
             }
 
+            // Refresh worktrees and PRs once git ref changes settle
+            if self
+                .last_git_change
+                .is_some_and(|at| at.elapsed() >= GIT_CHANGE_DEBOUNCE)
+                && !self.state.worktrees.loading
+            {
+                tracing::debug!("Git refs changed, refreshing worktrees and PRs");
+                self.last_git_change = None;
+                self.load_worktrees();
+                self.poll_pr_info_async();
+                self.last_pr_poll = std::time::Instant::now();
+            }
+
             // Tick animation for spinners
             if self.last_animation_tick.elapsed() >= ANIMATION_TICK_INTERVAL {
                 self.state.tick_animation();
@@ -266,6 +396,11 @@ impl App {
                 self.handle_event(event, terminal).await?;
             }
 
+            // Stored PR data can't be trusted for draft vs ready; fetch it live
+            self.refresh_selected_pr();
+            self.refresh_selected_status();
+            self.poll_focused_task();
+
             if self.state.should_quit {
                 break;
             }
@@ -281,6 +416,11 @@ impl App {
                 Ok(worktrees) => {
                     // Fetch PR info for all branches in a single batch query
                     self.fetch_pr_info_batch();
+                    if pin_current_enabled() {
+                        self.state.tasks.pinned_branch = std::env::current_dir()
+                            .ok()
+                            .and_then(|cwd| current_worktree_branch(&worktrees, &cwd));
+                    }
                     self.state.worktrees.set_worktrees(worktrees);
                     self.state.worktrees.loading = false;
                     self.state.worktrees.error = None;
@@ -296,7 +436,10 @@ impl App {
 
         // Non-blocking check for session results
         let mut sessions_updated = false;
-        while let Ok(result) = self.session_receiver.try_recv() {
+        while let Ok((generation, result)) = self.session_receiver.try_recv() {
+            if !self.session_generation.accept(generation) {
+                continue;
+            }
             match result {
                 Ok(sessions) => {
                     self.state.sessions.set_sessions(sessions);
@@ -323,8 +466,23 @@ impl App {
         }
 
         // Non-blocking check for batch PR info results
-        while let Ok(result) = self.pr_info_receiver.try_recv() {
-            self.state.pr_loading = false;
+        while let Ok((generation, result, rate_limit, default_branch, gh_login)) =
+            self.pr_info_receiver.try_recv()
+        {
+            // An older fetch finishing after a newer one would bring back stale PRs
+            if !self.pr_generation.accept(generation) {
+                continue;
+            }
+            self.state.pr_loading = !self.pr_generation.is_settled();
+            if rate_limit.is_some() {
+                self.state.gh_rate_limit = rate_limit;
+            }
+            if default_branch.is_some() {
+                self.state.worktrees.default_branch = default_branch;
+            }
+            if gh_login.is_some() {
+                self.state.worktrees.gh_login = gh_login;
+            }
             match result {
                 Ok(pr_map) => {
                     // Clear PRs for branches not in the response (no longer have open PRs)
@@ -346,11 +504,19 @@ impl App {
                     }
                     // Cleanup expired no-PR cache entries periodically
                     self.state.worktrees.cleanup_no_pr_cache();
+                    self.record_board_transitions();
+                    self.link_new_prs_on_linear();
+                    // Done is derived from PR state, so the startup sweep waits for it
+                    if let Some(days) = self.auto_archive_days.take() {
+                        self.auto_archive(days);
+                    }
                 }
                 Err(e) => {
                     tracing::error!("Failed to fetch PR info: {}", e);
                 }
             }
+            // One PR batch marks the end of a poll cycle
+            log_poll_timings();
         }
 
         // Non-blocking check for Linear results
@@ -404,6 +570,125 @@ impl App {
                 }
             }
         }
+        self.state.linear_rate_limit = LinearClient::last_rate_limit();
+
+        // Non-blocking check for Linear workflow states (state picker)
+        while let Ok((identifier, result)) = self.linear_states_receiver.try_recv() {
+            let current = self
+                .state
+                .linear_issue_statuses
+                .get(&identifier)
+                .map(|s| s.state_name.clone());
+            if let Some(picker) = self.state.state_picker.as_mut()
+                && picker.identifier == identifier
+            {
+                match result {
+                    Ok(states) => picker.set_states(states, current.as_deref()),
+                    Err(e) => {
+                        tracing::error!("Failed to fetch Linear states for {}: {}", identifier, e);
+                        picker.loading = false;
+                        picker.error = Some(e);
+                    }
+                }
+            }
+        }
+
+        // Non-blocking check for Linear attachments (task detail)
+        while let Ok((identifier, result)) = self.linear_attachments_receiver.try_recv() {
+            match result {
+                Ok(attachments) => {
+                    self.state
+                        .linear_attachments
+                        .insert(identifier, attachments);
+                }
+                Err(e) => {
+                    tracing::error!("Failed to fetch attachments for {}: {}", identifier, e);
+                }
+            }
+        }
+
+        // Non-blocking check for on-demand PR lookups (selected card, R)
+        while let Ok((branch, result)) = self.pr_refresh_receiver.try_recv() {
+            match result {
+                Ok(Some(pr_info)) => self.state.worktrees.set_branch_pr(branch, pr_info),
+                Ok(None) => {
+                    self.state.worktrees.clear_branch_pr(&branch);
+                    self.state.worktrees.mark_no_pr(branch);
+                }
+                Err(e) => {
+                    tracing::warn!("PR lookup failed for {}: {}", branch, e);
+                    self.pr_refreshing.remove(&branch);
+                }
+            }
+        }
+
+        // Non-blocking check for Linear issues created by promote
+        while let Ok((task_id, result)) = self.promote_receiver.try_recv() {
+            self.promoting.remove(&task_id);
+            match result {
+                Ok(created) => {
+                    match self.storage.link_linear_issue(
+                        &task_id,
+                        &created.identifier,
+                        &created.url,
+                    ) {
+                        Ok(_) => {
+                            self.state
+                                .set_notice(format!("promoted to {}", created.identifier));
+                            if let Ok(tasks) = self.storage.list_tasks() {
+                                self.state.tasks.set_tasks(tasks);
+                            }
+                        }
+                        Err(e) => {
+                            tracing::error!("Failed to link {}: {}", created.identifier, e);
+                            self.state.set_notice(format!(
+                                "created {} but failed to link it",
+                                created.identifier
+                            ));
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::error!("Failed to promote task: {}", e);
+                    self.state.set_notice(format!("Linear: {}", e));
+                }
+            }
+        }
+
+        // Non-blocking check for session scrollback dumps
+        while let Ok((session_name, result)) = self.scrollback_receiver.try_recv() {
+            if let Some(scrollback) = self.state.scrollback.as_mut()
+                && scrollback.session_name == session_name
+            {
+                match result {
+                    Ok(content) => scrollback.set_content(&content, SCROLLBACK_PAGE),
+                    Err(e) => {
+                        scrollback.loading = false;
+                        scrollback.error = Some(e);
+                    }
+                }
+            }
+        }
+
+        // Non-blocking check for PR diffs
+        while let Ok((pr, result)) = self.pr_diff_receiver.try_recv() {
+            if let Some(diff) = self.state.pr_diff.as_mut()
+                && diff.pr == pr
+            {
+                match result {
+                    Ok(content) => diff.set_content(&content),
+                    Err(e) => {
+                        diff.loading = false;
+                        diff.error = Some(e);
+                    }
+                }
+            }
+        }
+
+        // Git ref changes restart the debounce window
+        while self.git_receiver.try_recv().is_ok() {
+            self.last_git_change = Some(std::time::Instant::now());
+        }
 
         // Non-blocking check for activity file changes (event-driven)
         let mut activity_changed = false;
@@ -420,8 +705,9 @@ impl App {
         }
         if activity_changed {
             // Update session states and Claude count
+            let worktree_paths = self.session_worktree_paths();
             self.claude_activity_tracker
-                .update_sessions(&mut self.state.sessions.sessions);
+                .update_sessions(&mut self.state.sessions.sessions, &worktree_paths);
             self.state.claude_process_count = count_active_sessions();
             tracing::debug!("Updated {} sessions", self.state.sessions.sessions.len());
         }
@@ -436,15 +722,175 @@ impl App {
         }
     }
 
+    /// `R`: re-check just the focused card's PR and session activity,
+    /// instead of refetching the whole board
+    fn handle_refresh_selected(&mut self) {
+        if let Some(branch) = self.fetch_focused_task() {
+            self.state.set_notice(format!("refreshing {}", branch));
+        }
+    }
+
+    /// Poll the focused card's PR and session activity more often than the bulk
+    /// refresh, as scheduled by `FocusPoll`
+    fn poll_focused_task(&mut self) {
+        // A low GitHub budget is left to the (already slowed) bulk refresh
+        if self
+            .state
+            .gh_rate_limit
+            .is_some_and(|limit| limit.is_low(chrono::Utc::now()))
+        {
+            return;
+        }
+        let Some(task) = self.focused_task() else {
+            return;
+        };
+        let worktrees = &self.state.worktrees;
+        let (branch_pr, has_worktree) =
+            task_pr_and_worktree(task, &worktrees.branch_prs, &worktrees.worktrees);
+        let linear_status = task
+            .linear_issue_id
+            .as_ref()
+            .and_then(|id| self.state.linear_issue_statuses.get(id));
+        let terminal = matches!(
+            task.effective_status_with_pr(branch_pr, has_worktree, linear_status),
+            TaskStatus::Done | TaskStatus::Cancelled
+        );
+        let task_id = task.id.clone();
+
+        let now = std::time::Instant::now();
+        if self
+            .focus_poll
+            .due(&task_id, terminal, self.last_pr_poll, now)
+        {
+            self.focus_poll.polled(now);
+            self.fetch_focused_task();
+        }
+    }
+
+    /// Fetch the focused card's PR and update its session's activity; returns the branch
+    fn fetch_focused_task(&mut self) -> Option<String> {
+        let task = self.focused_task()?;
+        let pr_url = task.open_pr_url().map(String::from);
+        // PRs are keyed by the worktree's branch when it differs from the derived one
+        let branch = self
+            .state
+            .worktrees
+            .worktrees
+            .iter()
+            .find(|w| task.matches_branch(&w.branch))
+            .map_or_else(|| task.branch(), |w| w.branch.clone());
+
+        let worktree_paths = self.session_worktree_paths();
+        let session_name = session_name_for_branch(&branch);
+        if let Some(session) = self
+            .state
+            .sessions
+            .sessions
+            .iter_mut()
+            .find(|s| s.name == session_name)
+        {
+            self.claude_activity_tracker
+                .update_sessions(std::slice::from_mut(session), &worktree_paths);
+        }
+
+        let sender = self.pr_refresh_sender.clone();
+        let fetched = branch.clone();
+        tokio::task::spawn_blocking(move || {
+            let result =
+                get_pr_for_branch_or_url(&branch, pr_url.as_deref()).map_err(|e| e.to_string());
+            let _ = sender.blocking_send((branch, result));
+        });
+        Some(fetched)
+    }
+
+    /// Cards in the Done column are left out of the proactive refreshes; fetch the
+    /// focused one's Linear status and plan presence the first time it is selected
+    fn refresh_selected_status(&mut self) {
+        let Some(task) = self.focused_task() else {
+            return;
+        };
+        if self.lazily_refreshed.contains(&task.id) {
+            return;
+        }
+        let task = task.clone();
+        self.lazily_refreshed.insert(task.id.clone());
+
+        if self.state.linear_api_key_available
+            && let Some(identifier) = task
+                .linear_issue_id
+                .clone()
+                .filter(|id| !self.state.linear_issue_statuses.contains_key(id))
+        {
+            Self::spawn_linear_status_fetch(
+                linear_env_var_name(self.storage.project_name()),
+                vec![identifier],
+                self.linear_status_sender.clone(),
+            );
+        }
+        if !self.state.tasks_with_plans.contains(&task.id) {
+            self.poll_plan_presence_for(vec![task]);
+        }
+    }
+
+    /// Look up the focused card's PR when only stored PR fields describe it
+    fn refresh_selected_pr(&mut self) {
+        let Some(task) = self.focused_task() else {
+            return;
+        };
+        let (branch_pr, _) = task_pr_and_worktree(
+            task,
+            &self.state.worktrees.branch_prs,
+            &self.state.worktrees.worktrees,
+        );
+        if !task.needs_live_pr(branch_pr) {
+            return;
+        }
+        let branch = task.branch();
+        let pr_url = task.open_pr_url().map(String::from);
+        if self.state.worktrees.is_cached_no_pr(&branch)
+            || !self.pr_refreshing.insert(branch.clone())
+        {
+            return;
+        }
+
+        tracing::debug!("Fetching live PR for selected card: {}", branch);
+        let sender = self.pr_refresh_sender.clone();
+        tokio::task::spawn_blocking(move || {
+            let result =
+                get_pr_for_branch_or_url(&branch, pr_url.as_deref()).map_err(|e| e.to_string());
+            let _ = sender.blocking_send((branch, result));
+        });
+    }
+
     fn fetch_pr_info_batch(&mut self) {
+        // Spent budget: wait for the reset instead of failing every poll
+        if self
+            .state
+            .gh_rate_limit
+            .is_some_and(|limit| limit.is_exhausted(chrono::Utc::now()))
+        {
+            tracing::debug!("Skipping PR fetch: GitHub rate limit exhausted");
+            return;
+        }
         self.state.pr_loading = true;
+        let generation = self.pr_generation.start();
         let sender = self.pr_info_sender.clone();
 
         // Only do targeted lookups for branches that:
         // 1. Have a worktree (no worktree = unlikely to have PR)
         // 2. Aren't already in PR map
         // 3. Aren't in the "no PR" cache (recently checked, no PR found)
-        let branches_to_lookup = self.state.worktrees.branches_needing_pr_lookup();
+        // 4. On the board, belong to a card that gets proactive refreshes
+        let mut branches_to_lookup = self.state.worktrees.branches_needing_pr_lookup();
+        if matches!(self.state.view, View::Kanban | View::TaskDetail) {
+            let targets = self.refresh_targets();
+            let tasks = &self.state.tasks.tasks;
+            branches_to_lookup.retain(|b| {
+                tasks
+                    .iter()
+                    .any(|t| targets.contains(&t.id) && t.matches_branch(b))
+            });
+        }
 
         // Rate limit: max 10 targeted lookups per cycle to avoid rate limits
         const MAX_TARGETED_LOOKUPS: usize = 10;
@@ -456,14 +902,29 @@ impl App {
         tokio::task::spawn_blocking(move || {
             let start = std::time::Instant::now();
 
-            let mut pr_map = match get_all_open_prs() {
-                Ok(map) => {
-                    tracing::info!("Batch PR fetch: {} PRs in {:?}", map.len(), start.elapsed());
-                    map
+            let (mut pr_map, rate_limit, default_branch, gh_login) = match get_pr_batch() {
+                Ok(batch) => {
+                    tracing::info!(
+                        "Batch PR fetch: {} PRs in {:?}",
+                        batch.prs.len(),
+                        start.elapsed()
+                    );
+                    (
+                        batch.prs,
+                        batch.rate_limit,
+                        batch.default_branch,
+                        my_github_login(batch.viewer),
+                    )
                 }
                 Err(e) => {
                     tracing::error!("Batch PR fetch failed: {}", e);
-                    let _ = sender.blocking_send(Err(e.to_string()));
+                    let message = e.to_string();
+                    // The failed query carries no budget; ask for it so polling can wait
+                    let rate_limit = is_rate_limit_error(&message)
+                        .then(|| get_graphql_rate_limit().ok())
+                        .flatten();
+                    let _ =
+                        sender.blocking_send((generation, Err(message), rate_limit, None, None));
                     return;
                 }
             };
@@ -529,6 +990,9 @@ impl App {
                             status_check_rollup: None,
                             mergeable: None,
                             reviews: vec![],
+                            base_ref_name: None,
+                            author: None,
+                            mine: false,
                         },
                     );
                 }
@@ -546,22 +1010,36 @@ impl App {
                 pr_map.len(),
                 start.elapsed()
             );
-            let _ = sender.blocking_send(Ok(pr_map));
+            let _ = sender.blocking_send((
+                generation,
+                Ok(pr_map),
+                rate_limit,
+                default_branch,
+                gh_login,
+            ));
         });
     }
 
     fn render(&mut self, terminal: &mut Terminal) -> Result<()> {
+        let theme = self.theme;
         terminal.draw(|frame| {
+            if is_too_small(frame.area()) {
+                render_too_small(frame, frame.area(), &theme);
+                return;
+            }
+
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([
                     Constraint::Length(6), // Header with ASCII logo
+                    Constraint::Length(1), // Tab bar
                     Constraint::Min(0),    // Main content
                     Constraint::Length(2), // Footer
                 ])
                 .split(frame.area());
 
-            render_header(frame, chunks[0], &self.state);
+            render_header(frame, chunks[0], &self.state, &theme);
+            render_tab_bar(frame, chunks[1], self.state.view, &theme);
 
             match self.state.view {
                 View::Projects => {
@@ -571,13 +1049,14 @@ impl App {
                 View::Kanban => {
                     render_kanban_board(
                         frame,
-                        chunks[1],
+                        chunks[2],
                         &self.state.tasks,
                         &self.state.worktrees,
                         &self.state.sessions,
                         self.state.spinner_char(),
                         self.state.linear_pending_issues.len(),
                         &self.state.linear_issue_statuses,
+                        &theme,
                     );
                 }
                 View::TaskDetail => {
@@ -585,37 +1064,113 @@ impl App {
                     if let Some(task_id) = &self.state.selected_task_id
                         && let Some(task) = self.state.tasks.tasks.iter().find(|t| &t.id == task_id)
                     {
+                        let branch = task.branch();
                         render_task_detail_with_actions(
                             frame,
-                            chunks[1],
+                            chunks[2],
                             task,
-                            self.state.selected_task_plan.as_deref(),
+                            self.state.worktrees.pr_for_branch(&branch),
+                            task.linear_issue_id
+                                .as_ref()
+                                .and_then(|id| self.state.linear_attachments.get(id))
+                                .map_or(&[][..], Vec::as_slice),
+                            self.state.selected_task_plan.as_ref(),
                             self.state.plan_scroll_offset,
                             self.state.plan_line_count,
+                            &theme,
                         );
                     }
                 }
                 View::Worktrees => {
-                    render_worktrees(frame, chunks[1], &self.state.worktrees);
+                    let untracked = self
+                        .state
+                        .worktrees
+                        .untracked_branches(&self.state.tasks.tasks);
+                    render_worktrees(
+                        frame,
+                        chunks[2],
+                        &self.state.worktrees,
+                        &untracked,
+                        &self.state.sessions,
+                        self.state.spinner_char(),
+                        &theme,
+                    );
+                }
+                View::Sessions => {
+                    let task_labels = self.state.sessions.task_labels(&self.state.tasks.tasks);
+                    render_sessions(
+                        frame,
+                        chunks[2],
+                        &self.state.sessions,
+                        &task_labels,
+                        self.state.spinner_char(),
+                        &theme,
+                    );
                 }
                 View::Logs => {
-                    render_logs(frame, chunks[1], &self.state.logs);
+                    render_logs(frame, chunks[2], &self.state.logs, &theme);
                 }
                 View::Search => {
-                    render_search(frame, chunks[1], &self.state.search);
+                    render_search(frame, chunks[2], &self.state.search, &theme);
                 }
             }
 
-            render_footer(frame, chunks[2], &self.state);
+            render_footer(frame, chunks[3], &self.state, &theme);
 
             // Render logs overlay if visible (on top of everything except help modal)
             if self.state.logs_overlay_visible {
-                render_logs_overlay(frame, frame.area(), &self.state.logs);
+                render_logs_overlay(frame, frame.area(), &self.state.logs, &theme);
             }
 
             // Render modal if present
-            if let Some(Modal::Help) = &self.state.modal {
-                render_help_modal(frame, frame.area());
+            match &self.state.modal {
+                Some(Modal::Help) => render_help_modal(frame, frame.area(), &theme),
+                Some(Modal::LinearStatePicker) => {
+                    if let Some(picker) = &self.state.state_picker {
+                        render_state_picker_modal(frame, frame.area(), picker, &theme);
+                    }
+                }
+                Some(Modal::Scrollback) => {
+                    if let Some(scrollback) = &self.state.scrollback {
+                        render_scrollback_modal(frame, frame.area(), scrollback, &theme);
+                    }
+                }
+                Some(Modal::PrDiff) => {
+                    if let Some(diff) = &self.state.pr_diff {
+                        render_pr_diff_modal(frame, frame.area(), diff, &theme);
+                    }
+                }
+                Some(Modal::ConfirmDelete) => {
+                    if let Some(pending) = &self.state.pending_delete {
+                        render_confirm_delete_modal(frame, frame.area(), pending, &theme);
+                    }
+                }
+                Some(Modal::ConfirmCancel) => {
+                    if let Some(pending) = &self.state.pending_cancel {
+                        render_confirm_cancel_modal(frame, frame.area(), pending, &theme);
+                    }
+                }
+                Some(Modal::AssistantPicker) => {
+                    if let Some(picker) = &self.state.assistant_picker {
+                        render_assistant_picker_modal(frame, frame.area(), picker, &theme);
+                    }
+                }
+                Some(Modal::BranchPicker) => {
+                    if let Some(picker) = &self.state.branch_picker {
+                        render_branch_picker_modal(frame, frame.area(), picker, &theme);
+                    }
+                }
+                Some(Modal::ConfirmCompact) => {
+                    if let Some(pending) = &self.state.pending_compact {
+                        render_confirm_compact_modal(frame, frame.area(), pending, &theme);
+                    }
+                }
+                Some(Modal::ConfirmApprove) => {
+                    if let Some(pending) = &self.state.pending_approve {
+                        render_confirm_approve_modal(frame, frame.area(), pending, &theme);
+                    }
+                }
+                None => {}
             }
         })?;
 
@@ -642,8 +1197,23 @@ impl App {
 
         // Handle modal-specific actions
         if in_modal {
-            if let Action::Back = action {
-                self.state.modal = None;
+            match self.state.modal {
+                Some(Modal::LinearStatePicker) => self.handle_state_picker_action(action),
+                Some(Modal::Scrollback) => self.handle_scrollback_action(action),
+                Some(Modal::PrDiff) => self.handle_pr_diff_action(action),
+                Some(Modal::ConfirmDelete) => self.handle_confirm_delete_action(action)?,
+                Some(Modal::ConfirmCompact) => self.handle_confirm_compact_action(action),
+                Some(Modal::ConfirmApprove) => self.handle_confirm_approve_action(action),
+                Some(Modal::ConfirmCancel) => self.handle_confirm_cancel_action(action)?,
+                Some(Modal::AssistantPicker) => {
+                    self.handle_assistant_picker_action(action, terminal)?
+                }
+                Some(Modal::BranchPicker) => self.handle_branch_picker_action(action)?,
+                _ => {
+                    if let Action::Back = action {
+                        self.state.modal = None;
+                    }
+                }
             }
             return Ok(());
         }
@@ -677,6 +1247,9 @@ impl App {
             Action::Select => {
                 self.handle_select(terminal).await?;
             }
+            Action::RefreshSelected => {
+                self.handle_refresh_selected();
+            }
             Action::Refresh => {
                 // If logs overlay is visible, refresh logs
                 if self.state.logs_overlay_visible {
@@ -688,11 +1261,53 @@ impl App {
             Action::EditTask => {
                 self.handle_edit_task(terminal)?;
             }
+            Action::EditNotes => {
+                self.handle_edit_notes(terminal)?;
+            }
             Action::CreateTask => {
                 self.handle_create_task(terminal)?;
             }
             Action::DeleteTask => {
-                self.handle_delete_task()?;
+                self.handle_delete_task();
+            }
+            Action::CancelTask => {
+                self.handle_cancel_task();
+            }
+            Action::UndoDelete => {
+                self.handle_undo_delete()?;
+            }
+            Action::ApprovePr => {
+                self.handle_approve_pr();
+            }
+            Action::RequestPrChanges => {
+                self.handle_request_pr_changes(terminal)?;
+            }
+            Action::CompactSession => {
+                self.handle_compact_session();
+            }
+            Action::ToggleBacklogSort => {
+                self.state.tasks.backlog_by_age = !self.state.tasks.backlog_by_age;
+                // Card indices refer to the old order
+                self.state.tasks.selected_card_per_column
+                    [crate::state::TaskStatus::Backlog.column_index()] = 0;
+            }
+            Action::ToggleMyPrs => {
+                self.handle_toggle_my_prs();
+            }
+            Action::ToggleStatusDebug => {
+                self.state.tasks.show_status_debug = !self.state.tasks.show_status_debug;
+            }
+            Action::CycleLayout => {
+                let layout = self.state.tasks.layout.next();
+                self.state.tasks.layout = layout;
+                self.state
+                    .set_notice(format!("board layout: {}", layout.label()));
+            }
+            Action::CycleAssigneeFilter => {
+                self.handle_cycle_assignee_filter();
+            }
+            Action::CycleBaseFilter => {
+                self.handle_cycle_base_filter();
             }
             Action::ShowWorktrees => {
                 self.handle_show_worktrees()?;
@@ -700,41 +1315,80 @@ impl App {
             Action::CreateWorktree => {
                 // TODO: Implement worktree creation modal
             }
+            Action::TrackWorktree => {
+                self.handle_track_worktree()?;
+            }
             Action::SwitchWorktree => {
                 // TODO: Implement worktree switching
             }
+            Action::ShowSessions => {
+                self.state.view = View::Sessions;
+                self.poll_sessions_async();
+            }
+            Action::AttachSession => {
+                self.handle_attach_session(terminal)?;
+            }
             Action::LaunchSession => {
-                self.handle_launch_session(terminal, false, false)?;
+                self.handle_launch_session(terminal, self.assistant, false, false)?;
             }
             Action::LaunchSessionPlan => {
-                self.handle_launch_session(terminal, true, false)?;
+                self.handle_launch_session(terminal, self.assistant, true, false)?;
+            }
+            Action::ExecutePlan => {
+                self.handle_execute_plan(terminal)?;
             }
             Action::LaunchSessionWithPrime => {
-                self.handle_launch_session(terminal, false, true)?;
+                self.handle_launch_session(terminal, self.assistant, false, true)?;
+            }
+            Action::LaunchSessionPicker => {
+                self.handle_open_assistant_picker();
             }
             Action::LaunchPrime => {
                 self.handle_launch_prime(terminal)?;
             }
+            Action::OpenAttachment(index) => {
+                self.handle_open_attachment(index);
+            }
             Action::ViewPR => {
                 self.handle_view_pr()?;
             }
+            Action::ViewPrDiff => {
+                self.handle_view_pr_diff();
+            }
             Action::ViewPlan => {
                 self.handle_view_plan(terminal)?;
             }
-            Action::BindPR => {
-                // PR binding not available in standalone mode
-                tracing::info!("PR binding requires server mode");
+            Action::EditPlan => {
+                self.handle_edit_plan(terminal)?;
+            }
+            Action::ViewScrollback => {
+                self.handle_view_scrollback();
+            }
+            Action::AcknowledgeSession => {
+                self.handle_acknowledge_session();
+            }
+            // Paging and confirming with Linear are only bound inside modals
+            Action::PageUp | Action::PageDown | Action::ConfirmWithLinear => {}
+            Action::BindBranch => {
+                self.handle_open_branch_picker();
             }
             // Search actions
             Action::StartSearch => {
-                // Populate search with current tasks and switch to search view
-                self.state.search.set_tasks(self.state.tasks.tasks.clone());
-                self.state.view = View::Search;
+                if self.state.view == View::Worktrees {
+                    // Jump-to-branch picker reuses search input handling
+                    self.state.worktrees.open_picker();
+                } else {
+                    // Populate search with current tasks and switch to search view
+                    self.state.search.set_tasks(self.state.tasks.tasks.clone());
+                    self.state.view = View::Search;
+                }
                 self.state.search_active = true;
             }
             Action::SearchType(c) => {
                 if self.state.view == View::Search {
                     self.state.search.type_char(c);
+                } else if self.state.view == View::Worktrees {
+                    self.state.worktrees.picker_type(c);
                 } else {
                     self.state.search_query.push(c);
                 }
@@ -742,6 +1396,8 @@ impl App {
             Action::SearchBackspace => {
                 if self.state.view == View::Search {
                     self.state.search.backspace();
+                } else if self.state.view == View::Worktrees {
+                    self.state.worktrees.picker_backspace();
                 } else {
                     self.state.search_query.pop();
                 }
@@ -754,6 +1410,8 @@ impl App {
             Action::ClearSearch => {
                 if self.state.view == View::Search {
                     self.state.search.clear_query();
+                } else if self.state.view == View::Worktrees {
+                    self.state.worktrees.picker_clear();
                 } else {
                     self.state.search_query.clear();
                     self.state.tasks.search_filter.clear();
@@ -763,10 +1421,22 @@ impl App {
             Action::SyncLinear => {
                 self.handle_sync_linear()?;
             }
+            Action::PromoteTask => {
+                self.handle_promote_task();
+            }
+            Action::PickLinearState => {
+                self.handle_pick_linear_state();
+            }
 
             Action::ShowLogs => {
                 self.handle_show_logs();
             }
+            Action::NextView => {
+                self.handle_cycle_view(true)?;
+            }
+            Action::PrevView => {
+                self.handle_cycle_view(false)?;
+            }
             Action::ArchiveDone => {
                 self.handle_archive_done()?;
             }
@@ -804,6 +1474,27 @@ impl App {
         }
     }
 
+    /// Switch to the neighbouring tab. Each view keeps its own selection; the
+    /// target view is refreshed the same way its own key would refresh it.
+    fn handle_cycle_view(&mut self, forward: bool) -> Result<()> {
+        match self.state.view.cycle_tab(forward) {
+            View::Worktrees => self.handle_show_worktrees()?,
+            View::Sessions => {
+                self.state.view = View::Sessions;
+                self.poll_sessions_async();
+            }
+            View::Logs => {
+                self.state.view = View::Logs;
+                self.state.logs.load_logs();
+            }
+            _ => {
+                self.state.view = View::Kanban;
+                self.refetch_on_kanban_mount();
+            }
+        }
+        Ok(())
+    }
+
     fn handle_sync_linear(&mut self) -> Result<()> {
         if self.state.linear_pending_issues.is_empty() {
             tracing::info!("No pending Linear issues to import");
@@ -865,7 +1556,11 @@ impl App {
                 self.state.view = View::Kanban;
                 self.refetch_on_kanban_mount();
             }
-            View::Worktrees | View::Logs => {
+            View::Worktrees if self.state.worktrees.picker.is_some() => {
+                self.state.worktrees.close_picker();
+                self.state.search_active = false;
+            }
+            View::Worktrees | View::Sessions | View::Logs => {
                 self.state.view = View::Kanban;
                 self.refetch_on_kanban_mount();
             }
@@ -900,9 +1595,15 @@ impl App {
             View::TaskDetail => {
                 self.state.scroll_plan_up();
             }
+            View::Worktrees if self.state.worktrees.picker.is_some() => {
+                self.state.worktrees.picker_select_prev();
+            }
             View::Worktrees => {
                 self.state.worktrees.select_prev();
             }
+            View::Sessions => {
+                self.state.sessions.select_prev();
+            }
             View::Logs => {
                 self.state.logs.scroll_up();
             }
@@ -935,9 +1636,15 @@ impl App {
                 // Use a reasonable default for visible lines; actual height is available during render
                 self.state.scroll_plan_down(20);
             }
+            View::Worktrees if self.state.worktrees.picker.is_some() => {
+                self.state.worktrees.picker_select_next();
+            }
             View::Worktrees => {
                 self.state.worktrees.select_next();
             }
+            View::Sessions => {
+                self.state.sessions.select_next();
+            }
             View::Logs => {
                 self.state.logs.scroll_down();
             }
@@ -972,46 +1679,92 @@ impl App {
         if self.state.view == View::Kanban
             && let Some(task) = self.selected_task().cloned()
         {
-            self.state.selected_task_id = Some(task.id.clone());
-            self.load_plan_for_task(&task);
-            self.state.view = View::TaskDetail;
+            self.open_task_detail(&task);
         }
     }
 
-    /// Load the Claude Code plan for a task based on its branch.
-    fn load_plan_for_task(&mut self, task: &crate::state::Task) {
-        self.state.reset_plan_scroll();
+    /// Switch to the detail view for a task, loading its plan and Linear attachments
+    fn open_task_detail(&mut self, task: &crate::state::Task) {
+        self.state.selected_task_id = Some(task.id.clone());
+        self.load_plan_for_task(task);
+        if let Some(identifier) = task.linear_issue_id.clone() {
+            self.fetch_linear_attachments(identifier);
+        }
+        self.state.view = View::TaskDetail;
+    }
 
-        let branch = task_title_to_branch(&task.title, task.linear_issue_id.as_deref());
+    fn fetch_linear_attachments(&self, identifier: String) {
+        if !self.state.linear_api_key_available {
+            return;
+        }
+        let env_var = linear_env_var_name(self.storage.project_name());
+        let sender = self.linear_attachments_sender.clone();
+        tokio::spawn(async move {
+            let result = match read_secret(&env_var) {
+                Ok(api_key) => {
+                    LinearClient::new(api_key)
+                        .fetch_issue_attachments(&identifier)
+                        .await
+                }
+                Err(_) => Err(format!("{} not set", env_var)),
+            };
+            let _ = sender.send((identifier, result)).await;
+        });
+    }
 
-        // Find the worktree path for this task's branch
-        // Claude stores session data in ~/.claude/projects/{worktree-path}/, not the main project path
-        let project_path = self
-            .state
-            .worktrees
-            .worktrees
-            .iter()
-            .find(|w| {
-                w.branch == branch || w.branch.contains(&branch) || branch.contains(&w.branch)
-            })
+    /// Open the focused task's Nth Linear attachment in the browser
+    fn handle_open_attachment(&mut self, index: usize) {
+        let Some(url) = self
+            .focused_task()
+            .and_then(|t| t.linear_issue_id.as_ref())
+            .and_then(|id| self.state.linear_attachments.get(id))
+            .and_then(|attachments| attachments.get(index))
+            .map(|a| a.url.clone())
+        else {
+            return;
+        };
+        if let Err(e) = open_url(&url) {
+            tracing::error!("Failed to open attachment: {}", e);
+            self.state.set_notice(format!("Failed to open {}", url));
+        }
+    }
+
+    /// Where Claude keeps sessions (and so plans) for `branch`: its worktree, else the
+    /// project itself. Claude stores session data in ~/.claude/projects/{worktree-path}/,
+    /// not the main project path.
+    fn plan_project_path(&self, branch: &str) -> Option<String> {
+        self.state
+            .worktrees
+            .worktrees
+            .iter()
+            .find(|w| w.branch == branch || w.branch.contains(branch) || branch.contains(&w.branch))
             .map(|w| w.path.clone())
             .or_else(|| {
                 self.get_project_dir()
                     .map(|p| p.to_string_lossy().to_string())
-            });
+            })
+    }
+
+    /// Load the Claude Code plan for a task based on its branch.
+    fn load_plan_for_task(&mut self, task: &crate::state::Task) {
+        self.state.reset_plan_scroll();
 
-        if let Some(project_path) = project_path {
-            // Get both the plan path and content
+        let branch = task.branch();
+
+        if let Some(project_path) = self.plan_project_path(&branch) {
+            // Find the plan once, then read it (capped for huge files)
             self.state.selected_task_plan_path = self
                 .plan_reader
                 .find_plan_path_for_branch(&project_path, &branch);
 
             self.state.selected_task_plan = self
-                .plan_reader
-                .find_plan_for_branch(&project_path, &branch);
+                .state
+                .selected_task_plan_path
+                .as_deref()
+                .and_then(|path| self.plan_reader.read_plan_file(path));
 
             if let Some(ref plan) = self.state.selected_task_plan {
-                self.state.plan_line_count = plan.lines().count();
+                self.state.plan_line_count = plan.lines.len();
             }
         } else {
             self.state.selected_task_plan = None;
@@ -1026,18 +1779,24 @@ impl App {
             }
             View::Kanban => {
                 if let Some(task) = self.selected_task().cloned() {
-                    self.state.selected_task_id = Some(task.id.clone());
-                    self.load_plan_for_task(&task);
-                    self.state.view = View::TaskDetail;
+                    self.open_task_detail(&task);
                 }
             }
             View::TaskDetail => {
                 // Launch session for task
-                self.handle_launch_session(terminal, false, false)?;
+                self.handle_launch_session(terminal, self.assistant, false, false)?;
+            }
+            View::Worktrees if self.state.worktrees.picker.is_some() => {
+                // Jump the list selection to the picked branch
+                self.state.worktrees.confirm_picker();
+                self.state.search_active = false;
             }
             View::Worktrees => {
                 // Launch session in selected worktree
-                self.handle_launch_session(terminal, false, false)?;
+                self.handle_launch_session(terminal, self.assistant, false, false)?;
+            }
+            View::Sessions => {
+                self.handle_attach_session(terminal)?;
             }
             View::Logs => {
                 // Refresh logs on select
@@ -1046,11 +1805,9 @@ impl App {
             View::Search => {
                 // Select task from search results and go to detail view
                 if let Some(task) = self.state.search.selected_task().cloned() {
-                    self.state.selected_task_id = Some(task.id.clone());
-                    self.load_plan_for_task(&task);
                     self.state.search.clear();
                     self.state.search_active = false;
-                    self.state.view = View::TaskDetail;
+                    self.open_task_detail(&task);
                 }
             }
         }
@@ -1080,6 +1837,9 @@ impl App {
                 self.state.worktrees.clear_no_pr_cache();
                 self.poll_pr_info_async();
             }
+            View::Sessions => {
+                self.poll_sessions_async();
+            }
             View::Logs => {
                 self.state.logs.refresh();
             }
@@ -1093,6 +1853,483 @@ impl App {
         Ok(())
     }
 
+    /// Task the user is acting on: the open task in detail view, or the kanban selection
+    fn focused_task(&self) -> Option<&crate::state::Task> {
+        match self.state.view {
+            View::TaskDetail => self
+                .state
+                .selected_task_id
+                .as_ref()
+                .and_then(|id| self.state.tasks.tasks.iter().find(|t| &t.id == id)),
+            View::Kanban => self.selected_task(),
+            _ => None,
+        }
+    }
+
+    /// Session selected in the sessions view, or the focused task's session
+    fn focused_session_name(&self) -> Option<String> {
+        if self.state.view == View::Sessions {
+            return self.state.sessions.selected().map(|s| s.name.clone());
+        }
+        let task = self.focused_task()?;
+        let branch = task.branch();
+        if self.state.sessions.session_for_branch(&branch).is_none() {
+            tracing::info!("No session for {}", branch);
+            return None;
+        }
+        Some(session_name_for_branch(&branch))
+    }
+
+    fn handle_view_scrollback(&mut self) {
+        let Some(session_name) = self.focused_session_name() else {
+            return;
+        };
+
+        self.state.scrollback = Some(SessionScrollback::new(session_name.clone()));
+        self.state.modal = Some(Modal::Scrollback);
+
+        let sender = self.scrollback_sender.clone();
+        tokio::spawn(async move {
+            let name = session_name.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                dump_session_scrollback(&name).map_err(|e| e.to_string())
+            })
+            .await
+            .unwrap_or_else(|e| Err(e.to_string()));
+            let _ = sender.send((session_name, result)).await;
+        });
+    }
+
+    /// Quiet a session's attention flag until its screen changes, or flag it again
+    fn handle_acknowledge_session(&mut self) {
+        let Some(session_name) = self.focused_session_name() else {
+            return;
+        };
+        let notice = match self.state.sessions.toggle_acknowledged(&session_name) {
+            Some(true) => format!("{} acknowledged until its screen changes", session_name),
+            Some(false) => format!("{} can be flagged again", session_name),
+            None => format!("{}'s screen hasn't been checked yet", session_name),
+        };
+        self.state.set_notice(notice);
+    }
+
+    /// Attach to the session selected in the sessions view, task-backed or not
+    fn handle_attach_session(&mut self, terminal: &mut Terminal) -> Result<()> {
+        let Some(session) = self.state.sessions.selected() else {
+            return Ok(());
+        };
+        if session.is_current {
+            // zellij refuses to attach to the session we're running inside
+            self.state
+                .set_notice(format!("already inside session {}", session.name));
+            return Ok(());
+        }
+        let session_name = session.name.clone();
+
+        terminal.suspend()?;
+        let result = attach_zellij_foreground(&session_name);
+        terminal.resume()?;
+
+        if let Err(e) = result {
+            tracing::error!("Failed to attach to {}: {}", session_name, e);
+            self.state
+                .set_notice(format!("could not attach to {}", session_name));
+        }
+
+        // The session may have exited or been renamed while attached
+        self.poll_sessions_async();
+        self.last_session_poll = std::time::Instant::now();
+        Ok(())
+    }
+
+    /// Offer to `/compact` the focused task's session once its context is above the threshold
+    fn handle_compact_session(&mut self) {
+        let Some(task) = self.focused_task() else {
+            return;
+        };
+        let branch = task.branch();
+        let label = task
+            .linear_issue_id
+            .clone()
+            .unwrap_or_else(|| task.title.clone());
+
+        let session = self
+            .state
+            .sessions
+            .session_for_branch(&branch)
+            .filter(|s| !s.is_dead);
+        let Some(session) = session else {
+            self.state
+                .set_notice(format!("no running session for {}", label));
+            return;
+        };
+
+        let threshold = compact_threshold();
+        match session.context_percentage {
+            Some(pct) if pct >= threshold => {
+                self.state.pending_compact = Some(PendingCompact {
+                    session_name: session.name.clone(),
+                    label,
+                    context_percentage: pct,
+                });
+                self.state.modal = Some(Modal::ConfirmCompact);
+            }
+            Some(pct) => self.state.set_notice(format!(
+                "{} context at {:.0}% - compact is enabled from {:.0}%",
+                label, pct, threshold
+            )),
+            None => self
+                .state
+                .set_notice(format!("no context usage reported for {}", label)),
+        }
+    }
+
+    fn handle_confirm_compact_action(&mut self, action: Action) {
+        match action {
+            Action::Back => {
+                self.state.pending_compact = None;
+                self.state.modal = None;
+            }
+            Action::Select => {
+                self.state.modal = None;
+                let Some(pending) = self.state.pending_compact.take() else {
+                    return;
+                };
+                match send_compact(&pending.session_name) {
+                    Ok(()) => self
+                        .state
+                        .set_notice(format!("sent /compact to {}", pending.label)),
+                    Err(e) => {
+                        tracing::error!("Failed to compact {}: {}", pending.session_name, e);
+                        self.state
+                            .set_notice(format!("could not compact {}", pending.label));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The focused task's PR as (url, number, task label), or None (with a notice) when
+    /// there is none or it's mine - GitHub doesn't take reviews from a PR's author
+    fn reviewable_pr(&mut self) -> Option<(String, Option<i64>, String)> {
+        let task = self.focused_task()?;
+        let label = task
+            .linear_issue_id
+            .clone()
+            .unwrap_or_else(|| task.title.clone());
+        let worktrees = &self.state.worktrees;
+        let branch_pr = task_pr_and_worktree(task, &worktrees.branch_prs, &worktrees.worktrees).0;
+        if let (Some(pr), Some(login)) = (branch_pr, worktrees.gh_login.as_deref())
+            && pr.is_authored_by(login)
+        {
+            self.state.set_notice("can't review your own PR");
+            return None;
+        }
+        let pr = branch_pr
+            .map(|pr| (pr.url.clone(), Some(pr._number)))
+            .or_else(|| task.pr_url.clone().map(|url| (url, None)));
+        match pr {
+            Some((url, number)) => Some((url, number, label)),
+            None => {
+                self.state.set_notice("no PR for this task");
+                None
+            }
+        }
+    }
+
+    fn handle_approve_pr(&mut self) {
+        let Some((pr_url, pr_number, label)) = self.reviewable_pr() else {
+            return;
+        };
+        self.state.pending_approve = Some(PendingApprove {
+            pr_url,
+            pr_number,
+            label,
+        });
+        self.state.modal = Some(Modal::ConfirmApprove);
+    }
+
+    fn handle_confirm_approve_action(&mut self, action: Action) {
+        match action {
+            Action::Back => {
+                self.state.pending_approve = None;
+                self.state.modal = None;
+            }
+            Action::Select => {
+                self.state.modal = None;
+                let Some(pending) = self.state.pending_approve.take() else {
+                    return;
+                };
+                self.submit_review(&pending.pr_url, &pending.label, PrReview::Approve);
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_request_pr_changes(&mut self, terminal: &mut Terminal) -> Result<()> {
+        let Some((pr_url, _, label)) = self.reviewable_pr() else {
+            return Ok(());
+        };
+
+        terminal.suspend()?;
+        let edited = edit_markdown("");
+        terminal.resume()?;
+
+        match edited {
+            Ok(Some(body)) if !body.trim().is_empty() => {
+                let body = body.trim().to_string();
+                self.submit_review(&pr_url, &label, PrReview::RequestChanges(body));
+            }
+            Ok(_) => self
+                .state
+                .set_notice("review not sent: the changes requested were empty"),
+            Err(e) => self
+                .state
+                .set_notice(format!("could not open editor: {}", e)),
+        }
+        Ok(())
+    }
+
+    /// Send the review, then refresh the card's PR so its review state updates
+    fn submit_review(&mut self, pr_url: &str, label: &str, review: PrReview) {
+        let verb = match review {
+            PrReview::Approve => "approved",
+            PrReview::RequestChanges(_) => "requested changes on",
+        };
+        match submit_pr_review(pr_url, &review) {
+            Ok(()) => {
+                self.handle_refresh_selected();
+                self.state.set_notice(format!("{} {}", verb, label));
+            }
+            Err(e) => {
+                tracing::error!("Review of {} failed: {}", pr_url, e);
+                self.state.set_notice(format!("review failed: {}", e));
+            }
+        }
+    }
+
+    fn handle_scrollback_action(&mut self, action: Action) {
+        let Some(scrollback) = self.state.scrollback.as_mut() else {
+            self.state.modal = None;
+            return;
+        };
+        match action {
+            Action::Up => scrollback.scroll_up(1),
+            Action::Down => scrollback.scroll_down(1, SCROLLBACK_PAGE),
+            Action::PageUp => scrollback.scroll_up(SCROLLBACK_PAGE),
+            Action::PageDown => scrollback.scroll_down(SCROLLBACK_PAGE, SCROLLBACK_PAGE),
+            Action::Back | Action::Select => {
+                self.state.scrollback = None;
+                self.state.modal = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Fetch the focused task's PR diff in the background and show it in a popup
+    fn handle_view_pr_diff(&mut self) {
+        let Some(task) = self.focused_task() else {
+            return;
+        };
+        let worktrees = &self.state.worktrees;
+        let pr = task_pr_and_worktree(task, &worktrees.branch_prs, &worktrees.worktrees)
+            .0
+            .map(|pr| pr.url.clone())
+            .or_else(|| task.pr_url.clone());
+        let Some(pr) = pr else {
+            self.state.set_notice("no PR for this task");
+            return;
+        };
+
+        self.state.pr_diff = Some(PrDiff::new(pr.clone()));
+        self.state.modal = Some(Modal::PrDiff);
+
+        let sender = self.pr_diff_sender.clone();
+        tokio::spawn(async move {
+            let url = pr.clone();
+            let result =
+                tokio::task::spawn_blocking(move || get_pr_diff(&url).map_err(|e| e.to_string()))
+                    .await
+                    .unwrap_or_else(|e| Err(e.to_string()));
+            let _ = sender.send((pr, result)).await;
+        });
+    }
+
+    fn handle_pr_diff_action(&mut self, action: Action) {
+        let Some(diff) = self.state.pr_diff.as_mut() else {
+            self.state.modal = None;
+            return;
+        };
+        match action {
+            Action::Up => diff.scroll_up(1),
+            Action::Down => diff.scroll_down(1, SCROLLBACK_PAGE),
+            Action::PageUp => diff.scroll_up(SCROLLBACK_PAGE),
+            Action::PageDown => diff.scroll_down(SCROLLBACK_PAGE, SCROLLBACK_PAGE),
+            Action::Back | Action::Select => {
+                self.state.pr_diff = None;
+                self.state.modal = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Create a Linear issue from the focused local task; the task is linked once it exists
+    fn handle_promote_task(&mut self) {
+        let Some(task) = self.focused_task().cloned() else {
+            return;
+        };
+        if let Some(id) = &task.linear_issue_id {
+            self.state
+                .set_notice(format!("already tracked in Linear as {}", id));
+            return;
+        }
+        let env_var = linear_env_var_name(self.storage.project_name());
+        if !self.state.linear_api_key_available {
+            self.state
+                .set_notice(format!("Set {} to promote tasks to Linear", env_var));
+            return;
+        }
+        if !self.promoting.insert(task.id.clone()) {
+            return;
+        }
+
+        self.state
+            .set_notice(format!("creating Linear issue for {}...", task.title));
+        let sender = self.promote_sender.clone();
+        tokio::spawn(async move {
+            let result = match read_secret(&env_var) {
+                Ok(api_key) => {
+                    LinearClient::new(api_key)
+                        .create_issue(&task.title, task.description.as_deref(), &[], None)
+                        .await
+                }
+                Err(_) => Err(format!("{} not set", env_var)),
+            };
+            let _ = sender.send((task.id, result)).await;
+        });
+    }
+
+    fn handle_pick_linear_state(&mut self) {
+        let Some(identifier) = self.focused_task().and_then(|t| t.linear_issue_id.clone()) else {
+            tracing::info!("Task is not linked to a Linear issue");
+            return;
+        };
+        if !self.state.linear_api_key_available {
+            tracing::warn!("Linear API key not set; cannot change issue state");
+            return;
+        }
+
+        self.state.state_picker = Some(LinearStatePicker::new(identifier.clone()));
+        self.state.modal = Some(Modal::LinearStatePicker);
+
+        let env_var = linear_env_var_name(self.storage.project_name());
+        let sender = self.linear_states_sender.clone();
+        tokio::spawn(async move {
+            let result = match read_secret(&env_var) {
+                Ok(api_key) => LinearClient::new(api_key)
+                    .fetch_team_states(&identifier)
+                    .await
+                    .map_err(|e| e.to_string()),
+                Err(_) => Err(format!("{} not set", env_var)),
+            };
+            let _ = sender.send((identifier, result)).await;
+        });
+    }
+
+    fn handle_state_picker_action(&mut self, action: Action) {
+        match action {
+            Action::Up => {
+                if let Some(picker) = self.state.state_picker.as_mut() {
+                    picker.select_prev();
+                }
+            }
+            Action::Down => {
+                if let Some(picker) = self.state.state_picker.as_mut() {
+                    picker.select_next();
+                }
+            }
+            Action::Back => {
+                self.state.state_picker = None;
+                self.state.modal = None;
+            }
+            Action::Select => {
+                let Some(picker) = self.state.state_picker.take() else {
+                    return;
+                };
+                self.state.modal = None;
+                let Some(state) = picker.selected_state() else {
+                    return;
+                };
+
+                let identifier = picker.identifier.clone();
+                let state_name = state.name.clone();
+                let env_var = linear_env_var_name(self.storage.project_name());
+                let sender = self.linear_status_sender.clone();
+                tokio::spawn(async move {
+                    let Ok(api_key) = read_secret(&env_var) else {
+                        return;
+                    };
+                    let client = LinearClient::new(api_key);
+                    match client
+                        .set_issue_state_by_name(&identifier, &state_name)
+                        .await
+                    {
+                        Ok(status) => {
+                            tracing::info!("Moved {} to {}", identifier, state_name);
+                            let _ = sender.send(Ok(vec![status])).await;
+                        }
+                        Err(e) => {
+                            tracing::error!(
+                                "Failed to move {} to {}: {}",
+                                identifier,
+                                state_name,
+                                e
+                            );
+                            let _ = sender.send(Err(e)).await;
+                        }
+                    }
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// Fetch the Linear statuses of `identifiers` into the status cache
+    fn spawn_linear_status_fetch(
+        env_var: String,
+        identifiers: Vec<String>,
+        sender: mpsc::Sender<LinearStatusResult>,
+    ) {
+        if identifiers.is_empty() {
+            return;
+        }
+        tracing::info!("Fetching Linear statuses for {} tasks", identifiers.len());
+        tokio::spawn(async move {
+            match read_secret(&env_var) {
+                Ok(api_key) => {
+                    let client = LinearClient::new(api_key);
+                    match client.fetch_issue_statuses(&identifiers).await {
+                        Ok(statuses) => {
+                            tracing::info!(
+                                "Linear status fetch succeeded: {} statuses",
+                                statuses.len()
+                            );
+                            let _ = sender.send(Ok(statuses)).await;
+                        }
+                        Err(e) => {
+                            tracing::error!("Linear status fetch failed: {}", e);
+                            let _ = sender.send(Err(e)).await;
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::error!("Failed to read Linear API key {}: {}", env_var, e);
+                }
+            }
+        });
+    }
+
     fn refresh_linear(&self) {
         if !self.state.linear_api_key_available {
             return;
@@ -1103,7 +2340,7 @@ impl App {
         let sender = self.linear_sender.clone();
 
         tokio::spawn(async move {
-            if let Ok(api_key) = std::env::var(&env_var) {
+            if let Ok(api_key) = read_secret(&env_var) {
                 let client = LinearClient::new(api_key);
                 let result = client.fetch_backlog_issues().await;
                 let _ = sender.send(result).await;
@@ -1175,6 +2412,33 @@ impl App {
         Ok(())
     }
 
+    /// Edit the task's private notes; they stay in the task file and never reach Linear
+    fn handle_edit_notes(&mut self, terminal: &mut Terminal) -> Result<()> {
+        let task = match self.state.view {
+            View::TaskDetail => self
+                .state
+                .selected_task_id
+                .as_ref()
+                .and_then(|id| self.state.tasks.tasks.iter().find(|t| &t.id == id)),
+            View::Kanban => self.selected_task(),
+            _ => None,
+        };
+        let Some(task) = task.cloned() else {
+            return Ok(());
+        };
+
+        terminal.suspend()?;
+        let edited = edit_markdown(task.notes.as_deref().unwrap_or(""));
+        terminal.resume()?;
+
+        if let Ok(Some(notes)) = edited {
+            self.storage.set_task_notes(&task.id, Some(&notes))?;
+            self.refresh()?;
+        }
+
+        Ok(())
+    }
+
     fn handle_create_task(&mut self, terminal: &mut Terminal) -> Result<()> {
         // Suspend terminal for editor
         terminal.suspend()?;
@@ -1213,36 +2477,151 @@ impl App {
         Ok(())
     }
 
-    fn handle_delete_task(&mut self) -> Result<()> {
-        // Get the selected task
-        let task_id = match self.state.view {
-            View::TaskDetail => self.state.selected_task_id.clone(),
-            View::Kanban => self.selected_task().map(|t| t.id.clone()),
-            _ => None,
+    /// Ask for confirmation before deleting the focused task
+    fn handle_delete_task(&mut self) {
+        let Some(task) = self.focused_task() else {
+            return;
         };
+        let label = task
+            .linear_issue_id
+            .clone()
+            .unwrap_or_else(|| task.title.clone());
+
+        self.state.pending_delete = Some(PendingDelete {
+            task_id: task.id.clone(),
+            label,
+        });
+        self.state.modal = Some(Modal::ConfirmDelete);
+    }
 
-        let Some(task_id) = task_id else {
-            return Ok(());
-        };
+    fn handle_confirm_delete_action(&mut self, action: Action) -> Result<()> {
+        match action {
+            Action::Back => {
+                self.state.pending_delete = None;
+                self.state.modal = None;
+            }
+            Action::Select => {
+                self.state.modal = None;
+                let Some(pending) = self.state.pending_delete.take() else {
+                    return Ok(());
+                };
 
-        // Delete the task
-        self.storage.delete_task(&task_id)?;
+                let deleted = self.storage.delete_task(&pending.task_id)?;
+                self.state
+                    .set_notice(format!("deleted {} - u to undo", pending.label));
+                self.last_deleted = Some((pending.label, deleted));
 
-        // Go back if we were in task detail view
-        if self.state.view == View::TaskDetail {
-            self.state.selected_task_id = None;
-            self.state.view = View::Kanban;
+                // Go back if we were in task detail view
+                if self.state.view == View::TaskDetail {
+                    self.state.selected_task_id = None;
+                    self.state.view = View::Kanban;
+                }
+
+                // Refresh to get updated data
+                self.refresh()?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Ask for confirmation before cancelling the focused task
+    fn handle_cancel_task(&mut self) {
+        let Some(task) = self.focused_task() else {
+            return;
+        };
+        if task.status == TaskStatus::Cancelled {
+            self.state.set_notice("already cancelled");
+            return;
+        }
+        let label = task
+            .linear_issue_id
+            .clone()
+            .unwrap_or_else(|| task.title.clone());
+        let linear_id = task
+            .linear_issue_id
+            .clone()
+            .filter(|_| self.state.linear_api_key_available);
+
+        self.state.pending_cancel = Some(PendingCancel {
+            task_id: task.id.clone(),
+            label,
+            linear_id,
+        });
+        self.state.modal = Some(Modal::ConfirmCancel);
+    }
+
+    fn handle_confirm_cancel_action(&mut self, action: Action) -> Result<()> {
+        let with_linear = match action {
+            Action::Select => false,
+            Action::ConfirmWithLinear => true,
+            Action::Back => {
+                self.state.pending_cancel = None;
+                self.state.modal = None;
+                return Ok(());
+            }
+            _ => return Ok(()),
+        };
+        let Some(pending) = self.state.pending_cancel.take() else {
+            return Ok(());
+        };
+        self.state.modal = None;
+
+        self.storage
+            .set_task_status(&pending.task_id, TaskStatus::Cancelled)?;
+
+        match pending.linear_id.filter(|_| with_linear) {
+            Some(identifier) => {
+                self.state.set_notice(format!(
+                    "cancelled {}, moving Linear issue to canceled",
+                    pending.label
+                ));
+                let env_var = linear_env_var_name(self.storage.project_name());
+                let sender = self.linear_status_sender.clone();
+                tokio::spawn(async move {
+                    let Ok(api_key) = read_secret(&env_var) else {
+                        return;
+                    };
+                    let client = LinearClient::new(api_key);
+                    match client.update_issue_state(&identifier, "canceled").await {
+                        Ok(status) => {
+                            tracing::info!("Canceled {} in Linear", identifier);
+                            let _ = sender.send(Ok(vec![status])).await;
+                        }
+                        Err(e) => {
+                            tracing::error!("Failed to cancel {} in Linear: {}", identifier, e);
+                            let _ = sender.send(Err(e)).await;
+                        }
+                    }
+                });
+            }
+            None => self
+                .state
+                .set_notice(format!("cancelled {}", pending.label)),
         }
 
-        // Refresh to get updated data
-        self.refresh()?;
+        self.refresh()
+    }
 
+    fn handle_undo_delete(&mut self) -> Result<()> {
+        let Some((label, deleted)) = self.last_deleted.take() else {
+            return Ok(());
+        };
+        match self.storage.restore_task(&deleted) {
+            Ok(_) => {
+                self.state.set_notice(format!("restored {}", label));
+                self.refresh()?;
+            }
+            Err(e) => {
+                tracing::error!("Failed to restore {}: {}", label, e);
+                self.state
+                    .set_notice(format!("could not restore {}", label));
+            }
+        }
         Ok(())
     }
 
     fn handle_archive_done(&mut self) -> Result<()> {
-        use crate::state::TaskStatus;
-
         let done_task_ids: Vec<String> = self
             .state
             .tasks
@@ -1268,8 +2647,221 @@ impl App {
         Ok(())
     }
 
+    fn auto_archive(&mut self, older_than_days: i64) {
+        let ids = archive_candidates(
+            &self.state.tasks.tasks,
+            &self.state.worktrees.branch_prs,
+            &self.state.worktrees.worktrees,
+            &self.state.linear_issue_statuses,
+            older_than_days,
+            chrono::Utc::now().date_naive(),
+        );
+        if ids.is_empty() {
+            return;
+        }
+
+        match self.storage.archive_tasks(&ids) {
+            Ok(count) => {
+                tracing::info!(
+                    "Auto-archived {} done tasks older than {} days",
+                    count,
+                    older_than_days
+                );
+                match self.storage.list_tasks() {
+                    Ok(tasks) => self.state.tasks.set_tasks(tasks),
+                    Err(e) => tracing::error!("Failed to reload tasks: {}", e),
+                }
+            }
+            Err(e) => tracing::error!("Auto-archive failed: {}", e),
+        }
+    }
+
+    /// Show only one assignee's cards, stepping through everyone on the board
+    fn handle_cycle_assignee_filter(&mut self) {
+        if self
+            .state
+            .tasks
+            .assignees(&self.state.linear_issue_statuses)
+            .is_empty()
+        {
+            self.state
+                .set_notice("no assigned Linear issues on the board");
+            return;
+        }
+        self.state
+            .tasks
+            .cycle_assignee_filter(&self.state.linear_issue_statuses);
+        let notice = match &self.state.tasks.assignee_filter {
+            Some(assignee) => format!("showing {}'s cards", assignee),
+            None => "showing all assignees".to_string(),
+        };
+        self.state.set_notice(notice);
+    }
+
+    /// Show only cards whose PR targets one base branch, stepping through each
+    fn handle_cycle_base_filter(&mut self) {
+        let worktrees = &self.state.worktrees;
+        if self
+            .state
+            .tasks
+            .pr_bases(&worktrees.branch_prs, &worktrees.worktrees)
+            .is_empty()
+        {
+            self.state
+                .set_notice("no PRs with a known base on the board");
+            return;
+        }
+        self.state
+            .tasks
+            .cycle_base_filter(&worktrees.branch_prs, &worktrees.worktrees);
+        let notice = match &self.state.tasks.base_filter {
+            Some(base) => format!("showing PRs into {}", base),
+            None => "showing all base branches".to_string(),
+        };
+        self.state.set_notice(notice);
+    }
+
+    /// Hide cards whose PR was opened by a teammate
+    fn handle_toggle_my_prs(&mut self) {
+        if !self.state.tasks.mine_only && self.state.worktrees.gh_login.is_none() {
+            self.state
+                .set_notice("GitHub user unknown until PRs load (or set VIBE_GH_USER)");
+            return;
+        }
+        self.state.tasks.toggle_mine_only();
+        let notice = match (self.state.tasks.mine_only, &self.state.worktrees.gh_login) {
+            (true, Some(login)) => format!("showing only {}'s PRs", login),
+            _ => "showing everyone's PRs".to_string(),
+        };
+        self.state.set_notice(notice);
+    }
+
+    /// Record tasks whose board column (PR, worktree and Linear aware) moved
+    /// since their last recorded transition
+    fn record_board_transitions(&mut self) {
+        let board = board_statuses(
+            &self.state.tasks.tasks,
+            &self.state.worktrees.branch_prs,
+            &self.state.worktrees.worktrees,
+            &self.state.linear_issue_statuses,
+        );
+        for task in self.state.tasks.tasks.iter_mut() {
+            // Wait for the Linear status so a pending fetch doesn't look like a move
+            if self.state.linear_api_key_available
+                && task
+                    .linear_issue_id
+                    .as_ref()
+                    .is_some_and(|id| !self.state.linear_issue_statuses.contains_key(id))
+            {
+                continue;
+            }
+            let Some(&status) = board.get(&task.id) else {
+                continue;
+            };
+            if status == task.last_recorded_status() {
+                continue;
+            }
+            match self.storage.record_status_change(&task.id, status) {
+                Ok(Some(change)) => push_status_change(&mut task.status_history, change),
+                Ok(None) => {}
+                Err(e) => tracing::warn!("Failed to record status of {}: {}", task.id, e),
+            }
+        }
+    }
+
+    /// Comment the PR link on a task's Linear issue the first time its branch has an
+    /// open PR. The URL is stored before posting, so each task gets one comment at most.
+    fn link_new_prs_on_linear(&mut self) {
+        if !self.state.linear_api_key_available {
+            return;
+        }
+        let env_var = linear_env_var_name(self.storage.project_name());
+        for task in self.state.tasks.tasks.iter_mut() {
+            if task.pr_url.is_some() {
+                continue;
+            }
+            let Some(identifier) = task.linear_issue_id.clone() else {
+                continue;
+            };
+            let (branch_pr, _) = task_pr_and_worktree(
+                task,
+                &self.state.worktrees.branch_prs,
+                &self.state.worktrees.worktrees,
+            );
+            let Some(pr) = branch_pr.filter(|pr| pr.state == "OPEN") else {
+                continue;
+            };
+            let (url, number) = (pr.url.clone(), pr._number);
+
+            match self.storage.record_pr_url(&task.id, &url) {
+                Ok(_) => task.pr_url = Some(url.clone()),
+                Err(e) => {
+                    tracing::warn!("Failed to record PR of {}: {}", task.id, e);
+                    continue;
+                }
+            }
+
+            let env_var = env_var.clone();
+            tokio::spawn(async move {
+                let body = format!("Pull request opened: [#{}]({})", number, url);
+                let result = match read_secret(&env_var) {
+                    Ok(api_key) => {
+                        LinearClient::new(api_key)
+                            .add_comment(&identifier, &body)
+                            .await
+                    }
+                    Err(_) => Err(format!("{} not set", env_var)),
+                };
+                match result {
+                    Ok(()) => tracing::info!("Linked {} on {}", url, identifier),
+                    Err(e) => tracing::warn!("Failed to comment PR on {}: {}", identifier, e),
+                }
+            });
+        }
+    }
+
     // Worktree and session handlers
 
+    /// Create a task for the selected untracked worktree, titled from its branch
+    fn handle_track_worktree(&mut self) -> Result<()> {
+        let Some(wt) = self.state.worktrees.selected() else {
+            return Ok(());
+        };
+        let branch = wt.branch.clone();
+        if !self
+            .state
+            .worktrees
+            .untracked_branches(&self.state.tasks.tasks)
+            .contains(&branch)
+        {
+            tracing::info!("Worktree {} already has a task", branch);
+            return Ok(());
+        }
+
+        let Some((title, linear_id)) = branch_to_task_title(&branch) else {
+            tracing::warn!("Cannot derive a task title from branch {}", branch);
+            return Ok(());
+        };
+
+        let task = match linear_id {
+            Some(identifier) => self.storage.create_task_from_linear(&LinearIssue {
+                identifier,
+                title,
+                description: None,
+                url: String::new(),
+                labels: vec![],
+                priority: None,
+                estimate: None,
+            })?,
+            None => self.storage.create_task(&title, None)?,
+        };
+        tracing::info!("Created task '{}' for worktree {}", task.title, branch);
+
+        let tasks = self.storage.list_tasks()?;
+        self.state.tasks.set_tasks(tasks);
+        Ok(())
+    }
+
     fn handle_show_worktrees(&mut self) -> Result<()> {
         self.load_worktrees();
         self.state.view = View::Worktrees;
@@ -1297,10 +2889,11 @@ impl App {
         // Spawn background task to refresh session status
         // Only if not already loading (avoid stacking requests)
         if !self.state.sessions.loading {
+            let generation = self.session_generation.start();
             let sender = self.session_sender.clone();
             tokio::task::spawn_blocking(move || {
                 let result = list_sessions_with_status().map_err(|e| e.to_string());
-                let _ = sender.blocking_send(result);
+                let _ = sender.blocking_send((generation, result));
             });
         }
     }
@@ -1310,6 +2903,15 @@ impl App {
         self.fetch_pr_info_batch();
     }
 
+    /// Cards that get proactive status refreshes: the visible ones outside the Done column
+    fn refresh_targets(&self) -> std::collections::HashSet<String> {
+        self.state.tasks.refresh_targets(
+            &self.state.worktrees.branch_prs,
+            &self.state.worktrees.worktrees,
+            &self.state.linear_issue_statuses,
+        )
+    }
+
     /// Trigger immediate refetch of PR and session info, resetting poll timers.
     /// Call this when returning to the Kanban view (similar to TanStack Query's refetchOnMount).
     fn refetch_on_kanban_mount(&mut self) {
@@ -1322,13 +2924,37 @@ impl App {
 
     fn poll_claude_activity(&mut self) {
         // Update Claude activity state for all sessions
+        let worktree_paths = self.session_worktree_paths();
         self.claude_activity_tracker
-            .update_sessions(&mut self.state.sessions.sessions);
+            .update_sessions(&mut self.state.sessions.sessions, &worktree_paths);
+    }
+
+    /// Worktree directory of each session vibe knows the branch of, keyed by session name
+    fn session_worktree_paths(&self) -> HashMap<String, String> {
+        self.state
+            .worktrees
+            .worktrees
+            .iter()
+            .filter(|wt| !wt.path.is_empty())
+            .map(|wt| (session_name_for_branch(&wt.branch), wt.path.clone()))
+            .collect()
     }
 
-    /// Check plan presence for all tasks using worktree paths
+    /// Check plan presence for the board's refresh targets using worktree paths
     fn poll_plan_presence(&self) {
-        let tasks = self.state.tasks.tasks.clone();
+        let targets = self.refresh_targets();
+        let tasks = self
+            .state
+            .tasks
+            .tasks
+            .iter()
+            .filter(|t| targets.contains(&t.id))
+            .cloned()
+            .collect();
+        self.poll_plan_presence_for(tasks);
+    }
+
+    fn poll_plan_presence_for(&self, tasks: Vec<crate::state::Task>) {
         let worktrees = self.state.worktrees.worktrees.clone();
         let fallback_path = self
             .get_project_dir()
@@ -1336,7 +2962,7 @@ impl App {
 
         for task in tasks {
             let sender = self.plan_presence_sender.clone();
-            let branch = task_title_to_branch(&task.title, task.linear_issue_id.as_deref());
+            let branch = task.branch();
             let worktrees = worktrees.clone();
             let fallback = fallback_path.clone();
             let task_id = task.id.clone();
@@ -1367,9 +2993,22 @@ impl App {
         std::env::current_dir().ok()
     }
 
+    /// The project's Claude settings; a broken config is reported instead of launching
+    fn claude_config(&mut self) -> Option<ClaudeConfig> {
+        match ClaudeConfig::load(self.storage.project_name()) {
+            Ok(config) => Some(config),
+            Err(e) => {
+                tracing::error!("{:#}", e);
+                self.state.set_notice(format!("{:#}", e));
+                None
+            }
+        }
+    }
+
     fn handle_launch_session(
         &mut self,
         terminal: &mut Terminal,
+        assistant: AssistantCli,
         plan_mode: bool,
         with_prime: bool,
     ) -> Result<()> {
@@ -1392,13 +3031,17 @@ impl App {
         let task = match self.state.view {
             View::Worktrees => {
                 // If in worktrees view, use selected worktree directly
+                let Some(claude) = self.claude_config() else {
+                    return Ok(());
+                };
                 if let Some(wt) = self.state.worktrees.selected() {
                     terminal.suspend()?;
                     let result = launch_zellij_claude_in_worktree(
                         &wt.branch,
-                        self.assistant,
+                        assistant,
                         plan_mode,
                         &project_dir,
+                        &claude,
                     );
                     terminal.resume()?;
                     if let Err(e) = result {
@@ -1425,9 +3068,15 @@ impl App {
             tracing::warn!("No task selected for session launch");
             return Ok(());
         };
+        let task_id = task.id.clone();
 
         // Create branch slug from task title (with Linear ID prefix if available)
-        let branch = task_title_to_branch(&task.title, task.linear_issue_id.as_deref());
+        let branch = task.branch();
+
+        let standing_context = load_standing_context(None).unwrap_or_else(|e| {
+            tracing::warn!("Ignoring standing context: {}", e);
+            None
+        });
 
         // Build task context for fresh sessions
         let task_context = build_task_prompt(TaskPromptOptions {
@@ -1442,23 +3091,32 @@ impl App {
             }),
             project_name: self.storage.project_name(),
             with_prime,
+            standing_context: standing_context.as_deref(),
+            approved_plan: None,
         });
 
+        let Some(claude) = self.claude_config() else {
+            return Ok(());
+        };
+
         // Suspend TUI, create worktree if needed, launch claude
         terminal.suspend()?;
 
         let result = launch_zellij_claude_in_worktree_with_context(
             &branch,
             &task_context,
-            self.assistant,
+            assistant,
             plan_mode,
             &project_dir,
+            &claude,
         );
 
         terminal.resume()?;
 
-        if let Err(e) = result {
-            tracing::error!("Failed to launch session: {}", e);
+        match result {
+            Ok(()) if plan_mode => self.record_plan_phase(&task_id, PlanPhase::Planning),
+            Ok(()) => {}
+            Err(e) => tracing::error!("Failed to launch session: {}", e),
         }
 
         // After returning from session, go back to kanban board and refetch
@@ -1474,6 +3132,202 @@ impl App {
         Ok(())
     }
 
+    fn record_plan_phase(&mut self, task_id: &str, phase: PlanPhase) {
+        match self.storage.set_plan_phase(task_id, phase) {
+            Ok(updated) => {
+                if let Some(task) = self.state.tasks.tasks.iter_mut().find(|t| t.id == task_id) {
+                    task.plan_phase = updated.plan_phase;
+                }
+            }
+            Err(e) => tracing::warn!("Failed to record plan phase: {}", e),
+        }
+    }
+
+    /// `X`: relaunch a planned task's worktree in execute mode, with the plan in the prompt
+    fn handle_execute_plan(&mut self, terminal: &mut Terminal) -> Result<()> {
+        let Some(task) = self.focused_task().cloned() else {
+            return Ok(());
+        };
+        let Some(project_dir) = self.get_project_dir().filter(|dir| dir.exists()) else {
+            tracing::error!("Failed to get current directory");
+            return Ok(());
+        };
+        let branch = task.branch();
+
+        let plan = self
+            .plan_project_path(&branch)
+            .and_then(|path| self.plan_reader.find_plan_path_for_branch(&path, &branch))
+            .and_then(|path| self.plan_reader.read_plan_file(&path));
+        let Some(plan) = plan else {
+            self.state
+                .set_notice(format!("no plan for {} yet, press p to plan first", branch));
+            return Ok(());
+        };
+        let plan = plan.lines.join("\n");
+
+        let standing_context = load_standing_context(None).unwrap_or_else(|e| {
+            tracing::warn!("Ignoring standing context: {}", e);
+            None
+        });
+        let task_context = build_task_prompt(TaskPromptOptions {
+            title: &task.title,
+            description: task.description.as_deref(),
+            branch: &branch,
+            pull_request: self.state.worktrees.branch_prs.get(&branch).map(|pr_info| {
+                PullRequestContext {
+                    url: &pr_info.url,
+                    state: &pr_info.state,
+                }
+            }),
+            project_name: self.storage.project_name(),
+            with_prime: false,
+            standing_context: standing_context.as_deref(),
+            approved_plan: Some(&plan),
+        });
+
+        let Some(claude) = self.claude_config() else {
+            return Ok(());
+        };
+
+        terminal.suspend()?;
+        let result = launch_zellij_claude_executing_plan(
+            &branch,
+            &task_context,
+            self.assistant,
+            &project_dir,
+            &claude,
+        );
+        terminal.resume()?;
+
+        match result {
+            Ok(()) => self.record_plan_phase(&task.id, PlanPhase::Executing),
+            Err(e) => tracing::error!("Failed to launch session: {}", e),
+        }
+
+        if self.state.view == View::TaskDetail {
+            self.state.selected_task_id = None;
+            self.state.selected_task_plan = None;
+            self.state.view = View::Kanban;
+        }
+        self.refetch_on_kanban_mount();
+
+        Ok(())
+    }
+
+    /// `b`: bind the focused task to one of the worktree or PR branches, for when
+    /// fuzzy matching glues the wrong PR or worktree to it
+    fn handle_open_branch_picker(&mut self) {
+        let Some(task) = self.focused_task() else {
+            return;
+        };
+        let mut branches: Vec<String> = self
+            .state
+            .worktrees
+            .worktrees
+            .iter()
+            .filter(|w| !w.is_main)
+            .map(|w| w.branch.clone())
+            .chain(self.state.worktrees.branch_prs.keys().cloned())
+            .collect();
+        branches.sort();
+        branches.dedup();
+        let label = task
+            .linear_issue_id
+            .clone()
+            .unwrap_or_else(|| task.title.clone());
+        self.state.branch_picker = Some(BranchPicker::new(
+            task.id.clone(),
+            label,
+            branches,
+            task.branch_override.as_deref(),
+        ));
+        self.state.modal = Some(Modal::BranchPicker);
+    }
+
+    fn handle_branch_picker_action(&mut self, action: Action) -> Result<()> {
+        match action {
+            Action::Up => {
+                if let Some(picker) = self.state.branch_picker.as_mut() {
+                    picker.select_prev();
+                }
+            }
+            Action::Down => {
+                if let Some(picker) = self.state.branch_picker.as_mut() {
+                    picker.select_next();
+                }
+            }
+            Action::Back => {
+                self.state.branch_picker = None;
+                self.state.modal = None;
+            }
+            Action::Select => {
+                let Some(picker) = self.state.branch_picker.take() else {
+                    return Ok(());
+                };
+                self.state.modal = None;
+                let branch = picker.selected_branch();
+                self.storage.set_branch_override(&picker.task_id, branch)?;
+                self.state.set_notice(match branch {
+                    Some(branch) => format!("bound {} to {}", picker.label, branch),
+                    None => format!("{} follows its title again", picker.label),
+                });
+                return self.refresh();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Pick the assistant for one launch, starting from the project's last choice
+    fn handle_open_assistant_picker(&mut self) {
+        if self.focused_task().is_none() {
+            return;
+        }
+        let preferred =
+            AssistantCli::load_last(self.storage.project_name()).unwrap_or(self.assistant);
+        self.state.assistant_picker = Some(AssistantPicker::new(preferred));
+        self.state.modal = Some(Modal::AssistantPicker);
+    }
+
+    fn handle_assistant_picker_action(
+        &mut self,
+        action: Action,
+        terminal: &mut Terminal,
+    ) -> Result<()> {
+        match action {
+            Action::Up => {
+                if let Some(picker) = self.state.assistant_picker.as_mut() {
+                    picker.select_prev();
+                }
+            }
+            Action::Down => {
+                if let Some(picker) = self.state.assistant_picker.as_mut() {
+                    picker.select_next();
+                }
+            }
+            Action::Back => {
+                self.state.assistant_picker = None;
+                self.state.modal = None;
+            }
+            Action::Select => {
+                let Some(picker) = self.state.assistant_picker.take() else {
+                    return Ok(());
+                };
+                self.state.modal = None;
+                let Some(assistant) = picker.selected_assistant() else {
+                    self.state.set_notice("No assistant found on PATH");
+                    return Ok(());
+                };
+                if let Err(e) = assistant.save_last(self.storage.project_name()) {
+                    tracing::warn!("Failed to remember assistant choice: {}", e);
+                }
+                self.handle_launch_session(terminal, assistant, false, false)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     fn handle_launch_prime(&mut self, terminal: &mut Terminal) -> Result<()> {
         let project_dir = match self.get_project_dir() {
             Some(dir) if dir.exists() => dir,
@@ -1484,10 +3338,13 @@ impl App {
         };
 
         let project_name = self.storage.project_name().to_string();
+        let Some(claude) = self.claude_config() else {
+            return Ok(());
+        };
 
         terminal.suspend()?;
 
-        let result = launch_prime_session(&project_name, self.assistant, &project_dir);
+        let result = launch_prime_session(&project_name, self.assistant, &project_dir, &claude);
 
         terminal.resume()?;
 
@@ -1510,7 +3367,7 @@ impl App {
             }
 
             // Check locally detected PR info
-            let branch = task_title_to_branch(&task.title, task.linear_issue_id.as_deref());
+            let branch = task.branch();
             if let Some(pr_info) = self.state.worktrees.branch_prs.get(&branch) {
                 if let Err(e) = open::that(&pr_info.url) {
                     tracing::error!("Failed to open PR URL: {}", e);
@@ -1539,4 +3396,33 @@ impl App {
         }
         Ok(())
     }
+
+    /// Open the task's plan file in the editor, then reload the plan pane
+    fn handle_edit_plan(&mut self, terminal: &mut Terminal) -> Result<()> {
+        let Some(plan_path) = self.state.selected_task_plan_path.clone() else {
+            self.state.set_notice("No plan for this task");
+            return Ok(());
+        };
+
+        terminal.suspend()?;
+        if let Err(e) = crate::external::view_file(&plan_path) {
+            tracing::error!("Failed to open plan in editor: {}", e);
+        }
+        terminal.resume()?;
+
+        let task = self
+            .state
+            .selected_task_id
+            .as_ref()
+            .and_then(|id| self.state.tasks.tasks.iter().find(|t| &t.id == id))
+            .cloned();
+        if let Some(task) = task {
+            // Keep the reader's place unless the plan got shorter
+            let offset = self.state.plan_scroll_offset;
+            self.load_plan_for_task(&task);
+            self.state.plan_scroll_offset =
+                offset.min(self.state.plan_line_count.saturating_sub(1));
+        }
+        Ok(())
+    }
 }
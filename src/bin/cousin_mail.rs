@@ -16,12 +16,12 @@ fn project_name() -> Option<String> {
 }
 
 /// Match sanitize_session_name from src/external/zellij.rs:
-/// non-alphanumeric chars (except - and _) become -, truncate to 36 chars.
+/// non-ASCII-alphanumeric chars (except - and _) become -, truncate to 36 chars.
 fn sanitize_session_name(name: &str) -> String {
     let sanitized: String = name
         .chars()
         .map(|c| {
-            if c.is_alphanumeric() || c == '-' || c == '_' {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
                 c
             } else {
                 '-'
@@ -31,18 +31,27 @@ fn sanitize_session_name(name: &str) -> String {
         .trim_matches('-')
         .to_string();
 
-    if sanitized.len() > 36 {
-        sanitized[..36].trim_end_matches('-').to_string()
+    // Truncate by chars, not bytes, so a multi-byte boundary can never panic
+    if sanitized.chars().count() > 36 {
+        let truncated: String = sanitized.chars().take(36).collect();
+        truncated.trim_end_matches('-').to_string()
     } else {
         sanitized
     }
 }
 
+/// Match zellij_command from src/external/zellij.rs: apply VIBE_ZELLIJ_ARGS so
+/// messages reach sessions on a non-default zellij server.
+fn zellij() -> Command {
+    let mut cmd = Command::new("zellij");
+    if let Ok(raw) = std::env::var("VIBE_ZELLIJ_ARGS") {
+        cmd.args(raw.split_whitespace());
+    }
+    cmd
+}
+
 fn list_zellij_sessions() -> Vec<String> {
-    let Ok(output) = Command::new("zellij")
-        .args(["list-sessions", "-s"])
-        .output()
-    else {
+    let Ok(output) = zellij().args(["list-sessions", "-s"]).output() else {
         return Vec::new();
     };
     if !output.status.success() {
@@ -57,7 +66,7 @@ fn list_zellij_sessions() -> Vec<String> {
 
 /// Full session info (name, age, alive/exited) from zellij list-sessions
 fn list_zellij_sessions_full() -> Vec<(String, String, bool)> {
-    let Ok(output) = Command::new("zellij").args(["list-sessions"]).output() else {
+    let Ok(output) = zellij().args(["list-sessions"]).output() else {
         return Vec::new();
     };
     if !output.status.success() {
@@ -216,7 +225,7 @@ fn resolve_target(target: &str) -> Result<String, String> {
 
 fn send_interrupt(session: &str) -> Result<(), String> {
     // write 3 = Ctrl+C
-    let status = Command::new("zellij")
+    let status = zellij()
         .args(["-s", session, "action", "write", "3"])
         .status()
         .map_err(|e| format!("failed to run zellij: {}", e))?;
@@ -241,7 +250,7 @@ fn send_message(session: &str, message: &str) -> Result<(), String> {
         .collect::<Vec<_>>()
         .join(" ");
 
-    let status = Command::new("zellij")
+    let status = zellij()
         .args(["-s", session, "action", "write-chars", &flat])
         .status()
         .map_err(|e| format!("failed to run zellij: {}", e))?;
@@ -253,7 +262,7 @@ fn send_message(session: &str, message: &str) -> Result<(), String> {
     }
 
     // write 13 = Enter key
-    let status = Command::new("zellij")
+    let status = zellij()
         .args(["-s", session, "action", "write", "13"])
         .status()
         .map_err(|e| format!("failed to run zellij: {}", e))?;
@@ -363,6 +372,13 @@ mod tests {
         assert!(result.len() <= 36);
     }
 
+    #[test]
+    fn test_sanitize_unicode() {
+        assert_eq!(sanitize_session_name("café.prime"), "caf--prime");
+        let long = "é".repeat(50);
+        assert!(sanitize_session_name(&long).is_empty());
+    }
+
     #[test]
     fn test_arg_parsing_urgent_flag() {
         // simulate: cousin --urgent prime hello
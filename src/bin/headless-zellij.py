@@ -48,8 +48,11 @@ env.pop("ZELLIJ_SESSION_NAME", None)
 env.pop("CLAUDECODE", None)
 env.pop("CLAUDE_CODE_ENTRYPOINT", None)
 
+# Same server context as vibe's own zellij calls (see VIBE_ZELLIJ_ARGS)
+zellij_args = os.environ.get("VIBE_ZELLIJ_ARGS", "").split()
+
 proc = subprocess.Popen(
-    ["zellij", "-s", session_name],
+    ["zellij", *zellij_args, "-s", session_name],
     stdin=slave,
     stdout=slave,
     stderr=slave,
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use anyhow::Result;
 use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
@@ -13,6 +15,13 @@ use super::ClaudeActivityState;
 // Thresholds for activity detection based on file change events
 const THINKING_THRESHOLD_SECS: u64 = 2; // Got update within this time = actively working
 const WAITING_THRESHOLD_SECS: u64 = 120; // No updates for this long = waiting for user
+// A slow hook is abandoned for the built-in state
+const HOOK_TIMEOUT: Duration = Duration::from_millis(500);
+// A hook's answer for a working directory is reused this long before it runs again
+const HOOK_TTL: Duration = Duration::from_secs(5);
+
+/// Env var pointing the tracker at status files synced or mounted from another machine
+pub const ACTIVITY_DIR_ENV: &str = "VIBE_ACTIVITY_DIR";
 
 #[derive(Debug, Deserialize)]
 struct ClaudeStatusFile {
@@ -41,57 +50,278 @@ pub struct ActivityResult {
     pub context_percentage: Option<f64>,
 }
 
+/// One status file as `vibe debug activity` shows it, with the sessions it would feed
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusFileMatch {
+    pub path: PathBuf,
+    pub working_dir: String,
+    pub timestamp: u64,
+    pub sessions: Vec<String>,
+}
+
+/// Directory set via `VIBE_ACTIVITY_DIR`, e.g. a mount of a remote dev box's activity files
+fn remote_activity_root() -> Option<PathBuf> {
+    std::env::var(ACTIVITY_DIR_ENV)
+        .ok()
+        .map(|dir| dir.trim().to_string())
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Root of the status files written by the statusline and thinking hooks.
+/// Hooks may write into a `<project>` subdirectory to keep projects apart.
+fn activity_root() -> PathBuf {
+    remote_activity_root().unwrap_or_else(|| {
+        dirs::home_dir()
+            .map(|h| h.join(".vibe").join("claude-activity"))
+            .unwrap_or_else(|| PathBuf::from("/tmp/claude-activity"))
+    })
+}
+
+/// Optional user script that overrides the built-in activity heuristic
+fn activity_hook_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|h| h.join(".vibe").join("activity-hook.sh"))
+}
+
+/// Name of a state in the hook protocol
+fn hook_state_name(state: ClaudeActivityState) -> &'static str {
+    match state {
+        ClaudeActivityState::Thinking => "thinking",
+        ClaudeActivityState::WaitingForUser => "waiting",
+        ClaudeActivityState::Idle => "idle",
+        ClaudeActivityState::Unknown => "unknown",
+    }
+}
+
+/// Parse the first line a hook printed; None for anything outside the protocol
+fn parse_hook_state(output: &str) -> Option<ClaudeActivityState> {
+    match output.lines().next()?.trim().to_lowercase().as_str() {
+        "thinking" => Some(ClaudeActivityState::Thinking),
+        "waiting" => Some(ClaudeActivityState::WaitingForUser),
+        "idle" => Some(ClaudeActivityState::Idle),
+        "unknown" => Some(ClaudeActivityState::Unknown),
+        _ => None,
+    }
+}
+
+/// Run the hook as `hook <working_dir> <session_name>` with the built-in verdict in
+/// `VIBE_ACTIVITY_STATE`. None when it fails, times out or prints an unknown state.
+fn run_activity_hook(
+    hook: &Path,
+    working_dir: &str,
+    session_name: &str,
+    builtin: ClaudeActivityState,
+) -> Option<ClaudeActivityState> {
+    let output = super::timed("activity.hook", session_name, || {
+        let mut child = Command::new(hook)
+            .arg(working_dir)
+            .arg(session_name)
+            .env("VIBE_ACTIVITY_STATE", hook_state_name(builtin))
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| e.to_string())?;
+
+        let deadline = Instant::now() + HOOK_TIMEOUT;
+        loop {
+            match child.try_wait() {
+                Ok(Some(_)) => break,
+                Ok(None) if Instant::now() < deadline => {
+                    std::thread::sleep(Duration::from_millis(5))
+                }
+                _ => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(format!("timed out after {:?}", HOOK_TIMEOUT));
+                }
+            }
+        }
+
+        let output = child.wait_with_output().map_err(|e| e.to_string())?;
+        if !output.status.success() {
+            return Err(format!("exited with {}", output.status));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    });
+
+    match output {
+        Ok(stdout) => {
+            let state = parse_hook_state(&stdout);
+            if state.is_none() {
+                tracing::warn!(
+                    "Activity hook printed {:?}; expected thinking, waiting, idle or unknown",
+                    stdout.trim()
+                );
+            }
+            state
+        }
+        Err(e) => {
+            tracing::warn!("Activity hook {} failed: {}", hook.display(), e);
+            None
+        }
+    }
+}
+
+/// Latest activity hook verdict for a working directory; `state` is None when the
+/// hook deferred, failed, or hasn't answered yet
+#[derive(Debug, Clone, Copy)]
+struct HookAnswer {
+    state: Option<ClaudeActivityState>,
+    at: Instant,
+}
+
 pub struct ClaudeActivityTracker {
     state_dir: PathBuf,
+    /// Project subdirectory, scanned before the shared `state_dir`
+    project_dir: Option<PathBuf>,
     /// Track when we last received a file change event for each working_dir
     last_update_times: HashMap<String, Instant>,
+    /// Script consulted after the built-in heuristic, used only if it exists
+    hook: Option<PathBuf>,
+    /// Hook answers by working directory, filled in off the UI thread
+    hook_answers: Arc<Mutex<HashMap<String, HookAnswer>>>,
+    /// Status files come from another machine, whose checkouts live under a
+    /// different prefix, so worktrees also match by directory name
+    remote: bool,
 }
 
 impl ClaudeActivityTracker {
     pub fn new() -> Self {
-        let state_dir = dirs::home_dir()
-            .map(|h| h.join(".vibe").join("claude-activity"))
-            .unwrap_or_else(|| PathBuf::from("/tmp/claude-activity"));
+        Self::with_dirs(activity_root(), None)
+            .with_hook(activity_hook_path())
+            .with_remote(remote_activity_root().is_some())
+    }
+
+    /// Tracker that prefers the project's own status files over the shared directory
+    pub fn for_project(project_name: &str) -> Self {
+        Self::with_dirs(activity_root(), Some(project_name))
+            .with_hook(activity_hook_path())
+            .with_remote(remote_activity_root().is_some())
+    }
+
+    fn with_remote(mut self, remote: bool) -> Self {
+        self.remote = remote;
+        self
+    }
+
+    fn with_hook(mut self, hook: Option<PathBuf>) -> Self {
+        self.hook = hook;
+        self
+    }
 
+    fn with_dirs(state_dir: PathBuf, project_name: Option<&str>) -> Self {
         Self {
+            project_dir: project_name.map(|name| state_dir.join(name)),
             state_dir,
             last_update_times: HashMap::new(),
+            hook: None,
+            hook_answers: Arc::default(),
+            remote: false,
         }
     }
 
+    /// Directories holding status files, project first. The shared directory is
+    /// kept for hooks that don't write per-project files yet.
+    fn scan_dirs(&self) -> impl Iterator<Item = &Path> {
+        self.project_dir
+            .as_deref()
+            .into_iter()
+            .chain(std::iter::once(self.state_dir.as_path()))
+    }
+
     /// Record that we received a file change event for a working directory
     pub fn record_update(&mut self, working_dir: &str) {
         self.last_update_times
             .insert(working_dir.to_string(), Instant::now());
     }
 
-    pub fn get_activity_for_session(&mut self, session_name: &str) -> ActivityResult {
-        // Try to find a status file that matches this session name
-        // The status file is named by MD5 hash of the working directory
-        // We need to scan all files and match by session name in the working_dir
-
-        let Ok(entries) = fs::read_dir(&self.state_dir) else {
+    /// Activity for a session. With its worktree path known, only a status file for
+    /// that exact directory (or one below it) counts; otherwise fall back to matching
+    /// the session name against the working directory.
+    pub fn get_activity_for_session(
+        &mut self,
+        session_name: &str,
+        worktree_path: Option<&str>,
+    ) -> ActivityResult {
+        let statuses = self.read_status_files();
+        let Some(index) = self.matching_status(&statuses, session_name, worktree_path) else {
+            tracing::debug!(
+                session = session_name,
+                worktree = worktree_path.unwrap_or("-"),
+                working_dirs = ?statuses.iter().map(|(_, s)| s.working_dir.as_str()).collect::<Vec<_>>(),
+                "no status file matched session"
+            );
             return ActivityResult {
                 state: ClaudeActivityState::Unknown,
                 context_percentage: None,
             };
         };
+        self.resolve_state(&statuses[index].1, session_name)
+    }
 
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.extension().map(|e| e == "json").unwrap_or(false)
-                && let Ok(content) = fs::read_to_string(&path)
-                && let Ok(status) = serde_json::from_str::<ClaudeStatusFile>(&content)
-                && self.session_matches_working_dir(session_name, &status.working_dir)
-            {
-                return self.determine_state(&status);
-            }
+    /// Every parseable status file in the scanned directories, project first.
+    /// Files are named by a hash of the working directory, so matching goes by
+    /// the `working_dir` inside.
+    fn read_status_files(&self) -> Vec<(PathBuf, ClaudeStatusFile)> {
+        self.scan_dirs()
+            .filter_map(|dir| fs::read_dir(dir).ok())
+            .flat_map(|entries| entries.flatten())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().map(|e| e == "json").unwrap_or(false))
+            .filter_map(|path| {
+                let content = fs::read_to_string(&path).ok()?;
+                let status = serde_json::from_str::<ClaudeStatusFile>(&content).ok()?;
+                Some((path, status))
+            })
+            .collect()
+    }
+
+    /// Index of the status file feeding a session
+    fn matching_status(
+        &self,
+        statuses: &[(PathBuf, ClaudeStatusFile)],
+        session_name: &str,
+        worktree_path: Option<&str>,
+    ) -> Option<usize> {
+        match worktree_path {
+            Some(worktree) => statuses
+                .iter()
+                .position(|(_, status)| working_dir_within(&status.working_dir, worktree))
+                .or_else(|| {
+                    statuses.iter().position(|(_, status)| {
+                        self.remote && working_dir_named_like(&status.working_dir, worktree)
+                    })
+                }),
+            None => statuses.iter().position(|(_, status)| {
+                self.session_matches_working_dir(session_name, &status.working_dir)
+            }),
         }
+    }
 
-        ActivityResult {
-            state: ClaudeActivityState::Unknown,
-            context_percentage: None,
+    /// Each status file with the sessions it would feed, using the same matching as
+    /// `get_activity_for_session`. `sessions` pairs session names with their worktree
+    /// path when known.
+    pub fn status_file_matches(
+        &self,
+        sessions: &[(String, Option<String>)],
+    ) -> Vec<StatusFileMatch> {
+        let statuses = self.read_status_files();
+        let mut matches: Vec<StatusFileMatch> = statuses
+            .iter()
+            .map(|(path, status)| StatusFileMatch {
+                path: path.clone(),
+                working_dir: status.working_dir.clone(),
+                timestamp: status.timestamp,
+                sessions: Vec::new(),
+            })
+            .collect();
+        for (name, worktree) in sessions {
+            if let Some(index) = self.matching_status(&statuses, name, worktree.as_deref()) {
+                matches[index].sessions.push(name.clone());
+            }
         }
+        matches
     }
 
     fn session_matches_working_dir(&self, session_name: &str, working_dir: &str) -> bool {
@@ -117,7 +347,59 @@ impl ClaudeActivityTracker {
             .chars()
             .take(16)
             .collect::<String>();
-        self.state_dir.join(format!("{}.thinking", hash)).exists()
+        self.scan_dirs()
+            .any(|dir| dir.join(format!("{}.thinking", hash)).exists())
+    }
+
+    /// Built-in state, overridden by the activity hook's last answer for the working
+    /// directory. Once that answer is older than `HOOK_TTL` the hook runs again, on a
+    /// blocking task when there is a runtime so the UI never waits on it.
+    fn resolve_state(&mut self, status: &ClaudeStatusFile, session_name: &str) -> ActivityResult {
+        let mut result = self.determine_state(status);
+        let Some(hook) = self.hook.clone().filter(|hook| hook.is_file()) else {
+            return result;
+        };
+
+        let working_dir = status.working_dir.clone();
+        let previous = self.hook_answers.lock().unwrap().get(&working_dir).copied();
+        if previous.is_none_or(|answer| answer.at.elapsed() >= HOOK_TTL) {
+            // Mark the run so later polls keep the previous answer until it lands
+            self.hook_answers.lock().unwrap().insert(
+                working_dir.clone(),
+                HookAnswer {
+                    state: previous.and_then(|answer| answer.state),
+                    at: Instant::now(),
+                },
+            );
+            let answers = Arc::clone(&self.hook_answers);
+            let session_name = session_name.to_string();
+            let builtin = result.state;
+            let run = move || {
+                let state = run_activity_hook(&hook, &working_dir, &session_name, builtin);
+                answers.lock().unwrap().insert(
+                    working_dir,
+                    HookAnswer {
+                        state,
+                        at: Instant::now(),
+                    },
+                );
+            };
+            match tokio::runtime::Handle::try_current() {
+                Ok(runtime) => drop(runtime.spawn_blocking(run)),
+                Err(_) => run(),
+            }
+        }
+
+        if let Some(state) = self
+            .hook_answers
+            .lock()
+            .unwrap()
+            .get(&status.working_dir)
+            .and_then(|answer| answer.state)
+        {
+            result.state = state;
+        }
+        result
     }
 
     fn determine_state(&mut self, status: &ClaudeStatusFile) -> ActivityResult {
@@ -160,9 +442,16 @@ impl ClaudeActivityTracker {
         }
     }
 
-    pub fn update_sessions(&mut self, sessions: &mut [super::ZellijSession]) {
+    /// Refresh activity for every session. `worktree_paths` maps session names to
+    /// their worktree directory, for sessions vibe knows the checkout of.
+    pub fn update_sessions(
+        &mut self,
+        sessions: &mut [super::ZellijSession],
+        worktree_paths: &HashMap<String, String>,
+    ) {
         for session in sessions.iter_mut() {
-            let result = self.get_activity_for_session(&session.name);
+            let worktree = worktree_paths.get(&session.name).map(String::as_str);
+            let result = self.get_activity_for_session(&session.name, worktree);
             session.claude_activity = result.state;
             session.context_percentage = result.context_percentage;
         }
@@ -174,10 +463,28 @@ impl ClaudeActivityTracker {
         let status: ClaudeStatusFile = serde_json::from_str(&content).ok()?;
         // Record that we just received an update for this working directory
         self.record_update(&status.working_dir);
+        // The hook is left to `update_sessions`, which knows the session
         Some(self.determine_state(&status))
     }
 }
 
+/// Whether `working_dir` is `worktree` itself or a directory inside it
+fn working_dir_within(working_dir: &str, worktree: &str) -> bool {
+    let worktree = worktree.trim_end_matches('/');
+    !worktree.is_empty() && Path::new(working_dir).starts_with(worktree)
+}
+
+/// Whether `working_dir` is a directory named like `worktree`'s last component, or
+/// one inside it, wherever it lives: the same checkout seen from another machine
+fn working_dir_named_like(working_dir: &str, worktree: &str) -> bool {
+    let Some(name) = Path::new(worktree.trim_end_matches('/')).file_name() else {
+        return false;
+    };
+    Path::new(working_dir)
+        .components()
+        .any(|component| component.as_os_str() == name)
+}
+
 impl Default for ClaudeActivityTracker {
     fn default() -> Self {
         Self::new()
@@ -190,7 +497,8 @@ pub struct ActivityWatcher {
 }
 
 impl ActivityWatcher {
-    pub fn new(sender: mpsc::Sender<PathBuf>) -> Result<Self> {
+    /// Watch the shared activity directory and, if given, the project's own subdirectory
+    pub fn new(sender: mpsc::Sender<PathBuf>, project_name: Option<&str>) -> Result<Self> {
         let mut watcher = RecommendedWatcher::new(
             move |res: Result<Event, notify::Error>| {
                 if let Ok(event) = res
@@ -218,27 +526,42 @@ impl ActivityWatcher {
             Config::default(),
         )?;
 
-        let activity_dir = dirs::home_dir()
-            .ok_or_else(|| anyhow::anyhow!("No home directory"))?
-            .join(".vibe")
-            .join("claude-activity");
-
-        // Create directory if it doesn't exist
-        fs::create_dir_all(&activity_dir)?;
+        let activity_dir = activity_root();
 
-        watcher.watch(&activity_dir, RecursiveMode::NonRecursive)?;
+        let dirs = std::iter::once(activity_dir.clone())
+            .chain(project_name.map(|name| activity_dir.join(name)));
+        for dir in dirs {
+            // Create directory if it doesn't exist
+            fs::create_dir_all(&dir)?;
+            watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+        }
 
         Ok(Self { _watcher: watcher })
     }
 }
 
-/// Count active Claude sessions by counting activity files
+/// Count active Claude sessions by counting activity files, across all projects
 pub fn count_active_sessions() -> usize {
-    let activity_dir = dirs::home_dir()
-        .map(|h| h.join(".vibe").join("claude-activity"))
-        .unwrap_or_else(|| PathBuf::from("/tmp/claude-activity"));
+    count_status_files(&activity_root())
+}
 
-    fs::read_dir(&activity_dir)
+/// `.json` status files in `dir` and its per-project subdirectories
+fn count_status_files(dir: &Path) -> usize {
+    let project_dirs: usize = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|path| path.is_dir())
+                .map(|path| count_status_files_flat(&path))
+                .sum()
+        })
+        .unwrap_or(0);
+    count_status_files_flat(dir) + project_dirs
+}
+
+fn count_status_files_flat(dir: &Path) -> usize {
+    fs::read_dir(dir)
         .map(|entries| {
             entries
                 .filter_map(|e| e.ok())
@@ -298,6 +621,116 @@ mod tests {
         assert!(!tracker.session_matches_working_dir("other-branch", "/Users/test/feature-branch"));
     }
 
+    fn write_status(dir: &Path, working_dir: &str, used_percentage: f64) {
+        fs::create_dir_all(dir).unwrap();
+        let status = serde_json::json!({
+            "working_dir": working_dir,
+            "used_percentage": used_percentage,
+            "timestamp": 1700000000u64,
+        });
+        fs::write(
+            dir.join(format!("{}.json", hash_working_dir(working_dir))),
+            status.to_string(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_project_dir_scopes_same_named_branches() {
+        let root = tempfile::tempdir().unwrap();
+        write_status(&root.path().join("alpha"), "/src/alpha.fix-login", 11.0);
+        write_status(&root.path().join("beta"), "/src/beta.fix-login", 22.0);
+
+        // Basename matching alone would accept either project's file
+        let mut alpha = ClaudeActivityTracker::with_dirs(root.path().to_path_buf(), Some("alpha"));
+        let result = alpha.get_activity_for_session("fix-login", None);
+        assert_eq!(result.context_percentage, Some(11.0));
+
+        let mut beta = ClaudeActivityTracker::with_dirs(root.path().to_path_buf(), Some("beta"));
+        let result = beta.get_activity_for_session("fix-login", None);
+        assert_eq!(result.context_percentage, Some(22.0));
+    }
+
+    #[test]
+    fn test_worktree_path_matches_exact_directory() {
+        // Both projects still write to the shared directory
+        let root = tempfile::tempdir().unwrap();
+        write_status(root.path(), "/src/alpha.fix-login", 11.0);
+        write_status(root.path(), "/src/beta.fix-login/crates/api", 22.0);
+
+        let mut tracker = ClaudeActivityTracker::with_dirs(root.path().to_path_buf(), Some("beta"));
+        let result = tracker.get_activity_for_session("fix-login", Some("/src/beta.fix-login/"));
+        assert_eq!(result.context_percentage, Some(22.0));
+
+        let result = tracker.get_activity_for_session("fix-login", Some("/src/gamma.fix-login"));
+        assert_eq!(result.state, ClaudeActivityState::Unknown);
+        assert_eq!(result.context_percentage, None);
+    }
+
+    #[test]
+    fn test_working_dir_within() {
+        assert!(working_dir_within("/src/app.fix", "/src/app.fix"));
+        assert!(working_dir_within("/src/app.fix/sub", "/src/app.fix/"));
+        assert!(!working_dir_within("/src/app.fix-2", "/src/app.fix"));
+        assert!(!working_dir_within("/src/app.fix", ""));
+    }
+
+    #[test]
+    fn test_remote_working_dir_matches_by_directory_name() {
+        let root = tempfile::tempdir().unwrap();
+        write_status(root.path(), "/home/dev/src/app.fix-login/crates/api", 33.0);
+
+        let mut local = ClaudeActivityTracker::with_dirs(root.path().to_path_buf(), None);
+        let result = local.get_activity_for_session("fix-login", Some("/Users/me/app.fix-login"));
+        assert_eq!(result.context_percentage, None);
+
+        let mut remote =
+            ClaudeActivityTracker::with_dirs(root.path().to_path_buf(), None).with_remote(true);
+        let result = remote.get_activity_for_session("fix-login", Some("/Users/me/app.fix-login/"));
+        assert_eq!(result.context_percentage, Some(33.0));
+        let result = remote.get_activity_for_session("fix-login", Some("/Users/me/app.fix"));
+        assert_eq!(result.context_percentage, None);
+    }
+
+    #[test]
+    fn test_status_file_matches_explains_unmatched_sessions() {
+        let dir = tempfile::tempdir().unwrap();
+        write_status(dir.path(), "/repos/vibe-feature-auth", 10.0);
+        write_status(dir.path(), "/elsewhere/scratch", 20.0);
+        let tracker = ClaudeActivityTracker::with_dirs(dir.path().to_path_buf(), None);
+
+        let sessions = vec![
+            ("feature-auth".to_string(), None),
+            (
+                "fix-login".to_string(),
+                Some("/repos/vibe-fix-login".to_string()),
+            ),
+        ];
+        let mut matches = tracker.status_file_matches(&sessions);
+        matches.sort_by(|a, b| a.working_dir.cmp(&b.working_dir));
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].working_dir, "/elsewhere/scratch");
+        assert!(matches[0].sessions.is_empty());
+        assert_eq!(matches[1].working_dir, "/repos/vibe-feature-auth");
+        assert_eq!(matches[1].sessions, vec!["feature-auth"]);
+        // fix-login has a known worktree with no status file, so it matches nothing
+        assert!(
+            !matches
+                .iter()
+                .any(|m| m.sessions.contains(&"fix-login".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_count_status_files_includes_project_dirs() {
+        let root = tempfile::tempdir().unwrap();
+        write_status(root.path(), "/src/legacy", 1.0);
+        write_status(&root.path().join("alpha"), "/src/alpha.a", 1.0);
+        write_status(&root.path().join("alpha"), "/src/alpha.b", 1.0);
+        fs::write(root.path().join("alpha").join("x.thinking"), "").unwrap();
+        assert_eq!(count_status_files(root.path()), 3);
+    }
+
     #[test]
     fn test_parse_status_file_new_format() {
         let json = r#"{
@@ -484,6 +917,106 @@ mod tests {
         assert_eq!(result.context_percentage, Some(75.5));
     }
 
+    #[test]
+    fn test_parse_hook_state() {
+        assert_eq!(
+            parse_hook_state("waiting\n"),
+            Some(ClaudeActivityState::WaitingForUser)
+        );
+        assert_eq!(
+            parse_hook_state(" Thinking \nextra"),
+            Some(ClaudeActivityState::Thinking)
+        );
+        assert_eq!(parse_hook_state("busy"), None);
+        assert_eq!(parse_hook_state(""), None);
+    }
+
+    #[test]
+    fn test_activity_hook_overrides_builtin_state() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let hook = dir.path().join("activity-hook.sh");
+        // Waiting for one worktree, otherwise defer (non-zero exit)
+        fs::write(
+            &hook,
+            "#!/bin/sh\n[ \"$1\" = /work/blocked ] && [ \"$VIBE_ACTIVITY_STATE\" = idle ] && echo waiting && exit 0\nexit 1\n",
+        )
+        .unwrap();
+        fs::set_permissions(&hook, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let mut tracker =
+            ClaudeActivityTracker::with_dirs(dir.path().to_path_buf(), None).with_hook(Some(hook));
+        let status = |working_dir: &str| ClaudeStatusFile {
+            working_dir: working_dir.to_string(),
+            session_id: None,
+            thinking: false,
+            input_tokens: None,
+            output_tokens: None,
+            used_percentage: None,
+            api_duration_ms: None,
+            timestamp: 0,
+        };
+
+        let blocked = tracker.resolve_state(&status("/work/blocked"), "blocked");
+        assert_eq!(blocked.state, ClaudeActivityState::WaitingForUser);
+        // Hook failed: the built-in heuristic stands
+        let other = tracker.resolve_state(&status("/work/other"), "other");
+        assert_eq!(other.state, ClaudeActivityState::Idle);
+
+        // A configured but missing hook is ignored
+        let mut tracker = ClaudeActivityTracker::with_dirs(dir.path().to_path_buf(), None)
+            .with_hook(Some(dir.path().join("missing.sh")));
+        let result = tracker.resolve_state(&status("/work/blocked"), "blocked");
+        assert_eq!(result.state, ClaudeActivityState::Idle);
+    }
+
+    #[test]
+    fn test_activity_hook_answer_is_cached_per_working_dir() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let hook = dir.path().join("activity-hook.sh");
+        let runs = dir.path().join("runs");
+        fs::write(
+            &hook,
+            format!(
+                "#!/bin/sh
+echo run >> {}
+echo waiting
+",
+                runs.display()
+            ),
+        )
+        .unwrap();
+        fs::set_permissions(&hook, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let mut tracker =
+            ClaudeActivityTracker::with_dirs(dir.path().to_path_buf(), None).with_hook(Some(hook));
+        let status = ClaudeStatusFile {
+            working_dir: "/work/a".to_string(),
+            session_id: None,
+            thinking: false,
+            input_tokens: None,
+            output_tokens: None,
+            used_percentage: None,
+            api_duration_ms: None,
+            timestamp: 0,
+        };
+
+        for _ in 0..3 {
+            let result = tracker.resolve_state(&status, "a");
+            assert_eq!(result.state, ClaudeActivityState::WaitingForUser);
+        }
+        assert_eq!(fs::read_to_string(&runs).unwrap().lines().count(), 1);
+
+        // The file watcher path never runs the hook
+        let file = dir.path().join("a.json");
+        fs::write(&file, r#"{"working_dir":"/work/b","timestamp":0}"#).unwrap();
+        tracker.update_from_file(&file).unwrap();
+        assert_eq!(fs::read_to_string(&runs).unwrap().lines().count(), 1);
+    }
+
     #[test]
     #[ignore] // Run with: cargo test test_read_live_activity -- --ignored
     fn test_read_live_activity_file() {
@@ -531,7 +1064,7 @@ mod tests {
 
         // The session name would be derived from the branch
         let session_name = "close-a-claude-code-session-or-zellij-session";
-        let result = tracker.get_activity_for_session(session_name);
+        let result = tracker.get_activity_for_session(session_name, None);
 
         println!("Activity state: {:?}", result.state);
         println!("Context percentage: {:?}", result.context_percentage);
@@ -0,0 +1,154 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// Flags vibe sets itself to pick plan mode vs. skipped permissions.
+/// Dropped from the project flags so that choice stays with vibe.
+const MODE_FLAGS: [&str; 2] = ["--permission-mode", "--dangerously-skip-permissions"];
+
+/// Per-project Claude settings, read from `~/.vibe/projects/{project}/claude.yaml`:
+///
+/// ```yaml
+/// flags: ["--model", "opus", "--mcp-config", "/path/to/mcp.json"]
+/// prompt_wrapper: |
+///   Follow docs/CONVENTIONS.md.
+///
+///   {context}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ClaudeConfig {
+    /// Extra arguments passed to `claude` verbatim, after vibe's own
+    pub flags: Vec<String>,
+    /// Wraps the generated task prompt; `{context}` marks where it goes,
+    /// otherwise the wrapper is put in front of it
+    pub prompt_wrapper: Option<String>,
+}
+
+impl ClaudeConfig {
+    pub fn path(project_name: &str) -> Option<PathBuf> {
+        dirs::home_dir().map(|home| {
+            home.join(".vibe")
+                .join("projects")
+                .join(project_name)
+                .join("claude.yaml")
+        })
+    }
+
+    /// Load the project's config; a missing file means no extra settings
+    pub fn load(project_name: &str) -> Result<Self> {
+        match Self::path(project_name) {
+            Some(path) if path.is_file() => Self::load_from(&path),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    fn load_from(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read Claude config: {:?}", path))?;
+        if content.trim().is_empty() {
+            return Ok(Self::default());
+        }
+        serde_yaml::from_str(&content).with_context(|| format!("Invalid Claude config: {:?}", path))
+    }
+
+    /// Project flags minus the mode flags vibe controls (and their values)
+    pub fn extra_flags(&self) -> Vec<&str> {
+        let mut flags = Vec::new();
+        let mut args = self.flags.iter();
+        while let Some(arg) = args.next() {
+            let name = arg.split('=').next().unwrap_or(arg);
+            if !MODE_FLAGS.contains(&name) {
+                flags.push(arg.as_str());
+                continue;
+            }
+            tracing::warn!("Ignoring {} in claude.yaml: vibe picks the mode", arg);
+            if name == "--permission-mode" && !arg.contains('=') {
+                args.next();
+            }
+        }
+        flags
+    }
+
+    /// The task prompt with the project's wrapper applied
+    pub fn wrap_prompt(&self, context: &str) -> String {
+        match self.prompt_wrapper.as_deref().map(str::trim_end) {
+            Some(wrapper) if wrapper.contains("{context}") => wrapper.replace("{context}", context),
+            Some(wrapper) if !wrapper.is_empty() => format!("{}\n\n{}", wrapper, context),
+            _ => context.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_claude_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("claude.yaml");
+
+        std::fs::write(
+            &path,
+            "flags: [--model, opus, --allowedTools, \"Bash(git:*)\"]\nprompt_wrapper: \"Be brief.\"\n",
+        )
+        .unwrap();
+        let config = ClaudeConfig::load_from(&path).unwrap();
+        assert_eq!(
+            config.extra_flags(),
+            vec!["--model", "opus", "--allowedTools", "Bash(git:*)"]
+        );
+        assert_eq!(config.prompt_wrapper.as_deref(), Some("Be brief."));
+
+        std::fs::write(&path, "").unwrap();
+        assert_eq!(
+            ClaudeConfig::load_from(&path).unwrap(),
+            ClaudeConfig::default()
+        );
+
+        std::fs::write(&path, "flags: --model\n").unwrap();
+        assert!(ClaudeConfig::load_from(&path).is_err());
+    }
+
+    #[test]
+    fn test_extra_flags_keep_unknown_and_drop_mode_flags() {
+        let config = ClaudeConfig {
+            flags: [
+                "--some-future-flag",
+                "--permission-mode",
+                "acceptEdits",
+                "--dangerously-skip-permissions",
+                "--permission-mode=plan",
+                "--mcp-config",
+                "mcp.json",
+            ]
+            .map(String::from)
+            .to_vec(),
+            prompt_wrapper: None,
+        };
+        assert_eq!(
+            config.extra_flags(),
+            vec!["--some-future-flag", "--mcp-config", "mcp.json"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_prompt() {
+        let wrap = |wrapper: Option<&str>| {
+            ClaudeConfig {
+                flags: Vec::new(),
+                prompt_wrapper: wrapper.map(String::from),
+            }
+            .wrap_prompt("Task: X")
+        };
+        assert_eq!(wrap(None), "Task: X");
+        assert_eq!(wrap(Some("")), "Task: X");
+        assert_eq!(wrap(Some("Be brief.\n")), "Be brief.\n\nTask: X");
+        assert_eq!(
+            wrap(Some("<task>\n{context}\n</task>")),
+            "<task>\nTask: X\n</task>"
+        );
+    }
+}
@@ -4,6 +4,44 @@ use std::path::PathBuf;
 
 use serde::Deserialize;
 
+/// Plans longer than this are cut off; the rest stays readable in the editor
+const MAX_PLAN_LINES: usize = 10_000;
+/// Byte cap on top of the line cap, for plans with very long lines
+const MAX_PLAN_BYTES: usize = 2 * 1024 * 1024;
+
+/// A plan loaded for display, split into lines once so scrolling
+/// doesn't re-split the whole file every frame
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlanContent {
+    pub lines: Vec<String>,
+    /// Checked and total checkboxes over the loaded lines
+    pub checkbox_progress: Option<(usize, usize)>,
+    /// The file went past the caps and only its start was loaded
+    pub truncated: bool,
+}
+
+impl PlanContent {
+    /// Read lines until EOF or the caps are hit
+    pub fn read(reader: impl BufRead) -> Self {
+        let mut lines = Vec::new();
+        let mut bytes = 0;
+        let mut truncated = false;
+        for line in reader.lines().map_while(Result::ok) {
+            if lines.len() == MAX_PLAN_LINES || bytes + line.len() > MAX_PLAN_BYTES {
+                truncated = true;
+                break;
+            }
+            bytes += line.len() + 1;
+            lines.push(line);
+        }
+        Self {
+            checkbox_progress: plan_checkbox_progress(lines.iter().map(String::as_str)),
+            lines,
+            truncated,
+        }
+    }
+}
+
 /// Reads Claude Code plans from session files.
 ///
 /// Claude Code stores plans in `~/.claude/plans/{slug}.md` where `slug` is the session slug
@@ -36,12 +74,6 @@ impl ClaudePlanReader {
         }
     }
 
-    /// Find the plan for a specific branch in a project.
-    pub fn find_plan_for_branch(&self, project_path: &str, branch: &str) -> Option<String> {
-        let plan_path = self.find_plan_path_for_branch(project_path, branch)?;
-        self.read_plan_file(&plan_path)
-    }
-
     /// Check if a plan exists for a specific branch without reading its content.
     pub fn has_plan_for_branch(&self, project_path: &str, branch: &str) -> bool {
         self.find_plan_path_for_branch(project_path, branch)
@@ -79,7 +111,7 @@ impl ClaudePlanReader {
             .collect();
 
         // Sort by modification time, newest first
-        session_files.sort_by(|a, b| b.1.cmp(&a.1));
+        session_files.sort_by_key(|f| std::cmp::Reverse(f.1));
 
         // Check sessions from newest to oldest
         for (path, _) in session_files {
@@ -142,14 +174,10 @@ impl ClaudePlanReader {
         result
     }
 
-    /// Read the content of a plan file.
-    fn read_plan_file(&self, path: &str) -> Option<String> {
-        let plan_path = PathBuf::from(path);
-        if plan_path.exists() {
-            fs::read_to_string(&plan_path).ok()
-        } else {
-            None
-        }
+    /// Read a plan file, up to the size caps.
+    pub fn read_plan_file(&self, path: &str) -> Option<PlanContent> {
+        let file = fs::File::open(path).ok()?;
+        Some(PlanContent::read(BufReader::new(file)))
     }
 }
 
@@ -159,6 +187,42 @@ impl Default for ClaudePlanReader {
     }
 }
 
+/// Checked and total markdown checkboxes (`- [ ]` / `- [x]`) in a plan,
+/// or None when it has none
+fn plan_checkbox_progress<'a>(lines: impl IntoIterator<Item = &'a str>) -> Option<(usize, usize)> {
+    let mut done = 0;
+    let mut total = 0;
+    for line in lines {
+        let item = line.trim_start();
+        // Bullets ("-", "*", "+") and numbered items ("1." / "1)")
+        let rest = match item.strip_prefix(['-', '*', '+']) {
+            Some(rest) => rest,
+            None => {
+                let digits = item.trim_start_matches(|c: char| c.is_ascii_digit());
+                if digits.len() == item.len() {
+                    continue;
+                }
+                match digits.strip_prefix(['.', ')']) {
+                    Some(rest) => rest,
+                    None => continue,
+                }
+            }
+        };
+        let Some(rest) = rest.strip_prefix(' ') else {
+            continue;
+        };
+        match rest.trim_start().get(..3) {
+            Some("[ ]") => total += 1,
+            Some("[x]" | "[X]") => {
+                done += 1;
+                total += 1;
+            }
+            _ => {}
+        }
+    }
+    (total > 0).then_some((done, total))
+}
+
 /// Sanitize a project path to match Claude Code's directory naming.
 /// Claude replaces path separators and dots with dashes.
 fn sanitize_project_path(path: &str) -> String {
@@ -186,6 +250,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_plan_checkbox_progress() {
+        let plan = "# Plan\n\n- [x] Parse input\n- [ ] Wire UI\n  * [X] nested\n1. [ ] numbered\n- not a task [ ]\n-[x] no space\n";
+        assert_eq!(plan_checkbox_progress(plan.lines()), Some((2, 4)));
+        assert_eq!(
+            plan_checkbox_progress("# Plan\n\nJust prose.".lines()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_plan_content_is_capped() {
+        let plan = PlanContent::read("# Plan\n- [x] one\n- [ ] two\n".as_bytes());
+        assert_eq!(plan.lines, vec!["# Plan", "- [x] one", "- [ ] two"]);
+        assert_eq!(plan.checkbox_progress, Some((1, 2)));
+        assert!(!plan.truncated);
+
+        let huge = "- [ ] step\n".repeat(MAX_PLAN_LINES + 5);
+        let plan = PlanContent::read(huge.as_bytes());
+        assert_eq!(plan.lines.len(), MAX_PLAN_LINES);
+        assert_eq!(plan.checkbox_progress, Some((0, MAX_PLAN_LINES)));
+        assert!(plan.truncated);
+
+        let wide = format!("{}\n{}\n", "a".repeat(MAX_PLAN_BYTES - 10), "b".repeat(20));
+        let plan = PlanContent::read(wide.as_bytes());
+        assert_eq!(plan.lines.len(), 1);
+        assert!(plan.truncated);
+    }
+
     #[test]
     fn test_reader_creation() {
         let reader = ClaudePlanReader::new();
@@ -236,12 +329,17 @@ mod tests {
         }
 
         // Try to find the plan
-        let plan = reader.find_plan_for_branch(worktree_path, branch);
+        let plan = reader
+            .find_plan_path_for_branch(worktree_path, branch)
+            .and_then(|path| reader.read_plan_file(&path));
         println!("\nResult:");
         println!("  Plan found: {}", plan.is_some());
         if let Some(content) = &plan {
-            println!("  Plan length: {} chars", content.len());
-            println!("  First 200 chars:\n{}", &content[..content.len().min(200)]);
+            println!("  Plan length: {} lines", content.lines.len());
+            println!(
+                "  First lines:\n{}",
+                content.lines[..content.lines.len().min(5)].join("\n")
+            );
         }
 
         assert!(plan.is_some(), "Should find a plan for the current session");
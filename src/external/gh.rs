@@ -1,7 +1,14 @@
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use std::collections::HashMap;
-use std::process::Command;
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::timed;
 
 /// PR review from a user
 #[derive(Debug, Clone, Deserialize)]
@@ -32,6 +39,14 @@ pub struct BranchPrInfo {
     pub mergeable: Option<String>, // MERGEABLE, CONFLICTING, UNKNOWN
     #[serde(default)]
     pub reviews: Vec<Review>,
+    /// Branch the PR merges into
+    #[serde(rename = "baseRefName", default)]
+    pub base_ref_name: Option<String>,
+    #[serde(default)]
+    pub author: Option<ReviewAuthor>,
+    /// Whether `author` is the configured GitHub user, set when the PR is put on the board
+    #[serde(skip)]
+    pub mine: bool,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -40,22 +55,63 @@ pub struct StatusCheck {
     pub _typename: String,
     pub conclusion: Option<String>, // SUCCESS, FAILURE, etc.
     pub status: Option<String>,     // COMPLETED, IN_PROGRESS, etc.
+    /// Check run name, or the context for commit statuses
+    #[serde(default, alias = "context")]
+    pub name: Option<String>,
+    #[serde(default, rename = "workflowName")]
+    pub workflow_name: Option<String>,
+}
+
+/// Rolled-up CI state of a PR, see [`BranchPrInfo::checks_status`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksStatus {
+    /// No checks reported: CI isn't configured for the PR
+    None,
+    Pending,
+    Success,
+    Failure,
+}
+
+impl StatusCheck {
+    fn is_failure(&self) -> bool {
+        matches!(
+            self.conclusion.as_deref(),
+            Some("FAILURE") | Some("ERROR") | Some("TIMED_OUT")
+        )
+    }
 }
 
 impl BranchPrInfo {
-    /// Get overall checks status: SUCCESS, FAILURE, PENDING, or None
-    pub fn checks_status(&self) -> Option<String> {
-        let checks = self.status_check_rollup.as_ref()?;
-        if checks.is_empty() {
-            return None;
-        }
+    /// Whether the PR was opened by `login` (GitHub logins are case-insensitive)
+    pub fn is_authored_by(&self, login: &str) -> bool {
+        self.author
+            .as_ref()
+            .is_some_and(|a| a.login.eq_ignore_ascii_case(login))
+    }
+
+    /// The base branch, unless it's the repository default (or the default is unknown)
+    pub fn non_default_base(&self, default_branch: Option<&str>) -> Option<&str> {
+        let base = self.base_ref_name.as_deref()?;
+        (default_branch? != base).then_some(base)
+    }
+
+    /// Overall checks status, with `ChecksStatus::None` when the PR has no checks
+    /// at all (CI not set up) rather than checks that passed
+    pub fn checks_status(&self) -> ChecksStatus {
+        let checks = match self.status_check_rollup.as_deref() {
+            Some(checks) if !checks.is_empty() => checks,
+            _ => return ChecksStatus::None,
+        };
 
         let mut has_failure = false;
         let mut has_pending = false;
 
         for check in checks {
+            if check.is_failure() {
+                has_failure = true;
+                continue;
+            }
             match check.conclusion.as_deref() {
-                Some("FAILURE") | Some("ERROR") | Some("TIMED_OUT") => has_failure = true,
                 Some("SUCCESS") | Some("NEUTRAL") | Some("SKIPPED") => {}
                 _ => {
                     if check.status.as_deref() != Some("COMPLETED") {
@@ -66,14 +122,31 @@ impl BranchPrInfo {
         }
 
         if has_failure {
-            Some("FAILURE".to_string())
+            ChecksStatus::Failure
         } else if has_pending {
-            Some("PENDING".to_string())
+            ChecksStatus::Pending
         } else {
-            Some("SUCCESS".to_string())
+            ChecksStatus::Success
         }
     }
 
+    /// Name of the first failing check, e.g. "test-suite (ci)" when the
+    /// check belongs to a workflow. None unless some check failed.
+    pub fn first_failing_check(&self) -> Option<String> {
+        let check = self
+            .status_check_rollup
+            .as_ref()?
+            .iter()
+            .find(|c| c.is_failure())?;
+        let name = check.name.as_deref().unwrap_or("unknown");
+        Some(match check.workflow_name.as_deref() {
+            Some(workflow) if !workflow.is_empty() && workflow != name => {
+                format!("{} ({})", name, workflow)
+            }
+            _ => name.to_string(),
+        })
+    }
+
     /// Check if PR has merge conflicts
     pub fn has_conflicts(&self) -> bool {
         self.mergeable.as_deref() == Some("CONFLICTING")
@@ -104,17 +177,211 @@ struct GraphQLError {
 #[derive(Debug, Deserialize)]
 struct GraphQLData {
     repository: Option<GraphQLRepository>,
+    viewer: Option<GraphQLAuthor>,
+    #[serde(rename = "rateLimit")]
+    rate_limit: Option<RateLimit>,
+}
+
+/// Rate-limit budget: GitHub GraphQL for the authenticated user, or Linear's
+/// from its response headers
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct RateLimit {
+    pub limit: u64,
+    pub remaining: u64,
+    #[serde(rename = "resetAt")]
+    pub reset_at: DateTime<Utc>,
+}
+
+/// PR polling slows down once less than this share of the budget is left
+const RATE_LIMIT_LOW_FRACTION: f64 = 0.1;
+/// How much slower PR polling gets when the budget is low
+const RATE_LIMIT_BACKOFF: u32 = 4;
+
+impl RateLimit {
+    /// Whether the budget is nearly spent (but not reset yet)
+    pub fn is_low(&self, now: DateTime<Utc>) -> bool {
+        self.reset_at > now && (self.remaining as f64) < self.limit as f64 * RATE_LIMIT_LOW_FRACTION
+    }
+
+    /// Whether the budget is spent and the reset is still ahead
+    pub fn is_exhausted(&self, now: DateTime<Utc>) -> bool {
+        self.remaining == 0 && self.reset_at > now
+    }
+
+    /// Time left until the budget resets
+    pub fn resets_in(&self, now: DateTime<Utc>) -> Duration {
+        (self.reset_at - now).to_std().unwrap_or_default()
+    }
+
+    /// Delay before the next PR poll: `base` normally, longer when the budget
+    /// is low, and until the reset once it is spent
+    pub fn poll_delay(&self, base: Duration, now: DateTime<Utc>) -> Duration {
+        if self.is_exhausted(now) {
+            self.resets_in(now)
+        } else if self.is_low(now) {
+            base * RATE_LIMIT_BACKOFF
+        } else {
+            base
+        }
+    }
+}
+
+/// Env var overriding how long a `gh` call may run, in seconds
+pub const GH_TIMEOUT_ENV: &str = "VIBE_GH_TIMEOUT_SECS";
+const DEFAULT_GH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Timeout from `VIBE_GH_TIMEOUT_SECS`, or 30s
+fn gh_timeout() -> Duration {
+    std::env::var(GH_TIMEOUT_ENV)
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_GH_TIMEOUT)
+}
+
+/// A command that was still running at its deadline and got killed
+#[derive(Debug)]
+pub struct CommandTimeout {
+    pub program: String,
+    pub after: Duration,
+}
+
+impl std::fmt::Display for CommandTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} timed out after {:?}", self.program, self.after)
+    }
+}
+
+impl std::error::Error for CommandTimeout {}
+
+/// Like `cmd.output()`, but kills the process once it runs longer than `timeout`
+/// and fails with [`CommandTimeout`]. stdin is closed so an auth prompt fails
+/// instead of waiting, and output is drained while waiting so a large response
+/// can't fill the pipe and stall the child.
+fn output_with_timeout(cmd: &mut Command, timeout: Duration) -> Result<Output> {
+    let program = cmd.get_program().to_string_lossy().into_owned();
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        match child.try_wait()? {
+            Some(status) => break status,
+            None if Instant::now() < deadline => std::thread::sleep(Duration::from_millis(10)),
+            None => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(CommandTimeout {
+                    program,
+                    after: timeout,
+                }
+                .into());
+            }
+        }
+    };
+    Ok(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    })
+}
+
+/// Read a child's pipe to the end on its own thread
+fn drain(pipe: Option<impl Read + Send + 'static>) -> std::thread::JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        buf
+    })
+}
+
+/// Run a `gh` command with the configured timeout
+fn gh_output(cmd: &mut Command) -> Result<Output> {
+    output_with_timeout(cmd, gh_timeout())
+}
+
+/// Whether a `gh` failure was GitHub refusing the request for rate limiting
+pub fn is_rate_limit_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("rate limit") || message.contains("rate_limited")
+}
+
+/// Current GraphQL budget from `gh api rate_limit`, which doesn't count against it.
+/// Used after a rate-limited failure, when the batch query returned no budget.
+pub fn get_graphql_rate_limit() -> Result<RateLimit> {
+    let output = timed("gh.rate_limit", "", || {
+        let output = gh_output(Command::new("gh").args(["api", "rate_limit"]))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("Failed to get rate limit: {}", stderr);
+        }
+        Ok(output)
+    })?;
+    parse_rest_rate_limit(&output.stdout)
+}
+
+fn parse_rest_rate_limit(json: &[u8]) -> Result<RateLimit> {
+    #[derive(Deserialize)]
+    struct Response {
+        resources: Resources,
+    }
+    #[derive(Deserialize)]
+    struct Resources {
+        graphql: Resource,
+    }
+    #[derive(Deserialize)]
+    struct Resource {
+        limit: u64,
+        remaining: u64,
+        /// Unix seconds
+        reset: i64,
+    }
+
+    let response: Response = serde_json::from_slice(json)?;
+    let graphql = response.resources.graphql;
+    let reset_at = DateTime::from_timestamp(graphql.reset, 0)
+        .ok_or_else(|| anyhow::anyhow!("Invalid reset time: {}", graphql.reset))?;
+    Ok(RateLimit {
+        limit: graphql.limit,
+        remaining: graphql.remaining,
+        reset_at,
+    })
 }
 
 #[derive(Debug, Deserialize)]
 struct GraphQLRepository {
+    #[serde(rename = "defaultBranchRef")]
+    default_branch_ref: Option<GraphQLRef>,
     #[serde(rename = "pullRequests")]
     pull_requests: GraphQLPullRequests,
 }
 
+#[derive(Debug, Deserialize)]
+struct GraphQLRef {
+    name: String,
+}
+
 #[derive(Debug, Deserialize)]
 struct GraphQLPullRequests {
     nodes: Vec<GraphQLPullRequest>,
+    #[serde(rename = "pageInfo")]
+    page_info: Option<GraphQLPageInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQLPageInfo {
+    #[serde(rename = "hasNextPage")]
+    has_next_page: bool,
+    #[serde(rename = "endCursor")]
+    end_cursor: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -129,6 +396,9 @@ struct GraphQLPullRequest {
     mergeable: Option<String>,
     #[serde(rename = "headRefName")]
     head_ref_name: String,
+    #[serde(rename = "baseRefName")]
+    base_ref_name: Option<String>,
+    author: Option<GraphQLAuthor>,
     reviews: GraphQLReviews,
     #[serde(rename = "statusCheckRollup")]
     status_check_rollup: Option<GraphQLStatusCheckRollup>,
@@ -167,14 +437,42 @@ struct GraphQLContext {
     // CheckRun fields
     conclusion: Option<String>,
     status: Option<String>,
+    name: Option<String>,
+    #[serde(rename = "checkSuite")]
+    check_suite: Option<GraphQLCheckSuite>,
     // StatusContext fields
     state: Option<String>,
+    context: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQLCheckSuite {
+    #[serde(rename = "workflowRun")]
+    workflow_run: Option<GraphQLWorkflowRun>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQLWorkflowRun {
+    workflow: GraphQLWorkflow,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQLWorkflow {
+    name: String,
 }
 
 const BATCH_PR_QUERY: &str = r#"
-query($owner: String!, $repo: String!) {
+query($owner: String!, $repo: String!, $after: String) {
+  rateLimit {
+    limit
+    remaining
+    resetAt
+  }
+  viewer { login }
   repository(owner: $owner, name: $repo) {
-    pullRequests(states: [OPEN, MERGED, CLOSED], first: 100, orderBy: {field: UPDATED_AT, direction: DESC}) {
+    defaultBranchRef { name }
+    pullRequests(states: [OPEN, MERGED, CLOSED], first: 100, after: $after, orderBy: {field: UPDATED_AT, direction: DESC}) {
+      pageInfo { hasNextPage endCursor }
       nodes {
         number
         url
@@ -183,6 +481,8 @@ query($owner: String!, $repo: String!) {
         reviewDecision
         mergeable
         headRefName
+        baseRefName
+        author { login }
         reviews(first: 10, states: [APPROVED, CHANGES_REQUESTED, COMMENTED]) {
           nodes {
             state
@@ -196,9 +496,12 @@ query($owner: String!, $repo: String!) {
               ... on CheckRun {
                 conclusion
                 status
+                name
+                checkSuite { workflowRun { workflow { name } } }
               }
               ... on StatusContext {
                 state
+                context
               }
             }
           }
@@ -209,23 +512,107 @@ query($owner: String!, $repo: String!) {
 }
 "#;
 
-/// Fetch all PRs (open, merged, closed) for the repository in a single GraphQL query.
-/// Returns a map from branch name to PR info.
-///
-/// This is much more efficient than per-branch polling:
-/// - 1 API call instead of N calls for N branches
-/// - Reduces rate limit usage from N requests/poll to 1 request/poll
-///
-/// Note: Limited to 100 most recently updated PRs. For repos with more PRs,
-/// pagination would be needed (rare for active worktrees).
-pub fn get_all_open_prs() -> Result<HashMap<String, BranchPrInfo>> {
-    let start = std::time::Instant::now();
+/// Env var naming the git remote whose URL determines the GitHub repo.
+/// Useful for triangular setups (fork as `origin`, upstream as `upstream`).
+const GH_REMOTE_ENV: &str = "VIBE_GH_REMOTE";
+
+/// Env var naming the GitHub login whose PRs count as mine, e.g. `VIBE_GH_USER=octocat`.
+/// Defaults to the user `gh` is authenticated as.
+pub const GH_USER_ENV: &str = "VIBE_GH_USER";
+
+/// The configured GitHub login, else `viewer` from the last PR batch
+pub fn my_github_login(viewer: Option<String>) -> Option<String> {
+    std::env::var(GH_USER_ENV)
+        .ok()
+        .map(|login| login.trim().to_string())
+        .filter(|login| !login.is_empty())
+        .or(viewer)
+}
+
+/// Parse a git remote URL into (owner, repo).
+/// Handles `git@host:owner/repo.git`, `ssh://git@host/owner/repo.git`
+/// and `https://host/owner/repo(.git)` forms.
+pub fn parse_remote_url(url: &str) -> Option<(String, String)> {
+    let url = url.trim().trim_end_matches('/');
+    let path = if let Some((_, rest)) = url.split_once("://") {
+        // ssh://git@host[:port]/owner/repo or https://[user@]host/owner/repo
+        rest.split_once('/')?.1
+    } else {
+        // scp-like: git@host:owner/repo
+        url.split_once(':')?.1
+    };
+
+    let path = path.trim_end_matches(".git");
+    let mut parts = path.rsplitn(2, '/');
+    let repo = parts.next()?;
+    let owner = parts.next()?.rsplit('/').next()?;
+    if owner.is_empty() || repo.is_empty() {
+        return None;
+    }
+    Some((owner.to_string(), repo.to_string()))
+}
+
+/// What a resolved repo depends on: the directory vibe runs in and `VIBE_GH_REMOTE`
+#[derive(Debug, Clone, PartialEq)]
+struct RepoKey {
+    cwd: Option<PathBuf>,
+    remote: Option<String>,
+}
+
+impl RepoKey {
+    fn current() -> Self {
+        Self {
+            cwd: std::env::current_dir().ok(),
+            remote: std::env::var(GH_REMOTE_ENV).ok(),
+        }
+    }
+}
 
-    // Get owner and repo from gh CLI
-    tracing::trace!("gh api: repo view");
-    let repo_output = Command::new("gh")
-        .args(["repo", "view", "--json", "owner,name"])
-        .output()?;
+/// The last resolved `(owner, repo)`, so each refresh doesn't spawn `gh repo view`
+static RESOLVED_REPO: Mutex<Option<(RepoKey, (String, String))>> = Mutex::new(None);
+
+/// Resolve owner/name of the GitHub repo to query.
+/// Uses the remote named by `VIBE_GH_REMOTE` if set, otherwise defers to `gh repo view`.
+/// The answer is reused until the working directory or the remote changes.
+fn resolve_repo() -> Result<(String, String)> {
+    let key = RepoKey::current();
+    if let Some((cached_key, repo)) = RESOLVED_REPO.lock().unwrap().as_ref()
+        && *cached_key == key
+    {
+        return Ok(repo.clone());
+    }
+
+    let repo = timed("gh.resolve_repo", "", lookup_repo)?;
+    *RESOLVED_REPO.lock().unwrap() = Some((key, repo.clone()));
+    Ok(repo)
+}
+
+/// Drop the cached repo so the next call looks it up again
+fn forget_repo() {
+    *RESOLVED_REPO.lock().unwrap() = None;
+}
+
+/// Whether a GraphQL failure means the queried repository doesn't exist (renamed,
+/// transferred or deleted since it was resolved)
+fn is_repo_not_found(message: &str) -> bool {
+    message.contains("Could not resolve to a Repository")
+}
+
+fn lookup_repo() -> Result<(String, String)> {
+    if let Ok(remote) = std::env::var(GH_REMOTE_ENV) {
+        let output = Command::new("git")
+            .args(["remote", "get-url", &remote])
+            .output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("Failed to get URL for remote '{}': {}", remote, stderr);
+        }
+        let url = String::from_utf8_lossy(&output.stdout);
+        return parse_remote_url(&url)
+            .ok_or_else(|| anyhow::anyhow!("Cannot parse remote URL: {}", url.trim()));
+    }
+
+    let repo_output = gh_output(Command::new("gh").args(["repo", "view", "--json", "owner,name"]))?;
 
     if !repo_output.status.success() {
         let stderr = String::from_utf8_lossy(&repo_output.stderr);
@@ -243,20 +630,92 @@ pub fn get_all_open_prs() -> Result<HashMap<String, BranchPrInfo>> {
     }
 
     let repo_info: RepoInfo = serde_json::from_slice(&repo_output.stdout)?;
-    let owner = repo_info.owner.login;
-    let repo = repo_info.name;
-    tracing::trace!(
-        "gh api: repo view done in {:?} - {}/{}",
-        start.elapsed(),
-        owner,
-        repo
-    );
+    Ok((repo_info.owner.login, repo_info.name))
+}
+
+/// Fetch all PRs (open, merged, closed) for the repository in a single GraphQL query.
+/// Returns a map from branch name to PR info.
+///
+/// This is much more efficient than per-branch polling:
+/// - 1 API call instead of N calls for N branches
+/// - Reduces rate limit usage from N requests/poll to 1 request/poll
+///
+/// Pages through the most recently updated PRs 100 at a time, up to
+/// `VIBE_GH_MAX_PRS` (300 by default).
+pub fn get_all_open_prs() -> Result<HashMap<String, BranchPrInfo>> {
+    get_pr_batch().map(|batch| batch.prs)
+}
+
+/// Result of the batch PR query
+#[derive(Debug, Default)]
+pub struct PrBatch {
+    /// PR info by head branch
+    pub prs: HashMap<String, BranchPrInfo>,
+    /// GraphQL budget left after the query
+    pub rate_limit: Option<RateLimit>,
+    /// The repository's default branch, which PRs normally target
+    pub default_branch: Option<String>,
+    /// Login of the user `gh` is authenticated as
+    pub viewer: Option<String>,
+}
+
+/// Env var capping how many PRs the batch query pages through
+pub const GH_MAX_PRS_ENV: &str = "VIBE_GH_MAX_PRS";
+const DEFAULT_MAX_PRS: usize = 300;
+
+/// PR cap from `VIBE_GH_MAX_PRS`, or 300
+fn max_prs() -> usize {
+    std::env::var(GH_MAX_PRS_ENV)
+        .ok()
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .filter(|max| *max > 0)
+        .unwrap_or(DEFAULT_MAX_PRS)
+}
+
+/// Like [`get_all_open_prs`], also returning the rate limit and default branch
+pub fn get_pr_batch() -> Result<PrBatch> {
+    let (mut owner, mut repo) = resolve_repo()?;
+    let max = max_prs();
+
+    let mut first = fetch_pr_page(&owner, &repo, None);
+    if let Err(e) = &first
+        && is_repo_not_found(&format!("{:#}", e))
+    {
+        // The cached repo went stale; look it up again once
+        forget_repo();
+        (owner, repo) = resolve_repo()?;
+        first = fetch_pr_page(&owner, &repo, None);
+    }
+
+    let mut batch = PrBatch::default();
+    let mut fetched = 0;
+    let mut page = first?;
+    while let Some(data) = page {
+        let (count, next) = merge_pr_page(&mut batch, data);
+        fetched += count;
+        match next {
+            Some(next) if fetched < max => page = fetch_pr_page(&owner, &repo, Some(&next))?,
+            Some(_) => {
+                tracing::warn!(
+                    "{}/{} has more than {} PRs; only the {} most recently updated are tracked",
+                    owner,
+                    repo,
+                    max,
+                    max
+                );
+                break;
+            }
+            None => break,
+        }
+    }
+    Ok(batch)
+}
 
-    // Execute batch GraphQL query
-    let gql_start = std::time::Instant::now();
-    tracing::trace!("gh api: graphql batch PR query for {}/{}", owner, repo);
-    let output = Command::new("gh")
-        .args([
+/// One page of the batch PR query, starting after `after`
+fn fetch_pr_page(owner: &str, repo: &str, after: Option<&str>) -> Result<Option<GraphQLData>> {
+    let output = timed("gh.graphql_prs", &format!("{}/{}", owner, repo), || {
+        let mut cmd = Command::new("gh");
+        cmd.args([
             "api",
             "graphql",
             "-f",
@@ -265,14 +724,17 @@ pub fn get_all_open_prs() -> Result<HashMap<String, BranchPrInfo>> {
             &format!("owner={}", owner),
             "-f",
             &format!("repo={}", repo),
-        ])
-        .output()?;
-    tracing::trace!("gh api: graphql done in {:?}", gql_start.elapsed());
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("GraphQL query failed: {}", stderr);
-    }
+        ]);
+        if let Some(after) = after {
+            cmd.args(["-f", &format!("after={}", after)]);
+        }
+        let output = gh_output(&mut cmd)?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("GraphQL query failed: {}", stderr);
+        }
+        Ok(output)
+    })?;
 
     let response: GraphQLResponse = serde_json::from_slice(&output.stdout)?;
 
@@ -280,17 +742,35 @@ pub fn get_all_open_prs() -> Result<HashMap<String, BranchPrInfo>> {
         let messages: Vec<_> = errors.iter().map(|e| e.message.as_str()).collect();
         anyhow::bail!("GraphQL errors: {}", messages.join(", "));
     }
+    Ok(response.data)
+}
 
-    let Some(data) = response.data else {
-        return Ok(HashMap::new());
-    };
+/// Fold one page of the batch query into `batch`. Returns how many PRs the page
+/// had and the cursor of the next page, if any.
+fn merge_pr_page(batch: &mut PrBatch, data: GraphQLData) -> (usize, Option<String>) {
+    // Every page reports the budget; the last one is the most current
+    if data.rate_limit.is_some() {
+        batch.rate_limit = data.rate_limit;
+    }
+    if let Some(viewer) = data.viewer {
+        batch.viewer = Some(viewer.login);
+    }
 
     let Some(repository) = data.repository else {
-        return Ok(HashMap::new());
+        return (0, None);
     };
+    if let Some(branch) = repository.default_branch_ref {
+        batch.default_branch = Some(branch.name);
+    }
+    let next = repository
+        .pull_requests
+        .page_info
+        .as_ref()
+        .filter(|info| info.has_next_page)
+        .and_then(|info| info.end_cursor.clone());
 
     // Convert GraphQL response to our format
-    let mut result = HashMap::new();
+    let count = repository.pull_requests.nodes.len();
     for pr in repository.pull_requests.nodes {
         let branch = pr.head_ref_name.clone();
 
@@ -314,6 +794,11 @@ pub fn get_all_open_prs() -> Result<HashMap<String, BranchPrInfo>> {
                 .nodes
                 .into_iter()
                 .map(|ctx| {
+                    let workflow_name = ctx
+                        .check_suite
+                        .and_then(|cs| cs.workflow_run)
+                        .map(|run| run.workflow.name);
+                    let name = ctx.name.or(ctx.context);
                     // StatusContext uses 'state' field, CheckRun uses 'conclusion'/'status'
                     let (conclusion, status) = if ctx.typename == "StatusContext" {
                         // Map StatusContext state to conclusion format
@@ -331,6 +816,8 @@ pub fn get_all_open_prs() -> Result<HashMap<String, BranchPrInfo>> {
                         _typename: ctx.typename,
                         conclusion,
                         status,
+                        name,
+                        workflow_name,
                     }
                 })
                 .collect()
@@ -345,46 +832,122 @@ pub fn get_all_open_prs() -> Result<HashMap<String, BranchPrInfo>> {
             status_check_rollup,
             mergeable: pr.mergeable,
             reviews,
+            base_ref_name: pr.base_ref_name,
+            author: pr.author.map(|a| ReviewAuthor { login: a.login }),
+            mine: false,
         };
 
-        result.insert(branch, pr_info);
+        // Pages run newest first: a reused branch keeps its most recent PR
+        batch.prs.entry(branch).or_insert(pr_info);
     }
 
-    Ok(result)
+    (count, next)
 }
 
 /// Get PR info for a specific branch using `gh pr view`
 /// Returns None if no PR exists for the branch
 pub fn get_pr_for_branch(branch: &str) -> Result<Option<BranchPrInfo>> {
-    let start = std::time::Instant::now();
-    tracing::trace!("gh api: pr view {}", branch);
+    timed("gh.pr_view", branch, || pr_view(branch))
+}
 
-    let output = Command::new("gh")
-        .args([
-            "pr",
-            "view",
-            branch,
-            "--json",
-            "number,url,state,isDraft,reviewDecision,statusCheckRollup,mergeable,reviews",
-        ])
-        .output()?;
+/// Unified diff of a PR (number, URL or branch), without color codes
+pub fn get_pr_diff(pr: &str) -> Result<String> {
+    timed("gh.pr_diff", pr, || {
+        let mut cmd = Command::new("gh");
+        cmd.args(["pr", "diff", pr, "--color", "never"]);
+        if std::env::var(GH_REMOTE_ENV).is_ok() && !pr.contains("://") {
+            let (owner, repo) = resolve_repo()?;
+            cmd.args(["--repo", &format!("{}/{}", owner, repo)]);
+        }
+        let output = gh_output(&mut cmd)?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("gh pr diff {} failed: {}", pr, stderr.trim());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    })
+}
+
+/// A review verdict for `gh pr review`
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrReview {
+    Approve,
+    /// Request changes, with the review body explaining them
+    RequestChanges(String),
+}
+
+/// Submit a review on a PR (number, URL or branch). GitHub refuses reviews on
+/// your own PRs; that error comes back as a short message.
+pub fn submit_pr_review(pr: &str, review: &PrReview) -> Result<()> {
+    timed("gh.pr_review", pr, || {
+        let mut cmd = Command::new("gh");
+        cmd.args(["pr", "review", pr]);
+        match review {
+            PrReview::Approve => cmd.arg("--approve"),
+            PrReview::RequestChanges(body) => cmd.args(["--request-changes", "--body", body]),
+        };
+        if std::env::var(GH_REMOTE_ENV).is_ok() && !pr.contains("://") {
+            let (owner, repo) = resolve_repo()?;
+            cmd.args(["--repo", &format!("{}/{}", owner, repo)]);
+        }
+        let output = gh_output(&mut cmd)?;
+        if !output.status.success() {
+            anyhow::bail!(review_error(&String::from_utf8_lossy(&output.stderr)));
+        }
+        Ok(())
+    })
+}
+
+/// gh's review error, shortened for the footer
+fn review_error(stderr: &str) -> String {
+    let stderr = stderr.trim();
+    if stderr.to_lowercase().contains("your own pull request") {
+        return "can't review your own PR".to_string();
+    }
+    stderr
+        .lines()
+        .find(|l| !l.trim().is_empty())
+        .unwrap_or("gh pr review failed")
+        .trim()
+        .to_string()
+}
+
+/// Like [`get_pr_for_branch`], falling back to the PR at `url` when the branch
+/// no longer resolves, e.g. because it was deleted right after the merge
+pub fn get_pr_for_branch_or_url(branch: &str, url: Option<&str>) -> Result<Option<BranchPrInfo>> {
+    match (get_pr_for_branch(branch)?, url) {
+        (None, Some(url)) => {
+            tracing::debug!("No PR for branch {}, looking up {}", branch, url);
+            timed("gh.pr_view", url, || pr_view(url))
+        }
+        (pr, _) => Ok(pr),
+    }
+}
+
+/// `gh pr view` for a branch name, PR number or URL
+fn pr_view(branch: &str) -> Result<Option<BranchPrInfo>> {
+    let mut cmd = Command::new("gh");
+    cmd.args([
+        "pr",
+        "view",
+        branch,
+        "--json",
+        "number,url,state,isDraft,reviewDecision,statusCheckRollup,mergeable,reviews,baseRefName,author",
+    ]);
+    if std::env::var(GH_REMOTE_ENV).is_ok() {
+        let (owner, repo) = resolve_repo()?;
+        cmd.args(["--repo", &format!("{}/{}", owner, repo)]);
+    }
+    let output = gh_output(&mut cmd)?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
         if stderr.contains("no pull requests found") || stderr.contains("no open pull requests") {
-            tracing::trace!(
-                "gh api: pr view {} - no PR found ({:?})",
-                branch,
-                start.elapsed()
-            );
+            tracing::trace!("gh api: pr view {} - no PR found", branch);
             return Ok(None);
         }
         if stderr.contains("Could not resolve") {
-            tracing::trace!(
-                "gh api: pr view {} - branch not found ({:?})",
-                branch,
-                start.elapsed()
-            );
+            tracing::trace!("gh api: pr view {} - branch not found", branch);
             return Ok(None);
         }
         anyhow::bail!("gh pr view failed: {}", stderr);
@@ -393,18 +956,179 @@ pub fn get_pr_for_branch(branch: &str) -> Result<Option<BranchPrInfo>> {
     let stdout = String::from_utf8(output.stdout)?;
     let pr_info: BranchPrInfo = serde_json::from_str(&stdout)?;
     tracing::trace!(
-        "gh api: pr view {} - found PR #{} state={} ({:?})",
+        "gh api: pr view {} - found PR #{} state={}",
         branch,
         pr_info._number,
-        pr_info.state,
-        start.elapsed()
+        pr_info.state
     );
     Ok(Some(pr_info))
 }
 
+/// GitHub issue fetched from `gh issue view`
+#[derive(Debug, Clone, Deserialize)]
+pub struct GhIssue {
+    pub number: u64,
+    pub title: String,
+    #[serde(default)]
+    pub body: String,
+    pub url: String,
+    #[serde(default)]
+    pub labels: Vec<GhLabel>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GhLabel {
+    pub name: String,
+}
+
+/// Fetch an issue by number or URL (`gh issue view` accepts both)
+pub fn get_issue(issue: &str) -> Result<GhIssue> {
+    timed("gh.issue_view", issue, || {
+        let mut cmd = Command::new("gh");
+        cmd.args([
+            "issue",
+            "view",
+            issue,
+            "--json",
+            "number,title,body,url,labels",
+        ]);
+        // URLs name their repo; only numbers need the configured remote
+        if std::env::var(GH_REMOTE_ENV).is_ok() && !issue.contains("://") {
+            let (owner, repo) = resolve_repo()?;
+            cmd.args(["--repo", &format!("{}/{}", owner, repo)]);
+        }
+        let output = cmd.output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("gh issue view {} failed: {}", issue, stderr.trim());
+        }
+        Ok(serde_json::from_slice(&output.stdout)?)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::test_pr;
+
+    #[test]
+    fn test_review_error_is_short() {
+        assert_eq!(
+            review_error(
+                "failed to create review: GraphQL: Review Can not approve your own pull request (addPullRequestReview)\n"
+            ),
+            "can't review your own PR"
+        );
+        assert_eq!(
+            review_error("\nno pull requests found for branch \"x\"\nmore\n"),
+            "no pull requests found for branch \"x\""
+        );
+        assert_eq!(review_error(""), "gh pr review failed");
+    }
+
+    #[test]
+    fn test_output_with_timeout_kills_slow_commands() {
+        let start = Instant::now();
+        let err = output_with_timeout(
+            Command::new("sh").args(["-c", "sleep 5"]),
+            Duration::from_millis(100),
+        )
+        .unwrap_err();
+        assert!(start.elapsed() < Duration::from_secs(2));
+        let timeout = err.downcast_ref::<CommandTimeout>().unwrap();
+        assert_eq!(timeout.program, "sh");
+        assert_eq!(timeout.after, Duration::from_millis(100));
+
+        // More output than a pipe buffer holds still comes back whole
+        let output = output_with_timeout(
+            Command::new("sh").args(["-c", "head -c 200000 /dev/zero; echo oops >&2"]),
+            Duration::from_secs(5),
+        )
+        .unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout.len(), 200000);
+        assert_eq!(output.stderr, b"oops\n");
+    }
+
+    fn rate_limit(remaining: u64, reset_at: &str) -> RateLimit {
+        RateLimit {
+            limit: 5000,
+            remaining,
+            reset_at: reset_at.parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_poll_delay() {
+        let now: DateTime<Utc> = "2024-03-01T12:00:00Z".parse().unwrap();
+        let base = Duration::from_secs(15);
+
+        assert_eq!(
+            rate_limit(4000, "2024-03-01T12:30:00Z").poll_delay(base, now),
+            base
+        );
+        // Under 10% left: back off
+        let low = rate_limit(400, "2024-03-01T12:30:00Z");
+        assert!(low.is_low(now));
+        assert_eq!(low.poll_delay(base, now), base * 4);
+        // Spent: wait for the reset
+        let spent = rate_limit(0, "2024-03-01T12:10:00Z");
+        assert!(spent.is_exhausted(now));
+        assert_eq!(spent.poll_delay(base, now), Duration::from_secs(600));
+        // Reset already passed: the budget is back
+        let stale = rate_limit(0, "2024-03-01T11:00:00Z");
+        assert!(!stale.is_exhausted(now));
+        assert!(!stale.is_low(now));
+        assert_eq!(stale.poll_delay(base, now), base);
+    }
+
+    #[test]
+    fn test_parse_rate_limits() {
+        let json = r#"{"data": {
+            "rateLimit": {"limit": 5000, "remaining": 4321, "resetAt": "2024-03-01T12:30:00Z"},
+            "repository": null
+        }}"#;
+        let response: GraphQLResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            response.data.unwrap().rate_limit,
+            Some(rate_limit(4321, "2024-03-01T12:30:00Z"))
+        );
+
+        let rest = br#"{"resources": {
+            "core": {"limit": 5000, "remaining": 5000, "reset": 1709296200},
+            "graphql": {"limit": 5000, "remaining": 0, "reset": 1709296200}
+        }}"#;
+        assert_eq!(
+            parse_rest_rate_limit(rest).unwrap(),
+            rate_limit(0, "2024-03-01T12:30:00Z")
+        );
+
+        assert!(is_rate_limit_error(
+            "GraphQL query failed: gh: API rate limit exceeded for user ID 1."
+        ));
+        assert!(!is_rate_limit_error(
+            "GraphQL query failed: Could not resolve"
+        ));
+    }
+
+    #[test]
+    fn test_parse_gh_issue() {
+        let json = r#"{
+            "number": 42,
+            "title": "Crash on empty board",
+            "body": "Steps to reproduce...",
+            "url": "https://github.com/test/repo/issues/42",
+            "labels": [{ "id": "L1", "name": "bug", "color": "d73a4a" }]
+        }"#;
+        let issue: GhIssue = serde_json::from_str(json).unwrap();
+        assert_eq!(issue.number, 42);
+        assert_eq!(issue.labels[0].name, "bug");
+
+        let bare = r#"{"number": 7, "title": "T", "url": "u"}"#;
+        let issue: GhIssue = serde_json::from_str(bare).unwrap();
+        assert!(issue.body.is_empty());
+        assert!(issue.labels.is_empty());
+    }
 
     #[test]
     fn test_parse_graphql_pr_response() {
@@ -412,6 +1136,7 @@ mod tests {
         let json = r#"{
             "data": {
                 "repository": {
+                    "defaultBranchRef": { "name": "main" },
                     "pullRequests": {
                         "nodes": [
                             {
@@ -422,6 +1147,7 @@ mod tests {
                                 "reviewDecision": "APPROVED",
                                 "mergeable": "MERGEABLE",
                                 "headRefName": "feature-branch",
+                                "baseRefName": "release/2.1",
                                 "reviews": {
                                     "nodes": [
                                         {
@@ -460,6 +1186,8 @@ mod tests {
 
         assert_eq!(pr.number, 10);
         assert_eq!(pr.head_ref_name, "feature-branch");
+        assert_eq!(pr.base_ref_name.as_deref(), Some("release/2.1"));
+        assert_eq!(repo.default_branch_ref.as_ref().unwrap().name, "main");
         assert_eq!(pr.state, "OPEN");
         assert!(!pr.is_draft);
         assert_eq!(pr.review_decision, Some("APPROVED".to_string()));
@@ -469,6 +1197,90 @@ mod tests {
         assert_eq!(rollup.contexts.nodes.len(), 2);
     }
 
+    #[test]
+    fn test_pr_pages_merge_until_the_cursor_ends() {
+        fn page(prs: &[(i64, &str)], next: Option<&str>) -> GraphQLData {
+            let nodes: Vec<_> = prs
+                .iter()
+                .map(|(number, branch)| {
+                    serde_json::json!({
+                        "number": number,
+                        "url": format!("https://github.com/test/repo/pull/{}", number),
+                        "state": "OPEN",
+                        "isDraft": false,
+                        "reviewDecision": null,
+                        "mergeable": "MERGEABLE",
+                        "headRefName": branch,
+                        "baseRefName": "main",
+                        "reviews": { "nodes": [] },
+                        "statusCheckRollup": null
+                    })
+                })
+                .collect();
+            serde_json::from_value(serde_json::json!({
+                "viewer": { "login": "me" },
+                "repository": {
+                    "defaultBranchRef": { "name": "main" },
+                    "pullRequests": {
+                        "pageInfo": { "hasNextPage": next.is_some(), "endCursor": next },
+                        "nodes": nodes
+                    }
+                }
+            }))
+            .unwrap()
+        }
+
+        let mut batch = PrBatch::default();
+        let (count, next) = merge_pr_page(
+            &mut batch,
+            page(&[(30, "fresh-branch"), (29, "other-branch")], Some("c2")),
+        );
+        assert_eq!((count, next.as_deref()), (2, Some("c2")));
+
+        let (count, next) = merge_pr_page(&mut batch, page(&[(3, "old-branch")], None));
+        assert_eq!((count, next), (1, None));
+
+        assert_eq!(batch.prs.len(), 3);
+        assert_eq!(batch.prs["old-branch"]._number, 3);
+        assert_eq!(batch.prs["fresh-branch"]._number, 30);
+        assert_eq!(batch.default_branch.as_deref(), Some("main"));
+        assert_eq!(batch.viewer.as_deref(), Some("me"));
+    }
+
+    #[test]
+    fn test_reused_branch_keeps_its_newest_pr_across_pages() {
+        fn page(number: i64, state: &str, next: Option<&str>) -> GraphQLData {
+            serde_json::from_value(serde_json::json!({
+                "repository": {
+                    "pullRequests": {
+                        "pageInfo": { "hasNextPage": next.is_some(), "endCursor": next },
+                        "nodes": [{
+                            "number": number,
+                            "url": format!("https://github.com/test/repo/pull/{}", number),
+                            "state": state,
+                            "isDraft": false,
+                            "reviewDecision": null,
+                            "mergeable": "MERGEABLE",
+                            "headRefName": "fix-login",
+                            "baseRefName": "main",
+                            "reviews": { "nodes": [] },
+                            "statusCheckRollup": null
+                        }]
+                    }
+                }
+            }))
+            .unwrap()
+        }
+
+        let mut batch = PrBatch::default();
+        merge_pr_page(&mut batch, page(40, "OPEN", Some("c2")));
+        merge_pr_page(&mut batch, page(12, "CLOSED", None));
+
+        assert_eq!(batch.prs.len(), 1);
+        assert_eq!(batch.prs["fix-login"]._number, 40);
+        assert_eq!(batch.prs["fix-login"].state, "OPEN");
+    }
+
     #[test]
     fn test_parse_graphql_empty_response() {
         let json = r#"{
@@ -490,12 +1302,7 @@ mod tests {
     #[test]
     fn test_approvers() {
         let pr = BranchPrInfo {
-            _number: 1,
-            url: "https://github.com/test/repo/pull/1".to_string(),
-            state: "OPEN".to_string(),
-            is_draft: false,
             review_decision: Some("APPROVED".to_string()),
-            status_check_rollup: None,
             mergeable: Some("MERGEABLE".to_string()),
             reviews: vec![
                 Review {
@@ -517,6 +1324,7 @@ mod tests {
                     },
                 },
             ],
+            ..test_pr("OPEN")
         };
 
         let approvers = pr.approvers();
@@ -525,18 +1333,148 @@ mod tests {
 
     #[test]
     fn test_approvers_empty() {
+        let pr = test_pr("OPEN");
+
+        let approvers = pr.approvers();
+        assert!(approvers.is_empty());
+    }
+
+    #[test]
+    fn test_non_default_base() {
+        let pr = |base: Option<&str>| BranchPrInfo {
+            base_ref_name: base.map(String::from),
+            ..test_pr("OPEN")
+        };
+
+        assert_eq!(
+            pr(Some("release/2.1")).non_default_base(Some("main")),
+            Some("release/2.1")
+        );
+        assert_eq!(pr(Some("main")).non_default_base(Some("main")), None);
+        // Without knowing the default there's nothing to compare against
+        assert_eq!(pr(Some("release/2.1")).non_default_base(None), None);
+        assert_eq!(pr(None).non_default_base(Some("main")), None);
+    }
+
+    #[test]
+    fn test_resolved_repo_is_reused_for_the_same_directory() {
+        let key = RepoKey::current();
+        *RESOLVED_REPO.lock().unwrap() =
+            Some((key.clone(), ("cached".to_string(), "repo".to_string())));
+        assert_eq!(
+            resolve_repo().unwrap(),
+            ("cached".to_string(), "repo".to_string())
+        );
+
+        let elsewhere = RepoKey {
+            cwd: Some(PathBuf::from("/somewhere/else")),
+            ..key
+        };
+        assert_ne!(elsewhere, RepoKey::current());
+
+        forget_repo();
+        assert!(RESOLVED_REPO.lock().unwrap().is_none());
+        assert!(is_repo_not_found(
+            "GraphQL errors: Could not resolve to a Repository with the name 'o/r'."
+        ));
+        assert!(!is_repo_not_found("GraphQL errors: timeout"));
+    }
+
+    #[test]
+    fn test_parse_remote_url() {
+        let expected = Some(("piotrostr".to_string(), "vibe".to_string()));
+        assert_eq!(
+            parse_remote_url("git@github.com:piotrostr/vibe.git"),
+            expected
+        );
+        assert_eq!(parse_remote_url("git@github.com:piotrostr/vibe"), expected);
+        assert_eq!(
+            parse_remote_url("ssh://git@github.com/piotrostr/vibe.git"),
+            expected
+        );
+        assert_eq!(
+            parse_remote_url("ssh://git@github.com:22/piotrostr/vibe.git"),
+            expected
+        );
+        assert_eq!(
+            parse_remote_url("https://github.com/piotrostr/vibe.git"),
+            expected
+        );
+        assert_eq!(
+            parse_remote_url("https://github.com/piotrostr/vibe/"),
+            expected
+        );
+        assert_eq!(
+            parse_remote_url("https://user@github.com/piotrostr/vibe.git\n"),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_parse_remote_url_invalid() {
+        assert_eq!(parse_remote_url(""), None);
+        assert_eq!(parse_remote_url("not-a-url"), None);
+        assert_eq!(parse_remote_url("https://github.com/"), None);
+    }
+
+    fn check(name: &str, workflow: Option<&str>, conclusion: &str) -> StatusCheck {
+        StatusCheck {
+            _typename: "CheckRun".to_string(),
+            conclusion: Some(conclusion.to_string()),
+            status: Some("COMPLETED".to_string()),
+            name: Some(name.to_string()),
+            workflow_name: workflow.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_first_failing_check() {
+        let pr = BranchPrInfo {
+            status_check_rollup: Some(vec![
+                check("lint", Some("ci"), "SUCCESS"),
+                check("test-suite", Some("ci"), "FAILURE"),
+                check("build", None, "FAILURE"),
+            ]),
+            ..test_pr("OPEN")
+        };
+
+        assert_eq!(pr.checks_status(), ChecksStatus::Failure);
+        assert_eq!(pr.first_failing_check().as_deref(), Some("test-suite (ci)"));
+    }
+
+    #[test]
+    fn test_first_failing_check_none_when_passing() {
         let pr = BranchPrInfo {
-            _number: 1,
-            url: "https://github.com/test/repo/pull/1".to_string(),
-            state: "OPEN".to_string(),
-            is_draft: false,
-            review_decision: None,
+            status_check_rollup: Some(vec![check("build", None, "SUCCESS")]),
+            ..test_pr("OPEN")
+        };
+
+        assert_eq!(pr.checks_status(), ChecksStatus::Success);
+        assert!(pr.first_failing_check().is_none());
+
+        // An empty rollup is no CI, not a pass
+        let no_ci = BranchPrInfo {
+            status_check_rollup: Some(vec![]),
+            ..pr.clone()
+        };
+        assert_eq!(no_ci.checks_status(), ChecksStatus::None);
+        let no_rollup = BranchPrInfo {
             status_check_rollup: None,
-            mergeable: None,
-            reviews: vec![],
+            ..pr
         };
+        assert_eq!(no_rollup.checks_status(), ChecksStatus::None);
+    }
 
-        let approvers = pr.approvers();
-        assert!(approvers.is_empty());
+    #[test]
+    fn test_status_check_parses_gh_pr_view_json() {
+        let json = r#"[
+            {"__typename": "CheckRun", "name": "test-suite", "workflowName": "ci",
+             "conclusion": "FAILURE", "status": "COMPLETED"},
+            {"__typename": "StatusContext", "context": "ci/circleci", "state": "SUCCESS"}
+        ]"#;
+        let checks: Vec<StatusCheck> = serde_json::from_str(json).unwrap();
+        assert_eq!(checks[0].name.as_deref(), Some("test-suite"));
+        assert_eq!(checks[0].workflow_name.as_deref(), Some("ci"));
+        assert_eq!(checks[1].name.as_deref(), Some("ci/circleci"));
     }
 }
@@ -0,0 +1,107 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result};
+use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc;
+
+/// File watcher on the repository's HEAD and refs, so commits and branch
+/// switches made in another terminal show up without waiting for the next poll
+pub struct GitWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+impl GitWatcher {
+    /// Watch the git dir shared by all worktrees of the repository in the cwd.
+    /// Sends on every ref change; the receiver is expected to debounce.
+    pub fn new(sender: mpsc::Sender<()>) -> Result<Self> {
+        let git_dir = git_common_dir()?;
+        let root = git_dir.clone();
+        let mut watcher = RecommendedWatcher::new(
+            move |res: Result<Event, notify::Error>| {
+                if let Ok(event) = res
+                    && !matches!(event.kind, EventKind::Access(_))
+                    && event.paths.iter().any(|path| is_ref_change(&root, path))
+                {
+                    // A full channel already holds a pending change
+                    let _ = sender.try_send(());
+                }
+            },
+            Config::default(),
+        )?;
+
+        // HEAD and packed-refs are replaced by rename, so watch their directory
+        watcher.watch(&git_dir, RecursiveMode::NonRecursive)?;
+        for dir in ["refs", "worktrees"] {
+            let dir = git_dir.join(dir);
+            if dir.is_dir() {
+                watcher.watch(&dir, RecursiveMode::Recursive)?;
+            }
+        }
+
+        Ok(Self { _watcher: watcher })
+    }
+}
+
+fn git_common_dir() -> Result<PathBuf> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--path-format=absolute", "--git-common-dir"])
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git rev-parse failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(PathBuf::from(
+        String::from_utf8_lossy(&output.stdout).trim(),
+    ))
+}
+
+/// Whether a change at `path` moves HEAD or a branch: `HEAD`, `packed-refs`,
+/// anything under `refs/`, or a linked worktree's `HEAD`. Lock files are git
+/// mid-update; the rename that follows is what counts.
+fn is_ref_change(git_dir: &Path, path: &Path) -> bool {
+    let Ok(relative) = path.strip_prefix(git_dir) else {
+        return false;
+    };
+    if relative.extension().is_some_and(|ext| ext == "lock") {
+        return false;
+    }
+    let parts: Vec<_> = relative
+        .components()
+        .filter_map(|c| c.as_os_str().to_str())
+        .collect();
+    matches!(
+        parts.as_slice(),
+        ["HEAD"] | ["packed-refs"] | ["refs", ..] | ["worktrees", _, "HEAD"]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_ref_change() {
+        let git_dir = Path::new("/repo/.git");
+        let changed = |path: &str| is_ref_change(git_dir, Path::new(path));
+
+        assert!(changed("/repo/.git/HEAD"));
+        assert!(changed("/repo/.git/packed-refs"));
+        assert!(changed("/repo/.git/refs/heads/feature/login"));
+        assert!(changed("/repo/.git/refs/remotes/origin/main"));
+        assert!(changed("/repo/.git/worktrees/login/HEAD"));
+
+        // Git's own bookkeeping churns on every command
+        assert!(!changed("/repo/.git/index"));
+        assert!(!changed("/repo/.git/FETCH_HEAD"));
+        assert!(!changed("/repo/.git/ORIG_HEAD"));
+        assert!(!changed("/repo/.git/logs/HEAD"));
+        assert!(!changed("/repo/.git/worktrees/login/index"));
+        assert!(!changed("/repo/.git/HEAD.lock"));
+        assert!(!changed("/repo/.git/refs/heads/main.lock"));
+        assert!(!changed("/elsewhere/HEAD"));
+    }
+}
@@ -1,5 +1,14 @@
+use super::linear_query::{Field, connection, issue_core, issue_status, mutation, query, quote};
+use super::{RateLimit, timed_async};
+use chrono::{DateTime, Utc};
+use futures_util::future::join_all;
 use reqwest::Client;
+use reqwest::header::HeaderMap;
 use serde::Deserialize;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::sync::OnceCell;
 
 #[derive(Debug, Clone)]
 pub struct LinearIssue {
@@ -8,6 +17,10 @@ pub struct LinearIssue {
     pub description: Option<String>,
     pub url: String,
     pub labels: Vec<String>,
+    /// 0 no priority, 1 urgent, 2 high, 3 medium, 4 low
+    pub priority: Option<u8>,
+    /// Points on the team's estimate scale
+    pub estimate: Option<f64>,
 }
 
 #[derive(Debug, Clone)]
@@ -16,6 +29,91 @@ pub struct LinearIssueStatus {
     pub state_type: String, // "backlog", "unstarted", "started", "completed", "cancelled"
     #[allow(dead_code)] // Kept for debug output and future use
     pub state_name: String, // Human-readable like "In Progress"
+    /// Assignee's display name, None when unassigned
+    pub assignee: Option<String>,
+}
+
+/// `assignee { displayName }` of an issue node, None when unassigned
+fn assignee_name(issue: &serde_json::Value) -> Option<String> {
+    issue
+        .get("assignee")
+        .and_then(|a| a.get("displayName"))
+        .and_then(|v| v.as_str())
+        .map(String::from)
+}
+
+/// A workflow state on an issue's team (e.g. "Ready for QA")
+#[derive(Debug, Clone, Deserialize)]
+pub struct LinearWorkflowState {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub state_type: String,
+    #[serde(default)]
+    pub position: f64,
+}
+
+/// Find a workflow state by name (case-insensitive).
+/// On no match the error lists the states that are available.
+pub fn find_state_by_name<'a>(
+    states: &'a [LinearWorkflowState],
+    name: &str,
+) -> Result<&'a LinearWorkflowState, String> {
+    let wanted = name.trim();
+    states
+        .iter()
+        .find(|s| s.name.eq_ignore_ascii_case(wanted))
+        .ok_or_else(|| {
+            let available = states
+                .iter()
+                .map(|s| s.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("No state named '{}'. Available: {}", wanted, available)
+        })
+}
+
+/// First workflow state of a type (e.g. "canceled"), in board order.
+/// Teams name these freely ("Canceled", "Won't do"), but the type is fixed.
+/// The board's "cancelled" spelling is accepted for Linear's "canceled".
+pub fn find_state_by_type<'a>(
+    states: &'a [LinearWorkflowState],
+    state_type: &str,
+) -> Result<&'a LinearWorkflowState, String> {
+    let wanted = match state_type.trim().to_lowercase().as_str() {
+        "cancelled" => "canceled".to_string(),
+        other => other.to_string(),
+    };
+    states
+        .iter()
+        .find(|s| s.state_type == wanted)
+        .ok_or_else(|| {
+            let available = states
+                .iter()
+                .map(|s| format!("{} ({})", s.name, s.state_type))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("Team has no {} state. Available: {}", wanted, available)
+        })
+}
+
+/// A link attached to an issue (Figma file, doc, Slack thread, ...)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct LinearAttachment {
+    pub url: String,
+    #[serde(default)]
+    pub title: String,
+}
+
+impl LinearAttachment {
+    /// Title, or the URL for attachments without one
+    pub fn label(&self) -> &str {
+        if self.title.trim().is_empty() {
+            &self.url
+        } else {
+            &self.title
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -43,6 +141,56 @@ struct Viewer {
 #[derive(Debug, Deserialize)]
 struct IssueConnection {
     nodes: Vec<IssueNode>,
+    /// Only present when the query selects it (paged fetches)
+    #[serde(rename = "pageInfo")]
+    page_info: Option<PageInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PageInfo {
+    #[serde(rename = "hasNextPage")]
+    has_next_page: bool,
+    #[serde(rename = "endCursor")]
+    end_cursor: Option<String>,
+}
+
+/// Backlog issues fetched per request
+const BACKLOG_PAGE_SIZE: usize = 50;
+
+/// Stop paging after this many backlog issues, in case the cursor never ends
+const BACKLOG_MAX_ISSUES: usize = 500;
+
+/// One page of the viewer's backlog issues, starting after `cursor`
+fn backlog_page_query(cursor: Option<&str>) -> String {
+    let mut issues = connection("assignedIssues", issue_core())
+        .arg("filter", r#"{ state: { type: { eq: "backlog" } } }"#)
+        .arg("first", BACKLOG_PAGE_SIZE.to_string())
+        .select([Field::new("pageInfo").leaves(&["hasNextPage", "endCursor"])]);
+    if let Some(cursor) = cursor {
+        issues = issues.str_arg("after", cursor);
+    }
+    query([Field::new("viewer").select([issues])])
+}
+
+/// Assigned issues fetched per request
+const ASSIGNED_PAGE_SIZE: usize = 100;
+
+/// Stop paging after this many assigned issues, in case the cursor never ends
+const ASSIGNED_MAX_ISSUES: usize = 1000;
+
+/// One page of the viewer's non-canceled assigned issues, most recently updated first
+fn assigned_page_query(cursor: Option<&str>) -> String {
+    let mut fields = issue_core();
+    fields.push(Field::new("state").leaves(&["name", "type"]));
+    let mut issues = connection("assignedIssues", fields)
+        .arg("filter", r#"{ state: { type: { neq: "canceled" } } }"#)
+        .arg("orderBy", "updatedAt")
+        .arg("first", ASSIGNED_PAGE_SIZE.to_string())
+        .select([Field::new("pageInfo").leaves(&["hasNextPage", "endCursor"])]);
+    if let Some(cursor) = cursor {
+        issues = issues.str_arg("after", cursor);
+    }
+    query([Field::new("viewer").select([issues])])
 }
 
 #[derive(Debug, Deserialize)]
@@ -53,8 +201,39 @@ struct IssueNode {
     url: String,
     labels: Option<LabelConnection>,
     state: Option<StateNode>,
+    // Linear sends both as floats
+    #[serde(default)]
+    priority: Option<f64>,
+    #[serde(default)]
+    estimate: Option<f64>,
+}
+
+impl IssueNode {
+    fn priority(&self) -> Option<u8> {
+        self.priority
+            .filter(|p| (0.0..=4.0).contains(p))
+            .map(|p| p as u8)
+    }
+
+    fn into_issue(self) -> LinearIssue {
+        LinearIssue {
+            priority: self.priority(),
+            estimate: self.estimate,
+            identifier: self.identifier,
+            title: self.title,
+            description: self.description,
+            url: self.url,
+            labels: self
+                .labels
+                .map(|l| l.nodes.into_iter().map(|n| n.name).collect())
+                .unwrap_or_default(),
+        }
+    }
 }
 
+/// Most matches `LinearClient::search_issues` returns
+const SEARCH_LIMIT: usize = 20;
+
 #[derive(Debug, Deserialize)]
 struct StateNode {
     name: String,
@@ -72,149 +251,636 @@ struct LabelNode {
     name: String,
 }
 
+/// Label marking issues that are ready to be gassed
+pub const GASIT_LABEL: &str = "~gasit";
+
+/// An issue label, either scoped to a team or shared by the whole workspace
+#[derive(Debug, Clone, Deserialize)]
+pub struct LinearLabel {
+    pub id: String,
+    pub name: String,
+    pub team: Option<LinearLabelTeam>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LinearLabelTeam {
+    pub id: String,
+}
+
+/// Outcome of `LinearClient::ensure_team_label`
+#[derive(Debug, Clone)]
+pub struct EnsuredLabel {
+    pub name: String,
+    /// False when the label already existed
+    pub created: bool,
+}
+
+/// Find a label named `name` (case-insensitive) usable on `team_id`:
+/// one on that team, or a workspace label with no team.
+pub fn find_label_for_team<'a>(
+    labels: &'a [LinearLabel],
+    name: &str,
+    team_id: &str,
+) -> Option<&'a LinearLabel> {
+    labels.iter().find(|label| {
+        label.name.eq_ignore_ascii_case(name)
+            && label.team.as_ref().is_none_or(|team| team.id == team_id)
+    })
+}
+
+/// Look up each of `names` (case-insensitively) among the labels usable on `team_id`.
+/// Unknown names are an error listing every label the team can use.
+pub fn resolve_labels<'a>(
+    labels: &'a [LinearLabel],
+    names: &[String],
+    team_id: &str,
+) -> Result<Vec<&'a LinearLabel>, String> {
+    let mut found = Vec::new();
+    let mut missing = Vec::new();
+    for name in names {
+        match find_label_for_team(labels, name, team_id) {
+            Some(label) => found.push(label),
+            None => missing.push(name.as_str()),
+        }
+    }
+    if missing.is_empty() {
+        return Ok(found);
+    }
+
+    let mut valid: Vec<&str> = labels
+        .iter()
+        .filter(|label| label.team.as_ref().is_none_or(|team| team.id == team_id))
+        .map(|label| label.name.as_str())
+        .collect();
+    valid.sort_unstable_by_key(|name| name.to_lowercase());
+    valid.dedup();
+    Err(format!(
+        "unknown label{} {}; valid labels: {}",
+        if missing.len() == 1 { "" } else { "s" },
+        missing.join(", "),
+        if valid.is_empty() {
+            "none".to_string()
+        } else {
+            valid.join(", ")
+        }
+    ))
+}
+
+/// Aliased issue lookups sent per request by `fetch_issue_statuses`
+const STATUS_CHUNK_SIZE: usize = 40;
+
+/// One query with an alias per identifier,
+/// e.g. `query { i0: issue(id: "VIB-5") { ... } i1: issue(id: "VIB-6") { ... } }`
+fn issue_statuses_query(identifiers: &[String]) -> String {
+    query(identifiers.iter().enumerate().map(|(i, id)| {
+        Field::new("issue")
+            .alias(format!("i{}", i))
+            .str_arg("id", id)
+            .select(issue_status())
+    }))
+}
+
+/// Whether a single-issue lookup (aliased `i0`) came back empty because Linear has no
+/// such issue: no data for it, and only entity-not-found errors. Deleting local tasks
+/// hangs on this, so any other error (or an unknown code) means "can't tell".
+fn issue_is_gone(json: &serde_json::Value) -> bool {
+    let data_missing = json
+        .get("data")
+        .and_then(|d| d.get("i0"))
+        .is_none_or(|issue| issue.is_null());
+    let errors = json.get("errors").and_then(|e| e.as_array());
+    data_missing
+        && errors.is_some_and(|errors| {
+            !errors.is_empty()
+                && errors.iter().all(|e| {
+                    e.pointer("/extensions/code").and_then(|c| c.as_str())
+                        == Some(ENTITY_NOT_FOUND_CODE)
+                })
+        })
+}
+
+/// Read the `i0..i{count}` aliases back; issues that weren't found come back null and are skipped
+fn parse_issue_statuses(
+    data: &serde_json::Value,
+    count: usize,
+) -> Result<Vec<LinearIssueStatus>, String> {
+    let mut statuses = Vec::new();
+    for i in 0..count {
+        let key = format!("i{}", i);
+        if let Some(issue) = data.get(&key) {
+            // issue can be null if not found
+            if issue.is_null() {
+                continue;
+            }
+            let identifier = issue
+                .get("identifier")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+            let state = issue.get("state").ok_or("Missing state field")?;
+            let state_name = state
+                .get("name")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+            let state_type = state
+                .get("type")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+
+            statuses.push(LinearIssueStatus {
+                identifier,
+                state_type,
+                state_name,
+                assignee: assignee_name(issue),
+            });
+        }
+    }
+
+    Ok(statuses)
+}
+
+/// A team the user belongs to; `key` is the issue prefix (VIB in VIB-7)
+#[derive(Debug, Clone, Deserialize)]
+pub struct LinearTeam {
+    pub id: String,
+    pub key: String,
+    pub name: String,
+}
+
+/// The team keyed `team_key` (case-insensitive). Without a key the user's only team
+/// is used; with several, guessing would file issues in the wrong one, so it's an error.
+pub fn pick_team<'a>(
+    teams: &'a [LinearTeam],
+    team_key: Option<&str>,
+) -> Result<&'a LinearTeam, String> {
+    let keys = || {
+        teams
+            .iter()
+            .map(|team| format!("{} ({})", team.key, team.name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    match team_key {
+        Some(key) => teams
+            .iter()
+            .find(|team| team.key.eq_ignore_ascii_case(key))
+            .ok_or_else(|| format!("no Linear team with key {}; your teams: {}", key, keys())),
+        None => match teams {
+            [] => Err("No teams found".to_string()),
+            [team] => Ok(team),
+            _ => Err(format!(
+                "you're in several Linear teams ({}); pick one with --team <KEY>",
+                keys()
+            )),
+        },
+    }
+}
+
 /// Result of creating an issue
 #[derive(Debug, Clone)]
 pub struct CreatedIssue {
     pub identifier: String,
     pub url: String,
+    /// Names of the attached labels, as spelled in Linear
+    pub labels: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct LinearBoardIssue {
     pub identifier: String,
     pub title: String,
+    pub description: Option<String>,
+    pub url: String,
+    pub labels: Vec<String>,
+    pub priority: Option<u8>,
+    pub estimate: Option<f64>,
     pub state_name: String,
     pub state_type: String, // backlog, unstarted, started, completed, cancelled
 }
 
+impl LinearBoardIssue {
+    pub fn to_issue(&self) -> LinearIssue {
+        LinearIssue {
+            identifier: self.identifier.clone(),
+            title: self.title.clone(),
+            description: self.description.clone(),
+            url: self.url.clone(),
+            labels: self.labels.clone(),
+            priority: self.priority,
+            estimate: self.estimate,
+        }
+    }
+}
+
+/// Env var pointing the client at a proxy or mock instead of Linear's API
+pub const LINEAR_API_URL_ENV: &str = "VIBE_LINEAR_API_URL";
+const DEFAULT_LINEAR_API_URL: &str = "https://api.linear.app/graphql";
+
+/// GraphQL endpoint from `VIBE_LINEAR_API_URL`, or Linear's own
+fn linear_api_url() -> Result<reqwest::Url, String> {
+    parse_api_url(std::env::var(LINEAR_API_URL_ENV).ok().as_deref())
+}
+
+/// An unset or blank value means Linear; anything else must be an http(s) URL
+fn parse_api_url(value: Option<&str>) -> Result<reqwest::Url, String> {
+    let Some(value) = value.map(str::trim).filter(|v| !v.is_empty()) else {
+        return Ok(reqwest::Url::parse(DEFAULT_LINEAR_API_URL).expect("default URL is valid"));
+    };
+    reqwest::Url::parse(value)
+        .ok()
+        .filter(|url| matches!(url.scheme(), "http" | "https"))
+        .ok_or_else(|| {
+            format!(
+                "{} must be an http(s) URL like {}, got '{}'",
+                LINEAR_API_URL_ENV, DEFAULT_LINEAR_API_URL, value
+            )
+        })
+}
+
+/// Env var overriding the Linear request timeout, in seconds
+pub const LINEAR_TIMEOUT_ENV: &str = "VIBE_LINEAR_TIMEOUT_SECS";
+const DEFAULT_LINEAR_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Waits before each retry of a request that failed to connect or (for queries) got a 5xx
+const RETRY_BACKOFF: [Duration; 3] = [
+    Duration::from_millis(200),
+    Duration::from_millis(400),
+    Duration::from_millis(800),
+];
+
+/// Latest Linear budget from response headers. Shared by every client in the
+/// process: they're short-lived and all use the project's one API key.
+static RATE_LIMIT: Mutex<Option<RateLimit>> = Mutex::new(None);
+
+/// With this few requests left, hold off until the window resets
+const RATE_LIMIT_RESERVE: u64 = 5;
+
+/// `extensions.code` Linear puts on errors for an id that matches no entity
+const ENTITY_NOT_FOUND_CODE: &str = "ENTITY_NOT_FOUND";
+
+/// Longest wait for a reset; further off, requests fail with `RateLimited` instead
+const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(60);
+
+/// Whether waits are announced on stderr. The board turns this off: its header
+/// shows the budget already and stderr would garble the screen.
+static ANNOUNCE_RATE_LIMIT_WAITS: AtomicBool = AtomicBool::new(true);
+
+/// Read Linear's rate-limit headers (`X-RateLimit-Requests-*`, or the plain
+/// `X-RateLimit-*` names). The reset is an epoch timestamp in ms or seconds.
+fn parse_rate_limit(headers: &HeaderMap) -> Option<RateLimit> {
+    let number = |names: [&str; 2]| {
+        names.iter().find_map(|name| {
+            headers
+                .get(*name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        })
+    };
+    let remaining = number(["x-ratelimit-requests-remaining", "x-ratelimit-remaining"])?;
+    let reset = number(["x-ratelimit-requests-reset", "x-ratelimit-reset"])?;
+    let reset_at = if reset > 100_000_000_000 {
+        DateTime::from_timestamp_millis(reset as i64)?
+    } else {
+        DateTime::from_timestamp(reset as i64, 0)?
+    };
+    let limit = number(["x-ratelimit-requests-limit", "x-ratelimit-limit"]).unwrap_or(remaining);
+    Some(RateLimit {
+        limit,
+        remaining,
+        reset_at,
+    })
+}
+
+/// How long to wait before the next request: until the reset once the budget
+/// is down to `RATE_LIMIT_RESERVE`, otherwise not at all
+pub fn linear_rate_limit_wait(limit: &RateLimit, now: DateTime<Utc>) -> Option<Duration> {
+    (limit.remaining <= RATE_LIMIT_RESERVE && limit.reset_at > now).then(|| limit.resets_in(now))
+}
+
+/// The wait before the next request, or `RateLimited` when the reset is more
+/// than `MAX_RATE_LIMIT_WAIT` away
+fn rate_limit_hold(
+    limit: Option<RateLimit>,
+    now: DateTime<Utc>,
+) -> Result<Option<Duration>, LinearHttpError> {
+    let Some(limit) = limit else {
+        return Ok(None);
+    };
+    match linear_rate_limit_wait(&limit, now) {
+        Some(wait) if wait > MAX_RATE_LIMIT_WAIT => Err(LinearHttpError::RateLimited {
+            reset_at: limit.reset_at,
+        }),
+        wait => Ok(wait),
+    }
+}
+
+/// Request timeout from `VIBE_LINEAR_TIMEOUT_SECS`, or 15s
+fn linear_timeout() -> Duration {
+    std::env::var(LINEAR_TIMEOUT_ENV)
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_LINEAR_TIMEOUT)
+}
+
+/// Transport failure talking to Linear. Converts into the `String` errors
+/// used by the client's public methods.
+#[derive(Debug)]
+pub enum LinearHttpError {
+    /// No response (or no full body) within the configured timeout
+    Timeout(Duration),
+    Request(reqwest::Error),
+    /// `VIBE_LINEAR_API_URL` isn't a usable URL; nothing was sent
+    InvalidUrl(String),
+    /// The budget is spent and resets too far off to wait; nothing was sent
+    RateLimited {
+        reset_at: DateTime<Utc>,
+    },
+}
+
+impl std::fmt::Display for LinearHttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LinearHttpError::Timeout(after) => {
+                write!(f, "Linear request timed out after {:?}", after)
+            }
+            LinearHttpError::Request(e) => write!(f, "HTTP error: {}", e),
+            LinearHttpError::InvalidUrl(message) => write!(f, "{}", message),
+            LinearHttpError::RateLimited { reset_at } => write!(
+                f,
+                "Linear rate limit spent; try again after {}",
+                reset_at.with_timezone(&chrono::Local).format("%H:%M:%S")
+            ),
+        }
+    }
+}
+
+impl From<LinearHttpError> for String {
+    fn from(e: LinearHttpError) -> Self {
+        e.to_string()
+    }
+}
+
 pub struct LinearClient {
     http: Client,
     api_key: String,
+    timeout: Duration,
+    /// Invalid `VIBE_LINEAR_API_URL` values are kept as the error every request returns
+    url: Result<reqwest::Url, String>,
+    /// Filled on first use so repeated creates don't look the user and teams up
+    /// again; a client for another API key starts empty
+    viewer_id: OnceCell<String>,
+    teams: OnceCell<Vec<LinearTeam>>,
 }
 
 impl LinearClient {
-    const API_URL: &'static str = "https://api.linear.app/graphql";
-
+    /// Client for `VIBE_LINEAR_API_URL` (or Linear itself) with the configured timeout
     pub fn new(api_key: String) -> Self {
+        Self::with_timeout(api_key, linear_timeout())
+    }
+
+    /// Client whose connect and overall request time are both bounded by `timeout`
+    pub fn with_timeout(api_key: String, timeout: Duration) -> Self {
+        let http = Client::builder()
+            .connect_timeout(timeout)
+            .timeout(timeout)
+            .build()
+            .unwrap_or_else(|e| {
+                tracing::warn!("Falling back to default HTTP client: {}", e);
+                Client::new()
+            });
         Self {
-            http: Client::new(),
+            http,
             api_key,
+            timeout,
+            url: linear_api_url(),
+            viewer_id: OnceCell::new(),
+            teams: OnceCell::new(),
         }
     }
 
-    /// Get the current user's ID
-    async fn get_viewer_id(&self) -> Result<String, String> {
-        let query = r#"query { viewer { id } }"#;
-        let body = serde_json::json!({ "query": query });
+    /// Send requests to `url` instead of Linear (a local mock in tests)
+    #[cfg(test)]
+    fn with_url(mut self, url: String) -> Self {
+        self.url = parse_api_url(Some(&url));
+        self
+    }
 
-        let response = self
-            .http
-            .post(Self::API_URL)
-            .header("Authorization", &self.api_key)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| format!("HTTP error: {}", e))?;
+    /// Stop announcing rate-limit waits on stderr (the board shows them in its header)
+    pub fn quiet_rate_limit_waits() {
+        ANNOUNCE_RATE_LIMIT_WAITS.store(false, Ordering::Relaxed);
+    }
 
-        let json: serde_json::Value = response
-            .json()
-            .await
-            .map_err(|e| format!("JSON parse error: {}", e))?;
+    /// Most recent Linear rate-limit budget seen by any client, if a response had the headers
+    pub fn last_rate_limit() -> Option<RateLimit> {
+        *RATE_LIMIT.lock().unwrap()
+    }
 
-        json.get("data")
-            .and_then(|d| d.get("viewer"))
-            .and_then(|v| v.get("id"))
-            .and_then(|id| id.as_str())
-            .map(|s| s.to_string())
-            .ok_or_else(|| "Failed to get viewer ID".to_string())
+    /// POST a GraphQL query, timed under `operation`. Refused connections and 5xx
+    /// responses are retried after each `RETRY_BACKOFF` wait; 4xx and timeouts are not.
+    /// Waits for the rate-limit reset first when the budget is nearly spent and it
+    /// is at most `MAX_RATE_LIMIT_WAIT` away, and fails with `RateLimited` otherwise.
+    async fn post(
+        &self,
+        operation: &'static str,
+        body: &serde_json::Value,
+    ) -> Result<reqwest::Response, LinearHttpError> {
+        self.send(operation, body, true).await
     }
 
-    /// Get the user's default team ID (first team they belong to)
-    async fn get_default_team_id(&self) -> Result<String, String> {
-        let query = r#"query { teams { nodes { id name } } }"#;
-        let body = serde_json::json!({ "query": query });
+    /// POST a GraphQL mutation like `post`, but only retry when the connection was
+    /// never made: after a 5xx Linear may already have applied the write
+    async fn post_mutation(
+        &self,
+        operation: &'static str,
+        body: &serde_json::Value,
+    ) -> Result<reqwest::Response, LinearHttpError> {
+        self.send(operation, body, false).await
+    }
 
-        let response = self
-            .http
-            .post(Self::API_URL)
-            .header("Authorization", &self.api_key)
-            .json(&body)
-            .send()
+    async fn send(
+        &self,
+        operation: &'static str,
+        body: &serde_json::Value,
+        retry_server_errors: bool,
+    ) -> Result<reqwest::Response, LinearHttpError> {
+        let url = self.url.clone().map_err(LinearHttpError::InvalidUrl)?;
+
+        if let Some(wait) = rate_limit_hold(Self::last_rate_limit(), Utc::now())? {
+            tracing::warn!(
+                "Linear rate limit nearly spent; waiting {:?} before {}",
+                wait,
+                operation
+            );
+            if ANNOUNCE_RATE_LIMIT_WAITS.load(Ordering::Relaxed) {
+                eprintln!(
+                    "Linear rate limit nearly spent; waiting {}s for the reset at {} (Ctrl+C to cancel)",
+                    wait.as_secs().max(1),
+                    (Utc::now() + wait)
+                        .with_timezone(&chrono::Local)
+                        .format("%H:%M:%S")
+                );
+            }
+            // A plain sleep: the caller's cancellation (or the default Ctrl+C) ends it
+            tokio::time::sleep(wait).await;
+        }
+
+        let mut backoff = RETRY_BACKOFF.iter();
+        loop {
+            let result = timed_async(
+                operation,
+                "",
+                self.http
+                    .post(url.clone())
+                    .header("Authorization", &self.api_key)
+                    .json(body)
+                    .send(),
+            )
+            .await;
+
+            if let Ok(response) = &result
+                && let Some(limit) = parse_rate_limit(response.headers())
+            {
+                *RATE_LIMIT.lock().unwrap() = Some(limit);
+            }
+
+            let retryable = match &result {
+                Ok(response) => retry_server_errors && response.status().is_server_error(),
+                // A connect timeout is still a timeout
+                Err(e) => e.is_connect() && !e.is_timeout(),
+            };
+            match backoff.next() {
+                Some(wait) if retryable => {
+                    tracing::debug!("{} failed, retrying in {:?}", operation, wait);
+                    tokio::time::sleep(*wait).await;
+                }
+                _ => return result.map_err(|e| self.http_error(e)),
+            }
+        }
+    }
+
+    /// Reading the body is covered by the same timeout as sending the request
+    fn body_error(&self, e: reqwest::Error) -> String {
+        if e.is_timeout() {
+            LinearHttpError::Timeout(self.timeout).to_string()
+        } else {
+            format!("JSON parse error: {}", e)
+        }
+    }
+
+    fn http_error(&self, e: reqwest::Error) -> LinearHttpError {
+        if e.is_timeout() {
+            LinearHttpError::Timeout(self.timeout)
+        } else {
+            LinearHttpError::Request(e)
+        }
+    }
+
+    /// Get the current user's ID, fetched once per client
+    async fn get_viewer_id(&self) -> Result<String, String> {
+        self.viewer_id
+            .get_or_try_init(|| async {
+                let query = query([Field::new("viewer").leaves(&["id"])]);
+                let body = serde_json::json!({ "query": query });
+
+                let response = self.post("linear.viewer_id", &body).await?;
+
+                let json: serde_json::Value =
+                    response.json().await.map_err(|e| self.body_error(e))?;
+
+                json.get("data")
+                    .and_then(|d| d.get("viewer"))
+                    .and_then(|v| v.get("id"))
+                    .and_then(|id| id.as_str())
+                    .map(|s| s.to_string())
+                    .ok_or_else(|| "Failed to get viewer ID".to_string())
+            })
             .await
-            .map_err(|e| format!("HTTP error: {}", e))?;
+            .cloned()
+    }
+
+    /// Teams the user belongs to, fetched once per client
+    async fn get_teams(&self) -> Result<&[LinearTeam], String> {
+        self.teams
+            .get_or_try_init(|| async {
+                let query = query([connection(
+                    "teams",
+                    [Field::new("id"), Field::new("key"), Field::new("name")],
+                )]);
+                let body = serde_json::json!({ "query": query });
+
+                let response = self.post("linear.teams", &body).await?;
 
-        let json: serde_json::Value = response
-            .json()
+                let json: serde_json::Value =
+                    response.json().await.map_err(|e| self.body_error(e))?;
+
+                if let Some(errors) = json.get("errors") {
+                    return Err(format!("GraphQL error: {}", errors));
+                }
+
+                let nodes = json
+                    .get("data")
+                    .and_then(|d| d.get("teams"))
+                    .and_then(|t| t.get("nodes"))
+                    .cloned()
+                    .ok_or("Failed to list teams")?;
+                serde_json::from_value(nodes).map_err(|e| format!("JSON parse error: {}", e))
+            })
             .await
-            .map_err(|e| format!("JSON parse error: {}", e))?;
-
-        json.get("data")
-            .and_then(|d| d.get("teams"))
-            .and_then(|t| t.get("nodes"))
-            .and_then(|n| n.as_array())
-            .and_then(|arr| arr.first())
-            .and_then(|team| team.get("id"))
-            .and_then(|id| id.as_str())
-            .map(|s| s.to_string())
-            .ok_or_else(|| "No teams found".to_string())
+            .map(Vec::as_slice)
+    }
+
+    /// ID of the team keyed `team_key`, or of the user's only team when None
+    async fn get_team_id(&self, team_key: Option<&str>) -> Result<String, String> {
+        let teams = self.get_teams().await?;
+        pick_team(teams, team_key).map(|team| team.id.clone())
     }
 
-    /// Create a new issue assigned to self in the backlog
+    /// Create a new issue assigned to self in the backlog of the team keyed `team_key`
+    /// (see `pick_team`), tagged with `labels` (names of team or workspace labels;
+    /// an unknown name fails before creating)
     pub async fn create_issue(
         &self,
         title: &str,
         description: Option<&str>,
+        labels: &[String],
+        team_key: Option<&str>,
     ) -> Result<CreatedIssue, String> {
         let viewer_id = self.get_viewer_id().await?;
-        let team_id = self.get_default_team_id().await?;
-
-        let desc_value = description
-            .map(|d| {
-                format!(
-                    r#""{}""#,
-                    d.replace('\\', "\\\\")
-                        .replace('"', "\\\"")
-                        .replace('\n', "\\n")
-                )
-            })
-            .unwrap_or_else(|| "null".to_string());
-
-        let query = format!(
-            r#"mutation {{
-                issueCreate(input: {{
-                    title: "{}",
-                    description: {},
-                    teamId: "{}",
-                    assigneeId: "{}"
-                }}) {{
-                    success
-                    issue {{
-                        identifier
-                        url
-                    }}
-                }}
-            }}"#,
-            title.replace('"', "\\\""),
-            desc_value,
-            team_id,
-            viewer_id
+        let team_id = self.get_team_id(team_key).await?;
+
+        let (label_ids, label_names) = if labels.is_empty() {
+            (Vec::new(), Vec::new())
+        } else {
+            let team_labels = self.get_team_labels(&team_id).await?;
+            let resolved = resolve_labels(&team_labels, labels, &team_id)?;
+            resolved
+                .into_iter()
+                .map(|label| (quote(&label.id), label.name.clone()))
+                .unzip()
+        };
+
+        let input = format!(
+            "{{ title: {}, description: {}, teamId: {}, assigneeId: {}, labelIds: [{}] }}",
+            quote(title),
+            description.map(quote).unwrap_or_else(|| "null".to_string()),
+            quote(&team_id),
+            quote(&viewer_id),
+            label_ids.join(", ")
         );
+        let query = mutation([Field::new("issueCreate").arg("input", input).select([
+            Field::new("success"),
+            Field::new("issue").leaves(&["identifier", "url"]),
+        ])]);
 
         let body = serde_json::json!({ "query": query });
 
-        let response = self
-            .http
-            .post(Self::API_URL)
-            .header("Authorization", &self.api_key)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| format!("HTTP error: {}", e))?;
+        let response = self.post_mutation("linear.create_issue", &body).await?;
 
-        let json: serde_json::Value = response
-            .json()
-            .await
-            .map_err(|e| format!("JSON parse error: {}", e))?;
+        let json: serde_json::Value = response.json().await.map_err(|e| self.body_error(e))?;
 
         if let Some(errors) = json.get("errors") {
             return Err(format!("GraphQL error: {}", errors));
@@ -238,89 +904,412 @@ impl LinearClient {
             .ok_or("Missing url")?
             .to_string();
 
-        Ok(CreatedIssue { identifier, url })
+        Ok(CreatedIssue {
+            identifier,
+            url,
+            labels: label_names,
+        })
     }
 
-    /// Fetch backlog issues assigned to the current user (API key owner)
-    pub async fn fetch_backlog_issues(&self) -> Result<Vec<LinearIssue>, String> {
-        let query = r#"
-            query {
-                viewer {
-                    assignedIssues(filter: { state: { type: { eq: "backlog" } } }) {
-                        nodes {
-                            identifier
-                            title
-                            description
-                            url
-                            labels {
-                                nodes {
-                                    name
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        "#;
+    /// Labels usable on `team_id`: the team's own plus workspace labels
+    async fn get_team_labels(&self, team_id: &str) -> Result<Vec<LinearLabel>, String> {
+        let query = query([connection(
+            "issueLabels",
+            [
+                Field::new("id"),
+                Field::new("name"),
+                Field::new("team").leaves(&["id"]),
+            ],
+        )
+        .arg(
+            "filter",
+            format!(
+                "{{ or: [{{ team: {{ id: {{ eq: {} }} }} }}, {{ team: {{ null: true }} }}] }}",
+                quote(team_id)
+            ),
+        )
+        .arg("first", "250")]);
+        let body = serde_json::json!({ "query": query });
+
+        let response = self.post("linear.team_labels", &body).await?;
+
+        let json: serde_json::Value = response.json().await.map_err(|e| self.body_error(e))?;
+
+        if let Some(errors) = json.get("errors") {
+            return Err(format!("GraphQL error: {}", errors));
+        }
+
+        let nodes = json
+            .get("data")
+            .and_then(|d| d.get("issueLabels"))
+            .and_then(|l| l.get("nodes"))
+            .cloned()
+            .ok_or("Failed to list labels")?;
+        serde_json::from_value(nodes).map_err(|e| format!("JSON parse error: {}", e))
+    }
+
+    /// Make sure a label named `name` exists on the team (see `pick_team`), creating it
+    /// if missing. Safe to call repeatedly: an existing team or workspace label is reused.
+    pub async fn ensure_team_label(
+        &self,
+        name: &str,
+        color: &str,
+        team_key: Option<&str>,
+    ) -> Result<EnsuredLabel, String> {
+        let team_id = self.get_team_id(team_key).await?;
+
+        let query = query([connection(
+            "issueLabels",
+            [
+                Field::new("id"),
+                Field::new("name"),
+                Field::new("team").leaves(&["id"]),
+            ],
+        )
+        .arg(
+            "filter",
+            format!("{{ name: {{ eqIgnoreCase: {} }} }}", quote(name)),
+        )]);
+        let body = serde_json::json!({ "query": query });
+
+        let response = self.post("linear.find_label", &body).await?;
+
+        let json: serde_json::Value = response.json().await.map_err(|e| self.body_error(e))?;
+
+        if let Some(errors) = json.get("errors") {
+            return Err(format!("GraphQL error: {}", errors));
+        }
+
+        let nodes = json
+            .get("data")
+            .and_then(|d| d.get("issueLabels"))
+            .and_then(|l| l.get("nodes"))
+            .cloned()
+            .ok_or("Failed to list labels")?;
+        let labels: Vec<LinearLabel> =
+            serde_json::from_value(nodes).map_err(|e| format!("JSON parse error: {}", e))?;
+
+        if let Some(label) = find_label_for_team(&labels, name, &team_id) {
+            return Ok(EnsuredLabel {
+                name: label.name.clone(),
+                created: false,
+            });
+        }
+
+        let input = format!(
+            "{{ name: {}, color: {}, teamId: {} }}",
+            quote(name),
+            quote(color),
+            quote(&team_id)
+        );
+        let query = mutation([Field::new("issueLabelCreate").arg("input", input).select([
+            Field::new("success"),
+            Field::new("issueLabel").leaves(&["id", "name"]),
+        ])]);
+        let body = serde_json::json!({ "query": query });
+
+        let response = self.post_mutation("linear.create_label", &body).await?;
+
+        let json: serde_json::Value = response.json().await.map_err(|e| self.body_error(e))?;
+
+        if let Some(errors) = json.get("errors") {
+            return Err(format!("GraphQL error: {}", errors));
+        }
+
+        let created = json
+            .get("data")
+            .and_then(|d| d.get("issueLabelCreate"))
+            .ok_or("Failed to create label")?;
+        if created.get("success").and_then(|s| s.as_bool()) != Some(true) {
+            return Err(format!("Linear rejected creating label {}", name));
+        }
+        let label = created.get("issueLabel").ok_or("Missing created label")?;
+
+        Ok(EnsuredLabel {
+            name: label
+                .get("name")
+                .and_then(|n| n.as_str())
+                .unwrap_or(name)
+                .to_string(),
+            created: true,
+        })
+    }
+
+    /// Fetch the workflow states of the team an issue belongs to, in board order
+    pub async fn fetch_team_states(
+        &self,
+        identifier: &str,
+    ) -> Result<Vec<LinearWorkflowState>, String> {
+        let query = query([Field::new("issue")
+            .str_arg("id", identifier)
+            .select([Field::new("team").select([connection(
+                "states",
+                ["id", "name", "type", "position"].map(Field::new),
+            )])])]);
+        let body = serde_json::json!({ "query": query });
+
+        let response = self.post("linear.team_states", &body).await?;
+
+        let json: serde_json::Value = response.json().await.map_err(|e| self.body_error(e))?;
 
+        if let Some(errors) = json.get("errors") {
+            return Err(format!("GraphQL error: {}", errors));
+        }
+
+        let nodes = json
+            .get("data")
+            .and_then(|d| d.get("issue"))
+            .and_then(|i| i.get("team"))
+            .and_then(|t| t.get("states"))
+            .and_then(|s| s.get("nodes"))
+            .cloned()
+            .ok_or_else(|| format!("Issue {} not found", identifier))?;
+
+        let mut states: Vec<LinearWorkflowState> =
+            serde_json::from_value(nodes).map_err(|e| format!("JSON parse error: {}", e))?;
+        states.sort_by(|a, b| a.position.total_cmp(&b.position));
+        Ok(states)
+    }
+
+    /// Fetch the links attached to an issue, oldest first
+    pub async fn fetch_issue_attachments(
+        &self,
+        identifier: &str,
+    ) -> Result<Vec<LinearAttachment>, String> {
+        let query = query([Field::new("issue")
+            .str_arg("id", identifier)
+            .select([connection(
+                "attachments",
+                [Field::new("url"), Field::new("title")],
+            )])]);
+        let body = serde_json::json!({ "query": query });
+
+        let response = self.post("linear.attachments", &body).await?;
+
+        let json: serde_json::Value = response.json().await.map_err(|e| self.body_error(e))?;
+
+        if let Some(errors) = json.get("errors") {
+            return Err(format!("GraphQL error: {}", errors));
+        }
+
+        let nodes = json
+            .get("data")
+            .and_then(|d| d.get("issue"))
+            .and_then(|i| i.get("attachments"))
+            .and_then(|a| a.get("nodes"))
+            .cloned()
+            .ok_or_else(|| format!("Issue {} not found", identifier))?;
+
+        serde_json::from_value(nodes).map_err(|e| format!("JSON parse error: {}", e))
+    }
+
+    /// Post a markdown comment on an issue
+    pub async fn add_comment(&self, identifier: &str, body: &str) -> Result<(), String> {
+        let query = query([Field::new("issue")
+            .str_arg("id", identifier)
+            .leaves(&["id"])]);
+        let request = serde_json::json!({ "query": query });
+
+        let response = self.post("linear.issue_id", &request).await?;
+
+        let json: serde_json::Value = response.json().await.map_err(|e| self.body_error(e))?;
+
+        if let Some(errors) = json.get("errors") {
+            return Err(format!("GraphQL error: {}", errors));
+        }
+
+        let issue_id = json
+            .get("data")
+            .and_then(|d| d.get("issue"))
+            .and_then(|i| i.get("id"))
+            .and_then(|id| id.as_str())
+            .ok_or_else(|| format!("Issue {} not found", identifier))?;
+
+        let input = format!("{{ issueId: {}, body: {} }}", quote(issue_id), quote(body));
+        let query = mutation([Field::new("commentCreate")
+            .arg("input", input)
+            .leaves(&["success"])]);
+        let request = serde_json::json!({ "query": query });
+
+        let response = self.post_mutation("linear.add_comment", &request).await?;
+
+        let json: serde_json::Value = response.json().await.map_err(|e| self.body_error(e))?;
+
+        if let Some(errors) = json.get("errors") {
+            return Err(format!("GraphQL error: {}", errors));
+        }
+
+        let created = json
+            .get("data")
+            .and_then(|d| d.get("commentCreate"))
+            .and_then(|c| c.get("success"))
+            .and_then(|s| s.as_bool());
+        if created != Some(true) {
+            return Err(format!("Linear rejected the comment on {}", identifier));
+        }
+        Ok(())
+    }
+
+    /// Move an issue to the team workflow state with the given name
+    pub async fn set_issue_state_by_name(
+        &self,
+        identifier: &str,
+        state_name: &str,
+    ) -> Result<LinearIssueStatus, String> {
+        let states = self.fetch_team_states(identifier).await?;
+        let state = find_state_by_name(&states, state_name)?;
+        self.set_issue_state(identifier, state).await
+    }
+
+    /// Move an issue to the first workflow state of its team with the given type
+    /// (`backlog`, `unstarted`, `started`, `completed`, `canceled`), e.g. to mirror
+    /// a card moved on the board. Errors when the team has no state of that type.
+    pub async fn update_issue_state(
+        &self,
+        identifier: &str,
+        state_type: &str,
+    ) -> Result<LinearIssueStatus, String> {
+        let states = self.fetch_team_states(identifier).await?;
+        let state = find_state_by_type(&states, state_type)?;
+        self.set_issue_state(identifier, state).await
+    }
+
+    async fn set_issue_state(
+        &self,
+        identifier: &str,
+        state: &LinearWorkflowState,
+    ) -> Result<LinearIssueStatus, String> {
+        let query = mutation([Field::new("issueUpdate")
+            .str_arg("id", identifier)
+            .arg("input", format!("{{ stateId: {} }}", quote(&state.id)))
+            .select([
+                Field::new("success"),
+                Field::new("issue").select(issue_status()),
+            ])]);
         let body = serde_json::json!({ "query": query });
 
         let response = self
-            .http
-            .post(Self::API_URL)
-            .header("Authorization", &self.api_key)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| format!("HTTP error: {}", e))?;
+            .post_mutation("linear.update_issue_state", &body)
+            .await?;
 
-        let status = response.status();
-        if !status.is_success() {
-            let text = response.text().await.unwrap_or_default();
-            return Err(format!(
-                "HTTP {}: {}",
-                status.as_u16(),
-                text.chars().take(200).collect::<String>()
-            ));
+        let json: serde_json::Value = response.json().await.map_err(|e| self.body_error(e))?;
+
+        if let Some(errors) = json.get("errors") {
+            return Err(format!("GraphQL error: {}", errors));
         }
 
-        let result: GraphQLResponse<ViewerData> = response
-            .json()
-            .await
-            .map_err(|e| format!("JSON parse error: {}", e))?;
+        let update = json
+            .get("data")
+            .and_then(|d| d.get("issueUpdate"))
+            .ok_or("Failed to update issue")?;
+        if update.get("success").and_then(|s| s.as_bool()) != Some(true) {
+            return Err(format!("Linear rejected state change for {}", identifier));
+        }
 
-        if let Some(errors) = result.errors {
-            let msg = errors
-                .iter()
-                .map(|e| e.message.clone())
-                .collect::<Vec<_>>()
-                .join(", ");
-            return Err(format!("GraphQL error: {}", msg));
+        Ok(LinearIssueStatus {
+            identifier: identifier.to_string(),
+            state_type: state.state_type.clone(),
+            state_name: state.name.clone(),
+            assignee: update.get("issue").and_then(assignee_name),
+        })
+    }
+
+    /// Fetch backlog issues assigned to the current user (API key owner), following
+    /// pages of `BACKLOG_PAGE_SIZE` up to `BACKLOG_MAX_ISSUES`
+    pub async fn fetch_backlog_issues(&self) -> Result<Vec<LinearIssue>, String> {
+        let mut nodes = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let body = serde_json::json!({ "query": backlog_page_query(cursor.as_deref()) });
+
+            let response = self.post("linear.backlog_issues", &body).await?;
+
+            let status = response.status();
+            if !status.is_success() {
+                let text = response.text().await.unwrap_or_default();
+                return Err(format!(
+                    "HTTP {}: {}",
+                    status.as_u16(),
+                    text.chars().take(200).collect::<String>()
+                ));
+            }
+
+            let result: GraphQLResponse<ViewerData> =
+                response.json().await.map_err(|e| self.body_error(e))?;
+
+            if let Some(errors) = result.errors {
+                let msg = errors
+                    .iter()
+                    .map(|e| e.message.clone())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return Err(format!("GraphQL error: {}", msg));
+            }
+
+            let data = result.data.ok_or("No data in response")?;
+            let Some(page) = data.viewer.assigned_issues else {
+                break;
+            };
+            nodes.extend(page.nodes);
+
+            let next = page
+                .page_info
+                .filter(|info| info.has_next_page)
+                .and_then(|info| info.end_cursor);
+            match next {
+                Some(next) if nodes.len() < BACKLOG_MAX_ISSUES => cursor = Some(next),
+                Some(_) => {
+                    tracing::warn!(
+                        "Backlog has more than {} issues; importing the first {}",
+                        BACKLOG_MAX_ISSUES,
+                        BACKLOG_MAX_ISSUES
+                    );
+                    break;
+                }
+                None => break,
+            }
+        }
+
+        Ok(nodes
+            .into_iter()
+            .take(BACKLOG_MAX_ISSUES)
+            .map(IssueNode::into_issue)
+            .collect())
+    }
+
+    /// Full-text search over the workspace's issues, best matches first, up to
+    /// `SEARCH_LIMIT`
+    pub async fn search_issues(&self, search: &str) -> Result<Vec<LinearIssue>, String> {
+        let query = query([connection("issueSearch", issue_core())
+            .str_arg("query", search)
+            .arg("first", SEARCH_LIMIT.to_string())]);
+        let body = serde_json::json!({ "query": query });
+
+        let response = self.post("linear.search_issues", &body).await?;
+
+        let json: serde_json::Value = response.json().await.map_err(|e| self.body_error(e))?;
+
+        if let Some(errors) = json.get("errors") {
+            return Err(format!("GraphQL error: {}", errors));
         }
 
-        let data = result.data.ok_or("No data in response")?;
-        let issues = data
-            .viewer
-            .assigned_issues
-            .map(|c| c.nodes)
-            .unwrap_or_default();
+        let nodes = json
+            .get("data")
+            .and_then(|d| d.get("issueSearch"))
+            .and_then(|s| s.get("nodes"))
+            .cloned()
+            .ok_or("No data in response")?;
+        let nodes: Vec<IssueNode> =
+            serde_json::from_value(nodes).map_err(|e| format!("JSON parse error: {}", e))?;
 
-        Ok(issues
+        Ok(nodes
             .into_iter()
-            .map(|node| LinearIssue {
-                identifier: node.identifier,
-                title: node.title,
-                description: node.description,
-                url: node.url,
-                labels: node
-                    .labels
-                    .map(|l| l.nodes.into_iter().map(|n| n.name).collect())
-                    .unwrap_or_default(),
-            })
+            .take(SEARCH_LIMIT)
+            .map(IssueNode::into_issue)
             .collect())
     }
 
-    /// Fetch status for multiple issues by identifiers
     /// Uses GraphQL aliases to batch multiple `issue` queries into one request
     pub async fn fetch_issue_statuses(
         &self,
@@ -330,31 +1319,25 @@ impl LinearClient {
             return Ok(Vec::new());
         }
 
-        // Build a query with aliases for each identifier
-        // e.g., query { i0: issue(id: "VIB-5") { ... } i1: issue(id: "VIB-6") { ... } }
-        let fields: Vec<String> = identifiers
-            .iter()
-            .enumerate()
-            .map(|(i, id)| {
-                format!(
-                    r#"i{}: issue(id: "{}") {{ identifier state {{ name type }} }}"#,
-                    i, id
-                )
-            })
-            .collect();
-
-        let query = format!("query {{ {} }}", fields.join(" "));
+        // One aliased query per chunk, sent together; a big board in a single
+        // query is several kilobytes and Linear may reject or throttle it
+        let chunks = identifiers
+            .chunks(STATUS_CHUNK_SIZE)
+            .map(|chunk| self.fetch_status_chunk(chunk));
+        let mut statuses = Vec::with_capacity(identifiers.len());
+        for chunk in join_all(chunks).await {
+            statuses.extend(chunk?);
+        }
+        Ok(statuses)
+    }
 
-        let body = serde_json::json!({ "query": query });
+    async fn fetch_status_chunk(
+        &self,
+        identifiers: &[String],
+    ) -> Result<Vec<LinearIssueStatus>, String> {
+        let body = serde_json::json!({ "query": issue_statuses_query(identifiers) });
 
-        let response = self
-            .http
-            .post(Self::API_URL)
-            .header("Authorization", &self.api_key)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| format!("HTTP error: {}", e))?;
+        let response = self.post("linear.issue_statuses", &body).await?;
 
         let status = response.status();
         if !status.is_success() {
@@ -367,10 +1350,7 @@ impl LinearClient {
         }
 
         // Parse as dynamic JSON since the response shape depends on aliases
-        let json: serde_json::Value = response
-            .json()
-            .await
-            .map_err(|e| format!("JSON parse error: {}", e))?;
+        let json: serde_json::Value = response.json().await.map_err(|e| self.body_error(e))?;
 
         if let Some(errors) = json.get("errors")
             && let Some(arr) = errors.as_array()
@@ -386,76 +1366,18 @@ impl LinearClient {
         }
 
         let data = json.get("data").ok_or("No data in response")?;
-
-        let mut statuses = Vec::new();
-        for i in 0..identifiers.len() {
-            let key = format!("i{}", i);
-            if let Some(issue) = data.get(&key) {
-                // issue can be null if not found
-                if issue.is_null() {
-                    continue;
-                }
-                let identifier = issue
-                    .get("identifier")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or_default()
-                    .to_string();
-                let state = issue.get("state").ok_or("Missing state field")?;
-                let state_name = state
-                    .get("name")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or_default()
-                    .to_string();
-                let state_type = state
-                    .get("type")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or_default()
-                    .to_string();
-
-                statuses.push(LinearIssueStatus {
-                    identifier,
-                    state_type,
-                    state_name,
-                });
-            }
-        }
-
-        Ok(statuses)
+        parse_issue_statuses(data, identifiers.len())
     }
 
-    /// Fetch all non-cancelled issues assigned to the API key owner
-    pub async fn fetch_assigned_issues(&self) -> Result<Vec<LinearBoardIssue>, String> {
-        let query = r#"
-            query {
-                viewer {
-                    assignedIssues(
-                        filter: { state: { type: { neq: "cancelled" } } }
-                        orderBy: updatedAt
-                        first: 100
-                    ) {
-                        nodes {
-                            identifier
-                            title
-                            description
-                            url
-                            state { name type }
-                            labels { nodes { name } }
-                        }
-                    }
-                }
-            }
-        "#;
-
-        let body = serde_json::json!({ "query": query });
+    /// Look up one issue by identifier. `Ok(None)` means Linear says it doesn't exist
+    /// (deleted, or moved out of reach of this key); other failures are errors.
+    pub async fn fetch_issue_status(
+        &self,
+        identifier: &str,
+    ) -> Result<Option<LinearIssueStatus>, String> {
+        let body = serde_json::json!({ "query": issue_statuses_query(&[identifier.to_string()]) });
 
-        let response = self
-            .http
-            .post(Self::API_URL)
-            .header("Authorization", &self.api_key)
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| format!("HTTP error: {}", e))?;
+        let response = self.post("linear.issue_status", &body).await?;
 
         let status = response.status();
         if !status.is_success() {
@@ -467,37 +1389,102 @@ impl LinearClient {
             ));
         }
 
-        let result: GraphQLResponse<ViewerData> = response
-            .json()
-            .await
-            .map_err(|e| format!("JSON parse error: {}", e))?;
+        let json: serde_json::Value = response.json().await.map_err(|e| self.body_error(e))?;
 
-        if let Some(errors) = result.errors {
-            let msg = errors
+        if let Some(errors) = json.get("errors").and_then(|e| e.as_array()) {
+            if issue_is_gone(&json) {
+                return Ok(None);
+            }
+            let msgs: Vec<&str> = errors
                 .iter()
-                .map(|e| e.message.clone())
-                .collect::<Vec<_>>()
-                .join(", ");
-            return Err(format!("GraphQL error: {}", msg));
+                .filter_map(|e| e.get("message").and_then(|m| m.as_str()))
+                .collect();
+            if !msgs.is_empty() {
+                return Err(format!("GraphQL error: {}", msgs.join(", ")));
+            }
         }
 
-        let data = result.data.ok_or("No data in response")?;
-        let nodes = data
-            .viewer
-            .assigned_issues
-            .map(|c| c.nodes)
-            .unwrap_or_default();
+        let data = json.get("data").ok_or("No data in response")?;
+        Ok(parse_issue_statuses(data, 1)?.pop())
+    }
+
+    /// Fetch all non-canceled issues assigned to the API key owner, following
+    /// pages of `ASSIGNED_PAGE_SIZE` up to `ASSIGNED_MAX_ISSUES`
+    pub async fn fetch_assigned_issues(&self) -> Result<Vec<LinearBoardIssue>, String> {
+        let mut nodes = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let body = serde_json::json!({ "query": assigned_page_query(cursor.as_deref()) });
+
+            let response = self.post("linear.assigned_issues", &body).await?;
+
+            let status = response.status();
+            if !status.is_success() {
+                let text = response.text().await.unwrap_or_default();
+                return Err(format!(
+                    "HTTP {}: {}",
+                    status.as_u16(),
+                    text.chars().take(200).collect::<String>()
+                ));
+            }
+
+            let result: GraphQLResponse<ViewerData> =
+                response.json().await.map_err(|e| self.body_error(e))?;
+
+            if let Some(errors) = result.errors {
+                let msg = errors
+                    .iter()
+                    .map(|e| e.message.clone())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return Err(format!("GraphQL error: {}", msg));
+            }
+
+            let data = result.data.ok_or("No data in response")?;
+            let Some(page) = data.viewer.assigned_issues else {
+                break;
+            };
+            nodes.extend(page.nodes);
+
+            let next = page
+                .page_info
+                .filter(|info| info.has_next_page)
+                .and_then(|info| info.end_cursor);
+            match next {
+                Some(next) if nodes.len() < ASSIGNED_MAX_ISSUES => cursor = Some(next),
+                Some(_) => {
+                    tracing::warn!(
+                        "More than {} issues assigned; using the {} most recently updated",
+                        ASSIGNED_MAX_ISSUES,
+                        ASSIGNED_MAX_ISSUES
+                    );
+                    break;
+                }
+                None => break,
+            }
+        }
 
         Ok(nodes
             .into_iter()
+            .take(ASSIGNED_MAX_ISSUES)
             .map(|node| {
+                let priority = node.priority();
                 let (state_name, state_type) = node
                     .state
                     .map(|s| (s.name, s.state_type))
                     .unwrap_or_else(|| ("Unknown".to_string(), "unknown".to_string()));
                 LinearBoardIssue {
+                    priority,
+                    estimate: node.estimate,
                     identifier: node.identifier,
                     title: node.title,
+                    description: node.description,
+                    url: node.url,
+                    labels: node
+                        .labels
+                        .map(|l| l.nodes.into_iter().map(|n| n.name).collect())
+                        .unwrap_or_default(),
                     state_name,
                     state_type,
                 }
@@ -510,6 +1497,488 @@ impl LinearClient {
 mod tests {
     use super::*;
 
+    fn workflow_state(name: &str, state_type: &str) -> LinearWorkflowState {
+        LinearWorkflowState {
+            id: format!("id-{}", name),
+            name: name.to_string(),
+            state_type: state_type.to_string(),
+            position: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_find_state_by_name() {
+        let states = vec![
+            workflow_state("Todo", "unstarted"),
+            workflow_state("Ready for QA", "started"),
+        ];
+        let found = find_state_by_name(&states, "ready for qa").unwrap();
+        assert_eq!(found.id, "id-Ready for QA");
+
+        let err = find_state_by_name(&states, "Shipped").unwrap_err();
+        assert!(err.contains("Shipped"));
+        assert!(err.contains("Todo, Ready for QA"));
+    }
+
+    #[test]
+    fn test_attachment_nodes() {
+        let attachments: Vec<LinearAttachment> = serde_json::from_value(serde_json::json!([
+            { "url": "https://figma.com/file/abc", "title": "Login flow" },
+            { "url": "https://docs.example.com/spec", "title": "" },
+            { "url": "https://example.com/no-title" }
+        ]))
+        .unwrap();
+        assert_eq!(attachments[0].label(), "Login flow");
+        assert_eq!(attachments[1].label(), "https://docs.example.com/spec");
+        assert_eq!(attachments[2].label(), "https://example.com/no-title");
+    }
+
+    #[test]
+    fn test_find_state_by_type() {
+        let states = vec![
+            workflow_state("Todo", "unstarted"),
+            workflow_state("Won't do", "canceled"),
+            workflow_state("Duplicate", "canceled"),
+        ];
+        let found = find_state_by_type(&states, "canceled").unwrap();
+        assert_eq!(found.name, "Won't do");
+
+        // The board spells it with two l's
+        let found = find_state_by_type(&states, "Cancelled").unwrap();
+        assert_eq!(found.name, "Won't do");
+
+        let err = find_state_by_type(&states, "completed").unwrap_err();
+        assert_eq!(
+            err,
+            "Team has no completed state. Available: Todo (unstarted), \
+             Won't do (canceled), Duplicate (canceled)"
+        );
+    }
+
+    #[test]
+    fn test_workflow_state_deserializes_from_api_shape() {
+        let json = serde_json::json!([
+            { "id": "a", "name": "Done", "type": "completed", "position": 3.0 },
+            { "id": "b", "name": "Ready for QA", "type": "started" }
+        ]);
+        let states: Vec<LinearWorkflowState> = serde_json::from_value(json).unwrap();
+        assert_eq!(states[1].state_type, "started");
+        assert_eq!(states[1].position, 0.0);
+    }
+
+    #[test]
+    fn test_assignee_name() {
+        let assigned = serde_json::json!({ "assignee": { "displayName": "piotr" } });
+        assert_eq!(assignee_name(&assigned).as_deref(), Some("piotr"));
+        let unassigned = serde_json::json!({ "assignee": null });
+        assert_eq!(assignee_name(&unassigned), None);
+    }
+
+    #[test]
+    fn test_find_label_for_team() {
+        let labels: Vec<LinearLabel> = serde_json::from_value(serde_json::json!([
+            { "id": "l1", "name": "~gasit", "team": { "id": "team-b" } },
+            { "id": "l2", "name": "~GasIt", "team": { "id": "team-a" } },
+            { "id": "l3", "name": "bug", "team": null }
+        ]))
+        .unwrap();
+
+        let found = find_label_for_team(&labels, GASIT_LABEL, "team-a").unwrap();
+        assert_eq!(found.name, "~GasIt");
+        assert!(find_label_for_team(&labels, GASIT_LABEL, "team-c").is_none());
+        // Workspace labels apply to every team
+        let shared = find_label_for_team(&labels, "bug", "team-c").unwrap();
+        assert!(shared.team.is_none());
+    }
+
+    #[test]
+    fn test_backlog_page_query_pages_by_cursor() {
+        let first = backlog_page_query(None);
+        assert!(first.contains("first: 50"));
+        assert!(first.contains("pageInfo { hasNextPage endCursor }"));
+        assert!(!first.contains("after:"));
+        assert!(backlog_page_query(Some("abc")).contains(r#"after: "abc""#));
+
+        let page: IssueConnection = serde_json::from_value(serde_json::json!({
+            "nodes": [],
+            "pageInfo": { "hasNextPage": true, "endCursor": "abc" }
+        }))
+        .unwrap();
+        let info = page.page_info.unwrap();
+        assert!(info.has_next_page);
+        assert_eq!(info.end_cursor.as_deref(), Some("abc"));
+    }
+
+    #[test]
+    fn test_issue_statuses_are_chunked_and_merged() {
+        let identifiers: Vec<String> = (1..=100).map(|n| format!("VIB-{}", n)).collect();
+        let chunks: Vec<&[String]> = identifiers.chunks(STATUS_CHUNK_SIZE).collect();
+        assert_eq!(
+            chunks.iter().map(|c| c.len()).collect::<Vec<_>>(),
+            [40, 40, 20]
+        );
+
+        let mut statuses = Vec::new();
+        for chunk in &chunks {
+            let query = issue_statuses_query(chunk);
+            assert!(query.contains(&format!("i{}: issue", chunk.len() - 1)));
+            assert!(!query.contains(&format!("i{}: issue", chunk.len())));
+
+            // Fake each chunk's response; VIB-13 no longer exists
+            let data: serde_json::Map<String, serde_json::Value> = chunk
+                .iter()
+                .enumerate()
+                .map(|(i, id)| {
+                    let issue = if id == "VIB-13" {
+                        serde_json::Value::Null
+                    } else {
+                        serde_json::json!({
+                            "identifier": id,
+                            "state": { "name": "Todo", "type": "unstarted" },
+                            "assignee": null
+                        })
+                    };
+                    (format!("i{}", i), issue)
+                })
+                .collect();
+            statuses.extend(parse_issue_statuses(&data.into(), chunk.len()).unwrap());
+        }
+
+        assert_eq!(statuses.len(), 99);
+        assert_eq!(statuses[0].identifier, "VIB-1");
+        assert_eq!(statuses[98].identifier, "VIB-100");
+    }
+
+    #[test]
+    fn test_pick_team() {
+        let teams: Vec<LinearTeam> = serde_json::from_value(serde_json::json!([
+            { "id": "t1", "key": "VIB", "name": "Vibe" },
+            { "id": "t2", "key": "OPS", "name": "Ops" }
+        ]))
+        .unwrap();
+
+        assert_eq!(pick_team(&teams, Some("ops")).unwrap().id, "t2");
+        assert_eq!(
+            pick_team(&teams, Some("WEB")).unwrap_err(),
+            "no Linear team with key WEB; your teams: VIB (Vibe), OPS (Ops)"
+        );
+        // Several teams and no key: refuse rather than guess
+        assert!(pick_team(&teams, None).unwrap_err().contains("--team"));
+        assert_eq!(pick_team(&teams[..1], None).unwrap().id, "t1");
+        assert!(pick_team(&[], None).is_err());
+    }
+
+    #[test]
+    fn test_resolve_labels() {
+        let labels: Vec<LinearLabel> = serde_json::from_value(serde_json::json!([
+            { "id": "l1", "name": "Frontend", "team": { "id": "team-a" } },
+            { "id": "l2", "name": "ops", "team": { "id": "team-b" } },
+            { "id": "l3", "name": "bug", "team": null }
+        ]))
+        .unwrap();
+
+        let names = vec!["frontend".to_string(), "Bug".to_string()];
+        let resolved = resolve_labels(&labels, &names, "team-a").unwrap();
+        let ids: Vec<&str> = resolved.iter().map(|l| l.id.as_str()).collect();
+        assert_eq!(ids, ["l1", "l3"]);
+
+        // Another team's label isn't valid here, and isn't offered either
+        let names = vec!["ops".to_string(), "bug".to_string()];
+        assert_eq!(
+            resolve_labels(&labels, &names, "team-a").unwrap_err(),
+            "unknown label ops; valid labels: bug, Frontend"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hung_request_maps_to_timeout() {
+        // Accepts the connection but never answers
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/graphql", listener.local_addr().unwrap());
+
+        let client = LinearClient::with_timeout("key".to_string(), Duration::from_millis(200));
+        let err = client.http.post(&url).send().await.unwrap_err();
+        let err = client.http_error(err);
+        assert!(matches!(err, LinearHttpError::Timeout(_)));
+        assert_eq!(String::from(err), "Linear request timed out after 200ms");
+        drop(listener);
+    }
+
+    /// Serve one canned HTTP status and body per connection, in order, counting requests
+    async fn mock_linear(
+        replies: Vec<(u16, &'static str)>,
+    ) -> (String, std::sync::Arc<std::sync::atomic::AtomicUsize>) {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/graphql", listener.local_addr().unwrap());
+        let hits = std::sync::Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        tokio::spawn(async move {
+            for (status, body) in replies {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buf = [0u8; 4096];
+                let _ = stream.read(&mut buf).await;
+                counter.fetch_add(1, Ordering::SeqCst);
+                let response = format!(
+                    "HTTP/1.1 {} X\r\nContent-Type: application/json\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+        (url, hits)
+    }
+
+    #[tokio::test]
+    async fn test_server_errors_are_retried() {
+        use std::sync::atomic::Ordering;
+
+        let (url, hits) = mock_linear(vec![(502, "{}"), (503, "{}"), (200, "{}")]).await;
+        let client =
+            LinearClient::with_timeout("key".to_string(), Duration::from_secs(5)).with_url(url);
+        let response = client.post("test", &serde_json::json!({})).await.unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+
+        // Client errors come straight back
+        let (url, hits) = mock_linear(vec![(400, "{}"), (200, "{}")]).await;
+        let client =
+            LinearClient::with_timeout("key".to_string(), Duration::from_secs(5)).with_url(url);
+        let response = client.post("test", &serde_json::json!({})).await.unwrap();
+        assert_eq!(response.status(), 400);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+        // A write may have landed before the 5xx, so mutations aren't repeated
+        let (url, hits) = mock_linear(vec![(502, "{}"), (200, "{}")]).await;
+        let client =
+            LinearClient::with_timeout("key".to_string(), Duration::from_secs(5)).with_url(url);
+        let response = client
+            .post_mutation("test", &serde_json::json!({}))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 502);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_mutations_retry_refused_connections() {
+        // Nothing listens on the port until the first attempt has been refused
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let client = LinearClient::with_timeout("key".to_string(), Duration::from_secs(5))
+            .with_url(format!("http://127.0.0.1:{}/graphql", port));
+        let server = async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            let listener = tokio::net::TcpListener::bind(("127.0.0.1", port))
+                .await
+                .unwrap();
+            let (mut stream, _) = listener.accept().await.unwrap();
+            use tokio::io::{AsyncReadExt, AsyncWriteExt};
+            let mut buf = [0u8; 4096];
+            let _ = stream.read(&mut buf).await;
+            let _ = stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}")
+                .await;
+        };
+        let body = serde_json::json!({});
+        let (response, ()) = tokio::join!(client.post_mutation("test", &body), server);
+        assert_eq!(response.unwrap().status(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_custom_api_url_is_the_request_target() {
+        use std::sync::atomic::Ordering;
+
+        assert_eq!(
+            parse_api_url(None).unwrap().as_str(),
+            DEFAULT_LINEAR_API_URL
+        );
+        assert_eq!(
+            parse_api_url(Some("  ")).unwrap().as_str(),
+            DEFAULT_LINEAR_API_URL
+        );
+        let err = parse_api_url(Some("linear.internal/graphql")).unwrap_err();
+        assert!(err.starts_with("VIBE_LINEAR_API_URL must be an http(s) URL"));
+
+        let (url, hits) = mock_linear(vec![(200, r#"{"data":{"viewer":{"id":"u1"}}}"#)]).await;
+        let client =
+            LinearClient::with_timeout("key".to_string(), Duration::from_secs(5)).with_url(url);
+        assert_eq!(client.get_viewer_id().await.unwrap(), "u1");
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+        // A bad URL fails every request up front
+        let client = LinearClient::with_timeout("key".to_string(), Duration::from_secs(5))
+            .with_url("ftp://proxy".to_string());
+        let err = client.get_viewer_id().await.unwrap_err();
+        assert!(err.contains("got 'ftp://proxy'"));
+    }
+
+    #[tokio::test]
+    async fn test_viewer_and_teams_are_fetched_once() {
+        use std::sync::atomic::Ordering;
+
+        let (url, hits) = mock_linear(vec![
+            (200, r#"{"data":{"viewer":{"id":"u1"}}}"#),
+            (
+                200,
+                r#"{"data":{"teams":{"nodes":[{"id":"t1","key":"VIB","name":"Vibe"}]}}}"#,
+            ),
+        ])
+        .await;
+        let client =
+            LinearClient::with_timeout("key".to_string(), Duration::from_secs(5)).with_url(url);
+        for _ in 0..3 {
+            assert_eq!(client.get_viewer_id().await.unwrap(), "u1");
+            assert_eq!(client.get_team_id(Some("vib")).await.unwrap(), "t1");
+        }
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_assigned_issues_follow_pages() {
+        let (url, hits) = mock_linear(vec![
+            (
+                200,
+                r#"{"data":{"viewer":{"assignedIssues":{
+                    "pageInfo":{"hasNextPage":true,"endCursor":"c1"},
+                    "nodes":[{"identifier":"VIB-1","title":"Recent","description":null,
+                        "url":"https://linear.app/t/issue/VIB-1","state":{"name":"Todo","type":"unstarted"}}]}}}}"#,
+            ),
+            (
+                200,
+                r#"{"data":{"viewer":{"assignedIssues":{
+                    "pageInfo":{"hasNextPage":false,"endCursor":null},
+                    "nodes":[{"identifier":"VIB-2","title":"Older","description":null,
+                        "url":"https://linear.app/t/issue/VIB-2","state":{"name":"Backlog","type":"backlog"}}]}}}}"#,
+            ),
+        ])
+        .await;
+        let client =
+            LinearClient::with_timeout("key".to_string(), Duration::from_secs(5)).with_url(url);
+
+        let issues = client.fetch_assigned_issues().await.unwrap();
+        let ids: Vec<_> = issues.iter().map(|i| i.identifier.as_str()).collect();
+        assert_eq!(ids, vec!["VIB-1", "VIB-2"]);
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert!(assigned_page_query(Some("c1")).contains(r#"after: "c1""#));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_issue_status_tells_missing_from_failed() {
+        let (url, _) = mock_linear(vec![
+            (
+                200,
+                r#"{"data":{"i0":{"identifier":"VIB-3","state":{"name":"Canceled","type":"canceled"}}}}"#,
+            ),
+            (
+                200,
+                r#"{"data":null,"errors":[{"message":"Entity not found: Issue",
+                    "path":["i0"],"extensions":{"code":"ENTITY_NOT_FOUND"}}]}"#,
+            ),
+            (200, r#"{"data":null,"errors":[{"message":"Internal error"}]}"#),
+            // Only the wording says "not found"; that isn't enough to delete on
+            (
+                200,
+                r#"{"data":{"i0":null},"errors":[{"message":"Team not found",
+                    "extensions":{"code":"INTERNAL_SERVER_ERROR"}}]}"#,
+            ),
+        ])
+        .await;
+        let client =
+            LinearClient::with_timeout("key".to_string(), Duration::from_secs(5)).with_url(url);
+
+        let found = client.fetch_issue_status("VIB-3").await.unwrap().unwrap();
+        assert_eq!(found.state_type, "canceled");
+        assert!(client.fetch_issue_status("VIB-4").await.unwrap().is_none());
+        assert!(client.fetch_issue_status("VIB-5").await.is_err());
+        assert!(client.fetch_issue_status("VIB-6").await.is_err());
+
+        // Data for the issue wins over a stray not-found error
+        assert!(!issue_is_gone(&serde_json::json!({
+            "data": { "i0": { "identifier": "VIB-3" } },
+            "errors": [{ "extensions": { "code": "ENTITY_NOT_FOUND" } }],
+        })));
+    }
+
+    #[tokio::test]
+    async fn test_search_issues() {
+        let (url, _) = mock_linear(vec![(
+            200,
+            r#"{"data":{"issueSearch":{"nodes":[
+                {"identifier":"VIB-4","title":"Auth bug on login","description":null,
+                 "url":"https://linear.app/t/issue/VIB-4","priority":1,"estimate":null,
+                 "labels":{"nodes":[{"name":"bug"}]}},
+                {"identifier":"VIB-9","title":"Auth token refresh","description":"Expires early",
+                 "url":"https://linear.app/t/issue/VIB-9","priority":0,"estimate":2,
+                 "labels":{"nodes":[]}}
+            ]}}}"#,
+        )])
+        .await;
+        let client =
+            LinearClient::with_timeout("key".to_string(), Duration::from_secs(5)).with_url(url);
+
+        let issues = client.search_issues("auth bug").await.unwrap();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].identifier, "VIB-4");
+        assert_eq!(issues[0].labels, vec!["bug"]);
+        assert_eq!(issues[0].priority, Some(1));
+        assert_eq!(issues[1].estimate, Some(2.0));
+    }
+
+    #[test]
+    fn test_rate_limit_headers() {
+        let mut headers = HeaderMap::new();
+        assert!(parse_rate_limit(&headers).is_none());
+
+        headers.insert("x-ratelimit-requests-limit", "1500".parse().unwrap());
+        headers.insert("x-ratelimit-requests-remaining", "3".parse().unwrap());
+        headers.insert(
+            "x-ratelimit-requests-reset",
+            "1709296200000".parse().unwrap(),
+        );
+        let limit = parse_rate_limit(&headers).unwrap();
+        assert_eq!(limit.limit, 1500);
+        assert_eq!(limit.remaining, 3);
+        assert_eq!(limit.reset_at.to_rfc3339(), "2024-03-01T12:30:00+00:00");
+
+        // Nearly spent: wait for the reset, unless it has already passed
+        let now = "2024-03-01T12:29:00Z".parse().unwrap();
+        assert_eq!(
+            linear_rate_limit_wait(&limit, now),
+            Some(Duration::from_secs(60))
+        );
+        let later = "2024-03-01T12:31:00Z".parse().unwrap();
+        assert_eq!(linear_rate_limit_wait(&limit, later), None);
+
+        // A minute is worth waiting out; a reset further off fails right away
+        assert_eq!(
+            rate_limit_hold(Some(limit), now).unwrap(),
+            Some(Duration::from_secs(60))
+        );
+        let early = "2024-03-01T12:20:00Z".parse().unwrap();
+        assert!(matches!(
+            rate_limit_hold(Some(limit), early),
+            Err(LinearHttpError::RateLimited { .. })
+        ));
+        assert_eq!(rate_limit_hold(None, early).unwrap(), None);
+
+        // Plain names, reset in seconds
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", "900".parse().unwrap());
+        headers.insert("x-ratelimit-reset", "1709296200".parse().unwrap());
+        let limit = parse_rate_limit(&headers).unwrap();
+        assert_eq!(limit.limit, 900);
+        assert_eq!(linear_rate_limit_wait(&limit, now), None);
+    }
+
     fn get_test_api_key() -> Option<String> {
         std::env::var("VIBE_KANBAN_LINEAR_API_KEY").ok()
     }
@@ -0,0 +1,158 @@
+/// One field of a GraphQL selection: `alias: name(arg: value) { children }`.
+/// Queries are composed from these, so a new issue field is one more
+/// child rather than another hand-written query string.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    alias: Option<String>,
+    name: String,
+    args: Vec<(String, String)>,
+    children: Vec<Field>,
+}
+
+impl Field {
+    pub fn new(name: &str) -> Self {
+        Self {
+            alias: None,
+            name: name.to_string(),
+            args: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    pub fn alias(mut self, alias: impl Into<String>) -> Self {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    /// Argument with a raw GraphQL value, e.g. a filter object or an enum
+    pub fn arg(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.args.push((name.to_string(), value.into()));
+        self
+    }
+
+    /// Argument with a string value, quoted and escaped
+    pub fn str_arg(self, name: &str, value: &str) -> Self {
+        self.arg(name, quote(value))
+    }
+
+    pub fn select(mut self, children: impl IntoIterator<Item = Field>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// Select scalar fields by name
+    pub fn leaves(self, names: &[&str]) -> Self {
+        self.select(names.iter().map(|name| Field::new(name)))
+    }
+
+    fn write(&self, out: &mut String) {
+        if let Some(alias) = &self.alias {
+            out.push_str(alias);
+            out.push_str(": ");
+        }
+        out.push_str(&self.name);
+        if !self.args.is_empty() {
+            let args: Vec<String> = self
+                .args
+                .iter()
+                .map(|(name, value)| format!("{}: {}", name, value))
+                .collect();
+            out.push('(');
+            out.push_str(&args.join(", "));
+            out.push(')');
+        }
+        write_selection(&self.children, out);
+    }
+}
+
+fn write_selection(fields: &[Field], out: &mut String) {
+    if fields.is_empty() {
+        return;
+    }
+    out.push_str(" {");
+    for field in fields {
+        out.push(' ');
+        field.write(out);
+    }
+    out.push_str(" }");
+}
+
+/// A connection selecting `nodes { ... }`, e.g. `labels { nodes { name } }`
+pub fn connection(name: &str, node_fields: impl IntoIterator<Item = Field>) -> Field {
+    Field::new(name).select([Field::new("nodes").select(node_fields)])
+}
+
+/// A GraphQL string literal (JSON string syntax is valid GraphQL)
+pub fn quote(value: &str) -> String {
+    serde_json::Value::String(value.to_string()).to_string()
+}
+
+pub fn query(fields: impl IntoIterator<Item = Field>) -> String {
+    operation("query", fields)
+}
+
+pub fn mutation(fields: impl IntoIterator<Item = Field>) -> String {
+    operation("mutation", fields)
+}
+
+fn operation(kind: &str, fields: impl IntoIterator<Item = Field>) -> String {
+    let mut out = kind.to_string();
+    write_selection(&fields.into_iter().collect::<Vec<_>>(), &mut out);
+    out
+}
+
+/// Fields every issue read needs to become a task
+pub fn issue_core() -> Vec<Field> {
+    vec![
+        Field::new("identifier"),
+        Field::new("title"),
+        Field::new("description"),
+        Field::new("url"),
+        Field::new("priority"),
+        Field::new("estimate"),
+        connection("labels", [Field::new("name")]),
+    ]
+}
+
+/// Where an issue stands: workflow state and assignee
+pub fn issue_status() -> Vec<Field> {
+    vec![
+        Field::new("identifier"),
+        Field::new("state").leaves(&["name", "type"]),
+        Field::new("assignee").leaves(&["displayName"]),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_builder_renders_graphql() {
+        let q = query([
+            Field::new("viewer").select([connection("assignedIssues", issue_core())
+                .arg("filter", "{ state: { type: { eq: \"backlog\" } } }")
+                .arg("first", "100")]),
+        ]);
+        assert_eq!(
+            q,
+            "query { viewer { assignedIssues(filter: { state: { type: { eq: \"backlog\" } } }, first: 100) \
+             { nodes { identifier title description url priority estimate labels { nodes { name } } } } } }"
+        );
+
+        let q = query([Field::new("issue")
+            .alias("i0")
+            .str_arg("id", "VIB-5")
+            .select(issue_status())]);
+        assert_eq!(
+            q,
+            "query { i0: issue(id: \"VIB-5\") { identifier state { name type } assignee { displayName } } }"
+        );
+
+        assert_eq!(quote("say \"hi\"\nbye\\"), r#""say \"hi\"\nbye\\""#);
+        assert_eq!(
+            mutation([Field::new("issueUpdate").leaves(&["success"])]),
+            "mutation { issueUpdate { success } }"
+        );
+    }
+}
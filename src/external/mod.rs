@@ -1,23 +1,34 @@
 mod claude_activity;
+mod claude_config;
 mod claude_plans;
 #[allow(dead_code)]
 mod claude_usage;
 mod editor;
 mod gh;
+mod git_watch;
 mod linear;
+mod linear_query;
 #[allow(dead_code)]
 mod notifications;
 #[allow(dead_code)]
 mod opener;
 mod terminal_spawn;
+mod timing;
 mod worktrunk;
 mod zellij;
 
 pub use claude_activity::{ActivityWatcher, ClaudeActivityTracker, count_active_sessions};
-pub use claude_plans::ClaudePlanReader;
+pub use claude_config::ClaudeConfig;
+pub use claude_plans::{ClaudePlanReader, PlanContent};
 pub use editor::{edit_markdown, view_file};
 pub use gh::*;
-pub use linear::{LinearClient, LinearIssue, LinearIssueStatus};
+pub use git_watch::GitWatcher;
+pub use linear::{
+    CreatedIssue, GASIT_LABEL, LinearAttachment, LinearBoardIssue, LinearClient, LinearIssue,
+    LinearIssueStatus, LinearWorkflowState, linear_rate_limit_wait,
+};
+pub use opener::open_url;
 pub use terminal_spawn::*;
+pub use timing::*;
 pub use worktrunk::*;
 pub use zellij::*;
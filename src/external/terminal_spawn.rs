@@ -4,6 +4,8 @@ use anyhow::Result;
 use std::path::Path;
 use std::process::Command;
 
+use super::ClaudeConfig;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AssistantCli {
     #[default]
@@ -11,13 +13,79 @@ pub enum AssistantCli {
     Codex,
 }
 
+impl AssistantCli {
+    /// Every assistant a session can be launched with, in picker order
+    pub const ALL: [AssistantCli; 2] = [AssistantCli::Claude, AssistantCli::Codex];
+
+    /// Binary name, also how the choice is remembered on disk
+    pub fn binary(self) -> &'static str {
+        match self {
+            AssistantCli::Claude => "claude",
+            AssistantCli::Codex => "codex",
+        }
+    }
+
+    pub fn from_binary(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.binary() == name.trim())
+    }
+
+    pub fn is_available(self) -> bool {
+        find_on_path(self.binary()).is_some()
+    }
+
+    /// Where the last assistant picked for `project_name` is kept:
+    /// `~/.vibe/projects/{project}/assistant`
+    fn last_choice_path(project_name: &str) -> Option<std::path::PathBuf> {
+        dirs::home_dir().map(|home| {
+            home.join(".vibe")
+                .join("projects")
+                .join(project_name)
+                .join("assistant")
+        })
+    }
+
+    /// The assistant last picked for this project, if any
+    pub fn load_last(project_name: &str) -> Option<Self> {
+        let content = std::fs::read_to_string(Self::last_choice_path(project_name)?).ok()?;
+        Self::from_binary(&content)
+    }
+
+    pub fn save_last(self, project_name: &str) -> Result<()> {
+        let path = Self::last_choice_path(project_name)
+            .ok_or_else(|| anyhow::anyhow!("No home directory"))?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, format!("{}\n", self.binary()))?;
+        Ok(())
+    }
+}
+
+/// First executable named `name` in PATH
+pub fn find_on_path(name: &str) -> Option<std::path::PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path)
+        .map(|dir| dir.join(name))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Where a task's session runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SessionPlacement {
+    /// A dedicated worktree per branch, created via `wt`
+    #[default]
+    Worktree,
+    /// The project checkout itself, switched to the task branch
+    CurrentCheckout,
+}
+
 /// Open a new tmux pane running zellij with claude
 /// This creates a vertical split in tmux and runs the zellij session there
 pub fn open_tmux_pane_with_zellij_claude(session_name: &str, cwd: &Path) -> Result<()> {
     // Build the zellij command that will run claude
+    let zellij = zellij_shell();
     let zellij_cmd = format!(
-        "zellij attach {} 2>/dev/null || zellij -s {} -- claude --dangerously-skip-permissions",
-        session_name, session_name,
+        "{zellij} attach {session_name} 2>/dev/null || {zellij} -s {session_name} -- claude --dangerously-skip-permissions",
     );
 
     // Create a new tmux pane (vertical split) and run zellij in it
@@ -36,7 +104,7 @@ pub fn open_tmux_pane_with_zellij_claude(session_name: &str, cwd: &Path) -> Resu
 
 /// Attach to existing zellij session in a new tmux pane
 pub fn open_tmux_pane_attach_zellij(session_name: &str) -> Result<()> {
-    let zellij_cmd = format!("zellij attach {}", session_name);
+    let zellij_cmd = format!("{} attach {}", zellij_shell(), session_name);
 
     Command::new("tmux")
         .arg("split-window")
@@ -52,7 +120,8 @@ pub fn open_tmux_pane_attach_zellij(session_name: &str) -> Result<()> {
 #[allow(dead_code)]
 pub fn open_ghostty_with_zellij_claude(session_name: &str, cwd: &Path) -> Result<()> {
     let zellij_cmd = format!(
-        "zellij -s {} --cwd {} -- claude --dangerously-skip-permissions",
+        "{} -s {} --cwd {} -- claude --dangerously-skip-permissions",
+        zellij_shell(),
         session_name,
         cwd.to_string_lossy()
     );
@@ -79,7 +148,7 @@ pub fn open_ghostty_with_zellij_claude(session_name: &str, cwd: &Path) -> Result
 
 /// Open a new Ghostty terminal window and attach to existing zellij session
 pub fn open_ghostty_attach_zellij(session_name: &str) -> Result<()> {
-    let zellij_cmd = format!("zellij attach {}", shell_escape(session_name));
+    let zellij_cmd = format!("{} attach {}", zellij_shell(), shell_escape(session_name));
 
     // Wrap in /bin/zsh -c "..." as a single string for Ghostty's -e flag
     let full_cmd = format!("/bin/zsh -c \"{}\"", zellij_cmd);
@@ -112,6 +181,21 @@ pub fn open_ghostty_with_command(command: &str, cwd: Option<&Path>) -> Result<()
     Ok(())
 }
 
+/// `zellij` plus the configured global args, for commands run through a shell
+fn zellij_shell() -> String {
+    std::iter::once("zellij".to_string())
+        .chain(super::zellij_global_args().iter().map(|a| shell_escape(a)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Escape text for a plist <string> value
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
 /// Simple shell escape for command arguments
 fn shell_escape(s: &str) -> String {
     // If string contains no special chars, return as-is
@@ -124,15 +208,31 @@ fn shell_escape(s: &str) -> String {
     format!("'{}'", s.replace('\'', "'\\''"))
 }
 
+/// How the launcher treats a session that already exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LauncherMode {
+    /// Attach to a running session, resume an exited one
+    Normal,
+    /// Like `Normal`, but a running session not started in plan mode is restarted in it
+    Plan,
+    /// Replace a planning session (running or exited) with a fresh one that executes the plan
+    Execute,
+}
+
+impl LauncherMode {
+    fn from_plan_mode(plan_mode: bool) -> Self {
+        if plan_mode { Self::Plan } else { Self::Normal }
+    }
+}
+
 /// Create a launcher script for zellij session
 /// fresh_cmd: command for new sessions (with prompt)
 /// continue_cmd: command for resuming EXITED sessions (with --continue)
-/// plan_mode: if true, kill running sessions to restart in plan mode
 fn create_launcher_script(
     session_name: &str,
     fresh_cmd: &str,
     continue_cmd: &str,
-    plan_mode: bool,
+    mode: LauncherMode,
 ) -> Result<std::path::PathBuf> {
     use std::io::Write;
     use std::os::unix::fs::PermissionsExt;
@@ -172,65 +272,95 @@ fn create_launcher_script(
     // - Not found: create new with prompt
     // Use SHELL=/path/to/script zellij -s session to run script as the shell
     let launcher_path = script_dir.join(format!("{}-launch.sh", session_name));
-    let launcher_script = if plan_mode {
-        format!(
+    let zellij = zellij_shell();
+    let launcher_script = match mode {
+        LauncherMode::Plan => format!(
             r#"#!/bin/zsh
 # Strip ANSI color codes for reliable grep
-SESSION_LINE=$(zellij list-sessions 2>/dev/null | sed 's/\x1b\[[0-9;]*m//g' | grep "^{session}")
+SESSION_LINE=$({zellij} list-sessions 2>/dev/null | sed 's/\x1b\[[0-9;]*m//g' | grep "^{session}")
 # Reset terminal state - pipes above can corrupt it, sleep lets terminal settle
 stty sane 2>/dev/null
 sleep 0.1
 if [[ -n "$SESSION_LINE" ]]; then
   if echo "$SESSION_LINE" | grep -q "EXITED"; then
-    zellij delete-session {session} 2>/dev/null
+    {zellij} delete-session {session} 2>/dev/null
     touch {plan_marker}
-    SHELL={continue_script} exec zellij -s {session}
+    SHELL={continue_script} exec {zellij} -s {session}
   else
     # Plan mode requested - check if session was started in plan mode
     if [[ ! -f {plan_marker} ]]; then
       # Session running but not in plan mode - kill and restart in plan mode
-      zellij kill-session {session} 2>/dev/null
+      {zellij} kill-session {session} 2>/dev/null
       sleep 0.2
       touch {plan_marker}
-      SHELL={fresh_script} exec zellij -s {session}
+      SHELL={fresh_script} exec {zellij} -s {session}
     else
-      exec zellij attach {session}
+      exec {zellij} attach {session}
     fi
   fi
 fi
 touch {plan_marker}
-SHELL={fresh_script} exec zellij -s {session}
+SHELL={fresh_script} exec {zellij} -s {session}
 "#,
             session = session_name,
             fresh_script = fresh_script_path.display(),
             continue_script = continue_script_path.display(),
             plan_marker = plan_marker.display(),
-        )
-    } else {
-        format!(
+            zellij = zellij,
+        ),
+        LauncherMode::Execute => format!(
+            r#"#!/bin/zsh
+# Strip ANSI color codes for reliable grep
+SESSION_LINE=$({zellij} list-sessions 2>/dev/null | sed 's/\x1b\[[0-9;]*m//g' | grep "^{session}")
+# Reset terminal state - pipes above can corrupt it, sleep lets terminal settle
+stty sane 2>/dev/null
+sleep 0.1
+if [[ -n "$SESSION_LINE" ]]; then
+  if echo "$SESSION_LINE" | grep -q "EXITED"; then
+    # Start over rather than resume the planning conversation
+    {zellij} delete-session {session} 2>/dev/null
+  elif [[ -f {plan_marker} ]]; then
+    # Still planning - kill and restart executing the plan
+    {zellij} kill-session {session} 2>/dev/null
+    sleep 0.2
+  else
+    # Already executing
+    exec {zellij} attach {session}
+  fi
+fi
+rm -f {plan_marker}
+SHELL={fresh_script} exec {zellij} -s {session}
+"#,
+            session = session_name,
+            fresh_script = fresh_script_path.display(),
+            plan_marker = plan_marker.display(),
+            zellij = zellij,
+        ),
+        LauncherMode::Normal => format!(
             r#"#!/bin/zsh
 # Strip ANSI color codes for reliable grep
-SESSION_LINE=$(zellij list-sessions 2>/dev/null | sed 's/\x1b\[[0-9;]*m//g' | grep "^{session}")
+SESSION_LINE=$({zellij} list-sessions 2>/dev/null | sed 's/\x1b\[[0-9;]*m//g' | grep "^{session}")
 # Reset terminal state - pipes above can corrupt it, sleep lets terminal settle
 stty sane 2>/dev/null
 sleep 0.1
 if [[ -n "$SESSION_LINE" ]]; then
   if echo "$SESSION_LINE" | grep -q "EXITED"; then
-    zellij delete-session {session} 2>/dev/null
+    {zellij} delete-session {session} 2>/dev/null
     rm -f {plan_marker}
-    SHELL={continue_script} exec zellij -s {session}
+    SHELL={continue_script} exec {zellij} -s {session}
   else
-    exec zellij attach {session}
+    exec {zellij} attach {session}
   fi
 fi
 rm -f {plan_marker}
-SHELL={fresh_script} exec zellij -s {session}
+SHELL={fresh_script} exec {zellij} -s {session}
 "#,
             session = session_name,
             fresh_script = fresh_script_path.display(),
             continue_script = continue_script_path.display(),
             plan_marker = plan_marker.display(),
-        )
+            zellij = zellij,
+        ),
     };
     let mut file = std::fs::File::create(&launcher_path)?;
     file.write_all(launcher_script.as_bytes())?;
@@ -257,15 +387,41 @@ fn codex_flags(plan_mode: bool) -> &'static str {
     }
 }
 
-fn commands_for_existing_worktree(assistant: AssistantCli, plan_mode: bool) -> (String, String) {
+/// `claude` with vibe's mode flags, then the project's extra flags, then the
+/// prompt read from `prompt_file`
+fn claude_command(mode_flags: &str, claude: &ClaudeConfig, prompt_file: Option<&Path>) -> String {
+    let mut cmd = format!("claude {}", mode_flags);
+    for flag in claude.extra_flags() {
+        cmd.push(' ');
+        cmd.push_str(&shell_escape(flag));
+    }
+    if let Some(prompt_file) = prompt_file {
+        cmd.push_str(&format!(" \"$(cat {})\"", prompt_file.display()));
+    }
+    cmd
+}
+
+fn claude_mode_flags(plan_mode: bool) -> &'static str {
+    if plan_mode {
+        "--permission-mode plan"
+    } else {
+        "--dangerously-skip-permissions"
+    }
+}
+
+fn commands_for_existing_worktree(
+    assistant: AssistantCli,
+    plan_mode: bool,
+    claude: &ClaudeConfig,
+) -> (String, String) {
     match assistant {
         AssistantCli::Claude => {
             // For Claude we can safely use --continue for both fresh and resumed sessions.
-            let cmd = if plan_mode {
-                "claude --continue --permission-mode plan".to_string()
-            } else {
-                "claude --continue --dangerously-skip-permissions".to_string()
-            };
+            let cmd = claude_command(
+                &format!("--continue {}", claude_mode_flags(plan_mode)),
+                claude,
+                None,
+            );
             (cmd.clone(), cmd)
         }
         AssistantCli::Codex => {
@@ -282,26 +438,15 @@ fn commands_with_context(
     assistant: AssistantCli,
     plan_mode: bool,
     context_file: &std::path::Path,
+    claude: &ClaudeConfig,
 ) -> (String, String) {
     match assistant {
         AssistantCli::Claude => {
-            if plan_mode {
-                (
-                    format!(
-                        "claude --permission-mode plan \"$(cat {})\"",
-                        context_file.display()
-                    ),
-                    "claude --continue --permission-mode plan".to_string(),
-                )
-            } else {
-                (
-                    format!(
-                        "claude --dangerously-skip-permissions \"$(cat {})\"",
-                        context_file.display()
-                    ),
-                    "claude --continue --dangerously-skip-permissions".to_string(),
-                )
-            }
+            let mode = claude_mode_flags(plan_mode);
+            (
+                claude_command(mode, claude, Some(context_file)),
+                claude_command(&format!("--continue {}", mode), claude, None),
+            )
         }
         AssistantCli::Codex => {
             let flags = codex_flags(plan_mode);
@@ -313,6 +458,14 @@ fn commands_with_context(
     }
 }
 
+/// The task prompt as the assistant receives it: Claude gets the project's wrapper
+fn session_prompt(assistant: AssistantCli, task_context: &str, claude: &ClaudeConfig) -> String {
+    match assistant {
+        AssistantCli::Claude => claude.wrap_prompt(task_context),
+        AssistantCli::Codex => task_context.to_string(),
+    }
+}
+
 /// Launch assistant CLI in a zellij session for a worktree
 /// Uses `wt switch -x` to switch/create worktree AND launch zellij in one step
 /// The -x script inherits TTY from wt, which inherits from us (via .status())
@@ -322,6 +475,7 @@ pub fn launch_zellij_claude_in_worktree(
     assistant: AssistantCli,
     plan_mode: bool,
     project_dir: &std::path::Path,
+    claude: &ClaudeConfig,
 ) -> Result<()> {
     let session_name = super::session_name_for_branch(branch);
     let wt = wt_binary();
@@ -334,8 +488,13 @@ pub fn launch_zellij_claude_in_worktree(
         anyhow::bail!("project_dir does not exist: {:?}", project_dir);
     }
 
-    let (fresh_cmd, continue_cmd) = commands_for_existing_worktree(assistant, plan_mode);
-    let launcher = create_launcher_script(&session_name, &fresh_cmd, &continue_cmd, plan_mode)?;
+    let (fresh_cmd, continue_cmd) = commands_for_existing_worktree(assistant, plan_mode, claude);
+    let launcher = create_launcher_script(
+        &session_name,
+        &fresh_cmd,
+        &continue_cmd,
+        LauncherMode::from_plan_mode(plan_mode),
+    )?;
     let launcher_path = launcher.to_str().unwrap();
 
     // Use .status() to inherit TTY - this is critical for zellij to work!
@@ -373,6 +532,44 @@ pub fn launch_zellij_claude_in_worktree_with_context(
     assistant: AssistantCli,
     plan_mode: bool,
     project_dir: &std::path::Path,
+    claude: &ClaudeConfig,
+) -> Result<()> {
+    launch_in_worktree_with_context(
+        branch,
+        task_context,
+        assistant,
+        LauncherMode::from_plan_mode(plan_mode),
+        project_dir,
+        claude,
+    )
+}
+
+/// Relaunch a planned task's worktree to execute the plan: a session still in plan
+/// mode is replaced by a fresh one whose prompt (`task_context`) carries the plan
+pub fn launch_zellij_claude_executing_plan(
+    branch: &str,
+    task_context: &str,
+    assistant: AssistantCli,
+    project_dir: &std::path::Path,
+    claude: &ClaudeConfig,
+) -> Result<()> {
+    launch_in_worktree_with_context(
+        branch,
+        task_context,
+        assistant,
+        LauncherMode::Execute,
+        project_dir,
+        claude,
+    )
+}
+
+fn launch_in_worktree_with_context(
+    branch: &str,
+    task_context: &str,
+    assistant: AssistantCli,
+    mode: LauncherMode,
+    project_dir: &std::path::Path,
+    claude: &ClaudeConfig,
 ) -> Result<()> {
     let session_name = super::session_name_for_branch(branch);
     let wt = wt_binary();
@@ -392,11 +589,15 @@ pub fn launch_zellij_claude_in_worktree_with_context(
     std::fs::create_dir_all(&script_dir)?;
 
     let context_file = script_dir.join(format!("{}-context.txt", session_name));
-    std::fs::write(&context_file, task_context)?;
+    std::fs::write(
+        &context_file,
+        session_prompt(assistant, task_context, claude),
+    )?;
 
-    let (fresh_cmd, continue_cmd) = commands_with_context(assistant, plan_mode, &context_file);
+    let (fresh_cmd, continue_cmd) =
+        commands_with_context(assistant, mode == LauncherMode::Plan, &context_file, claude);
 
-    let launcher = create_launcher_script(&session_name, &fresh_cmd, &continue_cmd, plan_mode)?;
+    let launcher = create_launcher_script(&session_name, &fresh_cmd, &continue_cmd, mode)?;
     let launcher_path = launcher.to_str().unwrap();
 
     // Use .status() to inherit TTY - critical for zellij!
@@ -468,6 +669,7 @@ pub fn launch_prime_session(
     project_name: &str,
     assistant: AssistantCli,
     project_dir: &std::path::Path,
+    claude: &ClaudeConfig,
 ) -> Result<()> {
     let session_name = super::sanitize_session_name(&format!("{}.prime", project_name));
 
@@ -476,7 +678,7 @@ pub fn launch_prime_session(
     }
 
     // Check session state: alive, exited, or doesn't exist
-    let session_output = Command::new("zellij")
+    let session_output = super::zellij_command()
         .args(["list-sessions"])
         .output()
         .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
@@ -489,7 +691,7 @@ pub fn launch_prime_session(
     if !session_alive {
         if session_exited {
             // Clean up the dead session so we can recreate it
-            let _ = Command::new("zellij")
+            let _ = super::zellij_command()
                 .args(["delete-session", &session_name])
                 .status();
         }
@@ -504,11 +706,12 @@ pub fn launch_prime_session(
 
         let (fresh_cmd, continue_cmd) = match assistant {
             AssistantCli::Claude => (
-                format!(
-                    "claude --dangerously-skip-permissions \"$(cat {})\"",
-                    context_file.display()
+                claude_command(claude_mode_flags(false), claude, Some(&context_file)),
+                claude_command(
+                    &format!("--continue {}", claude_mode_flags(false)),
+                    claude,
+                    None,
                 ),
-                "claude --continue --dangerously-skip-permissions".to_string(),
             ),
             AssistantCli::Codex => {
                 let flags = codex_flags(false);
@@ -519,7 +722,12 @@ pub fn launch_prime_session(
             }
         };
 
-        let _launcher = create_launcher_script(&session_name, &fresh_cmd, &continue_cmd, false)?;
+        let _launcher = create_launcher_script(
+            &session_name,
+            &fresh_cmd,
+            &continue_cmd,
+            LauncherMode::Normal,
+        )?;
 
         // Use continue script if resuming a dead session, fresh if brand new
         let shell_script = if session_exited {
@@ -575,6 +783,17 @@ fn spawn_headless_via_launchd(
     // Collect current PATH and HOME for the plist
     let path = std::env::var("PATH").unwrap_or_else(|_| "/usr/bin:/bin".to_string());
     let home = std::env::var("HOME").unwrap_or_else(|_| "/tmp".to_string());
+    // launchd starts jobs with a clean environment, so carry over the zellij server context
+    let zellij_env: String = super::zellij_server_env()
+        .into_iter()
+        .map(|(key, value)| {
+            format!(
+                "\n        <key>{}</key>\n        <string>{}</string>",
+                key,
+                xml_escape(&value)
+            )
+        })
+        .collect();
 
     let plist = format!(
         r#"<?xml version="1.0" encoding="UTF-8"?>
@@ -603,7 +822,7 @@ fn spawn_headless_via_launchd(
         <key>TERM</key>
         <string>xterm-256color</string>
         <key>COLORTERM</key>
-        <string>truecolor</string>
+        <string>truecolor</string>{zellij_env}
     </dict>
 </dict>
 </plist>"#,
@@ -637,17 +856,43 @@ pub fn launch_headless_in_worktree(
     task_context: &str,
     assistant: AssistantCli,
     project_dir: &std::path::Path,
+    claude: &ClaudeConfig,
+) -> Result<()> {
+    launch_headless(
+        branch,
+        task_context,
+        assistant,
+        project_dir,
+        SessionPlacement::Worktree,
+        claude,
+    )
+}
+
+/// Launch a Claude session headlessly (no TTY required) wherever `placement` says.
+/// The workspace is prepared before any scripts are written, so a refused
+/// branch switch leaves nothing behind.
+pub fn launch_headless(
+    branch: &str,
+    task_context: &str,
+    assistant: AssistantCli,
+    project_dir: &std::path::Path,
+    placement: SessionPlacement,
+    claude: &ClaudeConfig,
 ) -> Result<()> {
     let session_name = super::session_name_for_branch(branch);
-    let wt = wt_binary();
 
-    if !std::path::Path::new(&wt).exists() {
-        anyhow::bail!("wt binary not found at: {}", wt);
-    }
     if !project_dir.exists() {
         anyhow::bail!("project_dir does not exist: {:?}", project_dir);
     }
 
+    let working_dir = match placement {
+        SessionPlacement::Worktree => ensure_worktree(branch, project_dir)?,
+        SessionPlacement::CurrentCheckout => {
+            switch_branch_in_checkout(branch, project_dir)?;
+            project_dir.to_path_buf()
+        }
+    };
+
     // Write task context to file
     let script_dir = dirs::cache_dir()
         .unwrap_or_else(|| std::path::PathBuf::from("/tmp"))
@@ -655,10 +900,81 @@ pub fn launch_headless_in_worktree(
     std::fs::create_dir_all(&script_dir)?;
 
     let context_file = script_dir.join(format!("{}-context.txt", session_name));
-    std::fs::write(&context_file, task_context)?;
+    std::fs::write(
+        &context_file,
+        session_prompt(assistant, task_context, claude),
+    )?;
+
+    let (fresh_cmd, continue_cmd) = commands_with_context(assistant, false, &context_file, claude);
+    let _launcher = create_launcher_script(
+        &session_name,
+        &fresh_cmd,
+        &continue_cmd,
+        LauncherMode::Normal,
+    )?;
+
+    // Fresh script is what headless-zellij uses as SHELL
+    let fresh_script = script_dir.join(format!("{}-fresh.sh", session_name));
 
-    let (fresh_cmd, continue_cmd) = commands_with_context(assistant, false, &context_file);
-    let _launcher = create_launcher_script(&session_name, &fresh_cmd, &continue_cmd, false)?;
+    spawn_headless_via_launchd(
+        &session_name,
+        fresh_script.to_str().unwrap(),
+        working_dir.to_str().unwrap(),
+    )?;
+
+    Ok(())
+}
+
+/// Switch the checkout at `project_dir` to `branch`, creating it if needed.
+/// Refuses when the working tree has uncommitted changes.
+pub fn switch_branch_in_checkout(branch: &str, project_dir: &Path) -> Result<()> {
+    let status = Command::new("git")
+        .current_dir(project_dir)
+        .args(["status", "--porcelain"])
+        .output()?;
+    if !status.status.success() {
+        anyhow::bail!(
+            "git status failed: {}",
+            String::from_utf8_lossy(&status.stderr).trim()
+        );
+    }
+    if !status.stdout.is_empty() {
+        anyhow::bail!(
+            "Working tree at {:?} has uncommitted changes; commit or stash them first",
+            project_dir
+        );
+    }
+
+    let exists = Command::new("git")
+        .current_dir(project_dir)
+        .args(["rev-parse", "--verify", "--quiet"])
+        .arg(format!("refs/heads/{}", branch))
+        .output()?
+        .status
+        .success();
+
+    let mut cmd = Command::new("git");
+    cmd.current_dir(project_dir).arg("switch");
+    if !exists {
+        cmd.arg("-c");
+    }
+    let output = cmd.arg(branch).output()?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git switch failed for branch {}: {}",
+            branch,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+/// Ensure a worktree exists for `branch` and return its path
+fn ensure_worktree(branch: &str, project_dir: &Path) -> Result<std::path::PathBuf> {
+    let wt = wt_binary();
+    if !std::path::Path::new(&wt).exists() {
+        anyhow::bail!("wt binary not found at: {}", wt);
+    }
 
     // Create worktree (wt switch without -x, just ensure worktree exists)
     let status = Command::new(&wt)
@@ -698,16 +1014,7 @@ pub fn launch_headless_in_worktree(
         project_dir.to_path_buf()
     };
 
-    // Fresh script is what headless-zellij uses as SHELL
-    let fresh_script = script_dir.join(format!("{}-fresh.sh", session_name));
-
-    spawn_headless_via_launchd(
-        &session_name,
-        fresh_script.to_str().unwrap(),
-        worktree_dir.to_str().unwrap(),
-    )?;
-
-    Ok(())
+    Ok(worktree_dir)
 }
 
 /// Attach to existing zellij session in current terminal (blocks)
@@ -724,10 +1031,91 @@ pub fn attach_zellij_foreground(session_name: &str) -> Result<()> {
     }
     args.push(session_name);
 
-    let status = Command::new("zellij").args(&args).status()?;
+    let status = super::zellij_command().args(&args).status()?;
 
     if !status.success() {
         anyhow::bail!("zellij attach exited with error");
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .current_dir(dir)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    fn repo() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        git(dir.path(), &["init", "-q", "-b", "main"]);
+        git(dir.path(), &["config", "user.email", "test@example.com"]);
+        git(dir.path(), &["config", "user.name", "test"]);
+        std::fs::write(dir.path().join("README"), "hi").unwrap();
+        git(dir.path(), &["add", "README"]);
+        git(dir.path(), &["commit", "-q", "-m", "init"]);
+        dir
+    }
+
+    #[test]
+    fn test_switch_branch_in_checkout_creates_then_switches() {
+        let dir = repo();
+        switch_branch_in_checkout("VIB-1/feature", dir.path()).unwrap();
+        assert_eq!(
+            git(dir.path(), &["branch", "--show-current"]),
+            "VIB-1/feature"
+        );
+
+        git(dir.path(), &["switch", "-q", "main"]);
+        switch_branch_in_checkout("VIB-1/feature", dir.path()).unwrap();
+        assert_eq!(
+            git(dir.path(), &["branch", "--show-current"]),
+            "VIB-1/feature"
+        );
+    }
+
+    #[test]
+    fn test_switch_branch_in_checkout_refuses_dirty_tree() {
+        let dir = repo();
+        std::fs::write(dir.path().join("scratch.txt"), "wip").unwrap();
+
+        let err = switch_branch_in_checkout("VIB-2/other", dir.path()).unwrap_err();
+        assert!(err.to_string().contains("uncommitted changes"));
+        assert_eq!(git(dir.path(), &["branch", "--show-current"]), "main");
+    }
+
+    #[test]
+    fn test_claude_commands_append_project_flags() {
+        let claude = ClaudeConfig {
+            flags: ["--model", "opus", "--allowedTools", "Bash(git:*)"]
+                .map(String::from)
+                .to_vec(),
+            prompt_wrapper: None,
+        };
+        let (fresh, resume) = commands_with_context(
+            AssistantCli::Claude,
+            true,
+            Path::new("/tmp/ctx.txt"),
+            &claude,
+        );
+        assert_eq!(
+            fresh,
+            "claude --permission-mode plan --model opus --allowedTools 'Bash(git:*)' \"$(cat /tmp/ctx.txt)\""
+        );
+        assert_eq!(
+            resume,
+            "claude --continue --permission-mode plan --model opus --allowedTools 'Bash(git:*)'"
+        );
+
+        // Codex doesn't take Claude flags
+        let (fresh, _) = commands_for_existing_worktree(AssistantCli::Codex, false, &claude);
+        assert!(!fresh.contains("opus"));
+    }
+}
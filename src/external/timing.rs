@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::future::Future;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use tracing::Instrument;
+
+static TIMINGS_ENABLED: AtomicBool = AtomicBool::new(false);
+static TIMINGS: Mutex<Vec<OpTiming>> = Mutex::new(Vec::new());
+
+/// One finished gh/zellij/wt/Linear operation
+#[derive(Debug, Clone)]
+pub struct OpTiming {
+    pub operation: &'static str,
+    pub target: String,
+    pub duration: Duration,
+    pub ok: bool,
+}
+
+/// Start collecting per-operation timings (for `--timings`)
+pub fn enable_timings() {
+    TIMINGS_ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn timings_enabled() -> bool {
+    TIMINGS_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Drain the timings collected since the last call
+pub fn take_timings() -> Vec<OpTiming> {
+    std::mem::take(&mut *TIMINGS.lock().unwrap())
+}
+
+/// Run a blocking operation inside an `op` span, logging its duration and outcome
+pub fn timed<T, E: Display>(
+    operation: &'static str,
+    target: &str,
+    f: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    let span = tracing::debug_span!("op", operation, target);
+    let _guard = span.enter();
+    let start = Instant::now();
+    let result = f();
+    finish(operation, target, start.elapsed(), result.as_ref().err());
+    result
+}
+
+/// Async counterpart of [`timed`]; the span covers every poll of the future
+pub async fn timed_async<T, E: Display>(
+    operation: &'static str,
+    target: &str,
+    fut: impl Future<Output = Result<T, E>>,
+) -> Result<T, E> {
+    let span = tracing::debug_span!("op", operation, target);
+    let start = Instant::now();
+    let result = fut.instrument(span.clone()).await;
+    let _guard = span.enter();
+    finish(operation, target, start.elapsed(), result.as_ref().err());
+    result
+}
+
+fn finish(operation: &'static str, target: &str, duration: Duration, error: Option<&impl Display>) {
+    let duration_ms = duration.as_millis() as u64;
+    match error {
+        None => tracing::debug!(duration_ms, outcome = "ok", "{} done", operation),
+        Some(e) => {
+            tracing::debug!(duration_ms, outcome = "error", error = %e, "{} failed", operation)
+        }
+    }
+
+    if timings_enabled() {
+        TIMINGS.lock().unwrap().push(OpTiming {
+            operation,
+            target: target.to_string(),
+            duration,
+            ok: error.is_none(),
+        });
+    }
+}
+
+/// Per-operation breakdown, slowest total first
+pub fn format_timings(timings: &[OpTiming]) -> String {
+    // operation -> (count, total, slowest call, failures)
+    let mut by_op: HashMap<&str, (usize, Duration, &OpTiming, usize)> = HashMap::new();
+    for t in timings {
+        let entry = by_op
+            .entry(t.operation)
+            .or_insert((0, Duration::ZERO, t, 0));
+        entry.0 += 1;
+        entry.1 += t.duration;
+        if t.duration > entry.2.duration {
+            entry.2 = t;
+        }
+        if !t.ok {
+            entry.3 += 1;
+        }
+    }
+
+    let mut rows: Vec<_> = by_op.into_iter().collect();
+    rows.sort_by(|a, b| b.1.1.cmp(&a.1.1).then(a.0.cmp(b.0)));
+
+    let mut out = String::new();
+    for (op, (count, total, slowest, errors)) in rows {
+        out.push_str(&format!(
+            "  {:<24} {:>3}x  total {:>6}ms  max {:>6}ms",
+            op,
+            count,
+            total.as_millis(),
+            slowest.duration.as_millis()
+        ));
+        if !slowest.target.is_empty() {
+            out.push_str(&format!(" ({})", slowest.target));
+        }
+        if errors > 0 {
+            out.push_str(&format!("  ({} failed)", errors));
+        }
+        out.push('\n');
+    }
+    let total: Duration = timings.iter().map(|t| t.duration).sum();
+    out.push_str(&format!(
+        "  {:<24} {:>3}x  total {:>6}ms",
+        "all",
+        timings.len(),
+        total.as_millis()
+    ));
+    out
+}
+
+/// Log the breakdown since the last poll; no-op unless `--timings` is on
+pub fn log_poll_timings() {
+    if !timings_enabled() {
+        return;
+    }
+    let timings = take_timings();
+    if !timings.is_empty() {
+        tracing::info!("poll timings:\n{}", format_timings(&timings));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(operation: &'static str, target: &str, ms: u64, ok: bool) -> OpTiming {
+        OpTiming {
+            operation,
+            target: target.to_string(),
+            duration: Duration::from_millis(ms),
+            ok,
+        }
+    }
+
+    #[test]
+    fn test_format_timings_groups_by_operation() {
+        let report = format_timings(&[
+            timing("gh.pr_view", "VIB-1/fast", 100, true),
+            timing("gh.pr_view", "VIB-2/slow", 300, false),
+            timing("wt.list", "", 50, true),
+        ]);
+        let lines: Vec<_> = report.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains("gh.pr_view"));
+        assert!(lines[0].contains("2x"));
+        assert!(lines[0].contains("total    400ms"));
+        assert!(lines[0].contains("max    300ms (VIB-2/slow)"));
+        assert!(lines[0].contains("(1 failed)"));
+        assert!(lines[1].contains("wt.list"));
+        assert!(lines[2].contains("all"));
+        assert!(lines[2].contains("total    450ms"));
+    }
+
+    #[test]
+    fn test_timed_records_outcome() {
+        enable_timings();
+        let ok: Result<u32, String> = timed("test.timed_ok", "a", || Ok(1));
+        let err: Result<u32, String> = timed("test.timed_err", "b", || Err("boom".to_string()));
+        assert_eq!(ok, Ok(1));
+        assert!(err.is_err());
+
+        // Other tests may record concurrently, so only look at ours
+        let ours: Vec<_> = take_timings()
+            .into_iter()
+            .filter(|t| t.operation.starts_with("test.timed"))
+            .collect();
+        assert_eq!(ours.len(), 2);
+        assert!(ours.iter().any(|t| t.operation == "test.timed_ok" && t.ok));
+        assert!(
+            ours.iter()
+                .any(|t| t.operation == "test.timed_err" && !t.ok && t.target == "b")
+        );
+    }
+}
@@ -4,6 +4,8 @@ use anyhow::Result;
 use serde::Deserialize;
 use std::process::Command;
 
+use super::timed;
+
 /// Get the wt binary path - check WORKTRUNK_BIN env or fall back to cargo bin
 fn wt_binary() -> String {
     std::env::var("WORKTRUNK_BIN").unwrap_or_else(|_| {
@@ -93,6 +95,10 @@ impl WorktreeInfo {
 }
 
 pub fn list_worktrees() -> Result<Vec<WorktreeInfo>> {
+    timed("wt.list", "", wt_list)
+}
+
+fn wt_list() -> Result<Vec<WorktreeInfo>> {
     let output = Command::new(wt_binary())
         .args(["list", "--format=json"])
         .output()?;
@@ -129,6 +135,23 @@ pub fn switch_worktree(branch: &str) -> Result<()> {
     Ok(())
 }
 
+/// `git branch -m old new`; a worktree with `old` checked out follows the rename
+pub fn rename_branch(old: &str, new: &str) -> Result<()> {
+    let output = Command::new("git")
+        .args(["branch", "-m", old, new])
+        .output()?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "git branch -m {} {} failed: {}",
+            old,
+            new,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
 pub fn remove_worktree() -> Result<()> {
     let status = Command::new(wt_binary()).args(["remove"]).status()?;
 
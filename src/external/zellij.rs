@@ -4,6 +4,49 @@ use anyhow::Result;
 use std::path::Path;
 use std::process::Command;
 
+use super::timed;
+
+/// Extra global arguments passed to every zellij invocation (e.g. `--config-dir ~/.zellij-work`)
+pub const ZELLIJ_ARGS_ENV: &str = "VIBE_ZELLIJ_ARGS";
+
+/// Environment that selects which zellij server and config a command talks to.
+/// Inherited by direct child processes, but must be forwarded explicitly to
+/// launchd jobs, which start from a clean environment.
+pub const ZELLIJ_SERVER_ENV: [&str; 4] = [
+    "ZELLIJ_SOCKET_DIR",
+    "ZELLIJ_CONFIG_DIR",
+    "ZELLIJ_CONFIG_FILE",
+    ZELLIJ_ARGS_ENV,
+];
+
+/// Split a `VIBE_ZELLIJ_ARGS` value into arguments (whitespace separated, no quoting)
+pub fn parse_zellij_args(raw: &str) -> Vec<String> {
+    raw.split_whitespace().map(String::from).collect()
+}
+
+/// Global zellij arguments from `VIBE_ZELLIJ_ARGS`
+pub fn zellij_global_args() -> Vec<String> {
+    std::env::var(ZELLIJ_ARGS_ENV)
+        .map(|raw| parse_zellij_args(&raw))
+        .unwrap_or_default()
+}
+
+/// A `zellij` command bound to the configured server context. Every zellij call
+/// (list, create, attach, kill, actions) goes through this so they all agree.
+pub fn zellij_command() -> Command {
+    let mut cmd = Command::new("zellij");
+    cmd.args(zellij_global_args());
+    cmd
+}
+
+/// The zellij server environment that is currently set, for forwarding to launchd
+pub fn zellij_server_env() -> Vec<(&'static str, String)> {
+    ZELLIJ_SERVER_ENV
+        .iter()
+        .filter_map(|&key| std::env::var(key).ok().map(|value| (key, value)))
+        .collect()
+}
+
 /// Strip ANSI escape sequences from a string
 fn strip_ansi(s: &str) -> String {
     let mut result = String::with_capacity(s.len());
@@ -44,12 +87,26 @@ pub struct ZellijSession {
     pub is_current: bool,
     pub is_dead: bool,
     pub needs_attention: bool,
+    /// Hash of the screen's last lines from the attention check
+    pub screen_hash: Option<u64>,
+    /// Attention was acknowledged and the screen hasn't changed since
+    pub acknowledged: bool,
     pub claude_activity: ClaudeActivityState,
     pub context_percentage: Option<f64>,
 }
 
+impl ZellijSession {
+    /// Waiting on the user, but acknowledged: shown muted instead of flagged
+    pub fn attention_acknowledged(&self) -> bool {
+        self.acknowledged
+            && (self.needs_attention || self.claude_activity == ClaudeActivityState::WaitingForUser)
+    }
+}
+
 pub fn list_sessions() -> Result<Vec<ZellijSession>> {
-    let output = Command::new("zellij").args(["list-sessions"]).output()?;
+    let output = timed("zellij.list_sessions", "", || {
+        zellij_command().args(["list-sessions"]).output()
+    })?;
 
     if !output.status.success() {
         // zellij returns error if no sessions exist
@@ -86,6 +143,8 @@ pub fn list_sessions() -> Result<Vec<ZellijSession>> {
                 is_current,
                 is_dead,
                 needs_attention: false,
+                screen_hash: None,
+                acknowledged: false,
                 claude_activity: ClaudeActivityState::Unknown,
                 context_percentage: None,
             }
@@ -95,30 +154,46 @@ pub fn list_sessions() -> Result<Vec<ZellijSession>> {
     Ok(sessions)
 }
 
-/// Check if a session is waiting for user input by dumping screen content
-pub fn check_session_needs_attention(session_name: &str) -> bool {
+/// Check if a session is waiting for user input by dumping screen content.
+/// Also returns a hash of the checked lines, to tell when the screen changes.
+pub fn check_session_needs_attention(session_name: &str) -> (bool, Option<u64>) {
     // Dump the last few lines of the session screen
-    let output = Command::new("zellij")
-        .args([
-            "action",
-            "--session",
-            session_name,
-            "dump-screen",
-            "/dev/stdout",
-        ])
-        .output();
+    let output = timed("zellij.dump_screen", session_name, || {
+        zellij_command()
+            .args([
+                "action",
+                "--session",
+                session_name,
+                "dump-screen",
+                "/dev/stdout",
+            ])
+            .output()
+    });
 
     let Ok(output) = output else {
-        return false;
+        return (false, None);
     };
 
     if !output.status.success() {
-        return false;
+        return (false, None);
     }
 
     let screen = String::from_utf8_lossy(&output.stdout);
     let last_lines: String = screen.lines().rev().take(10).collect::<Vec<_>>().join("\n");
+    (
+        screen_needs_attention(&last_lines),
+        Some(screen_hash(&last_lines)),
+    )
+}
+
+fn screen_hash(lines: &str) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    lines.hash(&mut hasher);
+    hasher.finish()
+}
 
+fn screen_needs_attention(last_lines: &str) -> bool {
     // Patterns that indicate Claude is waiting for input
     let attention_patterns = [
         "? ",             // Interactive prompt
@@ -139,11 +214,80 @@ pub fn check_session_needs_attention(session_name: &str) -> bool {
         .any(|pattern| last_lines.to_lowercase().contains(&pattern.to_lowercase()))
 }
 
+/// Dump a session's full scrollback (not just the visible screen), ANSI-stripped.
+/// Falls back to the visible screen on zellij versions without `dump-screen --full`.
+pub fn dump_session_scrollback(session_name: &str) -> Result<String> {
+    timed("zellij.dump_scrollback", session_name, || {
+        dump_scrollback(session_name)
+    })
+}
+
+fn dump_scrollback(session_name: &str) -> Result<String> {
+    let tmp = tempfile::NamedTempFile::new()?;
+    let path = tmp.path().to_string_lossy().to_string();
+
+    let full = zellij_command()
+        .args([
+            "action",
+            "--session",
+            session_name,
+            "dump-screen",
+            "--full",
+            &path,
+        ])
+        .output()?;
+
+    if !full.status.success() {
+        tracing::debug!(
+            "dump-screen --full unsupported for {}, falling back to visible screen: {}",
+            session_name,
+            String::from_utf8_lossy(&full.stderr).trim()
+        );
+        let screen = zellij_command()
+            .args(["action", "--session", session_name, "dump-screen", &path])
+            .output()?;
+        if !screen.status.success() {
+            anyhow::bail!(
+                "zellij dump-screen failed: {}",
+                String::from_utf8_lossy(&screen.stderr).trim()
+            );
+        }
+    }
+
+    let content = std::fs::read_to_string(&path)?;
+    Ok(strip_ansi(&content))
+}
+
+/// Type `/compact` into a session's focused pane and submit it
+pub fn send_compact(session_name: &str) -> Result<()> {
+    timed("zellij.write_chars", session_name, || {
+        write_to_session(session_name, &["write-chars", "/compact"])?;
+        // Enter, sent separately so the TUI sees a keypress rather than pasted text
+        write_to_session(session_name, &["write", "13"])
+    })
+}
+
+fn write_to_session(session_name: &str, action: &[&str]) -> Result<()> {
+    let output = zellij_command()
+        .args(["action", "--session", session_name])
+        .args(action)
+        .output()?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "zellij {} failed: {}",
+            action[0],
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
 /// List sessions with attention status (slower, checks each session)
 pub fn list_sessions_with_status() -> Result<Vec<ZellijSession>> {
     let mut sessions = list_sessions()?;
     for session in &mut sessions {
-        session.needs_attention = check_session_needs_attention(&session.name);
+        (session.needs_attention, session.screen_hash) =
+            check_session_needs_attention(&session.name);
     }
     Ok(sessions)
 }
@@ -165,7 +309,7 @@ pub fn get_session_status(name: &str) -> Option<bool> {
 pub fn create_session_with_command(name: &str, cwd: &Path, command: &str) -> Result<()> {
     // Create a zellij session that runs the specified command
     // We use `zellij -s <name> --cwd <path> -- <command>`
-    let status = Command::new("zellij")
+    let status = zellij_command()
         .arg("-s")
         .arg(name)
         .arg("--cwd")
@@ -192,7 +336,7 @@ pub fn attach_session_with_resurrect(name: &str, force_resurrect: bool) -> Resul
     }
     args.push(name);
 
-    let status = Command::new("zellij").args(&args).status()?;
+    let status = zellij_command().args(&args).status()?;
 
     if !status.success() {
         anyhow::bail!("Failed to attach to zellij session: {}", name);
@@ -200,10 +344,21 @@ pub fn attach_session_with_resurrect(name: &str, force_resurrect: bool) -> Resul
     Ok(())
 }
 
-pub fn kill_session(name: &str) -> Result<()> {
-    let status = Command::new("zellij")
-        .args(["kill-session", name])
-        .status()?;
+/// Refuse to kill the session this process is attached to unless `force` is set:
+/// killing it takes the terminal down with it
+pub fn ensure_not_current(sessions: &[ZellijSession], name: &str, force: bool) -> Result<()> {
+    if !force && sessions.iter().any(|s| s.name == name && s.is_current) {
+        anyhow::bail!(
+            "refusing to kill session '{}': it is the one you are attached to (use --force to kill it anyway)",
+            name
+        );
+    }
+    Ok(())
+}
+
+pub fn kill_session(name: &str, force: bool) -> Result<()> {
+    ensure_not_current(&list_sessions()?, name, force)?;
+    let status = zellij_command().args(["kill-session", name]).status()?;
 
     if !status.success() {
         anyhow::bail!("Failed to kill zellij session: {}", name);
@@ -218,7 +373,7 @@ pub fn sanitize_session_name(branch: &str) -> String {
     let sanitized: String = branch
         .chars()
         .map(|c| {
-            if c.is_alphanumeric() || c == '-' || c == '_' {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
                 c
             } else {
                 '-'
@@ -228,8 +383,10 @@ pub fn sanitize_session_name(branch: &str) -> String {
         .trim_matches('-')
         .to_string();
 
-    if sanitized.len() > 36 {
-        sanitized[..36].trim_end_matches('-').to_string()
+    // Truncate by chars, not bytes, so a multi-byte boundary can never panic
+    if sanitized.chars().count() > 36 {
+        let truncated: String = sanitized.chars().take(36).collect();
+        truncated.trim_end_matches('-').to_string()
     } else {
         sanitized
     }
@@ -240,7 +397,7 @@ pub fn session_name_for_branch(branch: &str) -> String {
 }
 
 pub fn is_zellij_installed() -> bool {
-    Command::new("zellij")
+    zellij_command()
         .arg("--version")
         .output()
         .map(|o| o.status.success())
@@ -251,6 +408,39 @@ pub fn is_zellij_installed() -> bool {
 mod tests {
     use super::*;
 
+    fn session(name: &str, is_current: bool) -> ZellijSession {
+        ZellijSession {
+            name: name.to_string(),
+            is_current,
+            is_dead: false,
+            needs_attention: false,
+            screen_hash: None,
+            acknowledged: false,
+            claude_activity: ClaudeActivityState::Unknown,
+            context_percentage: None,
+        }
+    }
+
+    #[test]
+    fn test_ensure_not_current_refuses_attached_session() {
+        let sessions = vec![session("vibe-prime", true), session("vib-12-fix", false)];
+
+        let err = ensure_not_current(&sessions, "vibe-prime", false).unwrap_err();
+        assert!(err.to_string().contains("attached"));
+        assert!(ensure_not_current(&sessions, "vibe-prime", true).is_ok());
+        assert!(ensure_not_current(&sessions, "vib-12-fix", false).is_ok());
+        assert!(ensure_not_current(&sessions, "gone", false).is_ok());
+    }
+
+    #[test]
+    fn test_parse_zellij_args() {
+        assert_eq!(
+            parse_zellij_args("  --config-dir /tmp/zj   --data-dir /tmp/zd "),
+            vec!["--config-dir", "/tmp/zj", "--data-dir", "/tmp/zd"]
+        );
+        assert!(parse_zellij_args("").is_empty());
+    }
+
     #[test]
     fn test_strip_ansi() {
         // Typical zellij output: ^[[32;1msession-name^[[m [Created ...]
@@ -280,4 +470,15 @@ mod tests {
         assert!(sanitized.len() <= 36);
         assert_eq!(sanitized, "close-a-claude-code-session-or-zelli");
     }
+
+    #[test]
+    fn test_sanitize_session_name_unicode() {
+        // Multi-byte chars straddling the truncation point must not panic
+        let branch = format!("{}ééééé", "a".repeat(34));
+        let sanitized = sanitize_session_name(&branch);
+        assert_eq!(sanitized, "a".repeat(34));
+
+        assert_eq!(sanitize_session_name("VIB-1/🚀-launch"), "VIB-1---launch");
+        assert!(sanitize_session_name(&"🚀".repeat(40)).is_empty());
+    }
 }
@@ -2,6 +2,8 @@
 pub enum Action {
     Up,
     Down,
+    PageUp,
+    PageDown,
     NextRow,
     PrevRow,
 
@@ -11,19 +13,51 @@ pub enum Action {
 
     CreateTask,
     EditTask,
+    EditNotes,
     DeleteTask,
+    UndoDelete,
+    CancelTask,
+    /// Confirm a prompt and apply it to the linked Linear issue too
+    ConfirmWithLinear,
+    ToggleBacklogSort,
+    CycleAssigneeFilter,
+    CycleBaseFilter,
+    /// Show only cards whose PR (if any) I opened
+    ToggleMyPrs,
+    ToggleStatusDebug,
+    CycleLayout,
+    CompactSession,
+    /// Approve the focused task's PR (asks to confirm)
+    ApprovePr,
+    /// Request changes on the focused task's PR, body written in $EDITOR
+    RequestPrChanges,
     OpenTask,
 
     ShowWorktrees,
     CreateWorktree,
     SwitchWorktree,
+    TrackWorktree,
 
     LaunchSession,
     LaunchSessionPlan,
+    ExecutePlan,
     LaunchSessionWithPrime,
+    /// Launch a session with an assistant chosen in a picker
+    LaunchSessionPicker,
     ViewPR,
+    /// Show the selected task's PR diff in a popup
+    ViewPrDiff,
+    /// Open the Linear attachment at this index (keys 1-9 in task detail)
+    OpenAttachment(usize),
     ViewPlan,
-    BindPR,
+    EditPlan,
+    ViewScrollback,
+    /// Stop flagging a session's attention until its screen changes (toggle)
+    AcknowledgeSession,
+    BindBranch,
+
+    ShowSessions,
+    AttachSession,
 
     StartSearch,
     SearchType(char),
@@ -42,7 +76,15 @@ pub enum Action {
 
     ShowHelp,
     Refresh,
+    /// Re-fetch the PR and session activity of the selected task only
+    RefreshSelected,
     SyncLinear,
+    PickLinearState,
+    /// Create a Linear issue for a local task and link the task to it
+    PromoteTask,
     ShowLogs,
+    /// Tab / Shift-Tab: move to the next or previous view in the tab bar
+    NextView,
+    PrevView,
     ArchiveDone,
 }
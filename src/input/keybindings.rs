@@ -13,9 +13,21 @@ pub fn key_to_action(
 ) -> Option<Action> {
     // Modal-specific bindings
     if in_modal {
-        return match key.code {
-            KeyCode::Esc => Some(Action::Back),
-            KeyCode::Enter => Some(Action::Select),
+        return match (key.code, key.modifiers) {
+            (KeyCode::Esc, _) => Some(Action::Back),
+            (KeyCode::Enter, _) => Some(Action::Select),
+            // Confirmation prompts
+            (KeyCode::Char('y'), KeyModifiers::NONE) => Some(Action::Select),
+            (KeyCode::Char('n'), KeyModifiers::NONE) => Some(Action::Back),
+            (KeyCode::Char('L'), KeyModifiers::SHIFT) => Some(Action::ConfirmWithLinear),
+            (KeyCode::Char('j') | KeyCode::Down, KeyModifiers::NONE) => Some(Action::Down),
+            (KeyCode::Char('k') | KeyCode::Up, KeyModifiers::NONE) => Some(Action::Up),
+            (KeyCode::Char('d'), KeyModifiers::CONTROL) | (KeyCode::PageDown, _) => {
+                Some(Action::PageDown)
+            }
+            (KeyCode::Char('u'), KeyModifiers::CONTROL) | (KeyCode::PageUp, _) => {
+                Some(Action::PageUp)
+            }
             _ => None,
         };
     }
@@ -50,6 +62,8 @@ pub fn key_to_action(
         (KeyCode::Char('/'), KeyModifiers::NONE) => return Some(Action::StartSearch),
         (KeyCode::Char(';'), KeyModifiers::NONE) => return Some(Action::StartCommand),
         (KeyCode::Char('I'), KeyModifiers::SHIFT) => return Some(Action::ShowLogs),
+        (KeyCode::Tab, KeyModifiers::NONE) => return Some(Action::NextView),
+        (KeyCode::BackTab, _) => return Some(Action::PrevView),
         (KeyCode::Esc, _) => return Some(Action::Back),
         _ => {}
     }
@@ -60,6 +74,7 @@ pub fn key_to_action(
         View::Kanban => kanban_bindings(key),
         View::TaskDetail => task_detail_bindings(key),
         View::Worktrees => worktrees_bindings(key),
+        View::Sessions => sessions_bindings(key),
         View::Logs => logs_bindings(key),
         View::Search => search_bindings(key),
     }
@@ -147,21 +162,40 @@ fn kanban_bindings(key: KeyEvent) -> Option<Action> {
         // Task operations
         (KeyCode::Char('c'), KeyModifiers::NONE) => Some(Action::CreateTask),
         (KeyCode::Char('e'), KeyModifiers::NONE) => Some(Action::EditTask),
+        (KeyCode::Char('N'), KeyModifiers::SHIFT) => Some(Action::EditNotes),
         (KeyCode::Char('d'), KeyModifiers::NONE) => Some(Action::DeleteTask),
+        (KeyCode::Char('u'), KeyModifiers::NONE) => Some(Action::UndoDelete),
+        (KeyCode::Char('x'), KeyModifiers::NONE) => Some(Action::CancelTask),
+        (KeyCode::Char('s'), KeyModifiers::NONE) => Some(Action::ToggleBacklogSort),
+        (KeyCode::Char('a'), KeyModifiers::NONE) => Some(Action::CycleAssigneeFilter),
+        (KeyCode::Char('B'), KeyModifiers::SHIFT) => Some(Action::CycleBaseFilter),
+        (KeyCode::Char('m'), KeyModifiers::NONE) => Some(Action::ToggleMyPrs),
+        (KeyCode::Char('D'), KeyModifiers::SHIFT) => Some(Action::ToggleStatusDebug),
+        (KeyCode::Char('z'), KeyModifiers::NONE) => Some(Action::CycleLayout),
 
         // Launch Claude Code session
         (KeyCode::Char('g'), KeyModifiers::NONE) => Some(Action::LaunchSession),
         (KeyCode::Char('G'), KeyModifiers::SHIFT) => Some(Action::LaunchSessionWithPrime),
+        (KeyCode::Char('g'), KeyModifiers::CONTROL) => Some(Action::LaunchSessionPicker),
         (KeyCode::Char('p'), KeyModifiers::NONE) => Some(Action::LaunchSessionPlan),
+        (KeyCode::Char('X'), KeyModifiers::SHIFT) => Some(Action::ExecutePlan),
         (KeyCode::Char('v'), KeyModifiers::NONE) => Some(Action::ViewPR),
-        (KeyCode::Char('b'), KeyModifiers::NONE) => Some(Action::BindPR),
+        (KeyCode::Char('b'), KeyModifiers::NONE) => Some(Action::BindBranch),
 
         // Worktrees view
         (KeyCode::Char('w'), KeyModifiers::NONE) => Some(Action::ShowWorktrees),
         (KeyCode::Char('W'), KeyModifiers::SHIFT) => Some(Action::CreateWorktree),
 
+        // Every zellij session, including ones started outside the board
+        (KeyCode::Char('S'), KeyModifiers::SHIFT) => Some(Action::ShowSessions),
+
         // Linear sync
         (KeyCode::Char('L'), KeyModifiers::SHIFT) => Some(Action::SyncLinear),
+        (KeyCode::Char('t'), KeyModifiers::NONE) => Some(Action::PickLinearState),
+        (KeyCode::Char('T'), KeyModifiers::SHIFT) => Some(Action::PromoteTask),
+        (KeyCode::Char('o'), KeyModifiers::NONE) => Some(Action::ViewScrollback),
+        (KeyCode::Char('M'), KeyModifiers::SHIFT) => Some(Action::AcknowledgeSession),
+        (KeyCode::Char('C'), KeyModifiers::SHIFT) => Some(Action::CompactSession),
 
         // Prime session
         (KeyCode::Char('P'), KeyModifiers::SHIFT) => Some(Action::LaunchPrime),
@@ -171,6 +205,7 @@ fn kanban_bindings(key: KeyEvent) -> Option<Action> {
 
         // Refresh
         (KeyCode::Char('r'), KeyModifiers::NONE) => Some(Action::Refresh),
+        (KeyCode::Char('R'), KeyModifiers::SHIFT) => Some(Action::RefreshSelected),
 
         _ => None,
     }
@@ -182,14 +217,32 @@ fn task_detail_bindings(key: KeyEvent) -> Option<Action> {
         (KeyCode::Char('k') | KeyCode::Up, _) => Some(Action::Up),
         (KeyCode::Char('h') | KeyCode::Left, _) => Some(Action::Back),
         (KeyCode::Char('e'), KeyModifiers::NONE) => Some(Action::EditTask),
+        (KeyCode::Char('N'), KeyModifiers::SHIFT) => Some(Action::EditNotes),
+        (KeyCode::Char('x'), KeyModifiers::NONE) => Some(Action::CancelTask),
         (KeyCode::Char('g'), KeyModifiers::NONE) => Some(Action::LaunchSession),
         (KeyCode::Char('G'), KeyModifiers::SHIFT) => Some(Action::LaunchSessionWithPrime),
+        (KeyCode::Char('g'), KeyModifiers::CONTROL) => Some(Action::LaunchSessionPicker),
         (KeyCode::Char('p'), KeyModifiers::NONE) => Some(Action::LaunchSessionPlan),
+        (KeyCode::Char('X'), KeyModifiers::SHIFT) => Some(Action::ExecutePlan),
         (KeyCode::Char('P'), KeyModifiers::SHIFT) => Some(Action::ViewPlan),
+        (KeyCode::Char('E'), KeyModifiers::SHIFT) => Some(Action::EditPlan),
         (KeyCode::Char('v'), KeyModifiers::NONE) => Some(Action::ViewPR),
+        (KeyCode::Char('D'), KeyModifiers::SHIFT) => Some(Action::ViewPrDiff),
+        (KeyCode::Char('A'), KeyModifiers::SHIFT) => Some(Action::ApprovePr),
+        (KeyCode::Char('F'), KeyModifiers::SHIFT) => Some(Action::RequestPrChanges),
+        (KeyCode::Char('b'), KeyModifiers::NONE) => Some(Action::BindBranch),
+        (KeyCode::Char('t'), KeyModifiers::NONE) => Some(Action::PickLinearState),
+        (KeyCode::Char('T'), KeyModifiers::SHIFT) => Some(Action::PromoteTask),
+        (KeyCode::Char('o'), KeyModifiers::NONE) => Some(Action::ViewScrollback),
+        (KeyCode::Char('M'), KeyModifiers::SHIFT) => Some(Action::AcknowledgeSession),
+        (KeyCode::Char('C'), KeyModifiers::SHIFT) => Some(Action::CompactSession),
         (KeyCode::Char('r'), KeyModifiers::NONE) => Some(Action::Refresh),
+        (KeyCode::Char('R'), KeyModifiers::SHIFT) => Some(Action::RefreshSelected),
         (KeyCode::Enter | KeyCode::Char(' '), _) => Some(Action::LaunchSession),
         (KeyCode::Char('w'), KeyModifiers::NONE) => Some(Action::ShowWorktrees),
+        (KeyCode::Char(c @ '1'..='9'), KeyModifiers::NONE) => {
+            Some(Action::OpenAttachment(c as usize - '1' as usize))
+        }
         _ => None,
     }
 }
@@ -202,6 +255,19 @@ fn worktrees_bindings(key: KeyEvent) -> Option<Action> {
         KeyCode::Char('g') => Some(Action::LaunchSession),
         KeyCode::Char('p') => Some(Action::LaunchSessionPlan),
         KeyCode::Char('W') => Some(Action::CreateWorktree),
+        KeyCode::Char('+') => Some(Action::TrackWorktree),
+        KeyCode::Char('r') => Some(Action::Refresh),
+        _ => None,
+    }
+}
+
+fn sessions_bindings(key: KeyEvent) -> Option<Action> {
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => Some(Action::Down),
+        KeyCode::Char('k') | KeyCode::Up => Some(Action::Up),
+        KeyCode::Enter | KeyCode::Char(' ') | KeyCode::Char('a') => Some(Action::AttachSession),
+        KeyCode::Char('o') => Some(Action::ViewScrollback),
+        KeyCode::Char('M') => Some(Action::AcknowledgeSession),
         KeyCode::Char('r') => Some(Action::Refresh),
         _ => None,
     }
@@ -8,6 +8,7 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 mod app;
 mod external;
 mod input;
+mod serve;
 mod state;
 mod storage;
 mod task_prompt;
@@ -15,10 +16,13 @@ mod terminal;
 mod ui;
 
 use app::App;
-use external::{AssistantCli, LinearClient, launch_headless_in_worktree};
-use state::task_title_to_branch;
-use storage::TaskStorage;
-use task_prompt::{TaskPromptOptions, build_task_prompt};
+use external::{
+    AssistantCli, ClaudeConfig, LinearBoardIssue, LinearClient, SessionPlacement, find_on_path,
+    launch_headless, launch_headless_in_worktree, zellij_command,
+};
+use state::{Task, TaskStatus, task_title_to_branch};
+use storage::{TaskStorage, TaskStore, split_import_frontmatter};
+use task_prompt::{TaskPromptOptions, build_task_prompt, load_standing_context};
 use terminal::Terminal;
 
 #[derive(Parser)]
@@ -29,6 +33,18 @@ struct Cli {
     #[arg(long, global = true)]
     codex: bool,
 
+    /// Report where time went in gh/zellij/wt/Linear calls (stderr, or per poll in vibe.log)
+    #[arg(long, global = true)]
+    timings: bool,
+
+    /// Run against this repository instead of the current directory
+    #[arg(long, global = true, value_name = "PATH")]
+    repo: Option<PathBuf>,
+
+    /// Open the board without the first-run setup, even with nothing configured
+    #[arg(long)]
+    skip_onboarding: bool,
+
     #[command(subcommand)]
     command: Option<Command>,
 }
@@ -45,6 +61,14 @@ enum Command {
         #[arg(short, long)]
         description: Option<String>,
 
+        /// Linear label to attach; repeat for several (must exist on the team)
+        #[arg(short, long = "label")]
+        labels: Vec<String>,
+
+        /// Linear team key (e.g. VIB); required when you belong to more than one team
+        #[arg(long)]
+        team: Option<String>,
+
         /// Immediately spawn a Claude session for the task
         #[arg(long)]
         gas_it: bool,
@@ -55,13 +79,18 @@ enum Command {
     },
     /// Import a task from a markdown file
     Import {
-        /// Path to markdown file (filename becomes title, contents become description)
+        /// Path to markdown file (filename becomes title, contents become description;
+        /// optional front-matter can set title, status, labels and priority)
         file: PathBuf,
 
         /// Override task title (default: derived from filename)
         #[arg(short, long)]
         title: Option<String>,
 
+        /// Linear team key (e.g. VIB); required when you belong to more than one team
+        #[arg(long)]
+        team: Option<String>,
+
         /// Immediately spawn a Claude session for the task
         #[arg(long)]
         gas_it: bool,
@@ -70,13 +99,53 @@ enum Command {
         #[arg(long, requires = "gas_it")]
         with_prime: bool,
     },
+    /// Import a task from a GitHub issue
+    ImportGh {
+        /// Issue number (42) or URL (https://github.com/owner/repo/issues/42)
+        issue: String,
+    },
+    /// Create a Linear issue for a local task and link the task to it
+    Promote {
+        /// Task identifier: task title substring, GitHub issue (#42), or UUID
+        target: String,
+
+        /// Linear team key (e.g. VIB); required when you belong to more than one team
+        #[arg(long)]
+        team: Option<String>,
+    },
     /// Tear down finished sessions (launchd + zellij + worktree)
     Cleanup {
         /// Specific session or ticket ID to clean up (e.g. VIB-21). Omit for all dead sessions.
         target: Option<String>,
+
+        /// Also remove the session this terminal is attached to
+        #[arg(long)]
+        force: bool,
     },
     /// Spawn a Claude session for an existing task
     Gas {
+        /// Task identifier: Linear ID (VIB-23), task title substring, or UUID
+        #[arg(required_unless_present = "search")]
+        target: Option<String>,
+
+        /// Search Linear for the issue instead, picking from the matches
+        #[arg(long, conflicts_with = "target")]
+        search: Option<String>,
+
+        /// Include vibe/prime rapporting instructions in the first prompt
+        #[arg(long)]
+        with_prime: bool,
+
+        /// Switch branches in the current checkout instead of creating a worktree
+        #[arg(long)]
+        no_worktree: bool,
+
+        /// Prepend instructions from this file (default: ~/.vibe/context.md if present)
+        #[arg(long)]
+        context_file: Option<PathBuf>,
+    },
+    /// Spawn a session for a task and move it to In Progress (local status)
+    Start {
         /// Task identifier: Linear ID (VIB-23), task title substring, or UUID
         target: String,
 
@@ -84,18 +153,159 @@ enum Command {
         #[arg(long)]
         with_prime: bool,
     },
+    /// Set a task's local status (for projects without Linear)
+    Move {
+        /// Task identifier: Linear ID (VIB-23), task title substring, or UUID
+        target: String,
+
+        /// backlog, todo, in-progress, review, done or cancelled
+        status: String,
+    },
+    /// Bind a task to a branch, overriding the PR/worktree matching by title
+    Bind {
+        /// Task identifier: Linear ID (VIB-23), task title substring, or UUID
+        target: String,
+
+        /// Branch to bind; omit with --clear or --rename
+        #[arg(
+            required_unless_present_any = ["clear", "rename"],
+            conflicts_with_all = ["clear", "rename"]
+        )]
+        branch: Option<String>,
+
+        /// Go back to the branch derived from the title
+        #[arg(long, conflicts_with = "rename")]
+        clear: bool,
+
+        /// Rename the bound git branch to the one derived from the title, then unbind
+        #[arg(long)]
+        rename: bool,
+    },
     /// Show Linear board state grouped by column
     Status,
+    /// Guided setup: task storage, tool check and Linear API key (safe to re-run)
+    Setup,
+    /// Make sure the trigger label (~gasit by default) exists on your Linear team (safe to re-run)
+    SetupLinear {
+        /// Trigger label to create instead of ~gasit
+        #[arg(long, default_value = external::GASIT_LABEL)]
+        label: String,
+
+        /// Linear team key (e.g. VIB); required when you belong to more than one team
+        #[arg(long)]
+        team: Option<String>,
+    },
+    /// Reconcile local tasks with issues currently assigned to you in Linear
+    Pull {
+        /// Delete local tasks whose Linear issue is no longer assigned to you
+        #[arg(long)]
+        delete_orphans: bool,
+    },
+    /// Archive Done tasks older than a given age (never tasks with open PRs)
+    Archive {
+        /// Minimum task age, e.g. 30d or 2w
+        #[arg(long, default_value = "30d")]
+        older_than: String,
+    },
+    /// Restore an archived task to the board
+    Unarchive {
+        /// Task UUID or Linear ID (e.g. VIB-23)
+        target: String,
+    },
+    /// Summarize throughput: tasks done, cycle time and current WIP
+    Stats {
+        /// Window to count done tasks in, e.g. 7d or 2w
+        #[arg(long, default_value = "7d")]
+        since: String,
+
+        /// Print the summary as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Report tasks, worktrees, sessions and PRs that have lost their counterpart
+    Audit,
+    /// Print the board for pasting into a standup, grouped by column
+    Export {
+        /// Output format (only `md`: GitHub-flavored markdown checklist)
+        #[arg(long, default_value = "md")]
+        format: String,
+    },
+    /// Print vibe's version
+    Version {
+        /// Also report zellij, gh, claude and git versions and where vibe keeps its files
+        #[arg(short, long)]
+        verbose: bool,
+    },
+    /// Serve the board as read-only JSON (/tasks, /sessions, /metrics) for dashboards
+    Serve {
+        /// Port to listen on
+        #[arg(short, long, default_value_t = 7777)]
+        port: u16,
+
+        /// Address to bind; anything but localhost exposes the board to the network
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+    },
+    /// Inspect how vibe derives what it shows
+    Debug {
+        #[command(subcommand)]
+        what: DebugCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum DebugCommand {
+    /// List Claude status files and the sessions each would feed activity to
+    Activity,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    let timings = cli.timings;
+    let is_tui = cli.command.is_none();
+    if timings {
+        external::enable_timings();
+    }
+
+    let result = run(cli).await;
+
+    // The TUI logs a breakdown per poll instead
+    if timings && !is_tui {
+        eprintln!(
+            "timings:\n{}",
+            external::format_timings(&external::take_timings())
+        );
+    }
+    result
+}
+
+async fn run(cli: Cli) -> Result<()> {
+    if let Some(repo) = &cli.repo {
+        std::env::set_current_dir(repo)
+            .with_context(|| format!("Cannot use --repo {}", repo.display()))?;
+    }
+
+    // Everything but cleanup (zellij + launchd only) and version keys off the repository
+    if !matches!(
+        cli.command,
+        Some(Command::Cleanup { .. } | Command::Version { .. })
+    ) {
+        let cwd = std::env::current_dir()?;
+        if !storage::is_git_repo(&cwd) {
+            if cli.command.is_none() {
+                return show_not_a_repo_screen(&cwd);
+            }
+            anyhow::bail!(storage::NOT_A_GIT_REPO);
+        }
+    }
 
     match cli.command {
         Some(Command::Create {
             title,
             description,
+            labels,
+            team,
             gas_it,
             with_prime,
         }) => {
@@ -106,10 +316,10 @@ async fn main() -> Result<()> {
             let project = project_name.to_uppercase().replace('-', "_");
             let env_var = format!("{}_LINEAR_API_KEY", project);
 
-            let (linear_id, task_desc) = if let Ok(api_key) = std::env::var(&env_var) {
+            let task = if let Ok(api_key) = storage::read_secret(&env_var) {
                 let client = LinearClient::new(api_key);
                 let created = client
-                    .create_issue(&title, description.as_deref())
+                    .create_issue(&title, description.as_deref(), &labels, team.as_deref())
                     .await
                     .map_err(|e| anyhow::anyhow!("Linear: {}", e))?;
 
@@ -118,21 +328,29 @@ async fn main() -> Result<()> {
                     title: title.clone(),
                     description: description.clone(),
                     url: created.url.clone(),
-                    labels: vec![],
+                    labels: created.labels.clone(),
+                    priority: None,
+                    estimate: None,
                 };
                 let task = storage.create_task_from_linear(&linear_issue)?;
                 println!("Created: {} [{}]", task.title, created.identifier);
                 println!("  {}", created.url);
-                (Some(created.identifier), task.description)
+                task
             } else {
+                if !labels.is_empty() {
+                    eprintln!("warning: --label needs {}; labels not set", env_var);
+                }
                 let task = storage.create_task(&title, description.as_deref())?;
                 println!("Created: {}", task.title);
-                (None, task.description)
+                task
             };
+            let task_desc = task.description.clone();
 
             if gas_it {
                 let project_dir = std::env::current_dir()?;
-                let branch = task_title_to_branch(&title, linear_id.as_deref());
+                // The board finds the task's worktree and PR by this same branch
+                let branch = task.branch();
+                let standing_context = load_standing_context(None)?;
 
                 let context = build_task_prompt(TaskPromptOptions {
                     title: &title,
@@ -141,6 +359,8 @@ async fn main() -> Result<()> {
                     pull_request: None,
                     project_name: &project_name,
                     with_prime,
+                    standing_context: standing_context.as_deref(),
+                    approved_plan: None,
                 });
 
                 let assistant = if cli.codex {
@@ -150,7 +370,8 @@ async fn main() -> Result<()> {
                 };
 
                 println!("Launching session...");
-                launch_headless_in_worktree(&branch, &context, assistant, &project_dir)?;
+                let claude = ClaudeConfig::load(&project_name)?;
+                launch_headless_in_worktree(&branch, &context, assistant, &project_dir, &claude)?;
                 println!(
                     "Session spawned headlessly. Attach with: zellij attach {}",
                     external::session_name_for_branch(&branch)
@@ -162,6 +383,7 @@ async fn main() -> Result<()> {
         Some(Command::Import {
             file,
             title: title_override,
+            team,
             gas_it,
             with_prime,
         }) => {
@@ -170,26 +392,29 @@ async fn main() -> Result<()> {
 
             let content = std::fs::read_to_string(&file)
                 .with_context(|| format!("Failed to read file: {:?}", file))?;
-            let title = title_override.unwrap_or_else(|| {
-                file.file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("untitled")
-                    .replace(['-', '_'], " ")
-            });
-            let description = if content.trim().is_empty() {
+            let (import, body) = split_import_frontmatter(&content);
+            let title = title_override
+                .or_else(|| import.title.clone())
+                .unwrap_or_else(|| {
+                    file.file_stem()
+                        .and_then(|s| s.to_str())
+                        .unwrap_or("untitled")
+                        .replace(['-', '_'], " ")
+                });
+            let description = if body.trim().is_empty() {
                 None
             } else {
-                Some(content)
+                Some(body.to_string())
             };
 
             // Route through Linear if API key is set
             let project = project_name.to_uppercase().replace('-', "_");
             let env_var = format!("{}_LINEAR_API_KEY", project);
 
-            let (linear_id, task_desc) = if let Ok(api_key) = std::env::var(&env_var) {
+            let (linear_id, task) = if let Ok(api_key) = storage::read_secret(&env_var) {
                 let client = LinearClient::new(api_key);
                 let created = client
-                    .create_issue(&title, description.as_deref())
+                    .create_issue(&title, description.as_deref(), &[], team.as_deref())
                     .await
                     .map_err(|e| anyhow::anyhow!("Linear: {}", e))?;
 
@@ -199,20 +424,30 @@ async fn main() -> Result<()> {
                     description: description.clone(),
                     url: created.url.clone(),
                     labels: vec![],
+                    priority: None,
+                    estimate: None,
                 };
                 let task = storage.create_task_from_linear(&linear_issue)?;
                 println!("Created: {} [{}]", task.title, created.identifier);
                 println!("  {}", created.url);
-                (Some(created.identifier), task.description)
+                (Some(created.identifier), task)
             } else {
                 let task = storage.create_task(&title, description.as_deref())?;
                 println!("Created: {}", task.title);
-                (None, task.description)
+                (None, task)
+            };
+            let task_desc = if import.sets_fields() {
+                storage
+                    .apply_import_frontmatter(&task.id, &import)?
+                    .description
+            } else {
+                task.description
             };
 
             if gas_it {
                 let project_dir = std::env::current_dir()?;
-                let branch = task_title_to_branch(&title, linear_id.as_deref());
+                let branch = task_title_to_branch(&title, linear_id.as_deref(), &task.id);
+                let standing_context = load_standing_context(None)?;
 
                 let context = build_task_prompt(TaskPromptOptions {
                     title: &title,
@@ -221,6 +456,8 @@ async fn main() -> Result<()> {
                     pull_request: None,
                     project_name: &project_name,
                     with_prime,
+                    standing_context: standing_context.as_deref(),
+                    approved_plan: None,
                 });
 
                 let assistant = if cli.codex {
@@ -230,7 +467,8 @@ async fn main() -> Result<()> {
                 };
 
                 println!("Launching session...");
-                launch_headless_in_worktree(&branch, &context, assistant, &project_dir)?;
+                let claude = ClaudeConfig::load(&project_name)?;
+                launch_headless_in_worktree(&branch, &context, assistant, &project_dir, &claude)?;
                 println!(
                     "Session spawned headlessly. Attach with: zellij attach {}",
                     external::session_name_for_branch(&branch)
@@ -239,47 +477,52 @@ async fn main() -> Result<()> {
 
             Ok(())
         }
-        Some(Command::Cleanup { target }) => {
-            cmd_cleanup(target.as_deref())?;
+        Some(Command::ImportGh { issue }) => {
+            let storage = TaskStorage::from_cwd()?;
+            let issue = external::get_issue(issue.trim_start_matches('#'))?;
+
+            if let Some(existing) = storage
+                .list_tasks()?
+                .into_iter()
+                .find(|t| t.github_issue == Some(issue.number))
+            {
+                anyhow::bail!(
+                    "issue #{} is already tracked as '{}'",
+                    issue.number,
+                    existing.title
+                );
+            }
+
+            let task = storage.create_task_from_github(&issue)?;
+            println!("Created: {} [#{}]", task.title, issue.number);
+            println!("  {}", issue.url);
+            Ok(())
+        }
+        Some(Command::Promote { target, team }) => {
+            cmd_promote(&target, team.as_deref()).await?;
+            Ok(())
+        }
+        Some(Command::Cleanup { target, force }) => {
+            cmd_cleanup(target.as_deref(), force)?;
             Ok(())
         }
-        Some(Command::Gas { target, with_prime }) => {
+        Some(Command::Gas {
+            target,
+            search,
+            with_prime,
+            no_worktree,
+            context_file,
+        }) => {
             let storage = TaskStorage::from_cwd()?;
-            let project_name = storage.project_name().to_string();
             let tasks = storage.list_tasks()?;
-
-            // Match by linear ID, title substring, or UUID
-            let upper = target.to_uppercase();
-            let task = tasks
-                .iter()
-                .find(|t| {
-                    t.linear_issue_id
-                        .as_ref()
-                        .is_some_and(|id| id.to_uppercase() == upper)
-                })
-                .or_else(|| tasks.iter().find(|t| t.id == target))
-                .or_else(|| {
-                    tasks
-                        .iter()
-                        .find(|t| t.title.to_uppercase().contains(&upper))
-                })
-                .ok_or_else(|| anyhow::anyhow!("no task matching '{}'", target))?;
-
-            let branch = task_title_to_branch(&task.title, task.linear_issue_id.as_deref());
-
-            let context = build_task_prompt(TaskPromptOptions {
-                title: &task.title,
-                description: task.description.as_deref(),
-                branch: &branch,
-                pull_request: None,
-                project_name: &project_name,
-                with_prime,
-            });
-
-            let assistant = if cli.codex {
-                AssistantCli::Codex
-            } else {
-                AssistantCli::Claude
+            let searched;
+            let task = match (target, search) {
+                (_, Some(search)) => {
+                    searched = search_task(&storage, &tasks, &search).await?;
+                    &searched
+                }
+                (Some(target), None) => find_task(&tasks, &target)?,
+                (None, None) => unreachable!("clap requires a target or --search"),
             };
 
             println!(
@@ -287,11 +530,96 @@ async fn main() -> Result<()> {
                 task.linear_issue_id.as_deref().unwrap_or(""),
                 task.title
             );
-            launch_headless_in_worktree(&branch, &context, assistant, &std::env::current_dir()?)?;
+            let placement = if no_worktree {
+                SessionPlacement::CurrentCheckout
+            } else {
+                SessionPlacement::Worktree
+            };
+            gas_task(
+                &storage,
+                task,
+                with_prime,
+                cli.codex,
+                placement,
+                context_file.as_deref(),
+            )?;
+
+            Ok(())
+        }
+        Some(Command::Start { target, with_prime }) => {
+            let storage = TaskStorage::from_cwd()?;
+            let tasks = storage.list_tasks()?;
+            let task = find_task(&tasks, &target)?;
+
             println!(
-                "Session spawned. Attach with: zellij attach {}",
-                external::session_name_for_branch(&branch)
+                "Starting: {} {}",
+                task.linear_issue_id.as_deref().unwrap_or(""),
+                task.title
             );
+            // Only record In Progress once the session is actually up
+            gas_task(
+                &storage,
+                task,
+                with_prime,
+                cli.codex,
+                SessionPlacement::Worktree,
+                None,
+            )?;
+            storage.set_task_status(&task.id, TaskStatus::Inprogress)?;
+            println!("Moved to {}", TaskStatus::Inprogress.label());
+
+            Ok(())
+        }
+        Some(Command::Move { target, status }) => {
+            let status = TaskStatus::parse(&status).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "unknown status '{}' (expected backlog, todo, in-progress, review, done, cancelled)",
+                    status
+                )
+            })?;
+            let storage = TaskStorage::from_cwd()?;
+            let tasks = storage.list_tasks()?;
+            let task = find_task(&tasks, &target)?;
+
+            if task.linear_issue_id.is_some() {
+                println!("note: Linear status takes precedence on the board for linked tasks");
+            }
+            storage.set_task_status(&task.id, status)?;
+            println!("{} -> {}", task.title, status.label());
+
+            Ok(())
+        }
+        Some(Command::Bind {
+            target,
+            branch,
+            rename,
+            ..
+        }) => {
+            let storage = TaskStorage::from_cwd()?;
+            let tasks = storage.list_tasks()?;
+            let task = find_task(&tasks, &target)?;
+
+            if rename {
+                let Some(old) = &task.branch_override else {
+                    anyhow::bail!("{} is not bound to a branch", task.title);
+                };
+                let new = task.title_branch();
+                if *old != new {
+                    external::rename_branch(old, &new)?;
+                }
+                storage.set_branch_override(&task.id, None)?;
+                println!("{} -> {} (renamed from {})", task.title, new, old);
+                println!(
+                    "push it and reopen the PR or relaunch the session if they used the old name"
+                );
+                return Ok(());
+            }
+
+            let task = storage.set_branch_override(&task.id, branch.as_deref())?;
+            match &task.branch_override {
+                Some(branch) => println!("{} -> {}", task.title, branch),
+                None => println!("{} -> {} (from title)", task.title, task.branch()),
+            }
 
             Ok(())
         }
@@ -299,9 +627,56 @@ async fn main() -> Result<()> {
             cmd_status().await?;
             Ok(())
         }
+        Some(Command::Setup) => cmd_setup(),
+        Some(Command::SetupLinear { label, team }) => {
+            cmd_setup_linear(&label, team.as_deref()).await?;
+            Ok(())
+        }
+        Some(Command::Pull { delete_orphans }) => {
+            cmd_pull(delete_orphans).await?;
+            Ok(())
+        }
+        Some(Command::Archive { older_than }) => {
+            cmd_archive(&older_than).await?;
+            Ok(())
+        }
+        Some(Command::Stats { since, json }) => {
+            cmd_stats(&since, json).await?;
+            Ok(())
+        }
+        Some(Command::Audit) => {
+            cmd_audit().await?;
+            Ok(())
+        }
+        Some(Command::Export { format }) => {
+            cmd_export(&format).await?;
+            Ok(())
+        }
+        Some(Command::Serve { port, host }) => {
+            serve::serve(TaskStorage::from_cwd()?, &host, port).await
+        }
+        Some(Command::Debug {
+            what: DebugCommand::Activity,
+        }) => cmd_debug_activity(),
+        Some(Command::Version { verbose }) => cmd_version(verbose),
+        Some(Command::Unarchive { target }) => {
+            let storage = TaskStorage::from_cwd()?;
+            let task = storage.unarchive_task(&target)?;
+            println!(
+                "Restored: {} {}",
+                task.linear_issue_id.as_deref().unwrap_or(""),
+                task.title
+            );
+            Ok(())
+        }
         None => {
-            init_tracing()?;
+            if !cli.skip_onboarding && needs_onboarding() {
+                cmd_setup()?;
+                println!();
+            }
+            init_tracing(cli.timings)?;
 
+            external::LinearClient::quiet_rate_limit_waits();
             let mut terminal = Terminal::new()?;
             let assistant = if cli.codex {
                 AssistantCli::Codex
@@ -319,7 +694,167 @@ async fn main() -> Result<()> {
     }
 }
 
-fn cmd_cleanup(target: Option<&str>) -> Result<()> {
+/// Explain why the board can't open, instead of failing inside the TUI
+fn show_not_a_repo_screen(cwd: &std::path::Path) -> Result<()> {
+    let theme = ui::Theme::load().unwrap_or_default();
+    let mut terminal = Terminal::new()?;
+    let result = terminal
+        .draw(|frame| ui::render_not_a_repo(frame, cwd, &theme))
+        .and_then(|_| {
+            loop {
+                if let crossterm::event::Event::Key(key) = crossterm::event::read()?
+                    && key.kind == crossterm::event::KeyEventKind::Press
+                {
+                    break Ok(());
+                }
+            }
+        });
+    terminal.restore()?;
+    result
+}
+
+/// Find a task by Linear ID, GitHub issue (#42), UUID, or title substring (in that order)
+fn find_task<'a>(tasks: &'a [Task], target: &str) -> Result<&'a Task> {
+    let upper = target.to_uppercase();
+    let gh_number = target.strip_prefix('#').and_then(|n| n.parse::<u64>().ok());
+    tasks
+        .iter()
+        .find(|t| {
+            t.linear_issue_id
+                .as_ref()
+                .is_some_and(|id| id.to_uppercase() == upper)
+        })
+        .or_else(|| gh_number.and_then(|n| tasks.iter().find(|t| t.github_issue == Some(n))))
+        .or_else(|| tasks.iter().find(|t| t.id == target))
+        .or_else(|| {
+            tasks
+                .iter()
+                .find(|t| t.title.to_uppercase().contains(&upper))
+        })
+        .ok_or_else(|| anyhow::anyhow!("no task matching '{}'", target))
+}
+
+/// Search Linear for `search` and return the board's task for the chosen issue,
+/// importing it first if it isn't on the board yet
+async fn search_task(storage: &TaskStorage, tasks: &[Task], search: &str) -> Result<Task> {
+    use std::io::{BufRead, IsTerminal, Write};
+
+    let project = storage.project_name().to_uppercase().replace('-', "_");
+    let env_var = format!("{}_LINEAR_API_KEY", project);
+    let api_key =
+        storage::read_secret(&env_var).map_err(|_| anyhow::anyhow!("{} not set", env_var))?;
+
+    let issues = LinearClient::new(api_key)
+        .search_issues(search)
+        .await
+        .map_err(|e| anyhow::anyhow!("Linear: {}", e))?;
+
+    let issue = match issues.as_slice() {
+        [] => anyhow::bail!("no Linear issues match '{}'", search),
+        [issue] => issue,
+        _ => {
+            for (i, issue) in issues.iter().enumerate() {
+                println!("  {:>2}. {} {}", i + 1, issue.identifier, issue.title);
+            }
+            if !std::io::stdin().is_terminal() {
+                anyhow::bail!(
+                    "{} issues match '{}'; gas one by its ID",
+                    issues.len(),
+                    search
+                );
+            }
+            print!("Pick an issue (1-{}): ", issues.len());
+            std::io::stdout().flush()?;
+            let mut answer = String::new();
+            std::io::stdin().lock().read_line(&mut answer)?;
+            let choice = parse_selection(&answer, issues.len())
+                .ok_or_else(|| anyhow::anyhow!("no issue picked"))?;
+            &issues[choice]
+        }
+    };
+
+    if let Some(task) = tasks
+        .iter()
+        .find(|t| t.linear_issue_id.as_deref() == Some(issue.identifier.as_str()))
+    {
+        return Ok(task.clone());
+    }
+    let task = storage.create_task_from_linear(issue)?;
+    println!("Added {} to the board", issue.identifier);
+    Ok(task)
+}
+
+/// Zero-based index for a 1-based answer to a numbered list of `count` items
+fn parse_selection(answer: &str, count: usize) -> Option<usize> {
+    answer
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .filter(|n| (1..=count).contains(n))
+        .map(|n| n - 1)
+}
+
+/// The task's branch, or its pre-ASCII name when only that one has a worktree, so
+/// relaunching doesn't start over in a fresh checkout
+fn launch_branch(task: &Task) -> String {
+    let branch = task.branch();
+    let Some(legacy) = task.legacy_title_branch() else {
+        return branch;
+    };
+    let worktrees = external::list_worktrees().unwrap_or_default();
+    if !worktrees.iter().any(|w| w.branch == branch) && worktrees.iter().any(|w| w.branch == legacy)
+    {
+        legacy
+    } else {
+        branch
+    }
+}
+
+/// Spawn a headless session for a task, in its worktree or the current checkout
+fn gas_task(
+    storage: &TaskStorage,
+    task: &Task,
+    with_prime: bool,
+    codex: bool,
+    placement: SessionPlacement,
+    context_file: Option<&std::path::Path>,
+) -> Result<()> {
+    let branch = launch_branch(task);
+    let standing_context = load_standing_context(context_file)?;
+
+    let context = build_task_prompt(TaskPromptOptions {
+        title: &task.title,
+        description: task.description.as_deref(),
+        branch: &branch,
+        pull_request: None,
+        project_name: storage.project_name(),
+        with_prime,
+        standing_context: standing_context.as_deref(),
+        approved_plan: None,
+    });
+
+    let assistant = if codex {
+        AssistantCli::Codex
+    } else {
+        AssistantCli::Claude
+    };
+
+    launch_headless(
+        &branch,
+        &context,
+        assistant,
+        &std::env::current_dir()?,
+        placement,
+        &ClaudeConfig::load(storage.project_name())?,
+    )?;
+    println!(
+        "Session spawned. Attach with: zellij attach {}",
+        external::session_name_for_branch(&branch)
+    );
+    Ok(())
+}
+
+fn cmd_cleanup(target: Option<&str>, force: bool) -> Result<()> {
     use std::process::Command as Cmd;
 
     let launchd_dir = dirs::cache_dir()
@@ -329,7 +864,7 @@ fn cmd_cleanup(target: Option<&str>) -> Result<()> {
     // Resolve which sessions to clean up
     let sessions: Vec<String> = if let Some(target) = target {
         // Specific target: resolve like cousin does (ticket ID or raw name)
-        let output = Cmd::new("zellij").args(["list-sessions", "-s"]).output()?;
+        let output = zellij_command().args(["list-sessions", "-s"]).output()?;
         let all: Vec<String> = String::from_utf8_lossy(&output.stdout)
             .lines()
             .filter(|l| !l.is_empty())
@@ -347,7 +882,7 @@ fn cmd_cleanup(target: Option<&str>) -> Result<()> {
         matches
     } else {
         // No target: clean up all EXITED sessions for this project
-        let output = Cmd::new("zellij").args(["list-sessions"]).output()?;
+        let output = zellij_command().args(["list-sessions"]).output()?;
         let raw = String::from_utf8_lossy(&output.stdout).to_string();
         // Strip ANSI
         let stripped: String = {
@@ -380,7 +915,14 @@ fn cmd_cleanup(target: Option<&str>) -> Result<()> {
         return Ok(());
     }
 
+    let live = external::list_sessions().unwrap_or_default();
+    let mut cleaned = 0;
     for session in &sessions {
+        if let Err(e) = external::ensure_not_current(&live, session, force) {
+            println!("  skipped: {}", e);
+            continue;
+        }
+
         // 1. Unload launchd plist
         let plist = launchd_dir.join(format!("com.vibe.headless.{}.plist", session));
         if plist.exists() {
@@ -392,14 +934,77 @@ fn cmd_cleanup(target: Option<&str>) -> Result<()> {
         }
 
         // 2. Kill + delete zellij session
-        let _ = Cmd::new("zellij").args(["kill-session", session]).output();
-        let _ = Cmd::new("zellij")
-            .args(["delete-session", session])
-            .output();
+        let _ = zellij_command().args(["kill-session", session]).output();
+        let _ = zellij_command().args(["delete-session", session]).output();
         println!("  removed session: {}", session);
+        cleaned += 1;
+    }
+
+    println!("cleaned {} session(s)", cleaned);
+    Ok(())
+}
+
+/// Create a Linear issue from a local task's title and description, then
+/// backfill the task's Linear ID so it isn't imported again as a duplicate
+async fn cmd_promote(target: &str, team_key: Option<&str>) -> Result<()> {
+    let storage = TaskStorage::from_cwd()?;
+    let tasks = storage.list_tasks()?;
+    let task = find_task(&tasks, target)?;
+    if let Some(id) = &task.linear_issue_id {
+        anyhow::bail!("'{}' is already tracked in Linear as {}", task.title, id);
+    }
+
+    let project = storage.project_name().to_uppercase().replace('-', "_");
+    let env_var = format!("{}_LINEAR_API_KEY", project);
+    let api_key =
+        storage::read_secret(&env_var).map_err(|_| anyhow::anyhow!("{} not set", env_var))?;
+
+    let created = LinearClient::new(api_key)
+        .create_issue(&task.title, task.description.as_deref(), &[], team_key)
+        .await
+        .map_err(|e| anyhow::anyhow!("Linear: {}", e))?;
+    storage
+        .link_linear_issue(&task.id, &created.identifier, &created.url)
+        .with_context(|| format!("Created {} but failed to link it", created.identifier))?;
+
+    println!("Promoted: {} [{}]", task.title, created.identifier);
+    println!("  {}", created.url);
+    println!(
+        "  new sessions use branch {}",
+        task_title_to_branch(&task.title, Some(&created.identifier), &task.id)
+    );
+    Ok(())
+}
+
+async fn cmd_setup_linear(label: &str, team_key: Option<&str>) -> Result<()> {
+    let label = label.trim();
+    if label.is_empty() {
+        anyhow::bail!("--label can't be empty");
     }
+    let storage = TaskStorage::from_cwd()?;
+    let project = storage.project_name().to_uppercase().replace('-', "_");
+    let env_var = format!("{}_LINEAR_API_KEY", project);
 
-    println!("cleaned {} session(s)", sessions.len());
+    let api_key =
+        storage::read_secret(&env_var).map_err(|_| anyhow::anyhow!("{} not set", env_var))?;
+
+    let client = LinearClient::new(api_key);
+    let label = client
+        .ensure_team_label(label, "#e8913a", team_key)
+        .await
+        .map_err(|e| anyhow::anyhow!("Linear: {}", e))?;
+
+    if label.created {
+        println!("Created label {} on your team", label.name);
+    } else {
+        println!("Label {} already exists, nothing to do", label.name);
+    }
+    println!();
+    println!(
+        "Add {} to an issue to mark it ready for a session,",
+        label.name
+    );
+    println!("then `vibe pull` to bring it onto the board and `vibe gas <ID>` to launch it.");
     Ok(())
 }
 
@@ -408,7 +1013,8 @@ async fn cmd_status() -> Result<()> {
     let project = storage.project_name().to_uppercase().replace('-', "_");
     let env_var = format!("{}_LINEAR_API_KEY", project);
 
-    let api_key = std::env::var(&env_var).map_err(|_| anyhow::anyhow!("{} not set", env_var))?;
+    let api_key =
+        storage::read_secret(&env_var).map_err(|_| anyhow::anyhow!("{} not set", env_var))?;
 
     let client = LinearClient::new(api_key);
     let issues = client
@@ -439,37 +1045,963 @@ async fn cmd_status() -> Result<()> {
     Ok(())
 }
 
-fn init_tracing() -> Result<()> {
-    let filter = tracing_subscriber::EnvFilter::try_from_default_env()
-        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("warn,tui=info"));
+/// Differences between local tasks and the issues assigned in Linear
+struct PullPlan<'a> {
+    /// Assigned, unfinished issues with no local task yet
+    new_issues: Vec<&'a LinearBoardIssue>,
+    /// Linked tasks whose title changed upstream
+    renamed: Vec<(&'a Task, &'a LinearBoardIssue)>,
+    /// Linked tasks whose issue isn't among our assigned issues: closed,
+    /// reassigned or deleted, which `settle_missing` tells apart
+    missing: Vec<&'a Task>,
+}
 
-    let log_dir = dirs::home_dir()
-        .unwrap_or_else(|| std::path::PathBuf::from("."))
-        .join(".vibe");
-    std::fs::create_dir_all(&log_dir)?;
+/// What became of the issues behind `PullPlan::missing`
+struct MissingIssues<'a> {
+    /// Completed or canceled upstream, with the status to store
+    closed: Vec<(&'a Task, TaskStatus)>,
+    /// Still exists but assigned elsewhere, or couldn't be looked up; kept
+    kept: Vec<&'a Task>,
+    /// Linear confirmed the issue doesn't exist
+    orphans: Vec<&'a Task>,
+}
 
-    let log_file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(log_dir.join("vibe.log"))?;
+/// Sort missing tasks by a direct lookup of their issue: `Some(None)` is a confirmed
+/// miss, an absent entry a lookup that failed (never grounds for deleting)
+fn settle_missing<'a>(
+    missing: &[&'a Task],
+    lookups: &std::collections::HashMap<String, Option<external::LinearIssueStatus>>,
+) -> MissingIssues<'a> {
+    let mut settled = MissingIssues {
+        closed: Vec::new(),
+        kept: Vec::new(),
+        orphans: Vec::new(),
+    };
+    for &task in missing {
+        let id = task.linear_issue_id.as_deref().unwrap_or("");
+        match lookups.get(id) {
+            Some(None) => settled.orphans.push(task),
+            Some(Some(issue)) if matches!(issue.state_type.as_str(), "completed" | "canceled") => {
+                settled
+                    .closed
+                    .push((task, TaskStatus::from_linear_state_type(&issue.state_type)));
+            }
+            _ => settled.kept.push(task),
+        }
+    }
+    settled
+}
 
-    tracing_subscriber::registry()
-        .with(filter)
-        .with(tracing_subscriber::fmt::layer().with_writer(Mutex::new(log_file)))
-        .init();
+fn plan_pull<'a>(tasks: &'a [Task], issues: &'a [LinearBoardIssue]) -> PullPlan<'a> {
+    let linked = |id: &str| {
+        tasks
+            .iter()
+            .find(|t| t.linear_issue_id.as_deref() == Some(id))
+    };
 
-    Ok(())
-}
+    let mut new_issues = Vec::new();
+    let mut renamed = Vec::new();
+    for issue in issues {
+        match linked(&issue.identifier) {
+            Some(task) if task.title != issue.title => renamed.push((task, issue)),
+            Some(_) => {}
+            None if issue.state_type != "completed" => new_issues.push(issue),
+            None => {}
+        }
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::{Cli, Command};
-    use clap::Parser;
+    let missing = tasks
+        .iter()
+        .filter(|t| {
+            t.linear_issue_id
+                .as_ref()
+                .is_some_and(|id| !issues.iter().any(|i| &i.identifier == id))
+        })
+        .collect();
 
-    #[test]
-    fn create_requires_gas_it_for_with_prime() {
-        let result = Cli::try_parse_from(["vibe", "create", "--title", "test", "--with-prime"]);
-        assert!(result.is_err());
+    PullPlan {
+        new_issues,
+        renamed,
+        missing,
+    }
+}
+
+async fn cmd_pull(delete_orphans: bool) -> Result<()> {
+    let storage = TaskStorage::from_cwd()?;
+    let project = storage.project_name().to_uppercase().replace('-', "_");
+    let env_var = format!("{}_LINEAR_API_KEY", project);
+
+    let api_key =
+        storage::read_secret(&env_var).map_err(|_| anyhow::anyhow!("{} not set", env_var))?;
+
+    let client = LinearClient::new(api_key);
+    let issues = client
+        .fetch_assigned_issues()
+        .await
+        .map_err(|e| anyhow::anyhow!("Linear: {}", e))?;
+    let tasks = storage.list_tasks()?;
+
+    // Open issues' statuses are derived from Linear on every load, so titles and
+    // membership are what needs reconciling; closed issues get their status stored
+    let plan = plan_pull(&tasks, &issues);
+
+    for issue in &plan.new_issues {
+        storage.create_task_from_linear(&issue.to_issue())?;
+        println!("  + {} {}", issue.identifier, issue.title);
+    }
+
+    // A new title derives a new branch; bind the old one so existing work stays attached
+    let worktrees = if plan.renamed.is_empty() {
+        Vec::new()
+    } else {
+        external::list_worktrees().unwrap_or_default()
+    };
+    let worktree_branches: Vec<&str> = worktrees.iter().map(|w| w.branch.as_str()).collect();
+    for (task, issue) in &plan.renamed {
+        if let Some(branch) = task.branch_to_keep_on_retitle(&issue.title, &worktree_branches) {
+            storage.set_branch_override(&task.id, Some(&branch))?;
+            println!(
+                "    kept branch {} (vibe bind {} --rename to follow the title)",
+                branch, issue.identifier
+            );
+        }
+        storage.update_task(&task.id, &issue.title, task.description.as_deref())?;
+        println!("  ~ {} {} -> {}", issue.identifier, task.title, issue.title);
+    }
+
+    // Not being assigned to us isn't proof the issue is gone; ask for each one
+    let mut lookups = std::collections::HashMap::new();
+    for task in &plan.missing {
+        let id = task.linear_issue_id.as_deref().unwrap_or("");
+        match client.fetch_issue_status(id).await {
+            Ok(issue) => {
+                lookups.insert(id.to_string(), issue);
+            }
+            Err(e) => eprintln!("warning: could not look up {}: {}", id, e),
+        }
+    }
+    let missing = settle_missing(&plan.missing, &lookups);
+
+    for (task, status) in &missing.closed {
+        if task.status != *status {
+            storage.set_task_status(&task.id, *status)?;
+        }
+        let id = task.linear_issue_id.as_deref().unwrap_or("");
+        println!("  = {} {} ({})", id, task.title, status.label());
+    }
+    for task in &missing.kept {
+        let id = task.linear_issue_id.as_deref().unwrap_or("");
+        println!("  ? {} {} (no longer assigned to you)", id, task.title);
+    }
+    for task in &missing.orphans {
+        let id = task.linear_issue_id.as_deref().unwrap_or("");
+        if delete_orphans {
+            storage.delete_task(&task.id)?;
+            println!("  - {} {} (deleted)", id, task.title);
+        } else {
+            println!("  ? {} {} (issue no longer exists)", id, task.title);
+        }
+    }
+
+    println!(
+        "added {}, updated {}, closed {}, orphaned {}",
+        plan.new_issues.len(),
+        plan.renamed.len(),
+        missing.closed.len(),
+        missing.orphans.len()
+    );
+    if !missing.orphans.is_empty() && !delete_orphans {
+        println!("re-run with --delete-orphans to remove orphaned tasks");
+    }
+
+    Ok(())
+}
+
+/// PR, worktree and Linear state the board derives task status from
+struct BoardInputs {
+    branch_prs: std::collections::HashMap<String, external::BranchPrInfo>,
+    worktrees: Vec<external::WorktreeInfo>,
+    linear_statuses: std::collections::HashMap<String, external::LinearIssueStatus>,
+}
+
+/// Gather board inputs, warning (not failing) when a source is unavailable
+async fn fetch_board_inputs(storage: &TaskStorage, tasks: &[Task]) -> BoardInputs {
+    let branch_prs = external::get_all_open_prs().unwrap_or_else(|e| {
+        eprintln!("warning: could not fetch PRs: {}", e);
+        Default::default()
+    });
+    let worktrees = external::list_worktrees().unwrap_or_default();
+
+    let project = storage.project_name().to_uppercase().replace('-', "_");
+    let env_var = format!("{}_LINEAR_API_KEY", project);
+    let mut linear_statuses = std::collections::HashMap::new();
+    if let Ok(api_key) = storage::read_secret(&env_var) {
+        let identifiers: Vec<String> = tasks
+            .iter()
+            .filter_map(|t| t.linear_issue_id.clone())
+            .collect();
+        match LinearClient::new(api_key)
+            .fetch_issue_statuses(&identifiers)
+            .await
+        {
+            Ok(statuses) => {
+                for status in statuses {
+                    linear_statuses.insert(status.identifier.clone(), status);
+                }
+            }
+            Err(e) => eprintln!("warning: could not fetch Linear statuses: {}", e),
+        }
+    }
+
+    BoardInputs {
+        branch_prs,
+        worktrees,
+        linear_statuses,
+    }
+}
+
+async fn cmd_archive(older_than: &str) -> Result<()> {
+    let older_than_days = state::parse_age_days(older_than)?;
+    let storage = TaskStorage::from_cwd()?;
+    let tasks = storage.list_tasks()?;
+
+    // Done is derived, so gather the same PR/worktree/Linear inputs as the board
+    let inputs = fetch_board_inputs(&storage, &tasks).await;
+
+    let today = chrono::Utc::now().date_naive();
+    let ids = state::archive_candidates(
+        &tasks,
+        &inputs.branch_prs,
+        &inputs.worktrees,
+        &inputs.linear_statuses,
+        older_than_days,
+        today,
+    );
+
+    for task in tasks.iter().filter(|t| ids.contains(&t.id)) {
+        println!(
+            "  archive {} {}",
+            task.linear_issue_id.as_deref().unwrap_or(""),
+            task.title
+        );
+    }
+    let count = storage.archive_tasks(&ids)?;
+    println!(
+        "archived {} done task(s) older than {} days",
+        count, older_than_days
+    );
+
+    Ok(())
+}
+
+async fn cmd_stats(since: &str, json: bool) -> Result<()> {
+    let since_days = state::parse_age_days(since)?;
+    let storage = TaskStorage::from_cwd()?;
+    let tasks = storage.list_tasks()?;
+
+    // WIP is derived the same way as the board columns
+    let inputs = fetch_board_inputs(&storage, &tasks).await;
+    let current = state::board_statuses(
+        &tasks,
+        &inputs.branch_prs,
+        &inputs.worktrees,
+        &inputs.linear_statuses,
+    );
+
+    let today = chrono::Utc::now().date_naive();
+    let stats = state::compute_stats(&tasks, &current, today - chrono::Duration::days(since_days));
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+        return Ok(());
+    }
+
+    println!("\n  Since {} ({} days)", stats.since, since_days);
+    println!("    done          {}", stats.done);
+    match stats.avg_cycle_days {
+        Some(days) => println!(
+            "    cycle time    {:.1} days avg (In Progress -> Done)",
+            days
+        ),
+        None => println!("    cycle time    n/a"),
+    }
+    println!(
+        "    wip           {} ({} in progress, {} in review)",
+        stats.wip(),
+        stats.in_progress,
+        stats.in_review
+    );
+    if stats.done_unrecorded > 0 {
+        println!(
+            "\n  {} done task(s) have no recorded move to Done and aren't counted",
+            stats.done_unrecorded
+        );
+    }
+    Ok(())
+}
+
+async fn cmd_export(format: &str) -> Result<()> {
+    if !matches!(format, "md" | "markdown") {
+        anyhow::bail!("unknown export format '{}' (expected md)", format);
+    }
+    let storage = TaskStorage::from_cwd()?;
+    let tasks = storage.list_tasks()?;
+
+    // Columns are resolved the same way the board resolves them
+    let inputs = fetch_board_inputs(&storage, &tasks).await;
+    print!(
+        "{}",
+        state::board_markdown(
+            &tasks,
+            &inputs.branch_prs,
+            &inputs.worktrees,
+            &inputs.linear_statuses,
+        )
+    );
+    Ok(())
+}
+
+fn cmd_debug_activity() -> Result<()> {
+    let storage = TaskStorage::from_cwd()?;
+    let tracker = external::ClaudeActivityTracker::for_project(storage.project_name());
+
+    // Same inputs the board uses: a session's worktree path when its branch has one
+    let worktrees = external::list_worktrees().unwrap_or_default();
+    let sessions: Vec<(String, Option<String>)> = external::list_sessions()
+        .unwrap_or_else(|e| {
+            eprintln!("warning: could not list sessions: {}", e);
+            Vec::new()
+        })
+        .into_iter()
+        .map(|s| {
+            let worktree = worktrees
+                .iter()
+                .find(|w| {
+                    !w.path.is_empty() && external::session_name_for_branch(&w.branch) == s.name
+                })
+                .map(|w| w.path.clone());
+            (s.name, worktree)
+        })
+        .collect();
+
+    let matches = tracker.status_file_matches(&sessions);
+    if matches.is_empty() {
+        println!("no status files (is the statusline hook installed?)");
+    }
+    for file in &matches {
+        println!("{}", file.path.display());
+        println!("  working_dir {}", file.working_dir);
+        println!("  timestamp   {}", file.timestamp);
+        if file.sessions.is_empty() {
+            println!("  sessions    (none)");
+        } else {
+            println!("  sessions    {}", file.sessions.join(", "));
+        }
+    }
+
+    let unmatched: Vec<_> = sessions
+        .iter()
+        .filter(|(name, _)| !matches.iter().any(|m| m.sessions.contains(name)))
+        .collect();
+    if !unmatched.is_empty() {
+        println!("\nsessions with no status file (shown as Unknown)");
+        for (name, worktree) in unmatched {
+            match worktree {
+                Some(path) => println!("  {} (needs a working_dir in {})", name, path),
+                None => println!("  {} (needs a working_dir containing the name)", name),
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn cmd_audit() -> Result<()> {
+    let storage = TaskStorage::from_cwd()?;
+    let tasks = storage.list_tasks()?;
+
+    // "In Progress" is the board's column, so derive it the same way
+    let inputs = fetch_board_inputs(&storage, &tasks).await;
+    let statuses = state::board_statuses(
+        &tasks,
+        &inputs.branch_prs,
+        &inputs.worktrees,
+        &inputs.linear_statuses,
+    );
+    let sessions = external::list_sessions().unwrap_or_else(|e| {
+        eprintln!("warning: could not list sessions: {}", e);
+        Vec::new()
+    });
+    let report = state::audit(
+        &tasks,
+        &statuses,
+        &inputs.worktrees,
+        &sessions,
+        &inputs.branch_prs,
+    );
+
+    if report.is_clean() {
+        println!("no drift: every task, worktree, session and open PR lines up");
+        return Ok(());
+    }
+
+    if !report.tasks_without_work.is_empty() {
+        println!("\n  In Progress tasks with no worktree or session");
+        for task in &report.tasks_without_work {
+            println!("    {}", task);
+        }
+        println!("  -> resume with `vibe gas <task>`, or `vibe move <task> backlog`");
+    }
+    if !report.worktrees_without_task.is_empty() {
+        println!("\n  Worktrees with no task");
+        for branch in &report.worktrees_without_task {
+            println!("    {}", branch);
+        }
+        println!(
+            "  -> import them from the worktrees view (`w` on the board), or `wt remove <branch>`"
+        );
+    }
+    if !report.sessions_without_task.is_empty() {
+        println!("\n  Sessions with no task");
+        for session in &report.sessions_without_task {
+            println!("    {}", session);
+        }
+        println!("  -> stop them with `vibe cleanup <session>`");
+    }
+    if !report.prs_without_task.is_empty() {
+        println!("\n  Open PRs with no task");
+        for (branch, url) in &report.prs_without_task {
+            println!("    {} {}", branch, url);
+        }
+        println!("  -> bind one to a task with `b` on the board, or close it on GitHub");
+    }
+    Ok(())
+}
+
+/// Tools checked by `vibe setup`, with what is missing without them
+const SETUP_TOOLS: [(&str, &str); 5] = [
+    ("git", "required"),
+    ("zellij", "sessions can't be launched or attached"),
+    ("claude", "Claude Code sessions (Codex works with --codex)"),
+    (
+        "gh",
+        "no PR status on cards; run `gh auth login` after installing",
+    ),
+    ("wt", "worktrees aren't listed or created (worktrunk)"),
+];
+
+/// First run: nothing under `~/.vibe` yet and no Linear key for this project
+fn needs_onboarding() -> bool {
+    let configured = dirs::home_dir().is_none_or(|home| home.join(".vibe").exists());
+    let has_key = TaskStorage::resolve_project_name()
+        .is_ok_and(|project| state::check_linear_api_key(&project));
+    !configured && !has_key
+}
+
+/// Create the project's storage, report missing tools and ask for the Linear
+/// API key. Every step is skipped when already done, so it can be re-run.
+fn cmd_setup() -> Result<()> {
+    use std::io::{BufRead, IsTerminal, Write};
+
+    let storage = TaskStorage::from_cwd()?;
+    let project = storage.project_name().to_string();
+    println!("vibe setup for {}", project);
+    println!("  tasks    {}", storage.tasks_dir().display());
+
+    println!("\n  Tools");
+    for (tool, without) in SETUP_TOOLS {
+        match find_on_path(tool) {
+            Some(path) => println!("    {:<8} {}", tool, path.display()),
+            None => println!("    {:<8} missing: {}", tool, without),
+        }
+    }
+
+    println!("\n  Linear");
+    let env_var = state::linear_env_var_name(&project);
+    if storage::read_secret(&env_var).is_ok() {
+        println!("    {} is set", env_var);
+    } else if !std::io::stdin().is_terminal() {
+        println!(
+            "    {} not set; run `vibe setup` in a terminal to add it",
+            env_var
+        );
+    } else {
+        print!("    API key for {} (Enter to skip): ", project);
+        std::io::stdout().flush()?;
+        let mut key = String::new();
+        std::io::stdin().lock().read_line(&mut key)?;
+        let key = key.trim();
+        if key.is_empty() {
+            println!("    skipped: the board works without Linear; re-run `vibe setup` to add it");
+        } else {
+            storage::save_secret(&env_var, key)?;
+            println!(
+                "    saved as {} in {}",
+                env_var,
+                storage::secrets_path().unwrap_or_default().display()
+            );
+        }
+    }
+
+    println!("\nDone. Run `vibe` to open the board, or `vibe setup` again any time.");
+    Ok(())
+}
+
+/// External tools vibe shells out to, as reported by `vibe version --verbose`
+const REPORTED_TOOLS: [&str; 4] = ["zellij", "gh", "claude", "git"];
+
+fn cmd_version(verbose: bool) -> Result<()> {
+    println!("vibe {}", env!("CARGO_PKG_VERSION"));
+    if !verbose {
+        return Ok(());
+    }
+
+    println!();
+    for tool in REPORTED_TOOLS {
+        match find_on_path(tool) {
+            Some(path) => {
+                let version = tool_version(tool).unwrap_or_else(|| "unknown version".to_string());
+                println!("{:<8} {} ({})", tool, version, path.display());
+            }
+            None => println!("{:<8} not found on PATH", tool),
+        }
+    }
+
+    let vibe_dir = dirs::home_dir()
+        .ok_or_else(|| anyhow::anyhow!("No home directory"))?
+        .join(".vibe");
+    println!();
+    println!("{:<8} {}", "config", vibe_dir.display());
+    println!("{:<8} {}", "tasks", vibe_dir.join("projects").display());
+    println!("{:<8} {}", "log", vibe_dir.join("vibe.log").display());
+    Ok(())
+}
+
+/// `<tool> --version`, reduced to its first line
+fn tool_version(tool: &str) -> Option<String> {
+    let output = std::process::Command::new(tool)
+        .arg("--version")
+        .output()
+        .ok()?;
+    // Some tools print their version to stderr
+    first_line(&output.stdout).or_else(|| first_line(&output.stderr))
+}
+
+fn first_line(output: &[u8]) -> Option<String> {
+    String::from_utf8_lossy(output)
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(String::from)
+}
+
+fn init_tracing(timings: bool) -> Result<()> {
+    let mut filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("warn,tui=info"));
+    if timings {
+        filter = filter.add_directive("vibe::external::timing=info".parse()?);
+    }
+
+    let log_dir = dirs::home_dir()
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+        .join(".vibe");
+    std::fs::create_dir_all(&log_dir)?;
+
+    let log_file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_dir.join("vibe.log"))?;
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer().with_writer(Mutex::new(log_file)))
+        .init();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Cli, Command, DebugCommand, LinearBoardIssue, PathBuf, find_task, first_line,
+        parse_selection, plan_pull, settle_missing,
+    };
+    use crate::external::LinearIssueStatus;
+    use crate::state::{Task, TaskStatus, test_task};
+    use clap::Parser;
+
+    fn linked_task(id: &str, linear_id: &str, title: &str) -> Task {
+        Task {
+            linear_issue_id: Some(linear_id.to_string()),
+            ..test_task(id, title)
+        }
+    }
+
+    fn issue(identifier: &str, title: &str, state_type: &str) -> LinearBoardIssue {
+        LinearBoardIssue {
+            identifier: identifier.to_string(),
+            title: title.to_string(),
+            description: None,
+            url: format!("https://linear.app/test/issue/{}", identifier),
+            labels: vec![],
+            priority: None,
+            estimate: None,
+            state_name: state_type.to_string(),
+            state_type: state_type.to_string(),
+        }
+    }
+
+    #[test]
+    fn pull_plan_classifies_adds_updates_and_orphans() {
+        let tasks = vec![
+            linked_task("1", "VIB-1", "Same title"),
+            linked_task("2", "VIB-2", "Old title"),
+            linked_task("3", "VIB-3", "Reassigned away"),
+        ];
+        let issues = vec![
+            issue("VIB-1", "Same title", "started"),
+            issue("VIB-2", "New title", "backlog"),
+            issue("VIB-4", "Fresh issue", "unstarted"),
+            issue("VIB-5", "Already shipped", "completed"),
+        ];
+
+        let plan = plan_pull(&tasks, &issues);
+
+        let new_ids: Vec<_> = plan.new_issues.iter().map(|i| &i.identifier).collect();
+        assert_eq!(new_ids, vec!["VIB-4"]);
+        assert_eq!(plan.renamed.len(), 1);
+        assert_eq!(plan.renamed[0].0.id, "2");
+        assert_eq!(plan.renamed[0].1.title, "New title");
+        let missing_ids: Vec<_> = plan.missing.iter().map(|t| &t.id).collect();
+        assert_eq!(missing_ids, vec!["3"]);
+    }
+
+    #[test]
+    fn pull_only_orphans_issues_linear_confirms_are_gone() {
+        let tasks = [
+            linked_task("1", "VIB-1", "Shipped"),
+            linked_task("2", "VIB-2", "Dropped"),
+            linked_task("3", "VIB-3", "Handed to a teammate"),
+            linked_task("4", "VIB-4", "Deleted upstream"),
+            linked_task("5", "VIB-5", "Lookup failed"),
+        ];
+        let missing: Vec<&Task> = tasks.iter().collect();
+        let status = |id: &str, state_type: &str| {
+            Some(LinearIssueStatus {
+                identifier: id.to_string(),
+                state_type: state_type.to_string(),
+                state_name: state_type.to_string(),
+                assignee: None,
+            })
+        };
+        let lookups = std::collections::HashMap::from([
+            ("VIB-1".to_string(), status("VIB-1", "completed")),
+            ("VIB-2".to_string(), status("VIB-2", "canceled")),
+            ("VIB-3".to_string(), status("VIB-3", "started")),
+            ("VIB-4".to_string(), None),
+        ]);
+
+        let settled = settle_missing(&missing, &lookups);
+
+        let closed: Vec<_> = settled
+            .closed
+            .iter()
+            .map(|(t, status)| (t.id.as_str(), *status))
+            .collect();
+        assert_eq!(
+            closed,
+            vec![("1", TaskStatus::Done), ("2", TaskStatus::Cancelled)]
+        );
+        let kept: Vec<_> = settled.kept.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(kept, vec!["3", "5"]);
+        let orphans: Vec<_> = settled.orphans.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(orphans, vec!["4"]);
+    }
+
+    #[test]
+    fn pull_accepts_delete_orphans() {
+        let cli = Cli::try_parse_from(["vibe", "pull", "--delete-orphans"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Pull {
+                delete_orphans: true
+            })
+        ));
+    }
+
+    #[test]
+    fn find_task_matches_local_tasks_without_linear() {
+        let mut local = linked_task("uuid-1", "X", "Refactor parser");
+        local.linear_issue_id = None;
+        let tasks = vec![local, linked_task("uuid-2", "VIB-2", "Parser docs")];
+
+        assert_eq!(find_task(&tasks, "vib-2").unwrap().id, "uuid-2");
+        assert_eq!(find_task(&tasks, "uuid-1").unwrap().id, "uuid-1");
+        assert_eq!(find_task(&tasks, "refactor").unwrap().id, "uuid-1");
+        assert!(find_task(&tasks, "nothing").is_err());
+    }
+
+    #[test]
+    fn find_task_matches_github_issue_number() {
+        let mut imported = linked_task("uuid-1", "X", "Crash on empty board");
+        imported.linear_issue_id = None;
+        imported.github_issue = Some(42);
+        let tasks = vec![linked_task("uuid-2", "VIB-42", "Other"), imported];
+
+        assert_eq!(find_task(&tasks, "#42").unwrap().id, "uuid-1");
+        assert_eq!(find_task(&tasks, "VIB-42").unwrap().id, "uuid-2");
+        assert!(find_task(&tasks, "#7").is_err());
+    }
+
+    #[test]
+    fn move_parses_target_and_status() {
+        let cli = Cli::try_parse_from(["vibe", "move", "parser", "in-progress"]).unwrap();
+        match cli.command {
+            Some(Command::Move { target, status }) => {
+                assert_eq!(target, "parser");
+                assert_eq!(TaskStatus::parse(&status), Some(TaskStatus::Inprogress));
+            }
+            _ => panic!("expected move command"),
+        }
+    }
+
+    #[test]
+    fn bind_needs_a_branch_or_clear() {
+        let cli = Cli::try_parse_from(["vibe", "bind", "VIB-7", "fix/login-v2"]).unwrap();
+        match cli.command {
+            Some(Command::Bind {
+                target,
+                branch,
+                clear,
+                rename,
+            }) => {
+                assert_eq!(target, "VIB-7");
+                assert_eq!(branch.as_deref(), Some("fix/login-v2"));
+                assert!(!clear);
+                assert!(!rename);
+            }
+            _ => panic!("expected bind command"),
+        }
+        assert!(Cli::try_parse_from(["vibe", "bind", "VIB-7", "--clear"]).is_ok());
+        assert!(Cli::try_parse_from(["vibe", "bind", "VIB-7"]).is_err());
+        assert!(Cli::try_parse_from(["vibe", "bind", "VIB-7", "x", "--clear"]).is_err());
+    }
+
+    #[test]
+    fn archive_parses_older_than() {
+        let cli = Cli::try_parse_from(["vibe", "archive", "--older-than", "2w"]).unwrap();
+        match cli.command {
+            Some(Command::Archive { older_than }) => assert_eq!(older_than, "2w"),
+            _ => panic!("expected archive command"),
+        }
+
+        let cli = Cli::try_parse_from(["vibe", "archive"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Archive { older_than }) if older_than == "30d"
+        ));
+    }
+
+    #[test]
+    fn stats_parses_since_and_json() {
+        let cli = Cli::try_parse_from(["vibe", "stats", "--since", "2w", "--json"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Stats { since, json: true }) if since == "2w"
+        ));
+
+        let cli = Cli::try_parse_from(["vibe", "stats"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Stats { since, json: false }) if since == "7d"
+        ));
+    }
+
+    #[test]
+    fn bind_rename_takes_no_branch() {
+        let cli = Cli::try_parse_from(["vibe", "bind", "VIB-7", "--rename"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Bind {
+                branch: None,
+                rename: true,
+                clear: false,
+                ..
+            })
+        ));
+        assert!(Cli::try_parse_from(["vibe", "bind", "VIB-7", "x", "--rename"]).is_err());
+        assert!(Cli::try_parse_from(["vibe", "bind", "VIB-7", "--clear", "--rename"]).is_err());
+    }
+
+    #[test]
+    fn serve_binds_localhost_by_default() {
+        let cli = Cli::try_parse_from(["vibe", "serve", "--port", "8080"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Serve { port: 8080, host }) if host == "127.0.0.1"
+        ));
+    }
+
+    #[test]
+    fn debug_activity_parses() {
+        let cli = Cli::try_parse_from(["vibe", "debug", "activity"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Debug {
+                what: DebugCommand::Activity
+            })
+        ));
+        assert!(Cli::try_parse_from(["vibe", "debug"]).is_err());
+    }
+
+    #[test]
+    fn export_defaults_to_markdown() {
+        let cli = Cli::try_parse_from(["vibe", "export"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Export { format }) if format == "md"));
+    }
+
+    #[test]
+    fn version_parses_verbose() {
+        let cli = Cli::try_parse_from(["vibe", "version", "-v"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Version { verbose: true })
+        ));
+
+        let cli = Cli::try_parse_from(["vibe", "version"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Version { verbose: false })
+        ));
+    }
+
+    #[test]
+    fn version_report_keeps_first_line() {
+        assert_eq!(
+            first_line(b"\ngh version 2.62.0 (2024-11-14)\nhttps://github.com/cli/cli\n")
+                .as_deref(),
+            Some("gh version 2.62.0 (2024-11-14)")
+        );
+        assert_eq!(first_line(b"  \n"), None);
+    }
+
+    #[test]
+    fn cleanup_parses_force() {
+        let cli = Cli::try_parse_from(["vibe", "cleanup", "VIB-21", "--force"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Cleanup { target: Some(t), force: true }) if t == "VIB-21"
+        ));
+    }
+
+    #[test]
+    fn repo_is_a_global_flag() {
+        let cli = Cli::try_parse_from(["vibe", "--repo", "/src/app"]).unwrap();
+        assert_eq!(cli.repo, Some(PathBuf::from("/src/app")));
+        assert!(cli.command.is_none());
+
+        let cli = Cli::try_parse_from(["vibe", "stats", "--repo", "../app"]).unwrap();
+        assert_eq!(cli.repo, Some(PathBuf::from("../app")));
+    }
+
+    #[test]
+    fn onboarding_can_be_skipped_or_rerun() {
+        let cli = Cli::try_parse_from(["vibe", "--skip-onboarding"]).unwrap();
+        assert!(cli.skip_onboarding);
+        assert!(cli.command.is_none());
+
+        let cli = Cli::try_parse_from(["vibe", "setup"]).unwrap();
+        assert!(!cli.skip_onboarding);
+        assert!(matches!(cli.command, Some(Command::Setup)));
+    }
+
+    #[test]
+    fn gas_takes_a_search_instead_of_a_target() {
+        let cli = Cli::try_parse_from(["vibe", "gas", "--search", "auth bug"]).unwrap();
+        match cli.command {
+            Some(Command::Gas { target, search, .. }) => {
+                assert_eq!(target, None);
+                assert_eq!(search.as_deref(), Some("auth bug"));
+            }
+            _ => panic!("expected gas command"),
+        }
+        assert!(Cli::try_parse_from(["vibe", "gas"]).is_err());
+        assert!(Cli::try_parse_from(["vibe", "gas", "VIB-1", "--search", "auth"]).is_err());
+
+        assert_eq!(parse_selection(" 2\n", 3), Some(1));
+        assert_eq!(parse_selection("0", 3), None);
+        assert_eq!(parse_selection("4", 3), None);
+        assert_eq!(parse_selection("", 3), None);
+    }
+
+    #[test]
+    fn gas_parses_no_worktree() {
+        let cli = Cli::try_parse_from(["vibe", "gas", "VIB-1", "--no-worktree"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Gas {
+                no_worktree: true,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn gas_parses_context_file() {
+        let cli =
+            Cli::try_parse_from(["vibe", "gas", "VIB-1", "--context-file", "rules.md"]).unwrap();
+        match cli.command {
+            Some(Command::Gas { context_file, .. }) => {
+                assert_eq!(context_file, Some(PathBuf::from("rules.md")));
+            }
+            _ => panic!("expected gas command"),
+        }
+    }
+
+    #[test]
+    fn create_requires_gas_it_for_with_prime() {
+        let result = Cli::try_parse_from(["vibe", "create", "--title", "test", "--with-prime"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn create_collects_repeated_labels() {
+        let cli = Cli::try_parse_from([
+            "vibe", "create", "--title", "test", "--label", "bug", "-l", "ui",
+        ])
+        .unwrap();
+        match cli.command {
+            Some(Command::Create { labels, .. }) => assert_eq!(labels, ["bug", "ui"]),
+            _ => panic!("expected create"),
+        }
+    }
+
+    #[test]
+    fn setup_linear_label_defaults_to_gasit() {
+        let cli = Cli::try_parse_from(["vibe", "setup-linear"]).unwrap();
+        match cli.command {
+            Some(Command::SetupLinear { label, team }) => {
+                assert_eq!(label, "~gasit");
+                assert_eq!(team, None);
+            }
+            _ => panic!("expected setup-linear"),
+        }
+        let cli = Cli::try_parse_from(["vibe", "setup-linear", "--label", "ready"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::SetupLinear { label, .. }) if label == "ready"
+        ));
+    }
+
+    #[test]
+    fn promote_takes_a_team_key() {
+        let cli = Cli::try_parse_from(["vibe", "promote", "login", "--team", "ops"]).unwrap();
+        match cli.command {
+            Some(Command::Promote { target, team }) => {
+                assert_eq!(target, "login");
+                assert_eq!(team.as_deref(), Some("ops"));
+            }
+            _ => panic!("expected promote"),
+        }
     }
 
     #[test]
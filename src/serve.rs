@@ -0,0 +1,362 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::external::{
+    ClaudeActivityState, ClaudeActivityTracker, ZellijSession, list_sessions_with_status,
+    session_name_for_branch,
+};
+use crate::state::{self, Task, TaskStatus, task_pr_and_worktree};
+use crate::storage::{TaskStorage, TaskStore};
+
+/// How often `vibe serve` gathers the board again
+pub const REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Requests bigger than this are cut off; only the request line is read anyway
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+
+/// Clients that connect but never finish their request are dropped after this
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Window for the throughput numbers in `/metrics`
+const METRICS_DAYS: i64 = 7;
+
+/// Latest JSON for each endpoint, replaced whole on every refresh
+#[derive(Debug, Default, Clone)]
+struct Snapshot {
+    tasks: Value,
+    sessions: Value,
+    metrics: Value,
+}
+
+/// Serve `/tasks`, `/sessions` and `/metrics` on `host:port` until interrupted,
+/// gathering the board the same way the CLI does every `REFRESH_INTERVAL`
+pub async fn serve(storage: TaskStorage, host: &str, port: u16) -> Result<()> {
+    let snapshot = Arc::new(RwLock::new(gather(&storage).await?));
+
+    let listener = TcpListener::bind((host, port))
+        .await
+        .with_context(|| format!("Failed to listen on {}:{}", host, port))?;
+    println!(
+        "serving {} on http://{}/ (/tasks, /sessions, /metrics)",
+        storage.project_name(),
+        listener.local_addr()?
+    );
+
+    // Only requests addressed to us by name, so a DNS-rebound page can't read the board
+    let local = listener.local_addr()?;
+    let mut allowed_hosts = vec![format!("{}:{}", host, local.port()), local.to_string()];
+    if local.ip().is_loopback() {
+        allowed_hosts.push(format!("localhost:{}", local.port()));
+    }
+    let allowed_hosts: Arc<[String]> = allowed_hosts.into();
+
+    let shared = Arc::clone(&snapshot);
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    let snapshot = Arc::clone(&shared);
+                    let allowed_hosts = Arc::clone(&allowed_hosts);
+                    tokio::spawn(async move {
+                        if let Err(e) = handle_connection(stream, &snapshot, &allowed_hosts).await {
+                            tracing::debug!("serve: connection failed: {}", e);
+                        }
+                    });
+                }
+                Err(e) => tracing::warn!("serve: accept failed: {}", e),
+            }
+        }
+    });
+
+    // Gathering isn't Send (the task store), so it stays on this task
+    loop {
+        tokio::time::sleep(REFRESH_INTERVAL).await;
+        match gather(&storage).await {
+            Ok(fresh) => *snapshot.write().unwrap() = fresh,
+            Err(e) => eprintln!("warning: refresh failed, serving the previous board: {}", e),
+        }
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    snapshot: &RwLock<Snapshot>,
+    allowed_hosts: &[String],
+) -> Result<()> {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 1024];
+    tokio::time::timeout(READ_TIMEOUT, async {
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") && request.len() < MAX_REQUEST_BYTES {
+            let n = stream.read(&mut buf).await?;
+            if n == 0 {
+                break;
+            }
+            request.extend_from_slice(&buf[..n]);
+        }
+        anyhow::Ok(())
+    })
+    .await
+    .context("Client did not send a request in time")??;
+
+    let request = String::from_utf8_lossy(&request);
+    let mut lines = request.lines();
+    let mut parts = lines.next().unwrap_or("").split_whitespace();
+    let method = parts.next().unwrap_or("");
+    let path = parts.next().unwrap_or("");
+
+    let (status, body) = if host_allowed(lines, allowed_hosts) {
+        route(method, path, &snapshot.read().unwrap())
+    } else {
+        (403, json!({ "error": "unexpected Host header" }))
+    };
+    stream.write_all(response(status, &body).as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+/// Whether the request's `Host` header names the address we're bound to
+fn host_allowed<'a>(headers: impl Iterator<Item = &'a str>, allowed_hosts: &[String]) -> bool {
+    headers
+        .take_while(|line| !line.is_empty())
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("host"))
+        .is_some_and(|(_, value)| {
+            allowed_hosts
+                .iter()
+                .any(|host| host.eq_ignore_ascii_case(value.trim()))
+        })
+}
+
+/// Status code and JSON body for a request. Read-only: anything but GET is refused.
+fn route(method: &str, path: &str, snapshot: &Snapshot) -> (u16, Value) {
+    if method != "GET" {
+        return (405, json!({ "error": "read-only: only GET is supported" }));
+    }
+    // Dashboards tend to add cache-busting query strings
+    match path.split('?').next().unwrap_or(path) {
+        "/tasks" => (200, snapshot.tasks.clone()),
+        "/sessions" => (200, snapshot.sessions.clone()),
+        "/metrics" => (200, snapshot.metrics.clone()),
+        "/" => (
+            200,
+            json!({ "endpoints": ["/tasks", "/sessions", "/metrics"] }),
+        ),
+        _ => (404, json!({ "error": "not found" })),
+    }
+}
+
+fn response(status: u16, body: &Value) -> String {
+    let reason = match status {
+        200 => "OK",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Error",
+    };
+    let body = body.to_string();
+    format!(
+        "HTTP/1.1 {} {}\r\n\
+         Content-Type: application/json\r\n\
+         Content-Length: {}\r\n\
+         Cache-Control: no-store\r\n\
+         Connection: close\r\n\r\n{}",
+        status,
+        reason,
+        body.len(),
+        body
+    )
+}
+
+/// One pass over tasks, PRs, worktrees, Linear and sessions
+async fn gather(storage: &TaskStorage) -> Result<Snapshot> {
+    let tasks = storage.list_tasks()?;
+    let inputs = crate::fetch_board_inputs(storage, &tasks).await;
+    let statuses = state::board_statuses(
+        &tasks,
+        &inputs.branch_prs,
+        &inputs.worktrees,
+        &inputs.linear_statuses,
+    );
+
+    let mut sessions = tokio::task::spawn_blocking(list_sessions_with_status)
+        .await?
+        .unwrap_or_else(|e| {
+            eprintln!("warning: could not list sessions: {}", e);
+            Vec::new()
+        });
+    let worktree_paths: HashMap<String, String> = inputs
+        .worktrees
+        .iter()
+        .filter(|wt| !wt.path.is_empty())
+        .map(|wt| (session_name_for_branch(&wt.branch), wt.path.clone()))
+        .collect();
+    ClaudeActivityTracker::for_project(storage.project_name())
+        .update_sessions(&mut sessions, &worktree_paths);
+
+    let today = chrono::Utc::now().date_naive();
+    let throughput = state::compute_stats(
+        &tasks,
+        &statuses,
+        today - chrono::Duration::days(METRICS_DAYS),
+    );
+
+    Ok(Snapshot {
+        tasks: tasks_json(&tasks, &statuses, &inputs.branch_prs, &inputs.worktrees),
+        sessions: sessions_json(&sessions, &tasks),
+        metrics: json!({
+            "columns": columns_json(&tasks, &statuses),
+            "sessions": activity_counts(&sessions),
+            "throughput": throughput,
+            "updated_at": chrono::Utc::now().to_rfc3339(),
+        }),
+    })
+}
+
+fn tasks_json(
+    tasks: &[Task],
+    statuses: &HashMap<String, TaskStatus>,
+    branch_prs: &HashMap<String, crate::external::BranchPrInfo>,
+    worktrees: &[crate::external::WorktreeInfo],
+) -> Value {
+    tasks
+        .iter()
+        .map(|task| {
+            let status = statuses.get(&task.id).copied().unwrap_or(task.status);
+            let (pr, has_worktree) = task_pr_and_worktree(task, branch_prs, worktrees);
+            json!({
+                "id": task.id,
+                "title": task.title,
+                "status": status,
+                "column": status.label(),
+                "branch": task.branch(),
+                "linear_issue_id": task.linear_issue_id,
+                "linear_url": task.linear_url,
+                "pr": pr.map(|pr| json!({
+                    "number": pr._number,
+                    "url": pr.url,
+                    "state": pr.state,
+                    "draft": pr.is_draft,
+                })),
+                "has_worktree": has_worktree,
+                "updated_at": task.updated_at,
+            })
+        })
+        .collect()
+}
+
+fn activity_label(session: &ZellijSession) -> &'static str {
+    if session.is_dead {
+        return "dead";
+    }
+    match session.claude_activity {
+        ClaudeActivityState::Thinking => "thinking",
+        ClaudeActivityState::WaitingForUser => "waiting",
+        ClaudeActivityState::Idle => "idle",
+        ClaudeActivityState::Unknown if session.needs_attention => "attention",
+        ClaudeActivityState::Unknown => "unknown",
+    }
+}
+
+fn sessions_json(sessions: &[ZellijSession], tasks: &[Task]) -> Value {
+    sessions
+        .iter()
+        .map(|session| {
+            let task = tasks
+                .iter()
+                .find(|t| session_name_for_branch(&t.branch()) == session.name);
+            json!({
+                "name": session.name,
+                "activity": activity_label(session),
+                "needs_attention": session.needs_attention,
+                "context_percentage": session.context_percentage,
+                "task_id": task.map(|t| t.id.as_str()),
+            })
+        })
+        .collect()
+}
+
+/// Cards per visible column, by column label
+fn columns_json(tasks: &[Task], statuses: &HashMap<String, TaskStatus>) -> Value {
+    let mut columns = serde_json::Map::new();
+    for column in TaskStatus::VISIBLE {
+        let count = tasks
+            .iter()
+            .filter(|t| {
+                statuses
+                    .get(&t.id)
+                    .copied()
+                    .unwrap_or(t.status)
+                    .column_index()
+                    == column.column_index()
+            })
+            .count();
+        columns.insert(column.label().to_string(), json!(count));
+    }
+    Value::Object(columns)
+}
+
+fn activity_counts(sessions: &[ZellijSession]) -> Value {
+    let mut counts = serde_json::Map::new();
+    counts.insert("total".to_string(), json!(sessions.len()));
+    for session in sessions {
+        let entry = counts
+            .entry(activity_label(session).to_string())
+            .or_insert(json!(0));
+        *entry = json!(entry.as_u64().unwrap_or(0) + 1);
+    }
+    Value::Object(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_routes_are_read_only_json() {
+        let snapshot = Snapshot {
+            tasks: json!([{ "id": "a" }]),
+            sessions: json!([]),
+            metrics: json!({ "columns": {} }),
+        };
+
+        assert_eq!(
+            route("GET", "/tasks", &snapshot),
+            (200, json!([{ "id": "a" }]))
+        );
+        assert_eq!(route("GET", "/sessions?t=1", &snapshot).1, json!([]));
+        assert_eq!(route("GET", "/nope", &snapshot).0, 404);
+        assert_eq!(route("POST", "/tasks", &snapshot).0, 405);
+
+        let raw = response(200, &json!([]));
+        assert!(raw.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(raw.contains("Content-Length: 2\r\n"));
+        assert!(raw.ends_with("\r\n\r\n[]"));
+        assert!(!raw.contains("Access-Control-Allow-Origin"));
+    }
+
+    #[test]
+    fn test_only_our_own_host_is_answered() {
+        let allowed = ["127.0.0.1:7777".to_string(), "localhost:7777".to_string()];
+        let headers = |raw: &'static str| raw.lines();
+
+        assert!(host_allowed(headers("Host: 127.0.0.1:7777\r\n"), &allowed));
+        assert!(host_allowed(
+            headers("accept: */*\r\nhost: LOCALHOST:7777"),
+            &allowed
+        ));
+        assert!(!host_allowed(headers("Host: evil.example:7777"), &allowed));
+        assert!(!host_allowed(headers("Host: 127.0.0.1:8080"), &allowed));
+        assert!(!host_allowed(headers("Accept: */*"), &allowed));
+        // Headers end at the blank line; a body can't smuggle one in
+        assert!(!host_allowed(
+            headers("Accept: */*\r\n\r\nHost: 127.0.0.1:7777"),
+            &allowed
+        ));
+    }
+}
@@ -1,7 +1,13 @@
 use std::collections::{HashMap, HashSet};
 
-use super::{LogsState, ProjectsState, SearchState, SessionsState, TasksState, WorktreesState};
-use crate::external::{LinearIssue, LinearIssueStatus};
+use super::{
+    LogsState, PrDiff, ProjectsState, SearchState, SessionScrollback, SessionsState, TasksState,
+    WorktreesState,
+};
+use crate::external::{
+    AssistantCli, LinearAttachment, LinearIssue, LinearIssueStatus, LinearWorkflowState,
+    PlanContent, RateLimit,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum View {
@@ -9,14 +15,254 @@ pub enum View {
     Kanban,
     TaskDetail,
     Worktrees,
-    #[allow(dead_code)]
+    Sessions,
     Logs,
     Search,
 }
 
+impl View {
+    /// Views Tab/Shift-Tab cycle through, in tab bar order
+    pub const TABS: [View; 4] = [View::Kanban, View::Worktrees, View::Sessions, View::Logs];
+
+    /// The tab this view belongs to; task details and search are part of the board
+    pub fn tab(self) -> View {
+        match self {
+            View::Worktrees | View::Sessions | View::Logs => self,
+            View::Projects | View::Kanban | View::TaskDetail | View::Search => View::Kanban,
+        }
+    }
+
+    /// The next tab (or previous with `forward == false`), wrapping around
+    pub fn cycle_tab(self, forward: bool) -> View {
+        let len = Self::TABS.len();
+        let index = Self::TABS
+            .iter()
+            .position(|v| *v == self.tab())
+            .unwrap_or(0);
+        let next = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+        Self::TABS[next]
+    }
+
+    pub fn tab_label(self) -> &'static str {
+        match self.tab() {
+            View::Worktrees => "Worktrees",
+            View::Sessions => "Sessions",
+            View::Logs => "Logs",
+            _ => "Board",
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Modal {
     Help,
+    LinearStatePicker,
+    Scrollback,
+    PrDiff,
+    ConfirmDelete,
+    ConfirmCompact,
+    ConfirmApprove,
+    ConfirmCancel,
+    AssistantPicker,
+    BranchPicker,
+}
+
+/// How long a footer notice stays visible
+const NOTICE_TTL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Task awaiting delete confirmation, shown with `Modal::ConfirmDelete`
+pub struct PendingDelete {
+    pub task_id: String,
+    /// Linear ID if linked, otherwise the title
+    pub label: String,
+}
+
+/// Task awaiting cancel confirmation, shown with `Modal::ConfirmCancel`
+pub struct PendingCancel {
+    pub task_id: String,
+    /// Linear ID if linked, otherwise the title
+    pub label: String,
+    /// Linear issue that can be canceled along with the task (needs the API key)
+    pub linear_id: Option<String>,
+}
+
+/// Session awaiting `/compact` confirmation, shown with `Modal::ConfirmCompact`
+pub struct PendingCompact {
+    pub session_name: String,
+    /// Linear ID if linked, otherwise the title
+    pub label: String,
+    pub context_percentage: f64,
+}
+
+/// PR awaiting approval confirmation, shown with `Modal::ConfirmApprove`
+pub struct PendingApprove {
+    pub pr_url: String,
+    pub pr_number: Option<i64>,
+    /// Linear ID if linked, otherwise the title
+    pub label: String,
+}
+
+/// Transient footer message, e.g. "deleted VIB-42 - u to undo"
+pub struct Notice {
+    pub text: String,
+    pub shown_at: std::time::Instant,
+}
+
+/// Picker for moving a task's Linear issue to one of its team's workflow states
+pub struct LinearStatePicker {
+    pub identifier: String,
+    pub states: Vec<LinearWorkflowState>,
+    pub selected: usize,
+    pub loading: bool,
+    pub error: Option<String>,
+}
+
+impl LinearStatePicker {
+    pub fn new(identifier: String) -> Self {
+        Self {
+            identifier,
+            states: Vec::new(),
+            selected: 0,
+            loading: true,
+            error: None,
+        }
+    }
+
+    /// Load states, preselecting the issue's current state if known
+    pub fn set_states(&mut self, states: Vec<LinearWorkflowState>, current: Option<&str>) {
+        self.selected = current
+            .and_then(|name| states.iter().position(|s| s.name == name))
+            .unwrap_or(0);
+        self.states = states;
+        self.loading = false;
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.states.is_empty() {
+            self.selected = (self.selected + 1) % self.states.len();
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        if !self.states.is_empty() {
+            self.selected = if self.selected == 0 {
+                self.states.len() - 1
+            } else {
+                self.selected - 1
+            };
+        }
+    }
+
+    pub fn selected_state(&self) -> Option<&LinearWorkflowState> {
+        self.states.get(self.selected)
+    }
+}
+
+/// Picker binding a task to one of the repo's branches, shown with `Modal::BranchPicker`
+pub struct BranchPicker {
+    pub task_id: String,
+    /// Linear ID if linked, otherwise the title
+    pub label: String,
+    /// Worktree and PR branches, sorted
+    pub branches: Vec<String>,
+    /// 0 unbinds (back to the title-derived branch), then one per branch
+    pub selected: usize,
+}
+
+impl BranchPicker {
+    /// Preselect the task's bound branch, if any
+    pub fn new(task_id: String, label: String, branches: Vec<String>, bound: Option<&str>) -> Self {
+        let selected = bound
+            .and_then(|bound| branches.iter().position(|b| b == bound))
+            .map_or(0, |i| i + 1);
+        Self {
+            task_id,
+            label,
+            branches,
+            selected,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.branches.len() + 1
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % self.len();
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = (self.selected + self.len() - 1) % self.len();
+    }
+
+    /// The branch to bind, or None to unbind
+    pub fn selected_branch(&self) -> Option<&str> {
+        self.selected
+            .checked_sub(1)
+            .and_then(|i| self.branches.get(i))
+            .map(String::as_str)
+    }
+}
+
+/// Picker for the assistant a single launch runs, shown with `Modal::AssistantPicker`
+pub struct AssistantPicker {
+    /// Every assistant and whether its binary is on PATH
+    pub options: Vec<(AssistantCli, bool)>,
+    pub selected: usize,
+}
+
+impl AssistantPicker {
+    /// Check PATH for each assistant, preselecting `preferred` when it is installed
+    pub fn new(preferred: AssistantCli) -> Self {
+        Self::with_options(
+            AssistantCli::ALL
+                .into_iter()
+                .map(|a| (a, a.is_available()))
+                .collect(),
+            preferred,
+        )
+    }
+
+    fn with_options(options: Vec<(AssistantCli, bool)>, preferred: AssistantCli) -> Self {
+        let selected = options
+            .iter()
+            .position(|&(a, available)| a == preferred && available)
+            .or_else(|| options.iter().position(|&(_, available)| available))
+            .unwrap_or(0);
+        Self { options, selected }
+    }
+
+    /// Move to the next installed assistant; unavailable ones are skipped
+    pub fn select_next(&mut self) {
+        self.step(1);
+    }
+
+    pub fn select_prev(&mut self) {
+        self.step(self.options.len().saturating_sub(1));
+    }
+
+    fn step(&mut self, by: usize) {
+        let len = self.options.len();
+        for offset in 1..len {
+            let index = (self.selected + offset * by) % len;
+            if self.options[index].1 {
+                self.selected = index;
+                return;
+            }
+        }
+    }
+
+    /// The highlighted assistant, if it can be launched
+    pub fn selected_assistant(&self) -> Option<AssistantCli> {
+        self.options
+            .get(self.selected)
+            .filter(|(_, available)| *available)
+            .map(|(a, _)| *a)
+    }
 }
 
 pub struct AppState {
@@ -33,8 +279,8 @@ pub struct AppState {
     pub selected_project_id: Option<String>,
     pub selected_task_id: Option<String>,
 
-    /// Cached plan content for the currently selected task
-    pub selected_task_plan: Option<String>,
+    /// Cached plan content for the currently selected task, split into lines
+    pub selected_task_plan: Option<PlanContent>,
 
     /// Path to the plan file for the currently selected task
     pub selected_task_plan_path: Option<String>,
@@ -68,6 +314,9 @@ pub struct AppState {
     /// Used to derive effective task status from Linear source of truth
     pub linear_issue_statuses: HashMap<String, LinearIssueStatus>,
 
+    /// Links attached to Linear issues, keyed by identifier; fetched when a task is opened
+    pub linear_attachments: HashMap<String, Vec<LinearAttachment>>,
+
     /// When true, logs are shown as an overlay on top of the current view
     pub logs_overlay_visible: bool,
 
@@ -81,8 +330,34 @@ pub struct AppState {
     /// True when PR info is being fetched in the background
     pub pr_loading: bool,
 
+    /// GitHub GraphQL budget as of the last PR fetch
+    pub gh_rate_limit: Option<RateLimit>,
+    /// Linear budget from the latest response headers
+    pub linear_rate_limit: Option<RateLimit>,
+
     /// Whether the prime session is currently active (detected from zellij sessions)
     pub prime_session_active: bool,
+
+    /// Linear workflow state picker, shown with `Modal::LinearStatePicker`
+    pub state_picker: Option<LinearStatePicker>,
+    /// Assistant picker, shown with `Modal::AssistantPicker`
+    pub assistant_picker: Option<AssistantPicker>,
+    /// Branch picker, shown with `Modal::BranchPicker`
+    pub branch_picker: Option<BranchPicker>,
+
+    /// Session scrollback popup, shown with `Modal::Scrollback`
+    pub scrollback: Option<SessionScrollback>,
+    /// PR diff popup, shown with `Modal::PrDiff`
+    pub pr_diff: Option<PrDiff>,
+
+    pub pending_delete: Option<PendingDelete>,
+
+    pub pending_cancel: Option<PendingCancel>,
+
+    pub pending_compact: Option<PendingCompact>,
+    pub pending_approve: Option<PendingApprove>,
+
+    pub notice: Option<Notice>,
 }
 
 impl AppState {
@@ -119,6 +394,7 @@ impl AppState {
             linear_pending_issues: Vec::new(),
             linear_error: None,
             linear_issue_statuses: HashMap::new(),
+            linear_attachments: HashMap::new(),
 
             logs_overlay_visible: false,
 
@@ -127,11 +403,43 @@ impl AppState {
             claude_process_count: 0,
 
             pr_loading: false,
+            gh_rate_limit: None,
+            linear_rate_limit: None,
 
             prime_session_active: false,
+
+            state_picker: None,
+            assistant_picker: None,
+            branch_picker: None,
+
+            scrollback: None,
+            pr_diff: None,
+
+            pending_delete: None,
+            pending_cancel: None,
+
+            pending_compact: None,
+            pending_approve: None,
+
+            notice: None,
         }
     }
 
+    pub fn set_notice(&mut self, text: impl Into<String>) {
+        self.notice = Some(Notice {
+            text: text.into(),
+            shown_at: std::time::Instant::now(),
+        });
+    }
+
+    /// The current notice, unless it has expired
+    pub fn active_notice(&self) -> Option<&str> {
+        self.notice
+            .as_ref()
+            .filter(|n| n.shown_at.elapsed() < NOTICE_TTL)
+            .map(|n| n.text.as_str())
+    }
+
     pub fn tick_animation(&mut self) {
         self.animation_frame = (self.animation_frame + 1) % 4;
     }
@@ -186,5 +494,59 @@ pub fn linear_env_var_name(project_name: &str) -> String {
 
 pub fn check_linear_api_key(project_name: &str) -> bool {
     let env_var = linear_env_var_name(project_name);
-    std::env::var(&env_var).is_ok()
+    crate::storage::read_secret(&env_var).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_view_tabs_cycle_both_ways() {
+        assert_eq!(View::Kanban.cycle_tab(true), View::Worktrees);
+        assert_eq!(View::Logs.cycle_tab(true), View::Kanban);
+        assert_eq!(View::Kanban.cycle_tab(false), View::Logs);
+        assert_eq!(View::Sessions.cycle_tab(false), View::Worktrees);
+        // Task details sits on the board tab
+        assert_eq!(View::TaskDetail.cycle_tab(true), View::Worktrees);
+        assert_eq!(View::TaskDetail.tab_label(), "Board");
+    }
+
+    #[test]
+    fn test_assistant_picker_skips_unavailable() {
+        let mut picker = AssistantPicker::with_options(
+            vec![(AssistantCli::Claude, false), (AssistantCli::Codex, true)],
+            AssistantCli::Claude,
+        );
+        assert_eq!(picker.selected_assistant(), Some(AssistantCli::Codex));
+        picker.select_next();
+        picker.select_prev();
+        assert_eq!(picker.selected_assistant(), Some(AssistantCli::Codex));
+
+        let picker = AssistantPicker::with_options(
+            vec![(AssistantCli::Claude, false), (AssistantCli::Codex, false)],
+            AssistantCli::Codex,
+        );
+        assert_eq!(picker.selected_assistant(), None);
+    }
+
+    #[test]
+    fn test_branch_picker_starts_on_bound_branch_and_can_unbind() {
+        let branches = vec!["fix-login".to_string(), "fix-login-v2".to_string()];
+        let mut picker = BranchPicker::new(
+            "t1".to_string(),
+            "VIB-1".to_string(),
+            branches.clone(),
+            Some("fix-login-v2"),
+        );
+        assert_eq!(picker.selected_branch(), Some("fix-login-v2"));
+        picker.select_next();
+        assert_eq!(picker.selected_branch(), None);
+        picker.select_prev();
+        picker.select_prev();
+        assert_eq!(picker.selected_branch(), Some("fix-login"));
+
+        let picker = BranchPicker::new("t1".to_string(), "VIB-1".to_string(), branches, None);
+        assert_eq!(picker.selected_branch(), None);
+    }
 }
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use chrono::NaiveDate;
+
+use super::tasks::{Task, TaskStatus, task_pr_and_worktree};
+use crate::external::{BranchPrInfo, LinearIssueStatus, WorktreeInfo};
+
+/// Env var enabling the startup sweep, e.g. `VIBE_AUTO_ARCHIVE_DAYS=30`
+pub const AUTO_ARCHIVE_ENV: &str = "VIBE_AUTO_ARCHIVE_DAYS";
+
+/// Parse an age like "30d", "2w" or "30" (days) into a number of days
+pub fn parse_age_days(age: &str) -> Result<i64> {
+    let age = age.trim();
+    let (digits, multiplier) = match age.char_indices().last() {
+        Some((i, 'd')) => (&age[..i], 1),
+        Some((i, 'w')) => (&age[..i], 7),
+        _ => (age, 1),
+    };
+    let value: i64 = digits
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Invalid age '{}': expected e.g. 30d or 2w", age))?;
+    if value < 0 {
+        anyhow::bail!("Invalid age '{}': must not be negative", age);
+    }
+    Ok(value * multiplier)
+}
+
+/// Days configured for the startup auto-archive sweep, if enabled
+pub fn auto_archive_days() -> Option<i64> {
+    let value = std::env::var(AUTO_ARCHIVE_ENV).ok()?;
+    match parse_age_days(&value) {
+        Ok(days) => Some(days),
+        Err(e) => {
+            tracing::warn!("Ignoring {}: {}", AUTO_ARCHIVE_ENV, e);
+            None
+        }
+    }
+}
+
+/// Whole days since the task was created, or None if the date can't be parsed.
+/// Accepts plain dates (2024-01-15) and RFC 3339 timestamps.
+pub fn task_age_days(task: &Task, today: NaiveDate) -> Option<i64> {
+    let date = task.created_at.get(..10)?;
+    let created = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+    Some((today - created).num_days())
+}
+
+/// Backlog cards start fading toward "stale" after this many days...
+const STALE_AFTER_DAYS: i64 = 7;
+/// ...and are fully stale at this age
+const FULLY_STALE_DAYS: i64 = 60;
+
+/// How stale a task of this age looks, from 0.0 (fresh) to 1.0 (forgotten)
+pub fn staleness(age_days: i64) -> f32 {
+    let span = (FULLY_STALE_DAYS - STALE_AFTER_DAYS) as f32;
+    ((age_days - STALE_AFTER_DAYS) as f32 / span).clamp(0.0, 1.0)
+}
+
+/// IDs of Done tasks older than `older_than_days`.
+/// Tasks with an open PR are never archived, whatever their derived status.
+pub fn archive_candidates(
+    tasks: &[Task],
+    branch_prs: &HashMap<String, BranchPrInfo>,
+    worktrees: &[WorktreeInfo],
+    linear_statuses: &HashMap<String, LinearIssueStatus>,
+    older_than_days: i64,
+    today: NaiveDate,
+) -> Vec<String> {
+    tasks
+        .iter()
+        .filter(|t| {
+            let (branch_pr, has_worktree) = task_pr_and_worktree(t, branch_prs, worktrees);
+            if branch_pr.is_some_and(|pr| pr.state == "OPEN") {
+                return false;
+            }
+            let linear_status = t
+                .linear_issue_id
+                .as_ref()
+                .and_then(|id| linear_statuses.get(id));
+            t.effective_status_with_pr(branch_pr, has_worktree, linear_status) == TaskStatus::Done
+        })
+        .filter(|t| task_age_days(t, today).is_some_and(|age| age >= older_than_days))
+        .map(|t| t.id.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{test_pr, test_task};
+
+    fn task(id: &str, title: &str, created: &str) -> Task {
+        Task {
+            created_at: created.to_string(),
+            updated_at: created.to_string(),
+            ..test_task(id, title)
+        }
+    }
+
+    fn pr(state: &str, is_draft: bool) -> BranchPrInfo {
+        BranchPrInfo {
+            is_draft,
+            ..test_pr(state)
+        }
+    }
+
+    fn day(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_parse_age_days() {
+        assert_eq!(parse_age_days("30d").unwrap(), 30);
+        assert_eq!(parse_age_days("2w").unwrap(), 14);
+        assert_eq!(parse_age_days(" 7 ").unwrap(), 7);
+        assert!(parse_age_days("d").is_err());
+        assert!(parse_age_days("30m").is_err());
+        assert!(parse_age_days("-3d").is_err());
+    }
+
+    #[test]
+    fn test_task_age_days() {
+        let today = day("2024-03-01");
+        assert_eq!(
+            task_age_days(&task("a", "A", "2024-02-01"), today),
+            Some(29)
+        );
+        assert_eq!(
+            task_age_days(&task("a", "A", "2024-02-29T23:59:00Z"), today),
+            Some(1)
+        );
+        assert_eq!(task_age_days(&task("a", "A", "2024-03-01"), today), Some(0));
+        assert_eq!(task_age_days(&task("a", "A", "garbage"), today), None);
+        assert_eq!(task_age_days(&task("a", "A", ""), today), None);
+    }
+
+    #[test]
+    fn test_staleness_gradient() {
+        assert_eq!(staleness(0), 0.0);
+        assert_eq!(staleness(STALE_AFTER_DAYS), 0.0);
+        assert!(staleness(30) > 0.0 && staleness(30) < 1.0);
+        assert!(staleness(30) < staleness(45));
+        assert_eq!(staleness(FULLY_STALE_DAYS), 1.0);
+        assert_eq!(staleness(365), 1.0);
+        // Future-dated tasks are just fresh
+        assert_eq!(staleness(-3), 0.0);
+    }
+
+    #[test]
+    fn test_archive_candidates_respects_age_and_open_prs() {
+        let mut tasks = vec![
+            task("old-merged", "Old merged", "2024-01-01"),
+            task("new-merged", "New merged", "2024-02-25"),
+            task("old-open", "Old open", "2024-01-01"),
+            task("old-linear-done", "Old linear done", "2024-01-01"),
+        ];
+        tasks[3].linear_issue_id = Some("VIB-1".to_string());
+
+        let mut prs = HashMap::new();
+        prs.insert("old-merged".to_string(), pr("MERGED", false));
+        prs.insert("new-merged".to_string(), pr("MERGED", false));
+        // Draft PR without a worktree would fall through to Linear's Done
+        prs.insert("VIB-1/old-linear-done".to_string(), pr("OPEN", true));
+        prs.insert("old-open".to_string(), pr("OPEN", false));
+
+        let mut linear = HashMap::new();
+        linear.insert(
+            "VIB-1".to_string(),
+            LinearIssueStatus {
+                identifier: "VIB-1".to_string(),
+                state_type: "completed".to_string(),
+                state_name: "Done".to_string(),
+                assignee: None,
+            },
+        );
+
+        let ids = archive_candidates(&tasks, &prs, &[], &linear, 30, day("2024-03-01"));
+        assert_eq!(ids, vec!["old-merged".to_string()]);
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use super::tasks::{Task, TaskStatus};
+use crate::external::{BranchPrInfo, WorktreeInfo, ZellijSession, session_name_for_branch};
+
+/// Drift between tasks and the worktrees, sessions and PRs around them, for `vibe audit`
+#[derive(Debug, Default, PartialEq)]
+pub struct AuditReport {
+    /// Tasks on the In Progress column with neither a worktree nor a session
+    pub tasks_without_work: Vec<String>,
+    /// Worktree branches (other than the main checkout) no task expects
+    pub worktrees_without_task: Vec<String>,
+    /// Sessions named after this repo's worktree or PR branches that no task expects
+    pub sessions_without_task: Vec<String>,
+    /// Open PRs as (branch, url) whose branch no task expects
+    pub prs_without_task: Vec<(String, String)>,
+}
+
+impl AuditReport {
+    pub fn is_clean(&self) -> bool {
+        self.tasks_without_work.is_empty()
+            && self.worktrees_without_task.is_empty()
+            && self.sessions_without_task.is_empty()
+            && self.prs_without_task.is_empty()
+    }
+}
+
+/// Cross-reference tasks with worktrees, sessions and PRs. `statuses` holds each
+/// task's board status by task id (see `board_statuses`), falling back to the stored one.
+/// zellij lists sessions from every repository, so only sessions named after one of
+/// this repository's branches are considered.
+pub fn audit(
+    tasks: &[Task],
+    statuses: &HashMap<String, TaskStatus>,
+    worktrees: &[WorktreeInfo],
+    sessions: &[ZellijSession],
+    branch_prs: &HashMap<String, BranchPrInfo>,
+) -> AuditReport {
+    let has_task = |branch: &str| tasks.iter().any(|t| t.matches_branch(branch));
+    let session_named = |branch: &str| {
+        let name = session_name_for_branch(branch);
+        sessions.iter().any(|s| s.name == name)
+    };
+
+    let tasks_without_work = tasks
+        .iter()
+        .filter(|t| statuses.get(&t.id).copied().unwrap_or(t.status) == TaskStatus::Inprogress)
+        .filter(|t| {
+            !worktrees.iter().any(|w| t.matches_branch(&w.branch)) && !session_named(&t.branch())
+        })
+        .map(|t| t.linear_issue_id.clone().unwrap_or_else(|| t.title.clone()))
+        .collect();
+
+    let worktrees_without_task = worktrees
+        .iter()
+        .filter(|w| !w.is_main && !has_task(&w.branch))
+        .map(|w| w.branch.clone())
+        .collect();
+
+    let repo_branches: Vec<&str> = worktrees
+        .iter()
+        .map(|w| w.branch.as_str())
+        .chain(branch_prs.keys().map(String::as_str))
+        .collect();
+    let mut sessions_without_task: Vec<String> = sessions
+        .iter()
+        .filter(|s| {
+            repo_branches
+                .iter()
+                .any(|b| session_name_for_branch(b) == s.name && !has_task(b))
+        })
+        .map(|s| s.name.clone())
+        .collect();
+    sessions_without_task.sort();
+
+    let mut prs_without_task: Vec<(String, String)> = branch_prs
+        .iter()
+        .filter(|(branch, pr)| pr.state == "OPEN" && !has_task(branch))
+        .map(|(branch, pr)| (branch.clone(), pr.url.clone()))
+        .collect();
+    prs_without_task.sort();
+
+    AuditReport {
+        tasks_without_work,
+        worktrees_without_task,
+        sessions_without_task,
+        prs_without_task,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::ClaudeActivityState;
+    use crate::state::{test_pr, test_task};
+
+    fn task(id: &str, title: &str, status: TaskStatus) -> Task {
+        Task {
+            status,
+            ..test_task(id, title)
+        }
+    }
+
+    fn worktree(branch: &str, is_main: bool) -> WorktreeInfo {
+        serde_json::from_value(serde_json::json!({
+            "branch": branch,
+            "path": format!("/repo/{}", branch),
+            "is_main": is_main,
+        }))
+        .unwrap()
+    }
+
+    fn session(name: &str) -> ZellijSession {
+        ZellijSession {
+            name: name.to_string(),
+            is_current: false,
+            is_dead: false,
+            needs_attention: false,
+            screen_hash: None,
+            acknowledged: false,
+            claude_activity: ClaudeActivityState::Unknown,
+            context_percentage: None,
+        }
+    }
+
+    #[test]
+    fn test_audit_finds_orphans_on_both_sides() {
+        let tasks = vec![
+            task("wired", "Fix login", TaskStatus::Inprogress),
+            task("stalled", "Add export", TaskStatus::Inprogress),
+            task("queued", "Write docs", TaskStatus::Backlog),
+        ];
+        let worktrees = vec![
+            worktree("main", true),
+            worktree("fix-login", false),
+            worktree("spike-cache", false),
+        ];
+        let sessions = vec![
+            session("fix-login"),
+            session("spike-cache"),
+            session("other-repo-work"),
+        ];
+        let branch_prs = HashMap::from([
+            ("fix-login".to_string(), test_pr("OPEN")),
+            ("hotfix-typo".to_string(), test_pr("OPEN")),
+            ("old-experiment".to_string(), test_pr("MERGED")),
+        ]);
+
+        let report = audit(&tasks, &HashMap::new(), &worktrees, &sessions, &branch_prs);
+        assert_eq!(report.tasks_without_work, vec!["Add export"]);
+        assert_eq!(report.worktrees_without_task, vec!["spike-cache"]);
+        assert_eq!(report.sessions_without_task, vec!["spike-cache"]);
+        assert_eq!(
+            report.prs_without_task,
+            vec![(
+                "hotfix-typo".to_string(),
+                "https://github.com/org/repo/pull/1".to_string()
+            )]
+        );
+        assert!(!report.is_clean());
+
+        // A board status out of In Progress (e.g. a merged PR) needs no worktree
+        let statuses = HashMap::from([("stalled".to_string(), TaskStatus::Done)]);
+        let report = audit(&tasks, &statuses, &worktrees, &sessions, &branch_prs);
+        assert!(report.tasks_without_work.is_empty());
+    }
+}
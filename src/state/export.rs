@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use super::tasks::{Task, TaskStatus, TasksState, task_pr_and_worktree};
+use crate::external::{BranchPrInfo, LinearIssueStatus, WorktreeInfo};
+
+/// The board as GitHub-flavored markdown for `vibe export`: one `##` section per
+/// column with its card count, cards in board order as checklist items
+pub fn board_markdown(
+    tasks: &[Task],
+    branch_prs: &HashMap<String, BranchPrInfo>,
+    worktrees: &[WorktreeInfo],
+    linear_statuses: &HashMap<String, LinearIssueStatus>,
+) -> String {
+    let mut board = TasksState::new();
+    board.set_tasks(tasks.to_vec());
+
+    let mut sections = Vec::new();
+    for column in TaskStatus::VISIBLE {
+        let cards = board.tasks_in_column_with_prs(column, branch_prs, worktrees, linear_statuses);
+        let mut section = format!("## {} ({})\n", column.label(), cards.len());
+        if !cards.is_empty() {
+            section.push('\n');
+        }
+        for task in cards {
+            let (branch_pr, has_worktree) = task_pr_and_worktree(task, branch_prs, worktrees);
+            let linear_status = task
+                .linear_issue_id
+                .as_ref()
+                .and_then(|id| linear_statuses.get(id));
+            let status = task.effective_status_with_pr(branch_pr, has_worktree, linear_status);
+            section.push_str(&checklist_item(task, status, branch_pr));
+            section.push('\n');
+        }
+        sections.push(section);
+    }
+    sections.join("\n")
+}
+
+/// `- [x] [VIB-7](linear) Title ([PR #12](url))`; cancelled cards are struck through
+fn checklist_item(task: &Task, status: TaskStatus, branch_pr: Option<&BranchPrInfo>) -> String {
+    let checkbox = if status == TaskStatus::Done {
+        "[x]"
+    } else {
+        "[ ]"
+    };
+    let mut item = format!("- {} ", checkbox);
+
+    match (&task.linear_issue_id, &task.linear_url) {
+        (Some(id), Some(url)) => item.push_str(&format!("[{}]({}) ", id, url)),
+        (Some(id), None) => item.push_str(&format!("{} ", id)),
+        _ => {}
+    }
+    let title = escape(&task.title);
+    if status == TaskStatus::Cancelled {
+        item.push_str(&format!("~~{}~~", title));
+    } else {
+        item.push_str(&title);
+    }
+
+    let mut links = Vec::new();
+    match branch_pr {
+        Some(pr) => links.push(format!("[PR #{}]({})", pr._number, pr.url)),
+        None => {
+            if let Some(url) = &task.pr_url {
+                links.push(format!("[PR]({})", url));
+            }
+        }
+    }
+    if let (Some(number), Some(url)) = (task.github_issue, &task.github_url) {
+        links.push(format!("[#{}]({})", number, url));
+    }
+    if !links.is_empty() {
+        item.push_str(&format!(" ({})", links.join(", ")));
+    }
+    item
+}
+
+/// Keep titles from turning into links, emphasis or new list items when pasted
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '[' | ']' | '*' | '_' | '`' | '~' | '<' | '>') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{test_pr, test_task};
+
+    fn task(id: &str, title: &str, status: TaskStatus) -> Task {
+        Task {
+            status,
+            ..test_task(id, title)
+        }
+    }
+
+    #[test]
+    fn test_board_markdown_groups_and_links() {
+        let mut linked = task("a", "Fix *login*", TaskStatus::Backlog);
+        linked.linear_issue_id = Some("VIB-7".to_string());
+        linked.linear_url = Some("https://linear.app/t/issue/VIB-7".to_string());
+        let tasks = vec![
+            linked,
+            task("b", "Add export", TaskStatus::Inprogress),
+            task("c", "Ship it", TaskStatus::Done),
+            task("d", "Old idea", TaskStatus::Cancelled),
+        ];
+        let branch_prs = HashMap::from([(
+            "add-export".to_string(),
+            BranchPrInfo {
+                _number: 12,
+                url: "https://github.com/o/r/pull/12".to_string(),
+                is_draft: true,
+                ..test_pr("OPEN")
+            },
+        )]);
+
+        let md = board_markdown(&tasks, &branch_prs, &[], &HashMap::new());
+        assert_eq!(
+            md,
+            "## Backlog (1)\n\n\
+             - [ ] [VIB-7](https://linear.app/t/issue/VIB-7) Fix \\*login\\*\n\
+             \n## In Progress (1)\n\n\
+             - [ ] Add export ([PR #12](https://github.com/o/r/pull/12))\n\
+             \n## In Review (0)\n\
+             \n## Done (2)\n\n\
+             - [x] Ship it\n\
+             - [ ] ~~Old idea~~\n"
+        );
+    }
+}
@@ -0,0 +1,84 @@
+/// Env var pinning the board layout, e.g. `VIBE_LAYOUT=compact`
+pub const LAYOUT_ENV: &str = "VIBE_LAYOUT";
+
+/// Below this many columns the four rows no longer fit a card's details,
+/// so the automatic layout switches to the compact list
+pub const COMPACT_WIDTH: u16 = 100;
+
+/// How the kanban board is drawn
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BoardLayout {
+    /// Rows on wide terminals, the compact list on narrow ones
+    #[default]
+    Auto,
+    /// One line per task: status letter, title and activity dot
+    Compact,
+    /// One row per column with every card detail
+    Rows,
+}
+
+impl BoardLayout {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "auto" => Some(Self::Auto),
+            "compact" | "narrow" => Some(Self::Compact),
+            "rows" | "wide" | "full" => Some(Self::Rows),
+            _ => None,
+        }
+    }
+
+    /// Layout from `VIBE_LAYOUT`, ignoring (and logging) an unknown value
+    pub fn from_env() -> Self {
+        let Ok(value) = std::env::var(LAYOUT_ENV) else {
+            return Self::default();
+        };
+        Self::parse(&value).unwrap_or_else(|| {
+            tracing::warn!("Ignoring {}: unknown layout '{}'", LAYOUT_ENV, value);
+            Self::default()
+        })
+    }
+
+    /// Auto -> Compact -> Rows -> Auto
+    pub fn next(self) -> Self {
+        match self {
+            Self::Auto => Self::Compact,
+            Self::Compact => Self::Rows,
+            Self::Rows => Self::Auto,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Compact => "compact",
+            Self::Rows => "rows",
+        }
+    }
+
+    /// Whether a board `width` columns wide is drawn as the compact list
+    pub fn is_compact(self, width: u16) -> bool {
+        match self {
+            Self::Auto => width < COMPACT_WIDTH,
+            Self::Compact => true,
+            Self::Rows => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_switches_on_width_unless_pinned() {
+        assert!(BoardLayout::Auto.is_compact(80));
+        assert!(!BoardLayout::Auto.is_compact(COMPACT_WIDTH));
+        assert!(BoardLayout::Compact.is_compact(200));
+        assert!(!BoardLayout::Rows.is_compact(80));
+
+        assert_eq!(BoardLayout::parse(" Compact "), Some(BoardLayout::Compact));
+        assert_eq!(BoardLayout::parse("wide"), Some(BoardLayout::Rows));
+        assert_eq!(BoardLayout::parse("tiles"), None);
+        assert_eq!(BoardLayout::Rows.next(), BoardLayout::Auto);
+    }
+}
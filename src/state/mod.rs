@@ -1,15 +1,31 @@
 mod app_state;
+mod archive;
+mod audit;
+mod export;
+mod layout;
 mod logs;
+mod pr_diff;
 mod projects;
+mod refresh;
 mod search;
 mod sessions;
+mod stats;
 mod tasks;
+mod wip;
 mod worktrees;
 
 pub use app_state::*;
+pub use archive::*;
+pub use audit::*;
+pub use export::*;
+pub use layout::*;
 pub use logs::*;
+pub use pr_diff::*;
 pub use projects::*;
+pub use refresh::*;
 pub use search::*;
 pub use sessions::*;
+pub use stats::*;
 pub use tasks::*;
+pub use wip::*;
 pub use worktrees::*;
@@ -0,0 +1,145 @@
+/// Diffs are cut off after this many lines; the rest is one `v` away in the browser
+pub const MAX_DIFF_LINES: usize = 5000;
+
+/// How a diff line is colored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    /// `diff --git`, `index`, `---`/`+++` and other file headers
+    File,
+    /// `@@ -1,4 +1,5 @@`
+    Hunk,
+    Added,
+    Removed,
+    Context,
+}
+
+impl DiffLineKind {
+    pub fn of(line: &str) -> Self {
+        if line.starts_with("+++") || line.starts_with("---") {
+            Self::File
+        } else if line.starts_with("@@") {
+            Self::Hunk
+        } else if line.starts_with('+') {
+            Self::Added
+        } else if line.starts_with('-') {
+            Self::Removed
+        } else if line.starts_with(' ') || line.is_empty() {
+            Self::Context
+        } else {
+            // diff --git, index, new file mode, rename from, Binary files ...
+            Self::File
+        }
+    }
+}
+
+/// A PR's diff from `gh pr diff`, shown in a scrollable popup
+pub struct PrDiff {
+    /// PR URL the diff was requested for
+    pub pr: String,
+    pub lines: Vec<String>,
+    /// Lines past `MAX_DIFF_LINES` that were dropped
+    pub omitted: usize,
+    /// Index of the first visible line
+    pub offset: usize,
+    pub loading: bool,
+    pub error: Option<String>,
+}
+
+impl PrDiff {
+    pub fn new(pr: String) -> Self {
+        Self {
+            pr,
+            lines: Vec::new(),
+            omitted: 0,
+            offset: 0,
+            loading: true,
+            error: None,
+        }
+    }
+
+    /// Load the diff, dropping escape codes (colors are ours to pick) and
+    /// everything past `MAX_DIFF_LINES`
+    pub fn set_content(&mut self, content: &str) {
+        let mut lines = content.lines();
+        self.lines = lines
+            .by_ref()
+            .take(MAX_DIFF_LINES)
+            .map(|l| strip_ansi(l).replace('\t', "    ").trim_end().to_string())
+            .collect();
+        self.omitted = lines.count();
+        self.offset = 0;
+        self.loading = false;
+    }
+
+    fn max_offset(&self, visible_height: usize) -> usize {
+        self.lines.len().saturating_sub(visible_height)
+    }
+
+    pub fn scroll_up(&mut self, amount: usize) {
+        self.offset = self.offset.saturating_sub(amount);
+    }
+
+    pub fn scroll_down(&mut self, amount: usize, visible_height: usize) {
+        self.offset = (self.offset + amount).min(self.max_offset(visible_height));
+    }
+}
+
+/// Remove CSI sequences (`ESC [ ... final byte`), e.g. colors from a pager config
+fn strip_ansi(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_is_capped_and_stripped() {
+        let mut diff = PrDiff::new("https://github.com/o/r/pull/1".to_string());
+        diff.set_content(
+            "diff --git a/x.rs b/x.rs\n--- a/x.rs\n+++ b/x.rs\n@@ -1 +1 @@\n-\u{1b}[31mold\u{1b}[0m\n+\tnew\n",
+        );
+        assert!(!diff.loading);
+        assert_eq!(diff.lines[4], "-old");
+        assert_eq!(diff.lines[5], "+    new");
+        assert_eq!(diff.omitted, 0);
+        let kinds: Vec<_> = diff.lines.iter().map(|l| DiffLineKind::of(l)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                DiffLineKind::File,
+                DiffLineKind::File,
+                DiffLineKind::File,
+                DiffLineKind::Hunk,
+                DiffLineKind::Removed,
+                DiffLineKind::Added,
+            ]
+        );
+
+        let huge: String = (0..MAX_DIFF_LINES + 25)
+            .map(|i| format!("+{}\n", i))
+            .collect();
+        diff.set_content(&huge);
+        assert_eq!(diff.lines.len(), MAX_DIFF_LINES);
+        assert_eq!(diff.omitted, 25);
+        diff.scroll_down(usize::MAX / 2, 20);
+        assert_eq!(diff.offset, MAX_DIFF_LINES - 20);
+        diff.scroll_up(100);
+        assert_eq!(diff.offset, MAX_DIFF_LINES - 120);
+    }
+}
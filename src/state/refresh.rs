@@ -0,0 +1,157 @@
+use std::time::{Duration, Instant};
+
+/// How often the focused card's PR and session activity are fetched on their own
+pub const FOCUS_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// A selection must stay put this long before it is fetched, so j/k doesn't fire requests
+pub const FOCUS_POLL_SETTLE: Duration = Duration::from_millis(750);
+/// Ceiling for the backed-off interval of a Done or Cancelled card
+pub const FOCUS_POLL_MAX_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Orders results of a background refresh that can be started again before the
+/// previous run finishes. Each run is tagged with `start()`; a result is applied
+/// only if it is newer than the last one applied, so a slow older run can't
+/// overwrite a newer one.
+#[derive(Debug, Default)]
+pub struct RefreshGeneration {
+    started: u64,
+    applied: u64,
+}
+
+impl RefreshGeneration {
+    /// Tag for a refresh about to start
+    pub fn start(&mut self) -> u64 {
+        self.started += 1;
+        self.started
+    }
+
+    /// Whether a result tagged `generation` should be applied; records it if so
+    pub fn accept(&mut self, generation: u64) -> bool {
+        if generation <= self.applied {
+            tracing::debug!(
+                generation,
+                applied = self.applied,
+                "Discarding stale refresh result"
+            );
+            return false;
+        }
+        self.applied = generation;
+        true
+    }
+
+    /// Whether the latest refresh started has been applied (nothing is in flight)
+    pub fn is_settled(&self) -> bool {
+        self.applied == self.started
+    }
+}
+
+/// Schedules single-card fetches for the focused task between the slower bulk
+/// refreshes. A new selection is fetched once it settles, then every
+/// `FOCUS_POLL_INTERVAL`, with a bulk refresh counting as a fetch. A Done or
+/// Cancelled card rarely changes, so its interval doubles after each fetch
+/// (up to `FOCUS_POLL_MAX_INTERVAL`) for as long as it stays selected.
+#[derive(Debug, Default)]
+pub struct FocusPoll {
+    task_id: Option<String>,
+    selected_at: Option<Instant>,
+    last_poll: Option<Instant>,
+    polls: u32,
+}
+
+impl FocusPoll {
+    /// Whether `task_id` should be fetched now. `last_bulk` is when the bulk refresh last ran.
+    pub fn due(&mut self, task_id: &str, terminal: bool, last_bulk: Instant, now: Instant) -> bool {
+        if self.task_id.as_deref() != Some(task_id) {
+            self.task_id = Some(task_id.to_string());
+            self.selected_at = Some(now);
+            self.last_poll = None;
+            self.polls = 0;
+            return false;
+        }
+        let selected_at = self.selected_at.unwrap_or(now);
+        if now.saturating_duration_since(selected_at) < FOCUS_POLL_SETTLE {
+            return false;
+        }
+        match self.last_poll {
+            None => true,
+            Some(last) => {
+                now.saturating_duration_since(last.max(last_bulk)) >= self.interval(terminal)
+            }
+        }
+    }
+
+    /// Record a fetch of the focused task
+    pub fn polled(&mut self, now: Instant) {
+        self.last_poll = Some(now);
+        self.polls = self.polls.saturating_add(1);
+    }
+
+    fn interval(&self, terminal: bool) -> Duration {
+        if !terminal {
+            return FOCUS_POLL_INTERVAL;
+        }
+        let backoff = 1u32 << self.polls.saturating_sub(1).min(8);
+        (FOCUS_POLL_INTERVAL * backoff).min(FOCUS_POLL_MAX_INTERVAL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_out_of_order_results_keep_the_newest() {
+        let mut generation = RefreshGeneration::default();
+        let slow = generation.start();
+        let fast = generation.start();
+        assert!(!generation.is_settled());
+
+        // The newer refresh lands first; the older one arriving later is dropped
+        assert!(generation.accept(fast));
+        assert!(!generation.accept(slow));
+        assert!(generation.is_settled());
+
+        // In order, every result is applied
+        let next = generation.start();
+        let last = generation.start();
+        assert!(generation.accept(next));
+        assert!(!generation.is_settled());
+        assert!(generation.accept(last));
+        assert!(!generation.accept(last));
+    }
+
+    #[test]
+    fn test_focus_poll_settles_then_backs_off_on_terminal_cards() {
+        let start = Instant::now();
+        let at = |secs: f64| start + Duration::from_secs_f64(secs);
+        let long_ago = start;
+        let mut poll = FocusPoll::default();
+
+        // Moving through cards fetches nothing until the selection settles
+        assert!(!poll.due("a", false, long_ago, at(0.0)));
+        assert!(!poll.due("b", false, long_ago, at(0.3)));
+        assert!(!poll.due("b", false, long_ago, at(0.6)));
+        assert!(poll.due("b", false, long_ago, at(1.1)));
+        poll.polled(at(1.1));
+
+        // Then every interval, unless a bulk refresh just covered it
+        assert!(!poll.due("b", false, long_ago, at(4.0)));
+        assert!(poll.due("b", false, long_ago, at(6.2)));
+        assert!(!poll.due("b", false, at(5.0), at(6.2)));
+        poll.polled(at(6.2));
+
+        // A terminal card waits 5s, 10s, 20s, ... up to the ceiling
+        let mut poll = FocusPoll::default();
+        poll.due("done", true, long_ago, at(0.0));
+        assert!(poll.due("done", true, long_ago, at(1.0)));
+        poll.polled(at(1.0));
+        assert!(poll.due("done", true, long_ago, at(6.0)));
+        poll.polled(at(6.0));
+        assert!(!poll.due("done", true, long_ago, at(15.0)));
+        assert!(poll.due("done", true, long_ago, at(16.0)));
+        for _ in 0..10 {
+            poll.polled(at(16.0));
+        }
+        assert!(!poll.due("done", true, long_ago, at(75.0)));
+        assert!(poll.due("done", true, long_ago, at(76.0)));
+    }
+}
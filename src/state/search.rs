@@ -1,5 +1,42 @@
 use super::tasks::Task;
 
+/// Score `candidate` against `query` as a case-insensitive subsequence match.
+/// Returns None if not every query char appears in order. Consecutive matches
+/// and matches at word starts score higher, so "fb" ranks "fix-bug" above "feedback".
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let mut score = 0i64;
+    let mut qi = 0;
+    let mut prev_match: Option<usize> = None;
+    let mut prev_char: Option<char> = None;
+
+    for (ci, c) in candidate.to_lowercase().chars().enumerate() {
+        if qi < query.len() && c == query[qi] {
+            score += 1;
+            if prev_match.is_some_and(|p| p + 1 == ci) {
+                score += 5;
+            }
+            if prev_char.is_none_or(|p| !p.is_alphanumeric()) {
+                score += 3;
+            }
+            prev_match = Some(ci);
+            qi += 1;
+        }
+        prev_char = Some(c);
+    }
+
+    if qi == query.len() {
+        // Prefer shorter candidates when the match quality is otherwise equal
+        Some(score * 100 - candidate.chars().count() as i64)
+    } else {
+        None
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SearchResult {
     pub id: String,
@@ -126,3 +163,46 @@ impl Default for SearchState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::test_task;
+
+    #[test]
+    fn test_task_search_matches_substrings_only() {
+        let mut search = SearchState::new();
+        search.set_tasks(vec![
+            test_task("1", "Fix bug"),
+            test_task("2", "Feedback form"),
+        ]);
+
+        search.query = "fb".to_string();
+        search.update_results();
+        assert!(search.results.is_empty());
+
+        search.query = "BUG".to_string();
+        search.update_results();
+        let ids: Vec<_> = search.results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["1"]);
+    }
+
+    #[test]
+    fn test_fuzzy_score_subsequence() {
+        assert!(fuzzy_score("fb", "fix-bug").is_some());
+        assert!(fuzzy_score("FB", "fix-bug").is_some());
+        assert!(fuzzy_score("bf", "fix-bug").is_none());
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_word_starts_and_runs() {
+        let word_starts = fuzzy_score("fb", "fix-bug").unwrap();
+        let scattered = fuzzy_score("fb", "feedback").unwrap();
+        assert!(word_starts > scattered);
+
+        let run = fuzzy_score("auth", "VIB-3/auth-refactor").unwrap();
+        let spread = fuzzy_score("auth", "add-user-to-the-house").unwrap();
+        assert!(run > spread);
+    }
+}
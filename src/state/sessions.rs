@@ -1,18 +1,53 @@
-use crate::external::ZellijSession;
+use std::collections::HashMap;
 
-/// Session state used for looking up Claude activity in kanban view
+use super::Task;
+use crate::external::{ZellijSession, session_name_for_branch};
+
+/// Env var for the context usage (percent) from which a session can be compacted
+pub const COMPACT_THRESHOLD_ENV: &str = "VIBE_COMPACT_THRESHOLD";
+const DEFAULT_COMPACT_THRESHOLD: f64 = 70.0;
+
+/// Context usage at which `C` offers to `/compact` a session
+pub fn compact_threshold() -> f64 {
+    parse_compact_threshold(std::env::var(COMPACT_THRESHOLD_ENV).ok().as_deref())
+}
+
+fn parse_compact_threshold(value: Option<&str>) -> f64 {
+    let Some(value) = value else {
+        return DEFAULT_COMPACT_THRESHOLD;
+    };
+    match value.trim().trim_end_matches('%').parse::<f64>() {
+        Ok(pct) if (0.0..=100.0).contains(&pct) => pct,
+        _ => {
+            tracing::warn!(
+                "Ignoring {}={}: expected a percentage from 0 to 100",
+                COMPACT_THRESHOLD_ENV,
+                value
+            );
+            DEFAULT_COMPACT_THRESHOLD
+        }
+    }
+}
+
+/// Session state used for looking up Claude activity in kanban view,
+/// and for the sessions view listing every zellij session
 pub struct SessionsState {
     pub sessions: Vec<ZellijSession>,
+    pub selected_index: usize,
     pub loading: bool,
     pub error: Option<String>,
+    /// Screen hash of each session whose attention flag was acknowledged
+    acknowledged: HashMap<String, u64>,
 }
 
 impl SessionsState {
     pub fn new() -> Self {
         Self {
             sessions: Vec::new(),
+            selected_index: 0,
             loading: false,
             error: None,
+            acknowledged: HashMap::new(),
         }
     }
 
@@ -24,8 +59,75 @@ impl SessionsState {
                 new_session.context_percentage = existing.context_percentage;
             }
         }
+        // An acknowledgement lasts until the screen changes (or the session goes away)
+        self.acknowledged.retain(|name, hash| {
+            new_sessions
+                .iter()
+                .any(|s| &s.name == name && s.screen_hash == Some(*hash))
+        });
+        for session in &mut new_sessions {
+            session.acknowledged = self.acknowledged.contains_key(&session.name);
+        }
+
+        // Keep the same session selected across polls, even if the list reorders
+        let selected_name = self.selected().map(|s| s.name.clone());
         self.sessions = new_sessions;
         self.error = None;
+        if let Some(idx) =
+            selected_name.and_then(|name| self.sessions.iter().position(|s| s.name == name))
+        {
+            self.selected_index = idx;
+        } else if self.selected_index >= self.sessions.len() {
+            self.selected_index = self.sessions.len().saturating_sub(1);
+        }
+    }
+
+    /// Label (Linear ID or title) of the task behind each session, keyed by session name.
+    /// Sessions missing from the map were started outside the board.
+    pub fn task_labels(&self, tasks: &[Task]) -> HashMap<String, String> {
+        tasks
+            .iter()
+            .map(|t| {
+                let branch = t.branch();
+                let label = t.linear_issue_id.clone().unwrap_or_else(|| t.title.clone());
+                (session_name_for_branch(&branch), label)
+            })
+            .filter(|(name, _)| self.sessions.iter().any(|s| &s.name == name))
+            .collect()
+    }
+
+    pub fn selected(&self) -> Option<&ZellijSession> {
+        self.sessions.get(self.selected_index)
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.sessions.is_empty() {
+            self.selected_index = (self.selected_index + 1) % self.sessions.len();
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        if !self.sessions.is_empty() {
+            self.selected_index = if self.selected_index == 0 {
+                self.sessions.len() - 1
+            } else {
+                self.selected_index - 1
+            };
+        }
+    }
+
+    /// Acknowledge the session's current screen, or take a previous acknowledgement back.
+    /// Returns whether it is now acknowledged; None before its screen was ever checked.
+    pub fn toggle_acknowledged(&mut self, name: &str) -> Option<bool> {
+        let session = self.sessions.iter_mut().find(|s| s.name == name)?;
+        let hash = session.screen_hash?;
+        session.acknowledged = !session.acknowledged;
+        if session.acknowledged {
+            self.acknowledged.insert(session.name.clone(), hash);
+        } else {
+            self.acknowledged.remove(name);
+        }
+        Some(session.acknowledged)
     }
 
     pub fn session_for_branch(&self, branch: &str) -> Option<&ZellijSession> {
@@ -34,8 +136,187 @@ impl SessionsState {
     }
 }
 
+/// Scrollback captured from a session, shown in a scrollable popup
+pub struct SessionScrollback {
+    pub session_name: String,
+    pub lines: Vec<String>,
+    /// Index of the first visible line
+    pub offset: usize,
+    pub loading: bool,
+    pub error: Option<String>,
+}
+
+impl SessionScrollback {
+    pub fn new(session_name: String) -> Self {
+        Self {
+            session_name,
+            lines: Vec::new(),
+            offset: 0,
+            loading: true,
+            error: None,
+        }
+    }
+
+    /// Load captured content and jump to the most recent output
+    pub fn set_content(&mut self, content: &str, visible_height: usize) {
+        self.lines = content
+            .trim_end()
+            .lines()
+            .map(|l| l.trim_end().to_string())
+            .collect();
+        self.loading = false;
+        self.offset = self.max_offset(visible_height);
+    }
+
+    fn max_offset(&self, visible_height: usize) -> usize {
+        self.lines.len().saturating_sub(visible_height)
+    }
+
+    pub fn scroll_up(&mut self, amount: usize) {
+        self.offset = self.offset.saturating_sub(amount);
+    }
+
+    pub fn scroll_down(&mut self, amount: usize, visible_height: usize) {
+        self.offset = (self.offset + amount).min(self.max_offset(visible_height));
+    }
+}
+
 impl Default for SessionsState {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::ClaudeActivityState;
+    use crate::state::{TaskStatus, test_task};
+
+    fn session(name: &str) -> ZellijSession {
+        ZellijSession {
+            name: name.to_string(),
+            is_current: false,
+            is_dead: false,
+            needs_attention: false,
+            screen_hash: None,
+            acknowledged: false,
+            claude_activity: ClaudeActivityState::Unknown,
+            context_percentage: None,
+        }
+    }
+
+    #[test]
+    fn test_acknowledgement_lasts_until_screen_changes() {
+        let screen = |name: &str, hash: u64| ZellijSession {
+            screen_hash: Some(hash),
+            needs_attention: true,
+            ..session(name)
+        };
+        let mut state = SessionsState::new();
+        state.set_sessions(vec![screen("a", 1), session("b")]);
+        // b's screen was never captured, so there is nothing to acknowledge
+        assert_eq!(state.toggle_acknowledged("b"), None);
+        assert_eq!(state.toggle_acknowledged("a"), Some(true));
+
+        state.set_sessions(vec![screen("a", 1)]);
+        assert!(state.sessions[0].acknowledged);
+
+        state.set_sessions(vec![screen("a", 2)]);
+        assert!(!state.sessions[0].acknowledged);
+        state.set_sessions(vec![screen("a", 1)]);
+        assert!(!state.sessions[0].acknowledged);
+
+        assert_eq!(state.toggle_acknowledged("a"), Some(true));
+        assert_eq!(state.toggle_acknowledged("a"), Some(false));
+        state.set_sessions(vec![screen("a", 1)]);
+        assert!(!state.sessions[0].acknowledged);
+    }
+
+    #[test]
+    fn test_selection_follows_session_across_polls() {
+        let mut state = SessionsState::new();
+        state.set_sessions(vec![session("a"), session("b"), session("adhoc")]);
+        state.select_next();
+        state.select_next();
+        assert_eq!(state.selected().unwrap().name, "adhoc");
+
+        state.set_sessions(vec![session("adhoc"), session("a")]);
+        assert_eq!(state.selected().unwrap().name, "adhoc");
+
+        // Selected session went away: clamp to the end of the list
+        state.select_next();
+        state.set_sessions(vec![session("adhoc")]);
+        assert_eq!(state.selected().unwrap().name, "adhoc");
+
+        state.set_sessions(Vec::new());
+        assert!(state.selected().is_none());
+        state.select_prev();
+        assert_eq!(state.selected_index, 0);
+    }
+
+    #[test]
+    fn test_task_labels_leave_adhoc_sessions_out() {
+        let mut state = SessionsState::new();
+        state.set_sessions(vec![session("VIB-1-tracked-work"), session("scratch")]);
+
+        let mut task = test_task("t1", "Tracked work");
+        task.status = TaskStatus::Inprogress;
+        task.linear_issue_id = Some("VIB-1".to_string());
+
+        let labels = state.task_labels(&[task]);
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels["VIB-1-tracked-work"], "VIB-1");
+        assert!(!labels.contains_key("scratch"));
+    }
+
+    #[test]
+    fn test_parse_compact_threshold() {
+        assert_eq!(parse_compact_threshold(None), DEFAULT_COMPACT_THRESHOLD);
+        assert_eq!(parse_compact_threshold(Some("85")), 85.0);
+        assert_eq!(parse_compact_threshold(Some(" 60% ")), 60.0);
+        assert_eq!(
+            parse_compact_threshold(Some("150")),
+            DEFAULT_COMPACT_THRESHOLD
+        );
+        assert_eq!(
+            parse_compact_threshold(Some("lots")),
+            DEFAULT_COMPACT_THRESHOLD
+        );
+    }
+
+    #[test]
+    fn test_scrollback_opens_at_bottom() {
+        let mut sb = SessionScrollback::new("s".to_string());
+        let content: String = (0..50).map(|i| format!("line {}\n", i)).collect();
+        sb.set_content(&content, 20);
+        assert_eq!(sb.lines.len(), 50);
+        assert_eq!(sb.offset, 30);
+        assert!(!sb.loading);
+    }
+
+    #[test]
+    fn test_scrollback_scroll_bounds() {
+        let mut sb = SessionScrollback::new("s".to_string());
+        let content: String = (0..50).map(|i| format!("line {}\n", i)).collect();
+        sb.set_content(&content, 20);
+
+        sb.scroll_down(5, 20);
+        assert_eq!(sb.offset, 30);
+        sb.scroll_up(20);
+        assert_eq!(sb.offset, 10);
+        sb.scroll_up(100);
+        assert_eq!(sb.offset, 0);
+        sb.scroll_down(20, 20);
+        assert_eq!(sb.offset, 20);
+    }
+
+    #[test]
+    fn test_scrollback_shorter_than_page() {
+        let mut sb = SessionScrollback::new("s".to_string());
+        sb.set_content("only\ntwo\n\n\n", 20);
+        assert_eq!(sb.lines, vec!["only", "two"]);
+        sb.scroll_down(1, 20);
+        assert_eq!(sb.offset, 0);
+    }
+}
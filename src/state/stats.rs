@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+
+use super::tasks::{Task, TaskStatus, task_pr_and_worktree};
+use crate::external::{BranchPrInfo, LinearIssueStatus, WorktreeInfo};
+
+/// Throughput numbers for `vibe stats`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ThroughputStats {
+    /// Start of the window (inclusive)
+    pub since: NaiveDate,
+    /// Tasks that moved to Done within the window
+    pub done: usize,
+    /// Average days from In Progress to Done, over tasks done in the window
+    /// whose In Progress move was also recorded
+    pub avg_cycle_days: Option<f64>,
+    /// Tasks currently In Progress (work in progress)
+    pub in_progress: usize,
+    /// Tasks currently In Review
+    pub in_review: usize,
+    /// Tasks that are Done with no recorded transition (e.g. closed by a merged PR),
+    /// so they can't be placed in or out of the window
+    pub done_unrecorded: usize,
+}
+
+impl ThroughputStats {
+    pub fn wip(&self) -> usize {
+        self.in_progress + self.in_review
+    }
+}
+
+/// Each task's status as the board shows it (PR, worktree and Linear aware), by task id
+pub fn board_statuses(
+    tasks: &[Task],
+    branch_prs: &HashMap<String, BranchPrInfo>,
+    worktrees: &[WorktreeInfo],
+    linear_statuses: &HashMap<String, LinearIssueStatus>,
+) -> HashMap<String, TaskStatus> {
+    tasks
+        .iter()
+        .map(|t| {
+            let (branch_pr, has_worktree) = task_pr_and_worktree(t, branch_prs, worktrees);
+            let linear_status = t
+                .linear_issue_id
+                .as_ref()
+                .and_then(|id| linear_statuses.get(id));
+            let status = t.effective_status_with_pr(branch_pr, has_worktree, linear_status);
+            (t.id.clone(), status)
+        })
+        .collect()
+}
+
+/// When the task last moved to Done, if that was recorded
+fn done_at(task: &Task) -> Option<DateTime<Utc>> {
+    task.status_history
+        .iter()
+        .rev()
+        .find(|change| change.status == TaskStatus::Done)
+        .map(|change| change.at)
+}
+
+/// First move to In Progress before `done`, i.e. when work on this round started
+fn started_at(task: &Task, done: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    task.status_history
+        .iter()
+        .find(|change| change.status == TaskStatus::Inprogress && change.at <= done)
+        .map(|change| change.at)
+}
+
+/// Summarize throughput since `since`. `current` holds each task's board status
+/// (by task id), falling back to the stored status for tasks missing from it.
+pub fn compute_stats(
+    tasks: &[Task],
+    current: &HashMap<String, TaskStatus>,
+    since: NaiveDate,
+) -> ThroughputStats {
+    let status_of = |t: &Task| current.get(&t.id).copied().unwrap_or(t.status);
+
+    let mut done = 0;
+    let mut done_unrecorded = 0;
+    let mut cycle_days = Vec::new();
+    for task in tasks {
+        match done_at(task) {
+            Some(at) if at.date_naive() >= since => {
+                done += 1;
+                if let Some(start) = started_at(task, at) {
+                    cycle_days.push((at - start).num_seconds() as f64 / 86_400.0);
+                }
+            }
+            Some(_) => {}
+            None if status_of(task) == TaskStatus::Done => done_unrecorded += 1,
+            None => {}
+        }
+    }
+
+    let avg_cycle_days = if cycle_days.is_empty() {
+        None
+    } else {
+        Some(cycle_days.iter().sum::<f64>() / cycle_days.len() as f64)
+    };
+
+    ThroughputStats {
+        since,
+        done,
+        avg_cycle_days,
+        in_progress: tasks
+            .iter()
+            .filter(|t| status_of(t) == TaskStatus::Inprogress)
+            .count(),
+        in_review: tasks
+            .iter()
+            .filter(|t| status_of(t) == TaskStatus::Inreview)
+            .count(),
+        done_unrecorded,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{StatusChange, test_task};
+
+    fn task(id: &str, status: TaskStatus, history: &[(TaskStatus, &str)]) -> Task {
+        let mut task = test_task(id, id);
+        task.status = status;
+        task.status_history = history
+            .iter()
+            .map(|(status, at)| StatusChange {
+                status: *status,
+                at: at.parse().unwrap(),
+            })
+            .collect();
+        task
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_done_in_window_and_cycle_time() {
+        let tasks = vec![
+            task(
+                "fast",
+                TaskStatus::Done,
+                &[
+                    (TaskStatus::Inprogress, "2024-03-10T00:00:00Z"),
+                    (TaskStatus::Done, "2024-03-11T00:00:00Z"),
+                ],
+            ),
+            task(
+                "slow",
+                TaskStatus::Done,
+                &[
+                    (TaskStatus::Inprogress, "2024-03-01T00:00:00Z"),
+                    (TaskStatus::Inreview, "2024-03-05T00:00:00Z"),
+                    (TaskStatus::Done, "2024-03-12T00:00:00Z"),
+                ],
+            ),
+            // Done without ever being marked In Progress: counted, no cycle time
+            task(
+                "skipped",
+                TaskStatus::Done,
+                &[(TaskStatus::Done, "2024-03-12T12:00:00Z")],
+            ),
+            // Done before the window
+            task(
+                "old",
+                TaskStatus::Done,
+                &[(TaskStatus::Done, "2024-02-01T00:00:00Z")],
+            ),
+        ];
+
+        let stats = compute_stats(&tasks, &HashMap::new(), date("2024-03-08"));
+        assert_eq!(stats.done, 3);
+        assert_eq!(stats.avg_cycle_days, Some(6.0));
+        assert_eq!(stats.done_unrecorded, 0);
+    }
+
+    #[test]
+    fn test_wip_uses_board_status() {
+        let tasks = vec![
+            task("local", TaskStatus::Inprogress, &[]),
+            task("pr-open", TaskStatus::Inprogress, &[]),
+            task("merged", TaskStatus::Inprogress, &[]),
+            task("idle", TaskStatus::Backlog, &[]),
+        ];
+        let current = HashMap::from([
+            ("pr-open".to_string(), TaskStatus::Inreview),
+            ("merged".to_string(), TaskStatus::Done),
+        ]);
+
+        let stats = compute_stats(&tasks, &current, date("2024-03-08"));
+        assert_eq!(stats.in_progress, 1);
+        assert_eq!(stats.in_review, 1);
+        assert_eq!(stats.wip(), 2);
+        assert_eq!(stats.done, 0);
+        assert_eq!(stats.done_unrecorded, 1);
+        assert_eq!(stats.avg_cycle_days, None);
+    }
+}
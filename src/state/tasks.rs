@@ -1,9 +1,56 @@
 use serde::{Deserialize, Serialize};
 
-/// Convert task title to a branch name slug.
-/// If linear_id is provided, prefixes the branch name with it (e.g., "AMB-67/add-feature").
-pub fn task_title_to_branch(title: &str, linear_id: Option<&str>) -> String {
-    let slug = title
+use super::{BoardLayout, WipLimits};
+
+/// ASCII replacement for common accented Latin letters (input is lowercase).
+fn transliterate(c: char) -> Option<&'static str> {
+    Some(match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ą' | 'ā' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'č' => "c",
+        'ď' | 'đ' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ę' | 'ě' | 'ē' => "e",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' => "i",
+        'ł' => "l",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+        'œ' => "oe",
+        'ř' => "r",
+        'ś' | 'š' => "s",
+        'ß' => "ss",
+        'ť' => "t",
+        'ù' | 'ú' | 'û' | 'ü' | 'ů' | 'ū' | 'ű' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    })
+}
+
+/// Convert a title to an ASCII slug of lowercase alphanumerics joined by dashes.
+/// Accented letters are transliterated; emoji and other symbols act as separators.
+pub fn slugify(title: &str) -> String {
+    let mut ascii = String::with_capacity(title.len());
+    for c in title.to_lowercase().chars() {
+        if c.is_ascii_alphanumeric() {
+            ascii.push(c);
+        } else if let Some(folded) = transliterate(c) {
+            ascii.push_str(folded);
+        } else {
+            ascii.push('-');
+        }
+    }
+
+    ascii
+        .split('-')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// The slug titles got before slugs were ASCII-only: any Unicode letter or digit
+/// kept as is. Existing worktrees, PRs and sessions may still carry it.
+fn legacy_slugify(title: &str) -> String {
+    title
         .to_lowercase()
         .chars()
         .map(|c| if c.is_alphanumeric() { c } else { '-' })
@@ -11,11 +58,71 @@ pub fn task_title_to_branch(title: &str, linear_id: Option<&str>) -> String {
         .split('-')
         .filter(|s| !s.is_empty())
         .collect::<Vec<_>>()
-        .join("-");
+        .join("-")
+}
 
-    match linear_id {
+/// Convert task title to a branch name slug.
+/// If linear_id is provided, prefixes the branch name with it (e.g., "AMB-67/add-feature").
+/// A title with nothing sluggable in it ("日本語", "!!!") becomes `task-<first 8 chars
+/// of task_id>`, so such tasks don't share a branch, worktree and session.
+pub fn task_title_to_branch(title: &str, linear_id: Option<&str>, task_id: &str) -> String {
+    let mut slug = slugify(title);
+    if slug.is_empty() && linear_id.is_none() {
+        slug = format!("task-{}", task_id.chars().take(8).collect::<String>());
+    }
+
+    let branch = match linear_id {
         Some(id) => format!("{}/{}", id, slug),
         None => slug,
+    };
+    valid_git_ref(&branch)
+}
+
+/// Rewrite `name` into a branch name git accepts (see `git check-ref-format`).
+/// Forbidden characters become dashes; `..`, `@{`, leading dots or dashes, `.lock`
+/// suffixes and empty components are dropped. Falls back to "task" if nothing is left.
+pub fn valid_git_ref(name: &str) -> String {
+    let replaced: String = name
+        .chars()
+        .map(|c| {
+            if c.is_control() || matches!(c, ' ' | '~' | '^' | ':' | '?' | '*' | '[' | '\\') {
+                '-'
+            } else {
+                c
+            }
+        })
+        .collect();
+    let replaced = replaced.replace("@{", "-");
+
+    let components: Vec<String> = replaced
+        .split('/')
+        .filter_map(|component| {
+            let mut component = component.to_string();
+            while component.contains("..") {
+                component = component.replace("..", ".");
+            }
+            // Repeat, since trimming can expose another suffix ("x.lock." -> "x.lock")
+            loop {
+                let trimmed = component.trim_matches('.');
+                let trimmed = trimmed.strip_suffix(".lock").unwrap_or(trimmed);
+                if trimmed.len() == component.len() {
+                    break;
+                }
+                component = trimmed.to_string();
+            }
+            (!component.is_empty()).then_some(component)
+        })
+        .collect();
+
+    // A leading dash would be parsed as an option by git
+    let branch = components
+        .join("/")
+        .trim_start_matches(['-', '/'])
+        .to_string();
+    if branch.is_empty() || branch == "@" {
+        "task".to_string()
+    } else {
+        branch
     }
 }
 
@@ -49,6 +156,18 @@ impl TaskStatus {
         }
     }
 
+    /// Single-letter status for the compact board
+    pub fn letter(&self) -> char {
+        match self {
+            TaskStatus::Backlog => 'B',
+            TaskStatus::Todo => 'T',
+            TaskStatus::Inprogress => 'I',
+            TaskStatus::Inreview => 'R',
+            TaskStatus::Done => 'D',
+            TaskStatus::Cancelled => 'X',
+        }
+    }
+
     pub fn column_index(&self) -> usize {
         match self {
             TaskStatus::Backlog => 0,
@@ -70,6 +189,24 @@ impl TaskStatus {
         }
     }
 
+    /// Parse a user-supplied status name ("in-progress", "review", "done", ...)
+    pub fn parse(name: &str) -> Option<Self> {
+        let normalized: String = name
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+        match normalized.as_str() {
+            "backlog" => Some(TaskStatus::Backlog),
+            "todo" => Some(TaskStatus::Todo),
+            "inprogress" | "started" | "wip" => Some(TaskStatus::Inprogress),
+            "inreview" | "review" => Some(TaskStatus::Inreview),
+            "done" | "completed" => Some(TaskStatus::Done),
+            "cancelled" | "canceled" => Some(TaskStatus::Cancelled),
+            _ => None,
+        }
+    }
+
     /// Convert Linear state type to TaskStatus
     pub fn from_linear_state_type(state_type: &str) -> Self {
         match state_type {
@@ -83,6 +220,64 @@ impl TaskStatus {
     }
 }
 
+/// Which rule of `Task::resolve_status` decided a card's column, highest priority first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusSource {
+    /// Live PR state fetched from GitHub
+    LivePr,
+    /// PR state stored on the task
+    StoredPr,
+    /// Linear issue completed or canceled
+    LinearTerminal,
+    /// A worktree exists, upgrading Backlog/Todo to In Progress
+    Worktree,
+    /// Linear issue state
+    Linear,
+    /// The task file's own status (an explicit cancel ranks above worktrees and Linear)
+    Stored,
+}
+
+impl StatusSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            StatusSource::LivePr => "live PR",
+            StatusSource::StoredPr => "stored PR",
+            StatusSource::LinearTerminal => "Linear (closed)",
+            StatusSource::Worktree => "worktree",
+            StatusSource::Linear => "Linear",
+            StatusSource::Stored => "stored",
+        }
+    }
+}
+
+/// A recorded move of a task into `status`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusChange {
+    pub status: TaskStatus,
+    pub at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Where a task is in the plan-then-execute flow: `p` starts planning, `X` executes
+/// the plan once one exists for the branch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlanPhase {
+    Planning,
+    Executing,
+}
+
+/// Most transitions kept per task; older entries are dropped first
+pub const STATUS_HISTORY_LIMIT: usize = 50;
+
+/// Append `change` to `history`, dropping the oldest entries beyond the limit
+pub fn push_status_change(history: &mut Vec<StatusChange>, change: StatusChange) {
+    history.push(change);
+    if history.len() > STATUS_HISTORY_LIMIT {
+        let excess = history.len() - STATUS_HISTORY_LIMIT;
+        history.drain(..excess);
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
     pub id: String,
@@ -94,7 +289,13 @@ pub struct Task {
     pub shared_task_id: Option<String>,
     pub linear_issue_id: Option<String>,
     pub linear_url: Option<String>,
+    /// Issue labels, comma-separated (from Linear or GitHub)
     pub linear_labels: Option<String>,
+    /// GitHub issue number for tasks imported with `vibe import-gh`
+    #[serde(default)]
+    pub github_issue: Option<u64>,
+    #[serde(default)]
+    pub github_url: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 
@@ -110,21 +311,115 @@ pub struct Task {
     pub pr_review_decision: Option<String>,
     pub pr_checks_status: Option<String>,
     pub pr_has_conflicts: Option<bool>,
+    /// Status transitions recorded in storage, oldest first
+    #[serde(default)]
+    pub status_history: Vec<StatusChange>,
+    /// Private scratchpad, kept in the task file and never sent to Linear
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub plan_phase: Option<PlanPhase>,
+    /// Branch picked with `b`; replaces the title-derived branch and the fuzzy
+    /// PR/worktree/session matching
+    #[serde(default)]
+    pub branch_override: Option<String>,
+    /// Linear's scale: 0 none, 1 urgent, 2 high, 3 medium, 4 low (see `priority_label`)
+    #[serde(default)]
+    pub priority: Option<u8>,
+    /// Linear estimate in points
+    #[serde(default)]
+    pub estimate: Option<f64>,
+}
+
+/// Name of a priority on Linear's 0-4 scale
+pub fn priority_label(priority: u8) -> &'static str {
+    match priority {
+        1 => "Urgent",
+        2 => "High",
+        3 => "Medium",
+        4 => "Low",
+        _ => "No priority",
+    }
 }
 
 use crate::external::{BranchPrInfo, LinearIssueStatus};
 
 impl Task {
+    /// The task's branch: the one bound with `b`, else derived from the title
+    pub fn branch(&self) -> String {
+        self.branch_override
+            .clone()
+            .unwrap_or_else(|| self.title_branch())
+    }
+
+    /// The branch derived from the title, ignoring any binding
+    pub fn title_branch(&self) -> String {
+        task_title_to_branch(&self.title, self.linear_issue_id.as_deref(), &self.id)
+    }
+
+    /// The branch the title derived to before slugs were ASCII-only, when that differs
+    /// from `title_branch` (titles with non-ASCII letters) and nothing is bound
+    pub fn legacy_title_branch(&self) -> Option<String> {
+        if self.branch_override.is_some() {
+            return None;
+        }
+        let slug = legacy_slugify(&self.title);
+        let legacy = match self.linear_issue_id.as_deref() {
+            Some(id) => format!("{}/{}", id, slug),
+            None => slug,
+        };
+        (!legacy.is_empty() && legacy != self.title_branch()).then_some(legacy)
+    }
+
+    /// Branch to bind before the title changes to `new_title`, so the task keeps its
+    /// worktree, PR and session: the worktree branch it already matches, else the one
+    /// derived from the current title. None when already bound or the branch stays the same.
+    pub fn branch_to_keep_on_retitle(
+        &self,
+        new_title: &str,
+        worktree_branches: &[&str],
+    ) -> Option<String> {
+        if self.branch_override.is_some()
+            || task_title_to_branch(new_title, self.linear_issue_id.as_deref(), &self.id)
+                == self.title_branch()
+        {
+            return None;
+        }
+        let existing = worktree_branches
+            .iter()
+            .find(|b| self.matches_branch(b))
+            .map(|b| b.to_string());
+        Some(existing.unwrap_or_else(|| self.title_branch()))
+    }
+
+    /// Whether `branch` (a worktree or PR branch) belongs to this task: exactly the
+    /// bound branch, else a fuzzy match on the derived one
+    pub fn matches_branch(&self, branch: &str) -> bool {
+        match &self.branch_override {
+            Some(bound) => bound == branch,
+            None => {
+                branch_matches(branch, &self.branch())
+                    || self.legacy_title_branch().as_deref() == Some(branch)
+            }
+        }
+    }
+
+    /// Status of the latest recorded transition, or the stored status if none was recorded
+    pub fn last_recorded_status(&self) -> TaskStatus {
+        self.status_history
+            .last()
+            .map(|change| change.status)
+            .unwrap_or(self.status)
+    }
+
+    /// Status from the PR fields stored on the task alone. Only used when there is
+    /// no live `BranchPrInfo`; see `resolve_status` for the precedence.
     pub fn effective_status(&self) -> TaskStatus {
         if let Some(ref pr_status) = self.pr_status {
             match pr_status.as_str() {
                 "merged" => return TaskStatus::Done,
                 "closed" => return TaskStatus::Cancelled,
-                "open" => {
-                    if self.pr_is_draft != Some(true) {
-                        return TaskStatus::Inreview;
-                    }
-                }
+                "open" if self.pr_is_draft != Some(true) => return TaskStatus::Inreview,
                 _ => {}
             }
         }
@@ -137,50 +432,205 @@ impl Task {
         has_worktree: bool,
         linear_status: Option<&LinearIssueStatus>,
     ) -> TaskStatus {
+        self.resolve_status(branch_pr, has_worktree, linear_status)
+            .0
+    }
+
+    /// Whether the stored PR fields are the only source for an open PR's draft
+    /// state, so the card's PR should be fetched live before it is trusted
+    pub fn needs_live_pr(&self, branch_pr: Option<&BranchPrInfo>) -> bool {
+        branch_pr.is_none() && self.pr_status.as_deref() == Some("open")
+    }
+
+    /// The stored PR's URL while it is still recorded as open. A merged PR's
+    /// branch is often deleted before the next lookup, so this is the fallback
+    /// that keeps the card from sliding back to the stale stored status.
+    pub fn open_pr_url(&self) -> Option<&str> {
+        self.pr_url
+            .as_deref()
+            .filter(|_| self.pr_status.as_deref() == Some("open"))
+    }
+
+    /// The board status together with the rule that decided it.
+    ///
+    /// Draft vs ready: a live `BranchPrInfo` always decides it, even when the
+    /// stored `pr_is_draft` disagrees (a PR marked ready or back to draft since
+    /// it was stored). Stored PR fields are only consulted without live data.
+    pub fn resolve_status(
+        &self,
+        branch_pr: Option<&BranchPrInfo>,
+        has_worktree: bool,
+        linear_status: Option<&LinearIssueStatus>,
+    ) -> (TaskStatus, StatusSource) {
         // Priority 1: Live fetched PR status (most accurate, up-to-date)
         if let Some(pr) = branch_pr {
             match pr.state.as_str() {
-                "MERGED" => return TaskStatus::Done,
-                "CLOSED" => return TaskStatus::Cancelled,
+                "MERGED" => return (TaskStatus::Done, StatusSource::LivePr),
+                "CLOSED" => return (TaskStatus::Cancelled, StatusSource::LivePr),
                 "OPEN" => {
                     if !pr.is_draft {
-                        return TaskStatus::Inreview;
+                        return (TaskStatus::Inreview, StatusSource::LivePr);
                     }
                     if has_worktree {
-                        return TaskStatus::Inprogress;
+                        return (TaskStatus::Inprogress, StatusSource::LivePr);
                     }
                 }
                 _ => {}
             }
         }
 
-        // Priority 2: Stored PR status (fallback if no live data)
-        if self.pr_status.is_some() {
-            return self.effective_status();
+        // Priority 2: Stored PR status (fallback if no live data). A live draft
+        // without a worktree skips this too, so a stale stored "ready" can't win.
+        if branch_pr.is_none() && self.pr_status.is_some() {
+            return (self.effective_status(), StatusSource::StoredPr);
         }
 
         // Priority 3: Linear terminal states (completed/cancelled) override worktree
         if let Some(linear) = linear_status {
             match linear.state_type.as_str() {
-                "completed" => return TaskStatus::Done,
-                "canceled" => return TaskStatus::Cancelled,
+                "completed" => return (TaskStatus::Done, StatusSource::LinearTerminal),
+                "canceled" => return (TaskStatus::Cancelled, StatusSource::LinearTerminal),
                 _ => {}
             }
         }
 
-        // Priority 4: Worktree presence upgrades backlog/unstarted to in-progress
-        if has_worktree {
-            return TaskStatus::Inprogress;
+        // Priority 4: An explicit local cancel outlasts worktrees and open Linear states
+        if self.status == TaskStatus::Cancelled {
+            return (TaskStatus::Cancelled, StatusSource::Stored);
+        }
+
+        // Priority 5: Worktree presence upgrades backlog/unstarted to in-progress.
+        // A local status that was moved further (review/done) is kept.
+        if has_worktree && matches!(self.status, TaskStatus::Backlog | TaskStatus::Todo) {
+            return (TaskStatus::Inprogress, StatusSource::Worktree);
         }
 
-        // Priority 5: Linear non-terminal status
+        // Priority 6: Linear non-terminal status
         if let Some(linear) = linear_status {
-            return TaskStatus::from_linear_state_type(&linear.state_type);
+            return (
+                TaskStatus::from_linear_state_type(&linear.state_type),
+                StatusSource::Linear,
+            );
         }
 
-        // Priority 6: Local stored status - fallback
-        self.status
+        // Priority 7: Local stored status - fallback
+        (self.status, StatusSource::Stored)
+    }
+}
+
+/// Whether a worktree branch belongs to a task's expected branch.
+/// Loose on purpose: either may carry extra prefixes (e.g. "feature/").
+pub fn branch_matches(branch: &str, expected_branch: &str) -> bool {
+    let branch = branch.to_lowercase();
+    let expected = expected_branch.to_lowercase();
+    branch == expected || branch.contains(&expected) || expected.contains(&branch)
+}
+
+/// Best-effort reverse of `task_title_to_branch` for branches created outside vibe.
+/// Returns (title, linear_id), or None when the branch has no usable words.
+pub fn branch_to_task_title(branch: &str) -> Option<(String, Option<String>)> {
+    let mut segments: Vec<&str> = branch.split('/').filter(|s| !s.is_empty()).collect();
+    let last = segments.pop()?;
+
+    // A leading "ABC-123/" segment is a Linear identifier
+    let linear_id = segments.last().and_then(|seg| {
+        let (team, number) = seg.split_once('-')?;
+        let is_id = !team.is_empty()
+            && team.chars().all(|c| c.is_ascii_alphabetic())
+            && !number.is_empty()
+            && number.chars().all(|c| c.is_ascii_digit());
+        is_id.then(|| seg.to_uppercase())
+    });
+
+    let words: Vec<&str> = last
+        .split(['-', '_', '.'])
+        .filter(|w| w.chars().any(|c| c.is_alphanumeric()))
+        .collect();
+    if !words.iter().any(|w| w.chars().any(|c| c.is_alphabetic())) {
+        return None;
+    }
+
+    let mut title = words.join(" ");
+    if let Some(first) = title.chars().next() {
+        let upper: String = first.to_uppercase().collect();
+        title.replace_range(..first.len_utf8(), &upper);
+    }
+    Some((title, linear_id))
+}
+
+/// Find the PR and worktree presence for a task, matching branches the same
+/// way session launch derives them. A bound branch is matched exactly.
+pub fn task_pr_and_worktree<'a>(
+    t: &Task,
+    branch_prs: &'a std::collections::HashMap<String, BranchPrInfo>,
+    worktrees: &[crate::external::WorktreeInfo],
+) -> (Option<&'a BranchPrInfo>, bool) {
+    if let Some(branch) = &t.branch_override {
+        let has_worktree = worktrees.iter().any(|w| &w.branch == branch);
+        return (branch_prs.get(branch), has_worktree);
     }
+
+    // Use the same branch derivation as session launch
+    let expected_branch = t.branch();
+
+    // Work started before slugs were ASCII-only lives on the old branch name
+    let legacy_branch = t.legacy_title_branch();
+
+    // Try to find matching worktree
+    let matching_branch = worktrees
+        .iter()
+        .find(|w| branch_matches(&w.branch, &expected_branch))
+        .or_else(|| {
+            let legacy = legacy_branch.as_deref()?;
+            worktrees.iter().find(|w| w.branch == legacy)
+        });
+
+    let has_worktree = matching_branch.is_some();
+
+    // Try to find PR info:
+    // 1. First via worktree branch name
+    // 2. Then via expected branch name (for merged PRs where worktree is deleted),
+    //    or the pre-ASCII one
+    // 3. Then search branch_prs for any branch containing the task slug,
+    //    preferring my PRs over a teammate's similarly named branch
+    let branch_pr = matching_branch
+        .and_then(|wt| branch_prs.get(&wt.branch))
+        .or_else(|| branch_prs.get(&expected_branch))
+        .or_else(|| branch_prs.get(legacy_branch.as_deref()?))
+        .or_else(|| {
+            // Fallback: search for any PR branch that matches the task slug
+            let task_slug = t.title.to_lowercase().replace(' ', "-");
+            branch_prs
+                .iter()
+                .filter(|(branch, _)| {
+                    let branch_lower = branch.to_lowercase();
+                    branch_lower.contains(&task_slug) || task_slug.contains(&branch_lower)
+                })
+                .map(|(_, pr)| pr)
+                .max_by_key(|pr| pr.mine)
+        });
+
+    (branch_pr, has_worktree)
+}
+
+/// Display name of whoever the task's Linear issue is assigned to
+pub fn task_assignee<'a>(
+    task: &Task,
+    linear_statuses: &'a std::collections::HashMap<String, LinearIssueStatus>,
+) -> Option<&'a str> {
+    task.linear_issue_id
+        .as_ref()
+        .and_then(|id| linear_statuses.get(id))
+        .and_then(|status| status.assignee.as_deref())
+}
+
+/// Up to two initials for a card, e.g. "Piotr Ostrowski" -> "PO", "piotr" -> "P"
+pub fn assignee_initials(name: &str) -> String {
+    name.split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .take(2)
+        .flat_map(char::to_uppercase)
+        .collect()
 }
 
 const NUM_VISIBLE_COLUMNS: usize = 4;
@@ -190,6 +640,21 @@ pub struct TasksState {
     pub selected_column: usize,
     pub selected_card_per_column: [usize; NUM_VISIBLE_COLUMNS],
     pub search_filter: String,
+    pub wip_limits: WipLimits,
+    /// Branch of the worktree vibe runs in; its task is listed first in its column
+    pub pinned_branch: Option<String>,
+    /// Order the backlog oldest first instead of newest first
+    pub backlog_by_age: bool,
+    /// Overlay each card with the inputs and rule behind its column (debugging aid)
+    pub show_status_debug: bool,
+    /// Only show cards whose Linear issue is assigned to this person (None shows all)
+    pub assignee_filter: Option<String>,
+    /// Only show cards whose PR targets this base branch (None shows all)
+    pub base_filter: Option<String>,
+    /// Hide cards whose PR was opened by someone else
+    pub mine_only: bool,
+    /// Rows or the one-line-per-task list (auto picks by terminal width)
+    pub layout: BoardLayout,
 }
 
 impl TasksState {
@@ -199,6 +664,14 @@ impl TasksState {
             selected_column: 0,
             selected_card_per_column: [0; NUM_VISIBLE_COLUMNS],
             search_filter: String::new(),
+            wip_limits: WipLimits::default(),
+            pinned_branch: None,
+            backlog_by_age: false,
+            show_status_debug: false,
+            assignee_filter: None,
+            base_filter: None,
+            mine_only: false,
+            layout: BoardLayout::default(),
         }
     }
 
@@ -207,6 +680,25 @@ impl TasksState {
         self.selected_card_per_column = [0; NUM_VISIBLE_COLUMNS];
     }
 
+    /// Ids of the cards the board shows under the active filters, outside the
+    /// Done column. Only these get proactive status refreshes; a Done or Cancelled
+    /// card is looked up when it is selected.
+    pub fn refresh_targets(
+        &self,
+        branch_prs: &std::collections::HashMap<String, BranchPrInfo>,
+        worktrees: &[crate::external::WorktreeInfo],
+        linear_statuses: &std::collections::HashMap<String, LinearIssueStatus>,
+    ) -> std::collections::HashSet<String> {
+        TaskStatus::VISIBLE
+            .into_iter()
+            .filter(|status| *status != TaskStatus::Done)
+            .flat_map(|status| {
+                self.tasks_in_column_with_prs(status, branch_prs, worktrees, linear_statuses)
+            })
+            .map(|t| t.id.clone())
+            .collect()
+    }
+
     pub fn tasks_in_column_with_prs(
         &self,
         status: TaskStatus,
@@ -215,47 +707,11 @@ impl TasksState {
         linear_statuses: &std::collections::HashMap<String, LinearIssueStatus>,
     ) -> Vec<&Task> {
         let column_index = status.column_index();
-        self.tasks
+        let mut tasks: Vec<&Task> = self
+            .tasks
             .iter()
             .filter(|t| {
-                // Use the same branch derivation as session launch
-                let expected_branch = task_title_to_branch(&t.title, t.linear_issue_id.as_deref());
-
-                // Try to find matching worktree
-                let matching_branch = worktrees.iter().find(|w| {
-                    w.branch == expected_branch
-                        || w.branch
-                            .to_lowercase()
-                            .contains(&expected_branch.to_lowercase())
-                        || expected_branch
-                            .to_lowercase()
-                            .contains(&w.branch.to_lowercase())
-                });
-
-                let has_worktree = matching_branch.is_some();
-
-                // Try to find PR info:
-                // 1. First via worktree branch name
-                // 2. Then via expected branch name (for merged PRs where worktree is deleted)
-                // 3. Then search branch_prs for any branch containing the task slug
-                let branch_pr = matching_branch
-                    .and_then(|wt| branch_prs.get(&wt.branch))
-                    .or_else(|| branch_prs.get(&expected_branch))
-                    .or_else(|| {
-                        // Fallback: search for any PR branch that matches the task slug
-                        let task_slug = t.title.to_lowercase().replace(' ', "-");
-                        branch_prs.iter().find_map(|(branch, pr)| {
-                            let branch_lower = branch.to_lowercase();
-                            if branch_lower.contains(&task_slug)
-                                || task_slug.contains(&branch_lower)
-                            {
-                                Some(pr)
-                            } else {
-                                None
-                            }
-                        })
-                    });
-
+                let (branch_pr, has_worktree) = task_pr_and_worktree(t, branch_prs, worktrees);
                 let linear_status = t
                     .linear_issue_id
                     .as_ref()
@@ -264,6 +720,25 @@ impl TasksState {
                     .column_index()
                     == column_index
             })
+            .filter(|t| match &self.assignee_filter {
+                Some(assignee) => task_assignee(t, linear_statuses) == Some(assignee.as_str()),
+                None => true,
+            })
+            .filter(|t| match &self.base_filter {
+                Some(base) => {
+                    task_pr_and_worktree(t, branch_prs, worktrees)
+                        .0
+                        .and_then(|pr| pr.base_ref_name.as_deref())
+                        == Some(base.as_str())
+                }
+                None => true,
+            })
+            .filter(|t| {
+                !self.mine_only
+                    || task_pr_and_worktree(t, branch_prs, worktrees)
+                        .0
+                        .is_none_or(|pr| pr.mine)
+            })
             .filter(|t| {
                 if self.search_filter.is_empty() {
                     return true;
@@ -274,7 +749,100 @@ impl TasksState {
                         .as_ref()
                         .is_some_and(|d| d.to_lowercase().contains(&query))
             })
-            .collect()
+            .collect();
+        // Dates are ISO 8601, so they sort lexically. Same-day tasks fall back to
+        // their id, so cards keep their places however the store listed them.
+        if self.backlog_by_age && column_index == TaskStatus::Backlog.column_index() {
+            tasks.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+        } else {
+            tasks.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(a.id.cmp(&b.id)));
+        }
+        // Stable, so the rest of the column keeps its order
+        tasks.sort_by_key(|t| !self.is_pinned(t));
+        tasks
+    }
+
+    /// Distinct assignees of the board's Linear issues, sorted
+    pub fn assignees(
+        &self,
+        linear_statuses: &std::collections::HashMap<String, LinearIssueStatus>,
+    ) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .tasks
+            .iter()
+            .filter_map(|t| task_assignee(t, linear_statuses))
+            .map(String::from)
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Step the assignee filter: all -> each assignee in turn -> all
+    pub fn cycle_assignee_filter(
+        &mut self,
+        linear_statuses: &std::collections::HashMap<String, LinearIssueStatus>,
+    ) {
+        let assignees = self.assignees(linear_statuses);
+        let next = match &self.assignee_filter {
+            None => assignees.first(),
+            Some(current) => assignees
+                .iter()
+                .position(|a| a == current)
+                .and_then(|i| assignees.get(i + 1)),
+        };
+        self.assignee_filter = next.cloned();
+        // Card indices refer to the old, unfiltered columns
+        self.selected_card_per_column = [0; NUM_VISIBLE_COLUMNS];
+    }
+
+    /// Distinct base branches of the board's PRs, sorted
+    pub fn pr_bases(
+        &self,
+        branch_prs: &std::collections::HashMap<String, BranchPrInfo>,
+        worktrees: &[crate::external::WorktreeInfo],
+    ) -> Vec<String> {
+        let mut bases: Vec<String> = self
+            .tasks
+            .iter()
+            .filter_map(|t| task_pr_and_worktree(t, branch_prs, worktrees).0)
+            .filter_map(|pr| pr.base_ref_name.clone())
+            .collect();
+        bases.sort();
+        bases.dedup();
+        bases
+    }
+
+    /// Step the base branch filter: all -> each base in turn -> all
+    pub fn cycle_base_filter(
+        &mut self,
+        branch_prs: &std::collections::HashMap<String, BranchPrInfo>,
+        worktrees: &[crate::external::WorktreeInfo],
+    ) {
+        let bases = self.pr_bases(branch_prs, worktrees);
+        let next = match &self.base_filter {
+            None => bases.first(),
+            Some(current) => bases
+                .iter()
+                .position(|b| b == current)
+                .and_then(|i| bases.get(i + 1)),
+        };
+        self.base_filter = next.cloned();
+        // Card indices refer to the old, unfiltered columns
+        self.selected_card_per_column = [0; NUM_VISIBLE_COLUMNS];
+    }
+
+    pub fn toggle_mine_only(&mut self) {
+        self.mine_only = !self.mine_only;
+        // Card indices refer to the old, unfiltered columns
+        self.selected_card_per_column = [0; NUM_VISIBLE_COLUMNS];
+    }
+
+    /// Whether the task belongs to the pinned (current) worktree
+    pub fn is_pinned(&self, task: &Task) -> bool {
+        self.pinned_branch
+            .as_deref()
+            .is_some_and(|branch| task.matches_branch(branch))
     }
 
     pub fn selected_task_with_prs(
@@ -372,33 +940,68 @@ impl Default for TasksState {
     }
 }
 
+/// Backlog task fixture shared by the state tests
+#[cfg(test)]
+pub(crate) fn test_task(id: &str, title: &str) -> Task {
+    Task {
+        id: id.to_string(),
+        project_id: "test-project".to_string(),
+        title: title.to_string(),
+        description: None,
+        status: TaskStatus::Backlog,
+        parent_workspace_id: None,
+        shared_task_id: None,
+        linear_issue_id: None,
+        linear_url: None,
+        linear_labels: None,
+        github_issue: None,
+        github_url: None,
+        created_at: "2024-01-01".to_string(),
+        updated_at: "2024-01-01".to_string(),
+        has_in_progress_attempt: false,
+        last_attempt_failed: false,
+        executor: String::new(),
+        pr_url: None,
+        pr_status: None,
+        pr_is_draft: None,
+        pr_review_decision: None,
+        pr_checks_status: None,
+        pr_has_conflicts: None,
+        status_history: Vec::new(),
+        notes: None,
+        plan_phase: None,
+        branch_override: None,
+        priority: None,
+        estimate: None,
+    }
+}
+
+/// Ready, unreviewed PR #1 in `state` (OPEN, MERGED, CLOSED) with no checks
+#[cfg(test)]
+pub(crate) fn test_pr(state: &str) -> BranchPrInfo {
+    BranchPrInfo {
+        _number: 1,
+        url: "https://github.com/org/repo/pull/1".to_string(),
+        state: state.to_string(),
+        is_draft: false,
+        review_decision: None,
+        status_check_rollup: None,
+        mergeable: None,
+        reviews: vec![],
+        base_ref_name: None,
+        author: None,
+        mine: false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     fn make_task(status: TaskStatus) -> Task {
         Task {
-            id: "test-id".to_string(),
-            project_id: "test-project".to_string(),
-            title: "Test Task".to_string(),
-            description: None,
             status,
-            parent_workspace_id: None,
-            shared_task_id: None,
-            linear_issue_id: None,
-            linear_url: None,
-            linear_labels: None,
-            created_at: "2024-01-01".to_string(),
-            updated_at: "2024-01-01".to_string(),
-            has_in_progress_attempt: false,
-            last_attempt_failed: false,
-            executor: String::new(),
-            pr_url: None,
-            pr_status: None,
-            pr_is_draft: None,
-            pr_review_decision: None,
-            pr_checks_status: None,
-            pr_has_conflicts: None,
+            ..test_task("test-id", "Test Task")
         }
     }
 
@@ -442,6 +1045,192 @@ mod tests {
         assert_eq!(task.effective_status(), TaskStatus::Cancelled);
     }
 
+    #[test]
+    fn test_task_status_parse() {
+        assert_eq!(
+            TaskStatus::parse("in-progress"),
+            Some(TaskStatus::Inprogress)
+        );
+        assert_eq!(
+            TaskStatus::parse("In Progress"),
+            Some(TaskStatus::Inprogress)
+        );
+        assert_eq!(TaskStatus::parse("review"), Some(TaskStatus::Inreview));
+        assert_eq!(TaskStatus::parse("DONE"), Some(TaskStatus::Done));
+        assert_eq!(TaskStatus::parse("canceled"), Some(TaskStatus::Cancelled));
+        assert_eq!(TaskStatus::parse("to-do"), Some(TaskStatus::Todo));
+        assert_eq!(TaskStatus::parse("shipped"), None);
+    }
+
+    #[test]
+    fn test_local_only_status_without_linear() {
+        // No Linear issue and no PR: stored status is the source of truth
+        let task = make_task(TaskStatus::Inreview);
+        assert_eq!(
+            task.effective_status_with_pr(None, false, None),
+            TaskStatus::Inreview
+        );
+
+        // A worktree upgrades backlog/todo to in-progress
+        let task = make_task(TaskStatus::Backlog);
+        assert_eq!(
+            task.effective_status_with_pr(None, true, None),
+            TaskStatus::Inprogress
+        );
+        let task = make_task(TaskStatus::Todo);
+        assert_eq!(
+            task.effective_status_with_pr(None, true, None),
+            TaskStatus::Inprogress
+        );
+
+        // ...but never drags a locally finished task back
+        let task = make_task(TaskStatus::Done);
+        assert_eq!(
+            task.effective_status_with_pr(None, true, None),
+            TaskStatus::Done
+        );
+    }
+
+    #[test]
+    fn test_local_only_status_pr_still_wins() {
+        let task = make_task(TaskStatus::Inprogress);
+        assert_eq!(
+            task.effective_status_with_pr(Some(&test_pr("MERGED")), true, None),
+            TaskStatus::Done
+        );
+        assert_eq!(
+            task.effective_status_with_pr(Some(&test_pr("OPEN")), true, None),
+            TaskStatus::Inreview
+        );
+    }
+
+    #[test]
+    fn test_local_cancel_outlasts_worktree_and_linear() {
+        let started = LinearIssueStatus {
+            identifier: "VIB-7".to_string(),
+            state_type: "started".to_string(),
+            state_name: "In Progress".to_string(),
+            assignee: None,
+        };
+        let task = make_task(TaskStatus::Cancelled);
+        assert_eq!(
+            task.resolve_status(None, true, Some(&started)),
+            (TaskStatus::Cancelled, StatusSource::Stored)
+        );
+
+        // A Linear terminal state still names the outcome
+        let done = LinearIssueStatus {
+            state_type: "completed".to_string(),
+            ..started
+        };
+        assert_eq!(
+            task.effective_status_with_pr(None, false, Some(&done)),
+            TaskStatus::Done
+        );
+    }
+
+    #[test]
+    fn test_live_pr_decides_draft_over_stored() {
+        let live = |is_draft: bool| BranchPrInfo {
+            is_draft,
+            ..test_pr("OPEN")
+        };
+
+        // Stored as draft, since marked ready
+        let mut task = make_task(TaskStatus::Inprogress);
+        task.pr_status = Some("open".to_string());
+        task.pr_is_draft = Some(true);
+        assert_eq!(
+            task.resolve_status(Some(&live(false)), false, None),
+            (TaskStatus::Inreview, StatusSource::LivePr)
+        );
+
+        // Stored as ready, since converted back to draft (no worktree)
+        task.pr_is_draft = Some(false);
+        assert_eq!(
+            task.resolve_status(Some(&live(true)), false, None),
+            (TaskStatus::Inprogress, StatusSource::Stored)
+        );
+        assert!(!task.needs_live_pr(Some(&live(true))));
+
+        // Without live data the stored fields decide, and ask for a fetch
+        assert_eq!(
+            task.resolve_status(None, false, None),
+            (TaskStatus::Inreview, StatusSource::StoredPr)
+        );
+        assert!(task.needs_live_pr(None));
+        task.pr_status = Some("merged".to_string());
+        assert!(!task.needs_live_pr(None));
+    }
+
+    #[test]
+    fn test_deleted_branch_resolves_merged_pr_by_url() {
+        let mut task = make_task(TaskStatus::Inprogress);
+        task.pr_url = Some("https://github.com/org/repo/pull/7".to_string());
+        task.pr_status = Some("open".to_string());
+
+        // The branch lookup found nothing (branch deleted after merge): the stored
+        // fields alone would keep the card in review, so look up the PR by URL
+        assert_eq!(
+            task.resolve_status(None, false, None).0,
+            TaskStatus::Inreview
+        );
+        assert_eq!(
+            task.open_pr_url(),
+            Some("https://github.com/org/repo/pull/7")
+        );
+
+        let merged = BranchPrInfo {
+            _number: 7,
+            url: "https://github.com/org/repo/pull/7".to_string(),
+            ..test_pr("MERGED")
+        };
+        assert_eq!(
+            task.resolve_status(Some(&merged), false, None),
+            (TaskStatus::Done, StatusSource::LivePr)
+        );
+
+        // Only an open stored PR is worth chasing
+        task.pr_status = Some("merged".to_string());
+        assert_eq!(task.open_pr_url(), None);
+        task.pr_status = Some("open".to_string());
+        task.pr_url = None;
+        assert_eq!(task.open_pr_url(), None);
+    }
+
+    #[test]
+    fn test_resolve_status_names_the_rule() {
+        let draft = BranchPrInfo {
+            is_draft: true,
+            ..test_pr("OPEN")
+        };
+        let started = LinearIssueStatus {
+            identifier: "VIB-6".to_string(),
+            state_type: "started".to_string(),
+            state_name: "In Progress".to_string(),
+            assignee: None,
+        };
+        let task = make_task(TaskStatus::Backlog);
+
+        assert_eq!(
+            task.resolve_status(None, false, None),
+            (TaskStatus::Backlog, StatusSource::Stored)
+        );
+        assert_eq!(
+            task.resolve_status(Some(&draft), true, None),
+            (TaskStatus::Inprogress, StatusSource::LivePr)
+        );
+        // A draft PR without a worktree falls through to the later rules
+        assert_eq!(
+            task.resolve_status(Some(&draft), false, Some(&started)),
+            (TaskStatus::Inprogress, StatusSource::Linear)
+        );
+        assert_eq!(
+            task.resolve_status(None, true, Some(&started)),
+            (TaskStatus::Inprogress, StatusSource::Worktree)
+        );
+    }
+
     #[test]
     fn test_tasks_in_column_with_pr_transitions() {
         let mut state = TasksState::new();
@@ -489,6 +1278,390 @@ mod tests {
         assert_eq!(done[0].id, "task3");
     }
 
+    #[test]
+    fn test_pinned_task_listed_first() {
+        let mut state = TasksState::new();
+        let titles = ["First task", "Second task", "Third task"];
+        state.set_tasks(
+            titles
+                .iter()
+                .enumerate()
+                .map(|(i, title)| {
+                    let mut task = make_task(TaskStatus::Inprogress);
+                    task.id = format!("task{}", i + 1);
+                    task.title = title.to_string();
+                    task
+                })
+                .collect(),
+        );
+
+        let empty_prs = std::collections::HashMap::new();
+        let empty_wt: Vec<crate::external::WorktreeInfo> = vec![];
+        let empty_linear = std::collections::HashMap::new();
+        let ids = |state: &TasksState| -> Vec<String> {
+            state
+                .tasks_in_column_with_prs(
+                    TaskStatus::Inprogress,
+                    &empty_prs,
+                    &empty_wt,
+                    &empty_linear,
+                )
+                .iter()
+                .map(|t| t.id.clone())
+                .collect()
+        };
+
+        assert_eq!(ids(&state), vec!["task1", "task2", "task3"]);
+
+        state.pinned_branch = Some("third-task".to_string());
+        assert_eq!(ids(&state), vec!["task3", "task1", "task2"]);
+        assert!(state.is_pinned(&state.tasks[2]));
+        assert!(!state.is_pinned(&state.tasks[0]));
+    }
+
+    #[test]
+    fn test_assignee_filter_cycles_and_filters() {
+        let mut state = TasksState::new();
+        let mut linear = std::collections::HashMap::new();
+        let owners = [Some("Zoe Park"), Some("Ann Lee"), None, Some("Ann Lee")];
+        state.set_tasks(
+            owners
+                .iter()
+                .enumerate()
+                .map(|(i, owner)| {
+                    let mut task = make_task(TaskStatus::Backlog);
+                    task.id = format!("task{}", i + 1);
+                    let issue = format!("VIB-{}", i + 1);
+                    linear.insert(
+                        issue.clone(),
+                        LinearIssueStatus {
+                            identifier: issue.clone(),
+                            state_type: "backlog".to_string(),
+                            state_name: "Backlog".to_string(),
+                            assignee: owner.map(String::from),
+                        },
+                    );
+                    task.linear_issue_id = Some(issue);
+                    task
+                })
+                .collect(),
+        );
+
+        let empty_prs = std::collections::HashMap::new();
+        let empty_wt: Vec<crate::external::WorktreeInfo> = vec![];
+        let ids = |state: &TasksState| -> Vec<String> {
+            state
+                .tasks_in_column_with_prs(TaskStatus::Backlog, &empty_prs, &empty_wt, &linear)
+                .iter()
+                .map(|t| t.id.clone())
+                .collect()
+        };
+
+        assert_eq!(state.assignees(&linear), vec!["Ann Lee", "Zoe Park"]);
+        assert_eq!(ids(&state).len(), 4);
+
+        state.cycle_assignee_filter(&linear);
+        assert_eq!(state.assignee_filter.as_deref(), Some("Ann Lee"));
+        assert_eq!(ids(&state), vec!["task2", "task4"]);
+
+        state.cycle_assignee_filter(&linear);
+        assert_eq!(ids(&state), vec!["task1"]);
+
+        // Past the last assignee it goes back to showing everyone
+        state.cycle_assignee_filter(&linear);
+        assert_eq!(state.assignee_filter, None);
+        assert_eq!(ids(&state).len(), 4);
+    }
+
+    #[test]
+    fn test_base_filter_cycles_and_filters() {
+        let mut state = TasksState::new();
+        let mut prs = std::collections::HashMap::new();
+        let bases = [Some("main"), Some("release/2.1"), None, Some("main")];
+        state.set_tasks(
+            bases
+                .iter()
+                .enumerate()
+                .map(|(i, base)| {
+                    let mut task = make_task(TaskStatus::Inprogress);
+                    task.id = format!("task{}", i + 1);
+                    task.title = format!("Task {}", i + 1);
+                    if let Some(base) = base {
+                        prs.insert(
+                            task_title_to_branch(&task.title, None, &task.id),
+                            BranchPrInfo {
+                                _number: i as i64,
+                                base_ref_name: Some(base.to_string()),
+                                ..test_pr("OPEN")
+                            },
+                        );
+                    }
+                    task
+                })
+                .collect(),
+        );
+
+        let empty_wt: Vec<crate::external::WorktreeInfo> = vec![];
+        let linear = std::collections::HashMap::new();
+        let ids = |state: &TasksState, status: TaskStatus| -> Vec<String> {
+            state
+                .tasks_in_column_with_prs(status, &prs, &empty_wt, &linear)
+                .iter()
+                .map(|t| t.id.clone())
+                .collect()
+        };
+
+        assert_eq!(state.pr_bases(&prs, &empty_wt), vec!["main", "release/2.1"]);
+        assert_eq!(ids(&state, TaskStatus::Inreview).len(), 3);
+        assert_eq!(ids(&state, TaskStatus::Inprogress), vec!["task3"]);
+
+        state.cycle_base_filter(&prs, &empty_wt);
+        assert_eq!(state.base_filter.as_deref(), Some("main"));
+        assert_eq!(ids(&state, TaskStatus::Inreview), vec!["task1", "task4"]);
+        // Cards without a PR have no base to match
+        assert!(ids(&state, TaskStatus::Inprogress).is_empty());
+
+        state.cycle_base_filter(&prs, &empty_wt);
+        assert_eq!(ids(&state, TaskStatus::Inreview), vec!["task2"]);
+
+        state.cycle_base_filter(&prs, &empty_wt);
+        assert_eq!(state.base_filter, None);
+        assert_eq!(ids(&state, TaskStatus::Inreview).len(), 3);
+    }
+
+    #[test]
+    fn test_my_prs_win_fuzzy_matches_and_filter() {
+        let pr = |number: i64, mine: bool| BranchPrInfo {
+            _number: number,
+            mine,
+            ..test_pr("OPEN")
+        };
+        let mut state = TasksState::new();
+        let mut export = make_task(TaskStatus::Inprogress);
+        export.id = "export".to_string();
+        export.title = "Add export".to_string();
+        let mut login = make_task(TaskStatus::Inprogress);
+        login.id = "login".to_string();
+        login.title = "Fix login".to_string();
+        state.set_tasks(vec![export, login]);
+
+        let empty_wt: Vec<crate::external::WorktreeInfo> = vec![];
+        let mut prs = std::collections::HashMap::new();
+        prs.insert("teammate/add-export-csv".to_string(), pr(1, false));
+        prs.insert("me/add-export".to_string(), pr(2, true));
+        prs.insert("teammate/fix-login".to_string(), pr(3, false));
+
+        let export = &state.tasks[0];
+        let (found, _) = task_pr_and_worktree(export, &prs, &empty_wt);
+        assert_eq!(found.map(|p| p._number), Some(2));
+
+        let linear = std::collections::HashMap::new();
+        let ids = |state: &TasksState| -> Vec<String> {
+            state
+                .tasks_in_column_with_prs(TaskStatus::Inreview, &prs, &empty_wt, &linear)
+                .iter()
+                .map(|t| t.id.clone())
+                .collect()
+        };
+        assert_eq!(ids(&state).len(), 2);
+        state.toggle_mine_only();
+        assert_eq!(ids(&state), vec!["export"]);
+    }
+
+    #[test]
+    fn test_branch_override_wins_over_fuzzy_matches() {
+        let pr = |number: i64, state: &str| BranchPrInfo {
+            _number: number,
+            mine: true,
+            ..test_pr(state)
+        };
+        let worktree = |branch: &str| -> crate::external::WorktreeInfo {
+            serde_json::from_value(serde_json::json!({
+                "branch": branch,
+                "path": format!("/repo/{}", branch),
+            }))
+            .unwrap()
+        };
+        let mut task = make_task(TaskStatus::Inprogress);
+        task.title = "Fix login".to_string();
+        let mut prs = std::collections::HashMap::new();
+        prs.insert("fix-login".to_string(), pr(1, "MERGED"));
+        prs.insert("spike/auth-rewrite".to_string(), pr(2, "OPEN"));
+        let worktrees = vec![worktree("fix-login"), worktree("spike/auth-rewrite")];
+
+        // Fuzzy matching glues the old merged PR to the task
+        let (found, _) = task_pr_and_worktree(&task, &prs, &worktrees);
+        assert_eq!(found.map(|p| p._number), Some(1));
+        assert!(task.matches_branch("fix-login-old"));
+
+        task.branch_override = Some("spike/auth-rewrite".to_string());
+        assert_eq!(task.branch(), "spike/auth-rewrite");
+        let (found, has_worktree) = task_pr_and_worktree(&task, &prs, &worktrees);
+        assert_eq!(found.map(|p| p._number), Some(2));
+        assert!(has_worktree);
+        assert!(!task.matches_branch("fix-login"));
+
+        let mut state = TasksState::new();
+        state.set_tasks(vec![task]);
+        let linear = std::collections::HashMap::new();
+        assert_eq!(
+            state
+                .tasks_in_column_with_prs(TaskStatus::Inreview, &prs, &worktrees, &linear)
+                .len(),
+            1
+        );
+
+        // A bound branch without a PR or worktree doesn't fall back to the title
+        state.tasks[0].branch_override = Some("elsewhere".to_string());
+        let (found, has_worktree) = task_pr_and_worktree(&state.tasks[0], &prs, &worktrees);
+        assert!(found.is_none());
+        assert!(!has_worktree);
+    }
+
+    #[test]
+    fn test_retitle_keeps_the_old_branch() {
+        let mut task = make_task(TaskStatus::Inprogress);
+        task.title = "Fix login".to_string();
+        task.linear_issue_id = Some("VIB-7".to_string());
+        let old_branch = task.branch();
+
+        // Same slug: nothing to pin
+        assert_eq!(task.branch_to_keep_on_retitle("Fix Login", &[]), None);
+
+        // New slug: pin the branch the work already lives on
+        let pinned = task.branch_to_keep_on_retitle("Fix login on Safari", &[]);
+        assert_eq!(pinned.as_deref(), Some(old_branch.as_str()));
+        let worktree = format!("{}-wip", old_branch);
+        assert_eq!(
+            task.branch_to_keep_on_retitle("Fix login on Safari", &["main", &worktree]),
+            Some(worktree.clone())
+        );
+
+        task.title = "Fix login on Safari".to_string();
+        task.branch_override = pinned;
+        assert_eq!(task.branch(), old_branch);
+        assert_ne!(task.title_branch(), old_branch);
+        assert!(task.matches_branch(&old_branch));
+
+        // Already bound: leave the binding alone
+        assert_eq!(task.branch_to_keep_on_retitle("Something else", &[]), None);
+    }
+
+    #[test]
+    fn test_assignee_initials() {
+        assert_eq!(assignee_initials("Piotr Ostrowski"), "PO");
+        assert_eq!(assignee_initials("piotr"), "P");
+        assert_eq!(assignee_initials("Anna Maria Lee"), "AM");
+        assert_eq!(assignee_initials(""), "");
+    }
+
+    #[test]
+    fn test_backlog_sorted_by_age() {
+        let mut state = TasksState::new();
+        let dates = ["2026-03-01", "2026-01-15", "2026-02-10"];
+        state.set_tasks(
+            dates
+                .iter()
+                .enumerate()
+                .map(|(i, date)| {
+                    let mut task = make_task(TaskStatus::Backlog);
+                    task.id = format!("task{}", i + 1);
+                    task.created_at = date.to_string();
+                    task
+                })
+                .collect(),
+        );
+
+        let empty_prs = std::collections::HashMap::new();
+        let empty_wt: Vec<crate::external::WorktreeInfo> = vec![];
+        let empty_linear = std::collections::HashMap::new();
+        let ids = |state: &TasksState| -> Vec<String> {
+            state
+                .tasks_in_column_with_prs(TaskStatus::Backlog, &empty_prs, &empty_wt, &empty_linear)
+                .iter()
+                .map(|t| t.id.clone())
+                .collect()
+        };
+
+        // Newest first unless sorted by age
+        assert_eq!(ids(&state), vec!["task1", "task3", "task2"]);
+
+        state.backlog_by_age = true;
+        assert_eq!(ids(&state), vec!["task2", "task3", "task1"]);
+    }
+
+    #[test]
+    fn test_column_order_ignores_listing_order() {
+        let tasks: Vec<Task> = [
+            ("b", "2026-02-01"),
+            ("a", "2026-02-01"),
+            ("c", "2026-03-01"),
+        ]
+        .iter()
+        .map(|(id, date)| {
+            let mut task = make_task(TaskStatus::Todo);
+            task.id = id.to_string();
+            task.created_at = date.to_string();
+            task
+        })
+        .collect();
+
+        let empty_prs = std::collections::HashMap::new();
+        let empty_wt: Vec<crate::external::WorktreeInfo> = vec![];
+        let empty_linear = std::collections::HashMap::new();
+        let ids = |tasks: Vec<Task>| -> Vec<String> {
+            let mut state = TasksState::new();
+            state.set_tasks(tasks);
+            state
+                .tasks_in_column_with_prs(TaskStatus::Todo, &empty_prs, &empty_wt, &empty_linear)
+                .iter()
+                .map(|t| t.id.clone())
+                .collect()
+        };
+
+        let mut shuffled = tasks.clone();
+        shuffled.reverse();
+        shuffled.swap(0, 1);
+        assert_eq!(ids(tasks), vec!["c", "a", "b"]);
+        assert_eq!(ids(shuffled), vec!["c", "a", "b"]);
+    }
+
+    #[test]
+    fn test_refresh_targets_skip_done_and_filtered_cards() {
+        let tasks: Vec<Task> = [
+            ("queued", TaskStatus::Backlog, "Queued work"),
+            ("active", TaskStatus::Inprogress, "Active work"),
+            ("other", TaskStatus::Inprogress, "Other thing"),
+            ("shipped", TaskStatus::Done, "Shipped work"),
+            ("dropped", TaskStatus::Cancelled, "Dropped work"),
+        ]
+        .iter()
+        .map(|(id, status, title)| {
+            let mut task = make_task(*status);
+            task.id = id.to_string();
+            task.title = title.to_string();
+            task
+        })
+        .collect();
+        let mut state = TasksState::new();
+        state.set_tasks(tasks);
+
+        let empty_prs = std::collections::HashMap::new();
+        let empty_linear = std::collections::HashMap::new();
+        let targets = |state: &TasksState| {
+            let mut ids: Vec<String> = state
+                .refresh_targets(&empty_prs, &[], &empty_linear)
+                .into_iter()
+                .collect();
+            ids.sort();
+            ids
+        };
+
+        assert_eq!(targets(&state), vec!["active", "other", "queued"]);
+        state.search_filter = "work".to_string();
+        assert_eq!(targets(&state), vec!["active", "queued"]);
+    }
+
     #[test]
     fn test_from_linear_state_type() {
         assert_eq!(
@@ -537,6 +1710,7 @@ mod tests {
             identifier: "VIB-6".to_string(),
             state_type: "started".to_string(),
             state_name: "In Progress".to_string(),
+            assignee: None,
         };
         assert_eq!(
             task.effective_status_with_pr(None, false, Some(&linear_status)),
@@ -548,6 +1722,7 @@ mod tests {
             identifier: "VIB-6".to_string(),
             state_type: "completed".to_string(),
             state_name: "Done".to_string(),
+            assignee: None,
         };
         assert_eq!(
             task.effective_status_with_pr(None, false, Some(&linear_done)),
@@ -565,6 +1740,7 @@ mod tests {
             identifier: "VIB-6".to_string(),
             state_type: "completed".to_string(),
             state_name: "Done".to_string(),
+            assignee: None,
         };
         assert_eq!(
             task.effective_status_with_pr(None, true, Some(&linear_done)),
@@ -576,6 +1752,7 @@ mod tests {
             identifier: "VIB-6".to_string(),
             state_type: "canceled".to_string(),
             state_name: "Cancelled".to_string(),
+            assignee: None,
         };
         assert_eq!(
             task.effective_status_with_pr(None, true, Some(&linear_cancelled)),
@@ -593,6 +1770,7 @@ mod tests {
             identifier: "VIB-6".to_string(),
             state_type: "backlog".to_string(),
             state_name: "Backlog".to_string(),
+            assignee: None,
         };
         assert_eq!(
             task.effective_status_with_pr(None, true, Some(&linear_backlog)),
@@ -604,6 +1782,7 @@ mod tests {
             identifier: "VIB-6".to_string(),
             state_type: "unstarted".to_string(),
             state_name: "Todo".to_string(),
+            assignee: None,
         };
         assert_eq!(
             task.effective_status_with_pr(None, true, Some(&linear_unstarted)),
@@ -613,26 +1792,181 @@ mod tests {
 
     #[test]
     fn test_task_title_to_branch_without_linear_id() {
-        assert_eq!(task_title_to_branch("Hello World", None), "hello-world");
         assert_eq!(
-            task_title_to_branch("Add feature: user auth", None),
+            task_title_to_branch("Hello World", None, "id"),
+            "hello-world"
+        );
+        assert_eq!(
+            task_title_to_branch("Add feature: user auth", None, "id"),
             "add-feature-user-auth"
         );
-        assert_eq!(task_title_to_branch("Fix bug #123", None), "fix-bug-123");
         assert_eq!(
-            task_title_to_branch("  Multiple   Spaces  ", None),
+            task_title_to_branch("Fix bug #123", None, "id"),
+            "fix-bug-123"
+        );
+        assert_eq!(
+            task_title_to_branch("  Multiple   Spaces  ", None, "id"),
             "multiple-spaces"
         );
     }
 
+    #[test]
+    fn test_task_title_to_branch_unicode() {
+        assert_eq!(
+            task_title_to_branch("Café crème brûlée", None, "id"),
+            "cafe-creme-brulee"
+        );
+        assert_eq!(
+            task_title_to_branch("Zażółć gęślą jaźń", None, "id"),
+            "zazolc-gesla-jazn"
+        );
+        assert_eq!(task_title_to_branch("Straße", None, "id"), "strasse");
+        assert_eq!(
+            task_title_to_branch("🚀 Launch rocket 🚀", None, "id"),
+            "launch-rocket"
+        );
+        // Nothing sluggable left: named after the task id, so such tasks stay apart
+        assert_eq!(
+            task_title_to_branch("日本語のタイトル", None, "3f2a9c71-aaaa"),
+            "task-3f2a9c71"
+        );
+        assert_eq!(
+            task_title_to_branch("Fix 🐛 in parser", Some("VIB-9"), "id"),
+            "VIB-9/fix-in-parser"
+        );
+    }
+
+    #[test]
+    fn test_accented_title_keeps_its_pre_ascii_branch() {
+        let mut task = make_task(TaskStatus::Backlog);
+        task.title = "Café crème".to_string();
+        assert_eq!(task.branch(), "cafe-creme");
+        assert_eq!(task.legacy_title_branch().as_deref(), Some("café-crème"));
+
+        let worktrees: Vec<crate::external::WorktreeInfo> = vec![
+            serde_json::from_value(serde_json::json!({
+                "branch": "café-crème",
+                "path": "/repo/café-crème",
+            }))
+            .unwrap(),
+        ];
+        let prs = std::collections::HashMap::from([(
+            "café-crème".to_string(),
+            BranchPrInfo {
+                _number: 7,
+                ..test_pr("OPEN")
+            },
+        )]);
+
+        let (pr, has_worktree) = task_pr_and_worktree(&task, &prs, &worktrees);
+        assert!(has_worktree);
+        assert_eq!(pr.map(|pr| pr._number), Some(7));
+        assert!(task.matches_branch("café-crème"));
+
+        // Plain ASCII titles never had another name
+        task.title = "Plain title".to_string();
+        assert_eq!(task.legacy_title_branch(), None);
+        // Neither did titles with nothing sluggable
+        task.title = "!!!".to_string();
+        assert_eq!(task.legacy_title_branch(), None);
+        task.title = "日本語のタイトル".to_string();
+        assert_eq!(
+            task.legacy_title_branch().as_deref(),
+            Some("日本語のタイトル")
+        );
+    }
+
+    #[test]
+    fn test_unsluggable_titles_get_their_own_branch() {
+        let mut japanese = make_task(TaskStatus::Backlog);
+        japanese.id = "11111111-aaaa".to_string();
+        japanese.title = "日本語".to_string();
+        let mut bangs = make_task(TaskStatus::Backlog);
+        bangs.id = "22222222-bbbb".to_string();
+        bangs.title = "!!!".to_string();
+
+        assert_eq!(japanese.branch(), "task-11111111");
+        assert_eq!(bangs.branch(), "task-22222222");
+        assert!(!japanese.matches_branch(&bangs.branch()));
+    }
+
+    #[test]
+    fn test_valid_git_ref() {
+        // Already valid names pass through untouched
+        assert_eq!(valid_git_ref("VIB-9/fix-parser"), "VIB-9/fix-parser");
+        assert_eq!(valid_git_ref("release-1.2"), "release-1.2");
+
+        assert_eq!(valid_git_ref(".hidden"), "hidden");
+        assert_eq!(valid_git_ref("VIB-1/.dotfile"), "VIB-1/dotfile");
+        assert_eq!(valid_git_ref("feature.lock"), "feature");
+        assert_eq!(valid_git_ref("a.lock/b"), "a/b");
+        assert_eq!(valid_git_ref("x.lock."), "x");
+        assert_eq!(valid_git_ref("a..b"), "a.b");
+        assert_eq!(valid_git_ref("a...b"), "a.b");
+        assert_eq!(valid_git_ref("trailing."), "trailing");
+        assert_eq!(valid_git_ref("ref@{1}"), "ref-1}");
+        assert_eq!(valid_git_ref("@"), "task");
+        assert_eq!(valid_git_ref("tab\there\u{7f}"), "tab-here-");
+        assert_eq!(valid_git_ref("a b~c^d:e?f*g[h\\i"), "a-b-c-d-e-f-g-h-i");
+        assert_eq!(valid_git_ref("VIB-9//slug/"), "VIB-9/slug");
+        assert_eq!(valid_git_ref("-option"), "option");
+        assert_eq!(valid_git_ref(""), "task");
+        assert_eq!(valid_git_ref("./.."), "task");
+    }
+
+    #[test]
+    fn test_task_title_to_branch_sanitizes_linear_id() {
+        assert_eq!(task_title_to_branch("", Some("VIB-9"), "id"), "VIB-9");
+        assert_eq!(
+            task_title_to_branch("Fix it", Some("..team.lock"), "id"),
+            "team/fix-it"
+        );
+    }
+
+    #[test]
+    fn test_branch_to_task_title() {
+        assert_eq!(
+            branch_to_task_title("fix-login-bug"),
+            Some(("Fix login bug".to_string(), None))
+        );
+        assert_eq!(
+            branch_to_task_title("VIB-12/add_dark_mode"),
+            Some(("Add dark mode".to_string(), Some("VIB-12".to_string())))
+        );
+        assert_eq!(
+            branch_to_task_title("feature/new-api"),
+            Some(("New api".to_string(), None))
+        );
+        // Round-trips back to a branch the task will match
+        let (title, id) = branch_to_task_title("VIB-12/add-dark-mode").unwrap();
+        assert_eq!(
+            task_title_to_branch(&title, id.as_deref(), "id"),
+            "VIB-12/add-dark-mode"
+        );
+    }
+
+    #[test]
+    fn test_branch_to_task_title_unparseable() {
+        assert_eq!(branch_to_task_title(""), None);
+        assert_eq!(branch_to_task_title("---"), None);
+        assert_eq!(branch_to_task_title("release/1.2.3"), None);
+    }
+
+    #[test]
+    fn test_branch_matches() {
+        assert!(branch_matches("VIB-1/foo", "VIB-1/foo"));
+        assert!(branch_matches("feature/foo-bar", "foo-bar"));
+        assert!(!branch_matches("main", "foo-bar"));
+    }
+
     #[test]
     fn test_task_title_to_branch_with_linear_id() {
         assert_eq!(
-            task_title_to_branch("Add some feature", Some("AMB-67")),
+            task_title_to_branch("Add some feature", Some("AMB-67"), "id"),
             "AMB-67/add-some-feature"
         );
         assert_eq!(
-            task_title_to_branch("Fix the bug", Some("TEAM-123")),
+            task_title_to_branch("Fix the bug", Some("TEAM-123"), "id"),
             "TEAM-123/fix-the-bug"
         );
     }
@@ -657,12 +1991,7 @@ mod tests {
             BranchPrInfo {
                 _number: 12,
                 url: "https://github.com/test/repo/pull/12".to_string(),
-                state: "MERGED".to_string(),
-                is_draft: false,
-                review_decision: None,
-                status_check_rollup: None,
-                mergeable: None,
-                reviews: vec![],
+                ..test_pr("MERGED")
             },
         );
 
@@ -687,4 +2016,45 @@ mod tests {
         assert_eq!(done.len(), 1, "Merged PR task should be in Done");
         assert_eq!(done[0].id, "task1");
     }
+
+    #[test]
+    fn test_refresh_from_store_moves_card_and_keeps_selection_in_bounds() {
+        use crate::storage::{MemoryTaskStore, TaskStore};
+        use std::collections::HashMap;
+
+        let store = MemoryTaskStore::new("test-project");
+        store.create_task("First", None).unwrap();
+        store.create_task("Second", None).unwrap();
+        let (prs, linear) = (HashMap::new(), HashMap::new());
+
+        let mut state = TasksState::default();
+        state.set_tasks(store.list_tasks().unwrap());
+        // Same-day cards are ordered by id, so look up which one comes second
+        let backlog = state.tasks_in_column_with_prs(TaskStatus::Backlog, &prs, &[], &linear);
+        let (kept, moved) = (backlog[0].id.clone(), backlog[1].id.clone());
+        state.select_next_card_with_prs(&prs, &[], &linear);
+        assert_eq!(
+            state
+                .selected_task_with_prs(&prs, &[], &linear)
+                .map(|t| t.id.as_str()),
+            Some(moved.as_str())
+        );
+
+        // What a refresh after `vibe start` sees
+        store
+            .set_task_status(&moved, TaskStatus::Inprogress)
+            .unwrap();
+        state.set_tasks(store.list_tasks().unwrap());
+        let backlog = state.tasks_in_column_with_prs(TaskStatus::Backlog, &prs, &[], &linear);
+        assert_eq!(backlog.len(), 1);
+        assert_eq!(
+            state
+                .selected_task_with_prs(&prs, &[], &linear)
+                .map(|t| t.id.as_str()),
+            Some(kept.as_str())
+        );
+        let in_progress =
+            state.tasks_in_column_with_prs(TaskStatus::Inprogress, &prs, &[], &linear);
+        assert_eq!(in_progress[0].id, moved);
+    }
 }
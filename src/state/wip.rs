@@ -0,0 +1,75 @@
+use super::TaskStatus;
+
+/// Env var with per-column WIP limits, e.g. `VIBE_WIP_LIMITS="in-progress=3,review=5"`
+pub const WIP_LIMITS_ENV: &str = "VIBE_WIP_LIMITS";
+
+/// Work-in-progress limits per visible board column; no limits by default
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WipLimits([Option<usize>; TaskStatus::VISIBLE.len()]);
+
+impl WipLimits {
+    /// Parse a comma-separated `column=limit` list. Column names go through
+    /// `TaskStatus::parse`, so "todo" limits the Backlog column it is shown in.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut limits = Self::default();
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (column, limit) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("expected column=limit, got '{}'", entry))?;
+            let status = TaskStatus::parse(column)
+                .ok_or_else(|| format!("unknown column '{}'", column.trim()))?;
+            let limit: usize = limit
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid limit '{}' for {}", limit.trim(), column.trim()))?;
+            limits.0[status.column_index()] = Some(limit);
+        }
+        Ok(limits)
+    }
+
+    /// Limits from `VIBE_WIP_LIMITS`, ignoring (and logging) a malformed value
+    pub fn from_env() -> Self {
+        let Ok(spec) = std::env::var(WIP_LIMITS_ENV) else {
+            return Self::default();
+        };
+        Self::parse(&spec).unwrap_or_else(|e| {
+            tracing::warn!("Ignoring {}: {}", WIP_LIMITS_ENV, e);
+            Self::default()
+        })
+    }
+
+    pub fn limit(&self, status: TaskStatus) -> Option<usize> {
+        self.0[status.column_index()]
+    }
+
+    pub fn is_exceeded(&self, status: TaskStatus, count: usize) -> bool {
+        self.limit(status).is_some_and(|limit| count > limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_wip_limits() {
+        let limits = WipLimits::parse("in-progress=3, review = 5").unwrap();
+        assert_eq!(limits.limit(TaskStatus::Inprogress), Some(3));
+        assert_eq!(limits.limit(TaskStatus::Inreview), Some(5));
+        assert_eq!(limits.limit(TaskStatus::Backlog), None);
+        assert_eq!(limits.limit(TaskStatus::Done), None);
+
+        assert_eq!(WipLimits::parse("").unwrap(), WipLimits::default());
+        assert!(WipLimits::parse("in-progress").is_err());
+        assert!(WipLimits::parse("doing=3").is_err());
+        assert!(WipLimits::parse("review=-1").is_err());
+    }
+
+    #[test]
+    fn test_wip_limit_exceeded() {
+        let limits = WipLimits::parse("wip=3").unwrap();
+        assert!(!limits.is_exceeded(TaskStatus::Inprogress, 3));
+        assert!(limits.is_exceeded(TaskStatus::Inprogress, 4));
+        assert!(!limits.is_exceeded(TaskStatus::Backlog, 100));
+    }
+}
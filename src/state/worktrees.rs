@@ -1,19 +1,90 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::time::Instant;
 
+use super::{Task, fuzzy_score};
 use crate::external::{BranchPrInfo, WorktreeInfo};
 
 /// How long to cache "no PR" results before re-checking
 const NO_PR_CACHE_TTL_SECS: u64 = 120;
 
+/// Set to `0`/`false`/`off` to stop pinning the current worktree's task to the top
+pub const PIN_CURRENT_ENV: &str = "VIBE_PIN_CURRENT";
+
+/// Whether the current worktree's task is pinned (on unless disabled via env)
+pub fn pin_current_enabled() -> bool {
+    std::env::var(PIN_CURRENT_ENV).map_or(true, |v| {
+        !matches!(
+            v.trim().to_lowercase().as_str(),
+            "0" | "false" | "off" | "no"
+        )
+    })
+}
+
+/// Set to `1`/`true`/`on` to refresh worktrees and PRs as soon as HEAD or a ref changes
+pub const WATCH_GIT_ENV: &str = "VIBE_WATCH_GIT";
+
+/// Whether to watch the repository's refs (off unless enabled via env)
+pub fn watch_git_enabled() -> bool {
+    std::env::var(WATCH_GIT_ENV).is_ok_and(|v| {
+        matches!(
+            v.trim().to_lowercase().as_str(),
+            "1" | "true" | "on" | "yes"
+        )
+    })
+}
+
+/// Branch of the worktree containing `cwd` (deepest path wins), falling back to
+/// wt's own `is_current`. The main checkout has no task, so it is never reported.
+pub fn current_worktree_branch(worktrees: &[WorktreeInfo], cwd: &Path) -> Option<String> {
+    let linked = || worktrees.iter().filter(|wt| !wt.is_main);
+    linked()
+        .filter(|wt| !wt.path.is_empty() && cwd.starts_with(&wt.path))
+        .max_by_key(|wt| wt.path.len())
+        .or_else(|| linked().find(|wt| wt.is_current))
+        .map(|wt| wt.branch.clone())
+}
+
+/// Jump-to-branch picker over the worktree list
+pub struct WorktreePicker {
+    pub query: String,
+    /// Indices into `WorktreesState::worktrees`, best match first
+    pub matches: Vec<usize>,
+    pub selected: usize,
+}
+
+impl WorktreePicker {
+    fn update_matches(&mut self, worktrees: &[WorktreeInfo]) {
+        let mut scored: Vec<(i64, usize)> = worktrees
+            .iter()
+            .enumerate()
+            .filter_map(|(i, wt)| fuzzy_score(&self.query, &wt.branch).map(|s| (s, i)))
+            .collect();
+        if !self.query.is_empty() {
+            scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        }
+        self.matches = scored.into_iter().map(|(_, i)| i).collect();
+        if self.selected >= self.matches.len() {
+            self.selected = 0;
+        }
+    }
+}
+
 pub struct WorktreesState {
     pub worktrees: Vec<WorktreeInfo>,
     pub selected_index: usize,
     pub loading: bool,
     pub error: Option<String>,
     pub branch_prs: HashMap<String, BranchPrInfo>,
+    /// Repository default branch, from the last PR batch; PRs targeting
+    /// another base are labelled with it
+    pub default_branch: Option<String>,
+    /// GitHub login whose PRs count as mine (`VIBE_GH_USER`, else the `gh` user)
+    pub gh_login: Option<String>,
     /// Branches we've checked that have no PR, with timestamp of last check
     no_pr_cache: HashMap<String, Instant>,
+    /// Active jump-to-branch picker, if open
+    pub picker: Option<WorktreePicker>,
 }
 
 impl WorktreesState {
@@ -24,7 +95,10 @@ impl WorktreesState {
             loading: false,
             error: None,
             branch_prs: HashMap::new(),
+            default_branch: None,
+            gh_login: None,
             no_pr_cache: HashMap::new(),
+            picker: None,
         }
     }
 
@@ -32,9 +106,13 @@ impl WorktreesState {
         self.branch_prs.get(branch)
     }
 
-    pub fn set_branch_pr(&mut self, branch: String, pr_info: BranchPrInfo) {
+    pub fn set_branch_pr(&mut self, branch: String, mut pr_info: BranchPrInfo) {
         // Clear from no-PR cache if we found a PR
         self.no_pr_cache.remove(&branch);
+        pr_info.mine = self
+            .gh_login
+            .as_deref()
+            .is_some_and(|login| pr_info.is_authored_by(login));
         self.branch_prs.insert(branch, pr_info);
     }
 
@@ -86,6 +164,84 @@ impl WorktreesState {
         } else if self.selected_index >= self.worktrees.len() {
             self.selected_index = self.worktrees.len().saturating_sub(1);
         }
+        if let Some(picker) = self.picker.as_mut() {
+            picker.update_matches(&self.worktrees);
+        }
+    }
+
+    pub fn open_picker(&mut self) {
+        let mut picker = WorktreePicker {
+            query: String::new(),
+            matches: Vec::new(),
+            selected: 0,
+        };
+        picker.update_matches(&self.worktrees);
+        self.picker = Some(picker);
+    }
+
+    pub fn close_picker(&mut self) {
+        self.picker = None;
+    }
+
+    pub fn picker_type(&mut self, c: char) {
+        if let Some(picker) = self.picker.as_mut() {
+            picker.query.push(c);
+            picker.update_matches(&self.worktrees);
+        }
+    }
+
+    pub fn picker_backspace(&mut self) {
+        if let Some(picker) = self.picker.as_mut() {
+            picker.query.pop();
+            picker.update_matches(&self.worktrees);
+        }
+    }
+
+    pub fn picker_clear(&mut self) {
+        if let Some(picker) = self.picker.as_mut() {
+            picker.query.clear();
+            picker.update_matches(&self.worktrees);
+        }
+    }
+
+    pub fn picker_select_next(&mut self) {
+        if let Some(picker) = self.picker.as_mut()
+            && !picker.matches.is_empty()
+        {
+            picker.selected = (picker.selected + 1) % picker.matches.len();
+        }
+    }
+
+    pub fn picker_select_prev(&mut self) {
+        if let Some(picker) = self.picker.as_mut()
+            && !picker.matches.is_empty()
+        {
+            picker.selected = if picker.selected == 0 {
+                picker.matches.len() - 1
+            } else {
+                picker.selected - 1
+            };
+        }
+    }
+
+    /// Close the picker and move the list selection to the picked worktree
+    pub fn confirm_picker(&mut self) {
+        if let Some(picker) = self.picker.take()
+            && let Some(&idx) = picker.matches.get(picker.selected)
+        {
+            self.selected_index = idx;
+        }
+    }
+
+    /// Branches of worktrees that no task maps to (e.g. created by hand).
+    /// The main checkout is never considered untracked.
+    pub fn untracked_branches(&self, tasks: &[Task]) -> HashSet<String> {
+        self.worktrees
+            .iter()
+            .filter(|wt| !wt.is_main)
+            .filter(|wt| !tasks.iter().any(|t| t.matches_branch(&wt.branch)))
+            .map(|wt| wt.branch.clone())
+            .collect()
     }
 
     pub fn selected(&self) -> Option<&WorktreeInfo> {
@@ -114,3 +270,95 @@ impl Default for WorktreesState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::test_task;
+
+    fn worktree(branch: &str) -> WorktreeInfo {
+        serde_json::from_value(serde_json::json!({
+            "branch": branch,
+            "path": format!("/tmp/{}", branch),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_current_worktree_branch() {
+        let mut main = worktree("main");
+        main.is_main = true;
+        main.path = "/repo".to_string();
+        let mut feature = worktree("VIB-1/feature");
+        feature.path = "/repo.VIB-1-feature".to_string();
+        let mut other = worktree("VIB-2/other");
+        other.is_current = true;
+        let worktrees = vec![main, feature, other];
+
+        assert_eq!(
+            current_worktree_branch(&worktrees, Path::new("/repo.VIB-1-feature/src")),
+            Some("VIB-1/feature".to_string())
+        );
+        // Inside the main checkout: fall back to wt's is_current
+        assert_eq!(
+            current_worktree_branch(&worktrees, Path::new("/repo/src")),
+            Some("VIB-2/other".to_string())
+        );
+        assert_eq!(
+            current_worktree_branch(&worktrees[..2], Path::new("/repo")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_picker_filters_and_jumps() {
+        let mut state = WorktreesState::new();
+        state.set_worktrees(vec![
+            worktree("main"),
+            worktree("VIB-1/fix-login-bug"),
+            worktree("VIB-2/add-billing"),
+        ]);
+
+        state.open_picker();
+        assert_eq!(state.picker.as_ref().unwrap().matches, vec![0, 1, 2]);
+
+        for c in "bill".chars() {
+            state.picker_type(c);
+        }
+        assert_eq!(state.picker.as_ref().unwrap().matches, vec![2]);
+
+        state.confirm_picker();
+        assert!(state.picker.is_none());
+        assert_eq!(state.selected_index, 2);
+    }
+
+    #[test]
+    fn test_untracked_branches() {
+        let mut state = WorktreesState::new();
+        let mut main = worktree("main");
+        main.is_main = true;
+        state.set_worktrees(vec![
+            main,
+            worktree("VIB-1/tracked-work"),
+            worktree("manual-experiment"),
+        ]);
+
+        let mut task = test_task("t1", "Tracked work");
+        task.linear_issue_id = Some("VIB-1".to_string());
+
+        let untracked = state.untracked_branches(&[task]);
+        assert_eq!(untracked, HashSet::from(["manual-experiment".to_string()]));
+    }
+
+    #[test]
+    fn test_picker_confirm_without_match_keeps_selection() {
+        let mut state = WorktreesState::new();
+        state.set_worktrees(vec![worktree("main"), worktree("feature")]);
+        state.selected_index = 1;
+
+        state.open_picker();
+        state.picker_type('z');
+        state.confirm_picker();
+        assert_eq!(state.selected_index, 1);
+    }
+}
@@ -0,0 +1,324 @@
+use std::sync::Mutex;
+
+use anyhow::Result;
+
+use super::{DeletedTask, ImportFrontmatter, TaskStore, parse_priority};
+use crate::external::{GhIssue, LinearIssue};
+use crate::state::{PlanPhase, StatusChange, Task, TaskStatus, push_status_change};
+
+/// Task store that never touches disk, for deterministic tests of app/state flows
+#[derive(Debug, Default)]
+pub struct MemoryTaskStore {
+    project_name: String,
+    inner: Mutex<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    /// Oldest first
+    tasks: Vec<Task>,
+    archived: Vec<Task>,
+}
+
+impl MemoryTaskStore {
+    pub fn new(project_name: &str) -> Self {
+        Self {
+            project_name: project_name.to_string(),
+            inner: Mutex::default(),
+        }
+    }
+
+    fn insert(&self, task: Task) -> Task {
+        self.inner.lock().unwrap().tasks.push(task.clone());
+        task
+    }
+
+    /// Apply `change` to the task and return its new state
+    fn modify(&self, task_id: &str, change: impl FnOnce(&mut Task)) -> Result<Task> {
+        let mut inner = self.inner.lock().unwrap();
+        let task = inner
+            .tasks
+            .iter_mut()
+            .find(|t| t.id == task_id)
+            .ok_or_else(|| anyhow::anyhow!("Task not found: {}", task_id))?;
+        change(task);
+        Ok(task.clone())
+    }
+
+    fn new_task(&self, title: &str, description: Option<&str>) -> Task {
+        let created = chrono::Utc::now().format("%Y-%m-%d").to_string();
+        Task {
+            id: uuid::Uuid::new_v4().to_string(),
+            project_id: self.project_name.clone(),
+            title: title.to_string(),
+            description: description.map(String::from),
+            status: TaskStatus::Backlog,
+            parent_workspace_id: None,
+            shared_task_id: None,
+            linear_issue_id: None,
+            linear_url: None,
+            linear_labels: None,
+            github_issue: None,
+            github_url: None,
+            created_at: created.clone(),
+            updated_at: created,
+            has_in_progress_attempt: false,
+            last_attempt_failed: false,
+            executor: String::new(),
+            pr_url: None,
+            pr_status: None,
+            pr_is_draft: None,
+            pr_review_decision: None,
+            pr_checks_status: None,
+            pr_has_conflicts: None,
+            status_history: Vec::new(),
+            notes: None,
+            plan_phase: None,
+            branch_override: None,
+            priority: None,
+            estimate: None,
+        }
+    }
+}
+
+/// Same rule as the file store: only a move away from the last recorded status counts
+fn record(task: &mut Task, status: TaskStatus) -> Option<StatusChange> {
+    if task.last_recorded_status() == status {
+        return None;
+    }
+    let change = StatusChange {
+        status,
+        at: chrono::Utc::now(),
+    };
+    push_status_change(&mut task.status_history, change.clone());
+    Some(change)
+}
+
+fn join_labels<'a>(labels: impl IntoIterator<Item = &'a str>) -> Option<String> {
+    let labels: Vec<_> = labels.into_iter().collect();
+    (!labels.is_empty()).then(|| labels.join(", "))
+}
+
+impl TaskStore for MemoryTaskStore {
+    fn project_name(&self) -> &str {
+        &self.project_name
+    }
+
+    fn list_tasks(&self) -> Result<Vec<Task>> {
+        let mut tasks = self.inner.lock().unwrap().tasks.clone();
+        tasks.reverse();
+        tasks.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(tasks)
+    }
+
+    fn create_task(&self, title: &str, description: Option<&str>) -> Result<Task> {
+        Ok(self.insert(self.new_task(title, description)))
+    }
+
+    fn create_task_from_linear(&self, issue: &LinearIssue) -> Result<Task> {
+        let mut task = self.new_task(&issue.title, issue.description.as_deref());
+        task.linear_issue_id = Some(issue.identifier.clone());
+        task.linear_url = (!issue.url.is_empty()).then(|| issue.url.clone());
+        task.linear_labels = join_labels(issue.labels.iter().map(String::as_str));
+        task.priority = issue.priority;
+        task.estimate = issue.estimate;
+        Ok(self.insert(task))
+    }
+
+    fn create_task_from_github(&self, issue: &GhIssue) -> Result<Task> {
+        let description = (!issue.body.trim().is_empty()).then_some(issue.body.as_str());
+        let mut task = self.new_task(&issue.title, description);
+        task.github_issue = Some(issue.number);
+        task.github_url = Some(issue.url.clone());
+        task.linear_labels = join_labels(issue.labels.iter().map(|l| l.name.as_str()));
+        Ok(self.insert(task))
+    }
+
+    fn apply_import_frontmatter(&self, task_id: &str, import: &ImportFrontmatter) -> Result<Task> {
+        self.modify(task_id, |task| {
+            if let Some(status) = import.status {
+                record(task, status);
+                task.status = status;
+            }
+            if let Some(priority) = import.priority.as_deref().and_then(parse_priority) {
+                task.priority = Some(priority);
+            }
+            let mut labels: Vec<String> = task
+                .linear_labels
+                .as_deref()
+                .map(|l| l.split(", ").map(String::from).collect())
+                .unwrap_or_default();
+            for label in import.labels() {
+                if !labels.contains(&label) {
+                    labels.push(label);
+                }
+            }
+            task.linear_labels = join_labels(labels.iter().map(String::as_str));
+        })
+    }
+
+    fn update_task(&self, task_id: &str, title: &str, description: Option<&str>) -> Result<Task> {
+        self.modify(task_id, |task| {
+            task.title = title.to_string();
+            task.description = description.map(String::from);
+        })
+    }
+
+    fn link_linear_issue(&self, task_id: &str, identifier: &str, url: &str) -> Result<Task> {
+        self.modify(task_id, |task| {
+            task.linear_issue_id = Some(identifier.to_string());
+            task.linear_url = (!url.is_empty()).then(|| url.to_string());
+        })
+    }
+
+    fn record_pr_url(&self, task_id: &str, url: &str) -> Result<Task> {
+        self.modify(task_id, |task| task.pr_url = Some(url.to_string()))
+    }
+
+    fn set_task_notes(&self, task_id: &str, notes: Option<&str>) -> Result<Task> {
+        self.modify(task_id, |task| {
+            task.notes = notes
+                .map(str::trim)
+                .filter(|n| !n.is_empty())
+                .map(String::from);
+        })
+    }
+
+    fn set_branch_override(&self, task_id: &str, branch: Option<&str>) -> Result<Task> {
+        self.modify(task_id, |task| {
+            task.branch_override = branch.map(String::from)
+        })
+    }
+
+    fn set_plan_phase(&self, task_id: &str, phase: PlanPhase) -> Result<Task> {
+        self.modify(task_id, |task| task.plan_phase = Some(phase))
+    }
+
+    fn set_task_status(&self, task_id: &str, status: TaskStatus) -> Result<Task> {
+        self.modify(task_id, |task| {
+            record(task, status);
+            task.status = status;
+        })
+    }
+
+    fn record_status_change(
+        &self,
+        task_id: &str,
+        status: TaskStatus,
+    ) -> Result<Option<StatusChange>> {
+        let mut change = None;
+        self.modify(task_id, |task| change = record(task, status))?;
+        Ok(change)
+    }
+
+    fn delete_task(&self, task_id: &str) -> Result<DeletedTask> {
+        let mut inner = self.inner.lock().unwrap();
+        let index = inner
+            .tasks
+            .iter()
+            .position(|t| t.id == task_id)
+            .ok_or_else(|| anyhow::anyhow!("Task not found: {}", task_id))?;
+        Ok(DeletedTask {
+            task: inner.tasks.remove(index),
+            file: None,
+        })
+    }
+
+    fn restore_task(&self, deleted: &DeletedTask) -> Result<Task> {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.tasks.iter().any(|t| t.id == deleted.task.id) {
+            anyhow::bail!("Cannot restore, task already exists: {}", deleted.task.id);
+        }
+        inner.tasks.push(deleted.task.clone());
+        Ok(deleted.task.clone())
+    }
+
+    fn archive_tasks(&self, task_ids: &[String]) -> Result<usize> {
+        let mut inner = self.inner.lock().unwrap();
+        let (archived, kept) = std::mem::take(&mut inner.tasks)
+            .into_iter()
+            .partition::<Vec<_>, _>(|t| task_ids.contains(&t.id));
+        inner.tasks = kept;
+        let count = archived.len();
+        inner.archived.extend(archived);
+        Ok(count)
+    }
+
+    fn unarchive_task(&self, target: &str) -> Result<Task> {
+        let mut inner = self.inner.lock().unwrap();
+        let index = inner
+            .archived
+            .iter()
+            .position(|t| {
+                t.id == target
+                    || t.linear_issue_id
+                        .as_deref()
+                        .is_some_and(|id| id.eq_ignore_ascii_case(target))
+            })
+            .ok_or_else(|| anyhow::anyhow!("Archived task not found: {}", target))?;
+        let task = inner.archived.remove(index);
+        inner.tasks.push(task.clone());
+        Ok(task)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_changes_are_recorded_once() {
+        let store = MemoryTaskStore::new("test-project");
+        let task = store.create_task("Ship it", None).unwrap();
+
+        store
+            .set_task_status(&task.id, TaskStatus::Inprogress)
+            .unwrap();
+        let task = store
+            .set_task_status(&task.id, TaskStatus::Inprogress)
+            .unwrap();
+        assert_eq!(task.status, TaskStatus::Inprogress);
+        assert_eq!(task.status_history.len(), 1);
+
+        // Board-derived moves leave the stored status alone
+        let change = store
+            .record_status_change(&task.id, TaskStatus::Inreview)
+            .unwrap();
+        assert_eq!(change.map(|c| c.status), Some(TaskStatus::Inreview));
+        let task = &store.list_tasks().unwrap()[0];
+        assert_eq!(task.status, TaskStatus::Inprogress);
+        assert_eq!(task.last_recorded_status(), TaskStatus::Inreview);
+    }
+
+    #[test]
+    fn test_delete_restore_and_archive_round_trip() {
+        let store = MemoryTaskStore::new("test-project");
+        let keep = store.create_task("Keep", None).unwrap();
+        let gone = store
+            .create_task_from_linear(&LinearIssue {
+                identifier: "VIB-9".to_string(),
+                title: "Gone".to_string(),
+                description: None,
+                url: String::new(),
+                labels: vec!["bug".to_string()],
+                priority: Some(2),
+                estimate: Some(3.0),
+            })
+            .unwrap();
+        assert_eq!(gone.linear_labels.as_deref(), Some("bug"));
+        assert_eq!((gone.priority, gone.estimate), (Some(2), Some(3.0)));
+
+        let deleted = store.delete_task(&gone.id).unwrap();
+        assert_eq!(store.list_tasks().unwrap().len(), 1);
+        store.restore_task(&deleted).unwrap();
+        assert!(store.restore_task(&deleted).is_err());
+
+        assert_eq!(
+            store.archive_tasks(std::slice::from_ref(&gone.id)).unwrap(),
+            1
+        );
+        assert_eq!(store.list_tasks().unwrap()[0].id, keep.id);
+        assert_eq!(store.unarchive_task("vib-9").unwrap().id, gone.id);
+        assert_eq!(store.list_tasks().unwrap().len(), 2);
+    }
+}
@@ -1,3 +1,11 @@
+#[cfg(test)]
+mod memory;
+mod secrets;
+mod store;
 mod tasks;
 
+#[cfg(test)]
+pub use memory::MemoryTaskStore;
+pub use secrets::*;
+pub use store::TaskStore;
 pub use tasks::*;
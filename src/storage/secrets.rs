@@ -0,0 +1,83 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// Secrets saved by `vibe setup`, one `NAME = "value"` per line in
+/// `~/.vibe/secrets.toml`. An environment variable of the same name wins.
+pub fn secrets_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".vibe").join("secrets.toml"))
+}
+
+/// The value of `name` from the environment, else from the secrets file
+pub fn read_secret(name: &str) -> Result<String, std::env::VarError> {
+    std::env::var(name).or_else(|e| {
+        secrets_path()
+            .and_then(|path| load_from(&path).remove(name))
+            .ok_or(e)
+    })
+}
+
+/// Save `value` under `name`, keeping the other secrets. The file is only
+/// readable by the current user.
+pub fn save_secret(name: &str, value: &str) -> Result<()> {
+    let path = secrets_path().ok_or_else(|| anyhow::anyhow!("No home directory"))?;
+    save_to(&path, name, value)
+}
+
+fn load_from(path: &Path) -> BTreeMap<String, String> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return BTreeMap::new();
+    };
+    toml::from_str(&content).unwrap_or_else(|e| {
+        tracing::warn!("Ignoring unreadable secrets file {:?}: {}", path, e);
+        BTreeMap::new()
+    })
+}
+
+fn save_to(path: &Path, name: &str, value: &str) -> Result<()> {
+    let mut secrets = load_from(path);
+    secrets.insert(name.to_string(), value.to_string());
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create directory: {:?}", dir))?;
+    }
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options
+        .open(path)
+        .with_context(|| format!("Failed to write secrets: {:?}", path))?;
+    std::io::Write::write_all(&mut file, toml::to_string(&secrets)?.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_secret_keeps_others() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secrets.toml");
+        assert!(load_from(&path).is_empty());
+
+        save_to(&path, "APP_LINEAR_API_KEY", "lin_api_1").unwrap();
+        save_to(&path, "OTHER_LINEAR_API_KEY", "lin_api_2").unwrap();
+        save_to(&path, "APP_LINEAR_API_KEY", "lin_api_3").unwrap();
+
+        let secrets = load_from(&path);
+        assert_eq!(secrets.len(), 2);
+        assert_eq!(secrets["APP_LINEAR_API_KEY"], "lin_api_3");
+        assert_eq!(secrets["OTHER_LINEAR_API_KEY"], "lin_api_2");
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+}
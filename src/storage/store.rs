@@ -0,0 +1,98 @@
+#![allow(dead_code)]
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use super::tasks::{DeletedTask, ImportFrontmatter, split_import_frontmatter, unslugify};
+use crate::external::{GhIssue, LinearIssue};
+use crate::state::{PlanPhase, StatusChange, Task, TaskStatus};
+
+/// Where a project's tasks live. [`TaskStorage`](super::TaskStorage) keeps them as
+/// markdown files; tests can use the in-memory `MemoryTaskStore` instead.
+pub trait TaskStore {
+    /// Project the tasks belong to
+    fn project_name(&self) -> &str;
+
+    /// List all (unarchived) tasks, newest first
+    fn list_tasks(&self) -> Result<Vec<Task>>;
+
+    /// Create a new task
+    fn create_task(&self, title: &str, description: Option<&str>) -> Result<Task>;
+
+    /// Create a task from a Linear issue
+    fn create_task_from_linear(&self, issue: &LinearIssue) -> Result<Task>;
+
+    /// Create a task from a GitHub issue, keeping its number and URL for linking back
+    fn create_task_from_github(&self, issue: &GhIssue) -> Result<Task>;
+
+    /// Apply the status, labels and priority from an imported file's front-matter.
+    /// Labels are merged into any the task already has.
+    fn apply_import_frontmatter(&self, task_id: &str, import: &ImportFrontmatter) -> Result<Task>;
+
+    /// Update an existing task
+    fn update_task(&self, task_id: &str, title: &str, description: Option<&str>) -> Result<Task>;
+
+    /// Link an existing task to a Linear issue (e.g. one just created for it)
+    fn link_linear_issue(&self, task_id: &str, identifier: &str, url: &str) -> Result<Task>;
+
+    /// Remember the PR a task's branch opened, so its link is only posted to Linear once
+    fn record_pr_url(&self, task_id: &str, url: &str) -> Result<Task>;
+
+    /// Replace a task's private notes; None or blank clears them
+    fn set_task_notes(&self, task_id: &str, notes: Option<&str>) -> Result<Task>;
+
+    /// Bind a task to `branch` instead of the one derived from its title; None unbinds
+    fn set_branch_override(&self, task_id: &str, branch: Option<&str>) -> Result<Task>;
+
+    /// Record which half of the plan-then-execute flow was last launched
+    fn set_plan_phase(&self, task_id: &str, phase: PlanPhase) -> Result<Task>;
+
+    /// Set the locally stored status of a task
+    fn set_task_status(&self, task_id: &str, status: TaskStatus) -> Result<Task>;
+
+    /// Record a move into `status` that the board derived (PR, worktree or Linear)
+    /// without changing the stored status. Returns the new entry, or None when
+    /// `status` is already the last recorded one.
+    fn record_status_change(
+        &self,
+        task_id: &str,
+        status: TaskStatus,
+    ) -> Result<Option<StatusChange>>;
+
+    /// Delete a task, returning what is needed to undo the deletion
+    fn delete_task(&self, task_id: &str) -> Result<DeletedTask>;
+
+    /// Put a deleted task back exactly as it was (same id, status and history)
+    fn restore_task(&self, deleted: &DeletedTask) -> Result<Task>;
+
+    /// Archive tasks, hiding them from `list_tasks`.
+    /// Returns the number of tasks archived.
+    fn archive_tasks(&self, task_ids: &[String]) -> Result<usize>;
+
+    /// Restore an archived task by ID or Linear identifier
+    fn unarchive_task(&self, target: &str) -> Result<Task>;
+
+    /// Create a task from a markdown file.
+    /// Filename (minus extension) becomes title, contents become description,
+    /// unless front-matter in the file says otherwise.
+    fn create_task_from_file(&self, path: &Path) -> Result<Task> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read file: {:?}", path))?;
+
+        let slug = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| anyhow::anyhow!("Invalid file name"))?;
+
+        let (import, body) = split_import_frontmatter(&content);
+        let title = import.title.clone().unwrap_or_else(|| unslugify(slug));
+        let description = (!body.trim().is_empty()).then_some(body);
+
+        let task = self.create_task(&title, description)?;
+        if import.sets_fields() {
+            return self.apply_import_frontmatter(&task.id, &import);
+        }
+        Ok(task)
+    }
+}
@@ -2,10 +2,13 @@
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
 use std::path::{Path, PathBuf};
 
-use crate::external::LinearIssue;
-use crate::state::{Task, TaskStatus};
+use super::TaskStore;
+use crate::external::{GhIssue, LinearIssue};
+use crate::state::{PlanPhase, StatusChange, Task, TaskStatus, push_status_change};
 
 /// File-based task storage.
 /// Tasks are stored as markdown files in ~/.vibe/projects/{project}/tasks/
@@ -25,7 +28,171 @@ pub struct TaskFrontmatter {
     pub linear_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub linear_labels: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github_issue: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github_url: Option<String>,
     pub created: String,
+    /// Locally stored status; the board derives status from PR/Linear when available
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<TaskStatus>,
+    /// Local status transitions, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub history: Vec<StatusChange>,
+    /// Priority from Linear (0-4) or an imported file's front-matter (free-form;
+    /// see `parse_priority` for what shows on the board)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    /// Private notes, edited with `N`; local only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    /// Last launch of the plan-then-execute flow
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan_phase: Option<PlanPhase>,
+    /// Branch bound with `b` or `vibe bind`, used instead of the title-derived one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_override: Option<String>,
+    /// First PR seen for the task, recorded once its link was posted to Linear
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pr_url: Option<String>,
+    /// Linear estimate in points
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<f64>,
+}
+
+impl TaskFrontmatter {
+    /// Append a transition into `status` unless it is already the last recorded one
+    fn record(&mut self, status: TaskStatus) -> Option<StatusChange> {
+        let last = self
+            .history
+            .last()
+            .map(|change| change.status)
+            .or(self.status)
+            .unwrap_or(TaskStatus::Backlog);
+        if last == status {
+            return None;
+        }
+        let change = StatusChange {
+            status,
+            at: chrono::Utc::now(),
+        };
+        push_status_change(&mut self.history, change.clone());
+        Some(change)
+    }
+}
+
+/// Shown when vibe is started outside a git repository
+pub const NOT_A_GIT_REPO: &str =
+    "vibe must be run inside a git repository; cd into your project or pass --repo";
+
+/// Whether `dir` is inside a git work tree (including linked worktrees)
+pub fn is_git_repo(dir: &Path) -> bool {
+    std::process::Command::new("git")
+        .args(["rev-parse", "--is-inside-work-tree"])
+        .current_dir(dir)
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Optional YAML front-matter at the top of a file passed to `vibe import`
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct ImportFrontmatter {
+    pub title: Option<String>,
+    pub status: Option<TaskStatus>,
+    #[serde(default)]
+    labels: Option<ImportLabels>,
+    #[serde(default, deserialize_with = "scalar_string")]
+    pub priority: Option<String>,
+}
+
+/// Labels may be a YAML list or a comma-separated string
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+enum ImportLabels {
+    List(Vec<String>),
+    Csv(String),
+}
+
+/// Priority on Linear's scale (0 none, 1 urgent .. 4 low) from a task file's
+/// `priority:`, which is Linear's number or a name like `high`; None for anything else
+pub fn parse_priority(value: &str) -> Option<u8> {
+    match value.trim().to_lowercase().as_str() {
+        "0" | "none" | "no priority" => Some(0),
+        "1" | "urgent" => Some(1),
+        "2" | "high" => Some(2),
+        "3" | "medium" | "normal" => Some(3),
+        "4" | "low" => Some(4),
+        _ => None,
+    }
+}
+
+/// Accept `priority: high` as well as `priority: 1`
+fn scalar_string<'de, D>(deserializer: D) -> std::result::Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(
+        match Option::<serde_yaml::Value>::deserialize(deserializer)? {
+            Some(serde_yaml::Value::String(s)) => Some(s),
+            Some(serde_yaml::Value::Number(n)) => Some(n.to_string()),
+            Some(serde_yaml::Value::Bool(b)) => Some(b.to_string()),
+            _ => None,
+        },
+    )
+}
+
+impl ImportFrontmatter {
+    pub fn labels(&self) -> Vec<String> {
+        let labels = match &self.labels {
+            Some(ImportLabels::List(list)) => list.clone(),
+            Some(ImportLabels::Csv(csv)) => csv.split(',').map(String::from).collect(),
+            None => Vec::new(),
+        };
+        labels
+            .into_iter()
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .collect()
+    }
+
+    /// Whether there is anything to apply beyond the title
+    pub fn sets_fields(&self) -> bool {
+        self.status.is_some() || self.priority.is_some() || !self.labels().is_empty()
+    }
+}
+
+/// Split optional front-matter off an imported file, returning it with the
+/// remaining body. Content without front-matter, or whose front-matter doesn't
+/// parse, is returned whole so it still becomes the description.
+pub fn split_import_frontmatter(content: &str) -> (ImportFrontmatter, &str) {
+    let plain = (ImportFrontmatter::default(), content);
+    let Some(rest) = content
+        .strip_prefix("---\n")
+        .or_else(|| content.strip_prefix("---\r\n"))
+    else {
+        return plain;
+    };
+    let Some(end) = rest.find("\n---") else {
+        return plain;
+    };
+    let (closing, body) = rest[end + 4..]
+        .split_once('\n')
+        .unwrap_or((&rest[end + 4..], ""));
+    if !closing.trim().is_empty() {
+        return plain;
+    }
+    match serde_yaml::from_str::<ImportFrontmatter>(&rest[..end]) {
+        Ok(frontmatter) => (frontmatter, body.trim_start()),
+        Err(_) => plain,
+    }
+}
+
+/// A deleted task, kept in memory for undo
+#[derive(Debug, Clone)]
+pub struct DeletedTask {
+    pub(super) task: Task,
+    /// Path and exact contents of the task file, for file-backed storage
+    pub(super) file: Option<(PathBuf, String)>,
 }
 
 impl TaskStorage {
@@ -38,7 +205,7 @@ impl TaskStorage {
 
     /// Resolve project name, handling git worktrees.
     /// Uses `git rev-parse --git-common-dir` to find the main repo.
-    fn resolve_project_name() -> Result<String> {
+    pub fn resolve_project_name() -> Result<String> {
         let cwd = std::env::current_dir().context("Failed to get current directory")?;
 
         // Try to get the git common directory (shared across worktrees)
@@ -103,16 +270,174 @@ impl TaskStorage {
         })
     }
 
-    pub fn project_name(&self) -> &str {
-        &self.project_name
-    }
-
     pub fn tasks_dir(&self) -> &PathBuf {
         &self.tasks_dir
     }
 
-    /// List all tasks from markdown files
-    pub fn list_tasks(&self) -> Result<Vec<Task>> {
+    /// Take an exclusive advisory lock on the tasks directory.
+    /// Held for the whole read-modify-write so a background `vibe` process and
+    /// the TUI serialize instead of clobbering each other. Released on drop.
+    fn lock(&self) -> Result<File> {
+        let lock_path = self.tasks_dir.join(".lock");
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&lock_path)
+            .with_context(|| format!("Failed to open lock file: {:?}", lock_path))?;
+        file.lock()
+            .with_context(|| format!("Failed to lock tasks directory: {:?}", lock_path))?;
+        Ok(file)
+    }
+
+    /// Find task file by ID
+    fn find_task_file(&self, task_id: &str) -> Result<(PathBuf, TaskFrontmatter)> {
+        let pattern = format!("{}/*.md", self.tasks_dir.display());
+        for entry in glob::glob(&pattern).context("Failed to read glob pattern")? {
+            let path = entry?;
+            if let Ok((frontmatter, _, _)) = self.parse_task_content(&path)
+                && frontmatter.id == task_id
+            {
+                return Ok((path, frontmatter));
+            }
+        }
+        anyhow::bail!("Task not found: {}", task_id)
+    }
+
+    /// Apply `change` to the task's frontmatter under one lock and return its new state
+    fn rewrite_frontmatter(
+        &self,
+        task_id: &str,
+        change: impl FnOnce(&mut TaskFrontmatter),
+    ) -> Result<Task> {
+        let _lock = self.lock()?;
+        let (path, mut frontmatter) = self.find_task_file(task_id)?;
+        change(&mut frontmatter);
+        self.write_frontmatter(&path, &frontmatter)?;
+        self.parse_task(&path)
+    }
+
+    /// Rewrite a task file's frontmatter, keeping its title and description
+    fn write_frontmatter(&self, path: &PathBuf, frontmatter: &TaskFrontmatter) -> Result<()> {
+        let (_, title, description) = self.parse_task_content(path)?;
+        let content = format!(
+            "---\n{}---\n\n# {}\n\n{}",
+            serde_yaml::to_string(frontmatter).unwrap_or_default(),
+            title,
+            description.as_deref().unwrap_or("")
+        );
+        write_atomic(path, &content)
+    }
+
+    /// Parse a task from a markdown file
+    fn parse_task(&self, path: &PathBuf) -> Result<Task> {
+        let (frontmatter, title, description) = self.parse_task_content(path)?;
+
+        Ok(Task {
+            id: frontmatter.id,
+            project_id: self.project_name.clone(),
+            title,
+            description,
+            // Effective status is derived from git/PR/Linear; this is the local fallback
+            status: frontmatter.status.unwrap_or(TaskStatus::Backlog),
+            parent_workspace_id: None,
+            shared_task_id: None,
+            linear_issue_id: frontmatter.linear_id,
+            linear_url: frontmatter.linear_url,
+            linear_labels: frontmatter.linear_labels,
+            github_issue: frontmatter.github_issue,
+            github_url: frontmatter.github_url,
+            created_at: frontmatter.created.clone(),
+            updated_at: frontmatter.created,
+            has_in_progress_attempt: false,
+            last_attempt_failed: false,
+            executor: String::new(),
+            pr_url: frontmatter.pr_url,
+            pr_status: None,
+            pr_is_draft: None,
+            pr_review_decision: None,
+            pr_checks_status: None,
+            pr_has_conflicts: None,
+            status_history: frontmatter.history,
+            notes: frontmatter.notes,
+            plan_phase: frontmatter.plan_phase,
+            branch_override: frontmatter.branch_override,
+            priority: frontmatter.priority.as_deref().and_then(parse_priority),
+            estimate: frontmatter.estimate,
+        })
+    }
+
+    /// Parse task content from a file
+    fn parse_task_content(
+        &self,
+        path: &PathBuf,
+    ) -> Result<(TaskFrontmatter, String, Option<String>)> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read task file: {:?}", path))?;
+
+        // Parse frontmatter, closed by the first line starting with "---" (notes may
+        // contain "---" too, but YAML indents them inside the block)
+        let split = content.strip_prefix("---").and_then(|rest| {
+            rest.find("\n---")
+                .map(|end| (&rest[..end], &rest[end + "\n---".len()..]))
+        });
+        let (frontmatter, body) = match split {
+            Some((yaml, body)) => {
+                let yaml = yaml.trim();
+                let body = body.trim();
+                let fm: TaskFrontmatter =
+                    serde_yaml::from_str(yaml).unwrap_or_else(|_| TaskFrontmatter {
+                        id: uuid::Uuid::new_v4().to_string(),
+                        linear_id: None,
+                        linear_url: None,
+                        linear_labels: None,
+                        github_issue: None,
+                        github_url: None,
+                        created: chrono::Utc::now().format("%Y-%m-%d").to_string(),
+                        status: None,
+                        history: Vec::new(),
+                        priority: None,
+                        notes: None,
+                        plan_phase: None,
+                        branch_override: None,
+                        pr_url: None,
+                        estimate: None,
+                    });
+                (fm, body.to_string())
+            }
+            None => (TaskFrontmatter::default(), content),
+        };
+
+        // Parse title from first heading
+        let mut lines = body.lines();
+        let title = lines
+            .find(|line| line.starts_with('#'))
+            .map(|line| line.trim_start_matches('#').trim().to_string())
+            .unwrap_or_else(|| {
+                path.file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("Untitled")
+                    .to_string()
+            });
+
+        // Rest is description
+        let description: String = lines.collect::<Vec<_>>().join("\n").trim().to_string();
+        let description = if description.is_empty() {
+            None
+        } else {
+            Some(description)
+        };
+
+        Ok((frontmatter, title, description))
+    }
+}
+
+impl TaskStore for TaskStorage {
+    fn project_name(&self) -> &str {
+        &self.project_name
+    }
+
+    fn list_tasks(&self) -> Result<Vec<Task>> {
         let pattern = format!("{}/*.md", self.tasks_dir.display());
         let paths: Vec<PathBuf> = glob::glob(&pattern)
             .context("Failed to read glob pattern")?
@@ -135,10 +460,10 @@ impl TaskStorage {
         Ok(tasks)
     }
 
-    /// Create a new task
-    pub fn create_task(&self, title: &str, description: Option<&str>) -> Result<Task> {
+    fn create_task(&self, title: &str, description: Option<&str>) -> Result<Task> {
         let id = uuid::Uuid::new_v4().to_string();
         let slug = slugify(title);
+        let _lock = self.lock()?;
         let path = self.tasks_dir.join(format!("{}.md", slug));
 
         // Handle duplicate filenames
@@ -155,7 +480,17 @@ impl TaskStorage {
             linear_id: None,
             linear_url: None,
             linear_labels: None,
+            github_issue: None,
+            github_url: None,
             created: created.clone(),
+            status: None,
+            history: Vec::new(),
+            priority: None,
+            notes: None,
+            plan_phase: None,
+            branch_override: None,
+            pr_url: None,
+            estimate: None,
         };
 
         let content = format!(
@@ -165,8 +500,7 @@ impl TaskStorage {
             description.unwrap_or("")
         );
 
-        std::fs::write(&path, &content)
-            .with_context(|| format!("Failed to write task file: {:?}", path))?;
+        write_atomic(&path, &content)?;
 
         Ok(Task {
             id,
@@ -179,6 +513,8 @@ impl TaskStorage {
             linear_issue_id: None,
             linear_url: None,
             linear_labels: None,
+            github_issue: None,
+            github_url: None,
             created_at: created.clone(),
             updated_at: created,
             has_in_progress_attempt: false,
@@ -190,13 +526,19 @@ impl TaskStorage {
             pr_review_decision: None,
             pr_checks_status: None,
             pr_has_conflicts: None,
+            status_history: Vec::new(),
+            notes: None,
+            plan_phase: None,
+            branch_override: None,
+            priority: None,
+            estimate: None,
         })
     }
 
-    /// Create a task from a Linear issue
-    pub fn create_task_from_linear(&self, issue: &LinearIssue) -> Result<Task> {
+    fn create_task_from_linear(&self, issue: &LinearIssue) -> Result<Task> {
         let id = uuid::Uuid::new_v4().to_string();
         let slug = slugify(&issue.title);
+        let _lock = self.lock()?;
         let path = self.tasks_dir.join(format!("{}.md", slug));
 
         // Handle duplicate filenames
@@ -214,12 +556,24 @@ impl TaskStorage {
             Some(issue.labels.join(", "))
         };
 
+        // Branch-derived tasks know the identifier but not the issue URL
+        let linear_url = (!issue.url.is_empty()).then(|| issue.url.clone());
         let frontmatter = TaskFrontmatter {
             id: id.clone(),
             linear_id: Some(issue.identifier.clone()),
-            linear_url: Some(issue.url.clone()),
+            linear_url: linear_url.clone(),
             linear_labels: labels_str.clone(),
+            github_issue: None,
+            github_url: None,
             created: created.clone(),
+            status: None,
+            history: Vec::new(),
+            priority: issue.priority.map(|p| p.to_string()),
+            notes: None,
+            plan_phase: None,
+            branch_override: None,
+            pr_url: None,
+            estimate: issue.estimate,
         };
 
         let content = format!(
@@ -229,8 +583,7 @@ impl TaskStorage {
             issue.description.as_deref().unwrap_or("")
         );
 
-        std::fs::write(&path, &content)
-            .with_context(|| format!("Failed to write task file: {:?}", path))?;
+        write_atomic(&path, &content)?;
 
         Ok(Task {
             id,
@@ -241,8 +594,10 @@ impl TaskStorage {
             parent_workspace_id: None,
             shared_task_id: None,
             linear_issue_id: Some(issue.identifier.clone()),
-            linear_url: Some(issue.url.clone()),
+            linear_url,
             linear_labels: labels_str,
+            github_issue: None,
+            github_url: None,
             created_at: created.clone(),
             updated_at: created,
             has_in_progress_attempt: false,
@@ -254,37 +609,129 @@ impl TaskStorage {
             pr_review_decision: None,
             pr_checks_status: None,
             pr_has_conflicts: None,
+            status_history: Vec::new(),
+            notes: None,
+            plan_phase: None,
+            branch_override: None,
+            priority: issue.priority,
+            estimate: issue.estimate,
         })
     }
 
-    /// Create a task from a markdown file
-    /// Filename (minus extension) becomes title, contents become description
-    pub fn create_task_from_file(&self, path: &Path) -> Result<Task> {
-        let content = std::fs::read_to_string(path)
-            .with_context(|| format!("Failed to read file: {:?}", path))?;
+    fn create_task_from_github(&self, issue: &GhIssue) -> Result<Task> {
+        let id = uuid::Uuid::new_v4().to_string();
+        let slug = slugify(&issue.title);
+        let _lock = self.lock()?;
+        let path = self.tasks_dir.join(format!("{}.md", slug));
 
-        let slug = path
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .ok_or_else(|| anyhow::anyhow!("Invalid file name"))?;
+        // Handle duplicate filenames
+        let path = if path.exists() {
+            let short_id = &id[..8];
+            self.tasks_dir.join(format!("{}-{}.md", slug, short_id))
+        } else {
+            path
+        };
 
-        let title = unslugify(slug);
-        let description = if content.trim().is_empty() {
+        let created = chrono::Utc::now().format("%Y-%m-%d").to_string();
+        let labels_str = if issue.labels.is_empty() {
             None
         } else {
-            Some(content)
+            Some(
+                issue
+                    .labels
+                    .iter()
+                    .map(|l| l.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )
         };
+        let description = (!issue.body.trim().is_empty()).then(|| issue.body.clone());
+
+        let frontmatter = TaskFrontmatter {
+            id: id.clone(),
+            linear_id: None,
+            linear_url: None,
+            linear_labels: labels_str.clone(),
+            github_issue: Some(issue.number),
+            github_url: Some(issue.url.clone()),
+            created: created.clone(),
+            status: None,
+            history: Vec::new(),
+            priority: None,
+            notes: None,
+            plan_phase: None,
+            branch_override: None,
+            pr_url: None,
+            estimate: None,
+        };
+
+        let content = format!(
+            "---\n{}---\n\n# {}\n\n{}",
+            serde_yaml::to_string(&frontmatter).unwrap_or_default(),
+            issue.title,
+            description.as_deref().unwrap_or("")
+        );
+
+        write_atomic(&path, &content)?;
+
+        Ok(Task {
+            id,
+            project_id: self.project_name.clone(),
+            title: issue.title.clone(),
+            description,
+            status: TaskStatus::Backlog,
+            parent_workspace_id: None,
+            shared_task_id: None,
+            linear_issue_id: None,
+            linear_url: None,
+            linear_labels: labels_str,
+            github_issue: Some(issue.number),
+            github_url: Some(issue.url.clone()),
+            created_at: created.clone(),
+            updated_at: created,
+            has_in_progress_attempt: false,
+            last_attempt_failed: false,
+            executor: String::new(),
+            pr_url: None,
+            pr_status: None,
+            pr_is_draft: None,
+            pr_review_decision: None,
+            pr_checks_status: None,
+            pr_has_conflicts: None,
+            status_history: Vec::new(),
+            notes: None,
+            plan_phase: None,
+            branch_override: None,
+            priority: None,
+            estimate: None,
+        })
+    }
 
-        self.create_task(&title, description.as_deref())
+    fn apply_import_frontmatter(&self, task_id: &str, import: &ImportFrontmatter) -> Result<Task> {
+        self.rewrite_frontmatter(task_id, |fm| {
+            if let Some(status) = import.status {
+                fm.record(status);
+                fm.status = Some(status);
+            }
+            let mut labels: Vec<String> = fm
+                .linear_labels
+                .as_deref()
+                .map(|l| l.split(", ").map(String::from).collect())
+                .unwrap_or_default();
+            for label in import.labels() {
+                if !labels.contains(&label) {
+                    labels.push(label);
+                }
+            }
+            fm.linear_labels = (!labels.is_empty()).then(|| labels.join(", "));
+            if import.priority.is_some() {
+                fm.priority = import.priority.clone();
+            }
+        })
     }
 
-    /// Update an existing task
-    pub fn update_task(
-        &self,
-        task_id: &str,
-        title: &str,
-        description: Option<&str>,
-    ) -> Result<Task> {
+    fn update_task(&self, task_id: &str, title: &str, description: Option<&str>) -> Result<Task> {
+        let _lock = self.lock()?;
         let (path, mut frontmatter) = self.find_task_file(task_id)?;
 
         let content = format!(
@@ -294,8 +741,7 @@ impl TaskStorage {
             description.unwrap_or("")
         );
 
-        std::fs::write(&path, &content)
-            .with_context(|| format!("Failed to write task file: {:?}", path))?;
+        write_atomic(&path, &content)?;
 
         // Rename file if title changed significantly
         let new_slug = slugify(title);
@@ -314,41 +760,118 @@ impl TaskStorage {
             project_id: self.project_name.clone(),
             title: title.to_string(),
             description: description.map(String::from),
-            status: TaskStatus::Backlog,
+            status: frontmatter.status.unwrap_or(TaskStatus::Backlog),
             parent_workspace_id: None,
             shared_task_id: None,
             linear_issue_id: frontmatter.linear_id.take(),
             linear_url: frontmatter.linear_url.take(),
             linear_labels: frontmatter.linear_labels.take(),
+            github_issue: frontmatter.github_issue,
+            github_url: frontmatter.github_url.take(),
             created_at: frontmatter.created.clone(),
             updated_at: chrono::Utc::now().format("%Y-%m-%d").to_string(),
             has_in_progress_attempt: false,
             last_attempt_failed: false,
             executor: String::new(),
-            pr_url: None,
+            pr_url: frontmatter.pr_url.take(),
             pr_status: None,
             pr_is_draft: None,
             pr_review_decision: None,
             pr_checks_status: None,
             pr_has_conflicts: None,
+            status_history: std::mem::take(&mut frontmatter.history),
+            notes: frontmatter.notes.take(),
+            plan_phase: frontmatter.plan_phase,
+            branch_override: frontmatter.branch_override.take(),
+            priority: frontmatter.priority.as_deref().and_then(parse_priority),
+            estimate: frontmatter.estimate,
         })
     }
 
-    /// Delete a task by ID
-    pub fn delete_task(&self, task_id: &str) -> Result<()> {
+    fn link_linear_issue(&self, task_id: &str, identifier: &str, url: &str) -> Result<Task> {
+        self.rewrite_frontmatter(task_id, |fm| {
+            fm.linear_id = Some(identifier.to_string());
+            fm.linear_url = (!url.is_empty()).then(|| url.to_string());
+        })
+    }
+
+    fn record_pr_url(&self, task_id: &str, url: &str) -> Result<Task> {
+        self.rewrite_frontmatter(task_id, |fm| fm.pr_url = Some(url.to_string()))
+    }
+
+    fn set_task_notes(&self, task_id: &str, notes: Option<&str>) -> Result<Task> {
+        self.rewrite_frontmatter(task_id, |fm| {
+            fm.notes = notes
+                .map(str::trim)
+                .filter(|n| !n.is_empty())
+                .map(String::from);
+        })
+    }
+
+    fn set_branch_override(&self, task_id: &str, branch: Option<&str>) -> Result<Task> {
+        self.rewrite_frontmatter(task_id, |fm| fm.branch_override = branch.map(String::from))
+    }
+
+    fn set_plan_phase(&self, task_id: &str, phase: PlanPhase) -> Result<Task> {
+        self.rewrite_frontmatter(task_id, |fm| fm.plan_phase = Some(phase))
+    }
+
+    fn set_task_status(&self, task_id: &str, status: TaskStatus) -> Result<Task> {
+        self.rewrite_frontmatter(task_id, |fm| {
+            fm.record(status);
+            fm.status = Some(status);
+        })
+    }
+
+    fn record_status_change(
+        &self,
+        task_id: &str,
+        status: TaskStatus,
+    ) -> Result<Option<StatusChange>> {
+        let _lock = self.lock()?;
+        let (path, mut frontmatter) = self.find_task_file(task_id)?;
+        let Some(change) = frontmatter.record(status) else {
+            return Ok(None);
+        };
+        self.write_frontmatter(&path, &frontmatter)?;
+        Ok(Some(change))
+    }
+
+    fn delete_task(&self, task_id: &str) -> Result<DeletedTask> {
+        let _lock = self.lock()?;
         let (path, _) = self.find_task_file(task_id)?;
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read task file: {:?}", path))?;
+        let task = self.parse_task(&path)?;
         std::fs::remove_file(&path)
             .with_context(|| format!("Failed to delete task file: {:?}", path))?;
-        Ok(())
+        Ok(DeletedTask {
+            task,
+            file: Some((path, content)),
+        })
     }
 
-    /// Archive tasks by moving their files to an archive/ subdirectory.
-    /// Returns the number of tasks archived.
-    pub fn archive_tasks(&self, task_ids: &[String]) -> Result<usize> {
+    fn restore_task(&self, deleted: &DeletedTask) -> Result<Task> {
+        let Some((path, content)) = &deleted.file else {
+            anyhow::bail!(
+                "Cannot restore {}: it was not deleted from disk",
+                deleted.task.id
+            );
+        };
+        let _lock = self.lock()?;
+        if path.exists() {
+            anyhow::bail!("Cannot restore, file already exists: {:?}", path);
+        }
+        write_atomic(path, content)?;
+        self.parse_task(path)
+    }
+
+    fn archive_tasks(&self, task_ids: &[String]) -> Result<usize> {
         if task_ids.is_empty() {
             return Ok(0);
         }
 
+        let _lock = self.lock()?;
         let archive_dir = self.tasks_dir.join("archive");
         std::fs::create_dir_all(&archive_dir)
             .with_context(|| format!("Failed to create archive directory: {:?}", archive_dir))?;
@@ -373,124 +896,75 @@ impl TaskStorage {
         Ok(archived)
     }
 
-    /// Find task file by ID
-    fn find_task_file(&self, task_id: &str) -> Result<(PathBuf, TaskFrontmatter)> {
-        let pattern = format!("{}/*.md", self.tasks_dir.display());
+    fn unarchive_task(&self, target: &str) -> Result<Task> {
+        let _lock = self.lock()?;
+        let archive_dir = self.tasks_dir.join("archive");
+        let pattern = format!("{}/*.md", archive_dir.display());
         for entry in glob::glob(&pattern).context("Failed to read glob pattern")? {
             let path = entry?;
-            if let Ok((frontmatter, _, _)) = self.parse_task_content(&path)
-                && frontmatter.id == task_id
-            {
-                return Ok((path, frontmatter));
+            let Ok((frontmatter, _, _)) = self.parse_task_content(&path) else {
+                continue;
+            };
+            let matches = frontmatter.id == target
+                || frontmatter
+                    .linear_id
+                    .as_deref()
+                    .is_some_and(|id| id.eq_ignore_ascii_case(target));
+            if !matches {
+                continue;
             }
-        }
-        anyhow::bail!("Task not found: {}", task_id)
-    }
-
-    /// Parse a task from a markdown file
-    fn parse_task(&self, path: &PathBuf) -> Result<Task> {
-        let (frontmatter, title, description) = self.parse_task_content(path)?;
-
-        Ok(Task {
-            id: frontmatter.id,
-            project_id: self.project_name.clone(),
-            title,
-            description,
-            status: TaskStatus::Backlog, // Status derived from git/PR state
-            parent_workspace_id: None,
-            shared_task_id: None,
-            linear_issue_id: frontmatter.linear_id,
-            linear_url: frontmatter.linear_url,
-            linear_labels: frontmatter.linear_labels,
-            created_at: frontmatter.created.clone(),
-            updated_at: frontmatter.created,
-            has_in_progress_attempt: false,
-            last_attempt_failed: false,
-            executor: String::new(),
-            pr_url: None,
-            pr_status: None,
-            pr_is_draft: None,
-            pr_review_decision: None,
-            pr_checks_status: None,
-            pr_has_conflicts: None,
-        })
-    }
 
-    /// Parse task content from a file
-    fn parse_task_content(
-        &self,
-        path: &PathBuf,
-    ) -> Result<(TaskFrontmatter, String, Option<String>)> {
-        let content = std::fs::read_to_string(path)
-            .with_context(|| format!("Failed to read task file: {:?}", path))?;
-
-        // Parse frontmatter
-        let (frontmatter, body) = if content.starts_with("---") {
-            let parts: Vec<&str> = content.splitn(3, "---").collect();
-            if parts.len() >= 3 {
-                let yaml = parts[1].trim();
-                let body = parts[2].trim();
-                let fm: TaskFrontmatter =
-                    serde_yaml::from_str(yaml).unwrap_or_else(|_| TaskFrontmatter {
-                        id: uuid::Uuid::new_v4().to_string(),
-                        linear_id: None,
-                        linear_url: None,
-                        linear_labels: None,
-                        created: chrono::Utc::now().format("%Y-%m-%d").to_string(),
-                    });
-                (fm, body.to_string())
-            } else {
-                (TaskFrontmatter::default(), content)
+            let filename = path.file_name().unwrap();
+            let dest = self.tasks_dir.join(filename);
+            if dest.exists() {
+                anyhow::bail!("Cannot unarchive: {:?} already exists", dest);
             }
-        } else {
-            (TaskFrontmatter::default(), content)
-        };
-
-        // Parse title from first heading
-        let mut lines = body.lines();
-        let title = lines
-            .find(|line| line.starts_with('#'))
-            .map(|line| line.trim_start_matches('#').trim().to_string())
-            .unwrap_or_else(|| {
-                path.file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("Untitled")
-                    .to_string()
-            });
-
-        // Rest is description
-        let description: String = lines.collect::<Vec<_>>().join("\n").trim().to_string();
-        let description = if description.is_empty() {
-            None
-        } else {
-            Some(description)
-        };
-
-        Ok((frontmatter, title, description))
+            std::fs::rename(&path, &dest).with_context(|| {
+                format!("Failed to unarchive task file: {:?} -> {:?}", path, dest)
+            })?;
+            return self.parse_task(&dest);
+        }
+        anyhow::bail!("Archived task not found: {}", target)
     }
 }
 
+/// Write a file by writing a temp file in the same directory and renaming it over
+/// the target, so readers never observe a half-written task.
+fn write_atomic(path: &Path, content: &str) -> Result<()> {
+    let dir = path
+        .parent()
+        .ok_or_else(|| anyhow::anyhow!("Invalid task path: {:?}", path))?;
+    let mut tmp = tempfile::NamedTempFile::new_in(dir)
+        .with_context(|| format!("Failed to create temp file in {:?}", dir))?;
+    tmp.write_all(content.as_bytes())
+        .and_then(|_| tmp.as_file().sync_all())
+        .with_context(|| format!("Failed to write task file: {:?}", path))?;
+    tmp.persist(path)
+        .with_context(|| format!("Failed to write task file: {:?}", path))?;
+    Ok(())
+}
+
 /// Convert a title to a filename-safe slug
 fn slugify(title: &str) -> String {
-    title
-        .to_lowercase()
-        .chars()
-        .map(|c| if c.is_alphanumeric() { c } else { '-' })
-        .collect::<String>()
-        .split('-')
-        .filter(|s| !s.is_empty())
-        .collect::<Vec<_>>()
-        .join("-")
+    let slug = crate::state::slugify(title);
+    // Titles made only of emoji/non-Latin script have no ASCII slug
+    if slug.is_empty() {
+        "untitled".to_string()
+    } else {
+        slug
+    }
 }
 
 /// Convert a slug back to a title (inverse of slugify)
-fn unslugify(slug: &str) -> String {
+pub(super) fn unslugify(slug: &str) -> String {
     slug.replace('-', " ")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::STATUS_HISTORY_LIMIT;
+    use std::collections::HashMap;
 
     #[test]
     fn test_slugify() {
@@ -498,6 +972,8 @@ mod tests {
         assert_eq!(slugify("Add feature: user auth"), "add-feature-user-auth");
         assert_eq!(slugify("Fix bug #123"), "fix-bug-123");
         assert_eq!(slugify("  Spaces  everywhere  "), "spaces-everywhere");
+        assert_eq!(slugify("Résumé ✨ polish"), "resume-polish");
+        assert_eq!(slugify("🎉🎉"), "untitled");
     }
 
     #[test]
@@ -533,6 +1009,134 @@ mod tests {
         assert!(task.description.is_none());
     }
 
+    #[test]
+    fn test_create_task_from_file_with_frontmatter() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskStorage::new_with_base(dir.path(), "test-project").unwrap();
+
+        let file_path = dir.path().join("draft.md");
+        std::fs::write(
+            &file_path,
+            "---\ntitle: Fix login redirect\nstatus: todo\nlabels: [bug, auth]\npriority: 2\n---\n\nUsers land on /404.\n",
+        )
+        .unwrap();
+
+        let task = storage.create_task_from_file(&file_path).unwrap();
+        assert_eq!(task.title, "Fix login redirect");
+        assert_eq!(task.description.as_deref(), Some("Users land on /404."));
+        assert_eq!(task.status, TaskStatus::Todo);
+        assert_eq!(task.linear_labels.as_deref(), Some("bug, auth"));
+
+        let (_, frontmatter) = storage.find_task_file(&task.id).unwrap();
+        assert_eq!(frontmatter.priority.as_deref(), Some("2"));
+        assert_eq!(frontmatter.history.len(), 1);
+    }
+
+    #[test]
+    fn test_split_import_frontmatter() {
+        // No front-matter: everything is the body
+        let (import, body) = split_import_frontmatter("Just notes\n---\nmore");
+        assert_eq!(import, ImportFrontmatter::default());
+        assert_eq!(body, "Just notes\n---\nmore");
+
+        // Title only; labels as a comma-separated string
+        let (import, body) =
+            split_import_frontmatter("---\ntitle: Ship it\nlabels: ui, , docs\n---\nBody");
+        assert_eq!(import.title.as_deref(), Some("Ship it"));
+        assert_eq!(import.labels(), vec!["ui", "docs"]);
+        assert!(import.sets_fields());
+        assert_eq!(body, "Body");
+
+        // Invalid front-matter is kept as plain content
+        let content = "---\nstatus: someday\n---\nBody";
+        let (import, body) = split_import_frontmatter(content);
+        assert_eq!(import, ImportFrontmatter::default());
+        assert_eq!(body, content);
+
+        // Unterminated front-matter is plain content too
+        let content = "---\ntitle: Never closed\nBody";
+        assert_eq!(split_import_frontmatter(content).1, content);
+    }
+
+    #[test]
+    fn test_create_task_from_github_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskStorage::new_with_base(dir.path(), "test-project").unwrap();
+
+        let issue = GhIssue {
+            number: 42,
+            title: "Crash on empty board".to_string(),
+            body: "Steps to reproduce".to_string(),
+            url: "https://github.com/test/repo/issues/42".to_string(),
+            labels: vec![
+                crate::external::GhLabel {
+                    name: "bug".to_string(),
+                },
+                crate::external::GhLabel {
+                    name: "p1".to_string(),
+                },
+            ],
+        };
+        let task = storage.create_task_from_github(&issue).unwrap();
+        assert_eq!(task.github_issue, Some(42));
+        assert_eq!(task.linear_labels.as_deref(), Some("bug, p1"));
+
+        let listed = storage.list_tasks().unwrap();
+        assert_eq!(listed[0].title, "Crash on empty board");
+        assert_eq!(listed[0].description.as_deref(), Some("Steps to reproduce"));
+        assert_eq!(listed[0].github_issue, Some(42));
+        assert_eq!(listed[0].github_url.as_deref(), Some(issue.url.as_str()));
+        assert!(listed[0].linear_issue_id.is_none());
+    }
+
+    #[test]
+    fn test_linear_priority_and_estimate_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskStorage::new_with_base(dir.path(), "test-project").unwrap();
+
+        let issue = LinearIssue {
+            identifier: "VIB-9".to_string(),
+            title: "Slow board refresh".to_string(),
+            description: None,
+            url: "https://linear.app/t/issue/VIB-9".to_string(),
+            labels: vec![],
+            priority: Some(2),
+            estimate: Some(3.0),
+        };
+        storage.create_task_from_linear(&issue).unwrap();
+
+        let listed = storage.list_tasks().unwrap();
+        assert_eq!(listed[0].priority, Some(2));
+        assert_eq!(listed[0].estimate, Some(3.0));
+
+        // Hand-written frontmatter can name the priority instead
+        assert_eq!(parse_priority("High"), Some(2));
+        assert_eq!(parse_priority(" 4 "), Some(4));
+        assert_eq!(parse_priority("whenever"), None);
+    }
+
+    #[test]
+    fn test_delete_then_restore_task() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskStorage::new_with_base(dir.path(), "test-project").unwrap();
+
+        let task = storage.create_task("Doomed task", Some("notes")).unwrap();
+        let task = storage
+            .set_task_status(&task.id, TaskStatus::Inprogress)
+            .unwrap();
+        let deleted = storage.delete_task(&task.id).unwrap();
+        assert!(storage.list_tasks().unwrap().is_empty());
+
+        let restored = storage.restore_task(&deleted).unwrap();
+        assert_eq!(restored.id, task.id);
+        assert_eq!(restored.status, TaskStatus::Inprogress);
+        assert_eq!(restored.description.as_deref(), Some("notes"));
+        assert_eq!(storage.list_tasks().unwrap().len(), 1);
+
+        // A second restore must not clobber the file
+        assert!(storage.restore_task(&deleted).is_err());
+    }
+
     #[test]
     fn test_parse_frontmatter() {
         let yaml = r#"
@@ -588,4 +1192,331 @@ created: 2024-01-15
         assert_eq!(archived, 0);
         assert_eq!(storage.list_tasks().unwrap().len(), 1);
     }
+
+    #[test]
+    fn test_concurrent_writers_do_not_lose_tasks() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().to_path_buf();
+        TaskStorage::new_with_base(&base, "test-project").unwrap();
+
+        // Two independent storage handles stand in for two vibe processes,
+        // both creating tasks with colliding titles at the same time.
+        let writers: Vec<_> = (0..2)
+            .map(|_| {
+                let base = base.clone();
+                std::thread::spawn(move || {
+                    let storage = TaskStorage::new_with_base(&base, "test-project").unwrap();
+                    for _ in 0..25 {
+                        storage.create_task("Same title", Some("body")).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        let storage = TaskStorage::new_with_base(&base, "test-project").unwrap();
+        let tasks = storage.list_tasks().unwrap();
+        assert_eq!(tasks.len(), 50);
+        assert!(
+            tasks
+                .iter()
+                .all(|t| t.description.as_deref() == Some("body"))
+        );
+    }
+
+    #[test]
+    fn test_write_atomic_replaces_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("task.md");
+        write_atomic(&path, "first").unwrap();
+        write_atomic(&path, "second").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "second");
+        // No temp files left behind
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_unarchive_task() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskStorage::new_with_base(dir.path(), "test-project").unwrap();
+
+        let task = storage.create_task("Task One", Some("body")).unwrap();
+        storage
+            .archive_tasks(std::slice::from_ref(&task.id))
+            .unwrap();
+        assert!(storage.list_tasks().unwrap().is_empty());
+
+        let restored = storage.unarchive_task(&task.id).unwrap();
+        assert_eq!(restored.id, task.id);
+        assert_eq!(storage.list_tasks().unwrap().len(), 1);
+
+        assert!(storage.unarchive_task(&task.id).is_err());
+    }
+
+    #[test]
+    fn test_set_task_status_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskStorage::new_with_base(dir.path(), "test-project").unwrap();
+
+        let task = storage.create_task("Local task", Some("notes")).unwrap();
+        assert_eq!(task.status, TaskStatus::Backlog);
+
+        let moved = storage
+            .set_task_status(&task.id, TaskStatus::Inprogress)
+            .unwrap();
+        assert_eq!(moved.status, TaskStatus::Inprogress);
+        assert_eq!(moved.description.as_deref(), Some("notes"));
+
+        // Status survives reload and title edits
+        let listed = storage.list_tasks().unwrap();
+        assert_eq!(listed[0].status, TaskStatus::Inprogress);
+        let edited = storage
+            .update_task(&task.id, "Local task", Some("more notes"))
+            .unwrap();
+        assert_eq!(edited.status, TaskStatus::Inprogress);
+        assert_eq!(
+            storage.list_tasks().unwrap()[0].status,
+            TaskStatus::Inprogress
+        );
+    }
+
+    #[test]
+    fn test_local_task_follows_its_worktree_and_pr() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskStorage::new_with_base(dir.path(), "test-project").unwrap();
+
+        // No Linear key: a plain local task, branch derived from the title alone
+        storage
+            .create_task("Fix: login page (Safari)", None)
+            .unwrap();
+        let tasks = storage.list_tasks().unwrap();
+        let task = &tasks[0];
+        assert_eq!(task.linear_issue_id, None);
+        assert_eq!(task.status, TaskStatus::Backlog);
+        let branch = task.branch();
+        assert_eq!(
+            branch,
+            crate::state::task_title_to_branch(&task.title, None, &task.id)
+        );
+
+        let worktrees: Vec<crate::external::WorktreeInfo> = vec![
+            serde_json::from_value(serde_json::json!({
+                "branch": branch,
+                "path": format!("/repo/{}", branch),
+            }))
+            .unwrap(),
+        ];
+        let no_prs = HashMap::new();
+        let no_linear = HashMap::new();
+        let statuses = crate::state::board_statuses(&tasks, &no_prs, &worktrees, &no_linear);
+        assert_eq!(statuses[&task.id], TaskStatus::Inprogress);
+
+        let prs = HashMap::from([(branch.clone(), crate::state::test_pr("OPEN"))]);
+        let statuses = crate::state::board_statuses(&tasks, &prs, &worktrees, &no_linear);
+        assert_eq!(statuses[&task.id], TaskStatus::Inreview);
+
+        // The stored status never moved; the board derives it
+        assert_eq!(storage.list_tasks().unwrap()[0].status, TaskStatus::Backlog);
+    }
+
+    #[test]
+    fn test_plan_phase_survives_edits() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskStorage::new_with_base(dir.path(), "test-project").unwrap();
+
+        let task = storage.create_task("Local task", Some("desc")).unwrap();
+        assert_eq!(task.plan_phase, None);
+        let planned = storage
+            .set_plan_phase(&task.id, PlanPhase::Planning)
+            .unwrap();
+        assert_eq!(planned.plan_phase, Some(PlanPhase::Planning));
+
+        storage
+            .set_plan_phase(&task.id, PlanPhase::Executing)
+            .unwrap();
+        storage
+            .update_task(&task.id, "Local task", Some("new desc"))
+            .unwrap();
+        assert_eq!(
+            storage.list_tasks().unwrap()[0].plan_phase,
+            Some(PlanPhase::Executing)
+        );
+    }
+
+    #[test]
+    fn test_task_notes_survive_edits() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskStorage::new_with_base(dir.path(), "test-project").unwrap();
+
+        let task = storage.create_task("Local task", Some("desc")).unwrap();
+        let noted = storage
+            .set_task_notes(
+                &task.id,
+                Some("- ask about retries\n\n---\nnot front-matter\n"),
+            )
+            .unwrap();
+        assert_eq!(
+            noted.notes.as_deref(),
+            Some("- ask about retries\n\n---\nnot front-matter")
+        );
+
+        storage
+            .update_task(&task.id, "Local task", Some("new desc"))
+            .unwrap();
+        let listed = &storage.list_tasks().unwrap()[0];
+        assert_eq!(listed.description.as_deref(), Some("new desc"));
+        assert_eq!(listed.notes, noted.notes);
+
+        let cleared = storage.set_task_notes(&task.id, Some("  \n")).unwrap();
+        assert_eq!(cleared.notes, None);
+    }
+
+    #[test]
+    fn test_link_linear_issue_keeps_task() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskStorage::new_with_base(dir.path(), "test-project").unwrap();
+
+        let task = storage.create_task("Local task", Some("notes")).unwrap();
+        storage
+            .set_task_status(&task.id, TaskStatus::Inprogress)
+            .unwrap();
+        let linked = storage
+            .link_linear_issue(&task.id, "VIB-12", "https://linear.app/t/issue/VIB-12")
+            .unwrap();
+        assert_eq!(linked.id, task.id);
+        assert_eq!(linked.linear_issue_id.as_deref(), Some("VIB-12"));
+        assert_eq!(
+            linked.linear_url.as_deref(),
+            Some("https://linear.app/t/issue/VIB-12")
+        );
+
+        let listed = storage.list_tasks().unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].linear_issue_id.as_deref(), Some("VIB-12"));
+        assert_eq!(listed[0].description.as_deref(), Some("notes"));
+        assert_eq!(listed[0].status, TaskStatus::Inprogress);
+    }
+
+    #[test]
+    fn test_recorded_pr_url_survives_edits() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskStorage::new_with_base(dir.path(), "test-project").unwrap();
+
+        let task = storage.create_task("Local task", None).unwrap();
+        assert_eq!(task.pr_url, None);
+        let url = "https://github.com/o/r/pull/3";
+        storage.record_pr_url(&task.id, url).unwrap();
+        storage.update_task(&task.id, "Renamed", None).unwrap();
+
+        let listed = &storage.list_tasks().unwrap()[0];
+        assert_eq!(listed.pr_url.as_deref(), Some(url));
+        // Not open as far as storage knows, so it never pins the card's status
+        assert_eq!(listed.open_pr_url(), None);
+    }
+
+    #[test]
+    fn test_set_task_status_records_transitions() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskStorage::new_with_base(dir.path(), "test-project").unwrap();
+
+        let task = storage.create_task("Tracked", None).unwrap();
+        assert!(task.status_history.is_empty());
+
+        storage
+            .set_task_status(&task.id, TaskStatus::Inprogress)
+            .unwrap();
+        // Re-setting the same status is not a transition
+        storage
+            .set_task_status(&task.id, TaskStatus::Inprogress)
+            .unwrap();
+        let done = storage.set_task_status(&task.id, TaskStatus::Done).unwrap();
+
+        let statuses: Vec<TaskStatus> = done.status_history.iter().map(|c| c.status).collect();
+        assert_eq!(statuses, vec![TaskStatus::Inprogress, TaskStatus::Done]);
+        assert!(done.status_history[0].at <= done.status_history[1].at);
+
+        // History survives edits
+        let edited = storage
+            .update_task(&task.id, "Tracked", Some("notes"))
+            .unwrap();
+        assert_eq!(edited.status_history.len(), 2);
+        assert_eq!(storage.list_tasks().unwrap()[0].status_history.len(), 2);
+    }
+
+    #[test]
+    fn test_is_git_repo() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(!is_git_repo(dir.path()));
+
+        let status = std::process::Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(dir.path())
+            .status()
+            .unwrap();
+        assert!(status.success());
+        assert!(is_git_repo(dir.path()));
+        std::fs::create_dir(dir.path().join("nested")).unwrap();
+        assert!(is_git_repo(&dir.path().join("nested")));
+    }
+
+    #[test]
+    fn test_record_status_change_keeps_stored_status() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskStorage::new_with_base(dir.path(), "test-project").unwrap();
+        let task = storage.create_task("Derived", None).unwrap();
+
+        let change = storage
+            .record_status_change(&task.id, TaskStatus::Inreview)
+            .unwrap();
+        assert_eq!(change.map(|c| c.status), Some(TaskStatus::Inreview));
+        // Same column again is not a transition
+        assert!(
+            storage
+                .record_status_change(&task.id, TaskStatus::Inreview)
+                .unwrap()
+                .is_none()
+        );
+
+        let stored = &storage.list_tasks().unwrap()[0];
+        assert_eq!(stored.status, TaskStatus::Backlog);
+        assert_eq!(stored.last_recorded_status(), TaskStatus::Inreview);
+        assert_eq!(stored.status_history.len(), 1);
+    }
+
+    #[test]
+    fn test_status_history_is_capped() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = TaskStorage::new_with_base(dir.path(), "test-project").unwrap();
+        let task = storage.create_task("Flapping", None).unwrap();
+
+        for i in 0..STATUS_HISTORY_LIMIT + 5 {
+            let status = if i % 2 == 0 {
+                TaskStatus::Inprogress
+            } else {
+                TaskStatus::Inreview
+            };
+            storage.record_status_change(&task.id, status).unwrap();
+        }
+
+        let history = &storage.list_tasks().unwrap()[0].status_history;
+        assert_eq!(history.len(), STATUS_HISTORY_LIMIT);
+        // Oldest entries were dropped; the latest is kept
+        assert_eq!(history.last().unwrap().status, TaskStatus::Inprogress);
+    }
+
+    #[test]
+    fn test_frontmatter_without_status_defaults_to_backlog() {
+        let yaml = "id: abc\ncreated: 2024-01-15\n";
+        let fm: TaskFrontmatter = serde_yaml::from_str(yaml).unwrap();
+        assert!(fm.status.is_none());
+
+        let fm = TaskFrontmatter {
+            status: Some(TaskStatus::Inreview),
+            ..fm
+        };
+        let yaml = serde_yaml::to_string(&fm).unwrap();
+        assert!(yaml.contains("status: inreview"));
+    }
 }
@@ -1,3 +1,6 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
 use crate::external::rapporting_instructions;
 
 pub struct PullRequestContext<'a> {
@@ -12,10 +15,45 @@ pub struct TaskPromptOptions<'a> {
     pub pull_request: Option<PullRequestContext<'a>>,
     pub project_name: &'a str,
     pub with_prime: bool,
+    /// Standing instructions placed before the task itself
+    pub standing_context: Option<&'a str>,
+    /// A plan made in plan mode, handed over for execution
+    pub approved_plan: Option<&'a str>,
+}
+
+/// Default standing-context file, picked up when no `--context-file` is given
+pub fn default_context_file() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".vibe").join("context.md"))
+}
+
+/// Read standing instructions from `path`, or from `~/.vibe/context.md` if it exists.
+/// An explicit path must be readable; a missing default file is not an error.
+pub fn load_standing_context(path: Option<&Path>) -> Result<Option<String>> {
+    let content = match path {
+        Some(path) => std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read context file: {:?}", path))?,
+        None => match default_context_file().filter(|p| p.is_file()) {
+            Some(path) => std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read context file: {:?}", path))?,
+            None => return Ok(None),
+        },
+    };
+
+    let content = content.trim();
+    Ok((!content.is_empty()).then(|| content.to_string()))
 }
 
 pub fn build_task_prompt(options: TaskPromptOptions<'_>) -> String {
-    let mut prompt = format!("Task: {}", options.title);
+    let mut prompt = String::new();
+
+    if let Some(standing) = options
+        .standing_context
+        .filter(|standing| !standing.is_empty())
+    {
+        prompt.push_str(&format!("{standing}\n\n"));
+    }
+
+    prompt.push_str(&format!("Task: {}", options.title));
 
     if let Some(description) = options
         .description
@@ -30,6 +68,16 @@ pub fn build_task_prompt(options: TaskPromptOptions<'_>) -> String {
         prompt.push_str(&format!("\nPR: {} ({})", pr.url, pr.state));
     }
 
+    if let Some(plan) = options
+        .approved_plan
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+    {
+        prompt.push_str(&format!(
+            "\n\nThe plan below was made and reviewed in plan mode. Execute it:\n\n{plan}"
+        ));
+    }
+
     prompt.push_str("\n\nRun `just setup` if available to initialize the worktree environment.");
 
     if options.with_prime {
@@ -41,7 +89,7 @@ pub fn build_task_prompt(options: TaskPromptOptions<'_>) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{PullRequestContext, TaskPromptOptions, build_task_prompt};
+    use super::{PullRequestContext, TaskPromptOptions, build_task_prompt, load_standing_context};
 
     #[test]
     fn omits_prime_instructions_by_default() {
@@ -52,6 +100,8 @@ mod tests {
             pull_request: None,
             project_name: "vibe",
             with_prime: false,
+            standing_context: None,
+            approved_plan: None,
         });
 
         assert!(prompt.contains("Task: Ship opt-in prompts"));
@@ -73,10 +123,65 @@ mod tests {
             }),
             project_name: "vibe",
             with_prime: true,
+            standing_context: None,
+            approved_plan: None,
         });
 
         assert!(prompt.contains("PR: https://github.com/piotrostr/vibe/pull/123 (OPEN)"));
         assert!(prompt.contains("cousin prime"));
         assert!(prompt.contains("Full protocol: cat ~/.claude/skills/vibe/SKILL.md"));
     }
+
+    #[test]
+    fn prepends_standing_context_before_task() {
+        let prompt = build_task_prompt(TaskPromptOptions {
+            title: "Ship opt-in prompts",
+            description: Some("Linear description."),
+            branch: "vib-123-ship-opt-in-prompts",
+            pull_request: None,
+            project_name: "vibe",
+            with_prime: false,
+            standing_context: Some("Always run cargo fmt."),
+            approved_plan: None,
+        });
+
+        assert!(prompt.starts_with("Always run cargo fmt.\n\nTask: Ship opt-in prompts"));
+        assert!(prompt.contains("Description:\nLinear description."));
+    }
+
+    #[test]
+    fn hands_the_approved_plan_to_execution() {
+        let prompt = build_task_prompt(TaskPromptOptions {
+            title: "Ship opt-in prompts",
+            description: None,
+            branch: "vib-123-ship-opt-in-prompts",
+            pull_request: None,
+            project_name: "vibe",
+            with_prime: false,
+            standing_context: None,
+            approved_plan: Some("# Plan\n- [ ] Drop the appendix\n"),
+        });
+
+        assert!(prompt.contains(
+            "Branch: vib-123-ship-opt-in-prompts\n\nThe plan below was made and reviewed in plan mode. \
+             Execute it:\n\n# Plan\n- [ ] Drop the appendix\n\nRun `just setup`"
+        ));
+    }
+
+    #[test]
+    fn loads_explicit_context_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("context.md");
+
+        std::fs::write(&path, "  Follow repo conventions.\n\n").unwrap();
+        assert_eq!(
+            load_standing_context(Some(&path)).unwrap().as_deref(),
+            Some("Follow repo conventions.")
+        );
+
+        std::fs::write(&path, "\n").unwrap();
+        assert_eq!(load_standing_context(Some(&path)).unwrap(), None);
+
+        assert!(load_standing_context(Some(&dir.path().join("missing.md"))).is_err());
+    }
 }
@@ -1,12 +1,18 @@
 use ratatui::{
     Frame,
-    layout::Rect,
+    layout::{Alignment, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
 
-use crate::state::{AppState, linear_env_var_name};
+use super::Theme;
+use crate::external::{RateLimit, linear_rate_limit_wait};
+use crate::state::{
+    AppState, AssistantPicker, BranchPicker, DiffLineKind, LinearStatePicker, PendingApprove,
+    PendingCancel, PendingCompact, PendingDelete, PrDiff, SessionScrollback, View,
+    linear_env_var_name,
+};
 
 const LOGO: &str = r#"
  __   _(_) |__   ___
@@ -14,15 +20,15 @@ const LOGO: &str = r#"
   \ V /| | |_) |  __/
    \_/ |_|_.__/ \___|"#;
 
-pub fn render_header(frame: &mut Frame, area: Rect, state: &AppState) {
+pub fn render_header(frame: &mut Frame, area: Rect, state: &AppState, theme: &Theme) {
     if area.height >= 5 {
-        render_header_with_logo(frame, area, state);
+        render_header_with_logo(frame, area, state, theme);
     } else {
-        render_header_compact(frame, area, state);
+        render_header_compact(frame, area, state, theme);
     }
 }
 
-fn render_header_with_logo(frame: &mut Frame, area: Rect, state: &AppState) {
+fn render_header_with_logo(frame: &mut Frame, area: Rect, state: &AppState, theme: &Theme) {
     let (project_info, project_name) = match &state.selected_project_id {
         Some(id) => {
             let name = state
@@ -41,9 +47,9 @@ fn render_header_with_logo(frame: &mut Frame, area: Rect, state: &AppState) {
     let linear_info = if let Some(ref name) = project_name {
         let env_var = linear_env_var_name(name);
         if state.linear_api_key_available {
-            Some((env_var, "set", Color::Green))
+            Some((env_var, "set", theme.success))
         } else {
-            Some((env_var, "not set", Color::DarkGray))
+            Some((env_var, "not set", theme.muted))
         }
     } else {
         None
@@ -61,7 +67,7 @@ fn render_header_with_logo(frame: &mut Frame, area: Rect, state: &AppState) {
         let mut spans = vec![Span::styled(
             *logo_line,
             Style::default()
-                .fg(super::ACCENT)
+                .fg(theme.accent)
                 .add_modifier(Modifier::BOLD),
         )];
 
@@ -70,72 +76,80 @@ fn render_header_with_logo(frame: &mut Frame, area: Rect, state: &AppState) {
             // Line 1: Process counts + loading indicator
             spans.push(Span::raw("  "));
             if claude_count > 0 {
-                spans.push(Span::styled("Claude: ", Style::default().fg(Color::White)));
+                spans.push(Span::styled("Claude: ", Style::default().fg(theme.text)));
                 spans.push(Span::styled(
                     format!("{}", claude_count),
-                    Style::default().fg(super::ACCENT),
+                    Style::default().fg(theme.accent),
                 ));
             } else {
-                spans.push(Span::styled(
-                    "Claude: 0",
-                    Style::default().fg(Color::DarkGray),
-                ));
+                spans.push(Span::styled("Claude: 0", Style::default().fg(theme.muted)));
             }
-            spans.push(Span::styled(" | ", Style::default().fg(Color::DarkGray)));
+            spans.push(Span::styled(" | ", Style::default().fg(theme.muted)));
             if zellij_count > 0 {
-                spans.push(Span::styled("Zellij: ", Style::default().fg(Color::White)));
+                spans.push(Span::styled("Zellij: ", Style::default().fg(theme.text)));
                 spans.push(Span::styled(
                     format!("{}", zellij_count),
-                    Style::default().fg(Color::Green),
+                    Style::default().fg(theme.success),
                 ));
             } else {
-                spans.push(Span::styled(
-                    "Zellij: 0",
-                    Style::default().fg(Color::DarkGray),
-                ));
+                spans.push(Span::styled("Zellij: 0", Style::default().fg(theme.muted)));
+            }
+            if let Some(limit) = &state.gh_rate_limit {
+                let (label, color) = rate_limit_label(limit, chrono::Utc::now(), theme);
+                spans.push(Span::styled(" | ", Style::default().fg(theme.muted)));
+                spans.push(Span::styled(label, Style::default().fg(color)));
             }
             // Show loading indicator when refreshing
             if state.pr_loading || state.worktrees.loading || state.sessions.loading {
-                spans.push(Span::styled(" | ", Style::default().fg(Color::DarkGray)));
+                spans.push(Span::styled(" | ", Style::default().fg(theme.muted)));
                 spans.push(Span::styled(
                     format!("{} syncing", state.spinner_char()),
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(theme.warning),
                 ));
             }
         } else if i == 1 && !project_info.is_empty() {
             spans.push(Span::raw("  "));
-            spans.push(Span::styled("Project: ", Style::default().fg(Color::White)));
+            spans.push(Span::styled("Project: ", Style::default().fg(theme.text)));
             let project_name_display = project_info
                 .strip_prefix("Project: ")
                 .unwrap_or(&project_info);
             spans.push(Span::styled(
                 project_name_display.to_string(),
-                Style::default().fg(super::ACCENT),
+                Style::default().fg(theme.accent),
             ));
         } else if i == 2 {
             if let Some((ref env_var, status, label_color)) = linear_info {
                 spans.push(Span::raw("  "));
-                spans.push(Span::styled("Linear: ", Style::default().fg(Color::White)));
+                spans.push(Span::styled("Linear: ", Style::default().fg(theme.text)));
                 spans.push(Span::styled(
                     format!("{} {}", env_var, status),
                     Style::default().fg(label_color),
                 ));
+                if let Some(limit) = &state.linear_rate_limit
+                    && let Some((label, color)) =
+                        linear_rate_limit_label(limit, chrono::Utc::now(), theme)
+                {
+                    spans.push(Span::styled(
+                        format!(" ({})", label),
+                        Style::default().fg(color),
+                    ));
+                }
             }
             // Prime session indicator
-            spans.push(Span::styled(" | ", Style::default().fg(Color::DarkGray)));
+            spans.push(Span::styled(" | ", Style::default().fg(theme.muted)));
             if state.prime_session_active {
-                spans.push(Span::styled("Prime: ", Style::default().fg(Color::White)));
-                spans.push(Span::styled("active", Style::default().fg(super::ACCENT)));
+                spans.push(Span::styled("Prime: ", Style::default().fg(theme.text)));
+                spans.push(Span::styled("active", Style::default().fg(theme.accent)));
             } else {
                 spans.push(Span::styled(
                     "Prime: P to launch",
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme.muted),
                 ));
             }
-            spans.push(Span::styled(" | ", Style::default().fg(Color::DarkGray)));
+            spans.push(Span::styled(" | ", Style::default().fg(theme.muted)));
             spans.push(Span::styled(
                 env!("GIT_HASH"),
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
             ));
         }
 
@@ -147,7 +161,58 @@ fn render_header_with_logo(frame: &mut Frame, area: Rect, state: &AppState) {
     frame.render_widget(header, area);
 }
 
-fn render_header_compact(frame: &mut Frame, area: Rect, state: &AppState) {
+/// Header text for the GitHub budget: remaining calls, or a countdown once spent
+fn rate_limit_label(
+    limit: &RateLimit,
+    now: chrono::DateTime<chrono::Utc>,
+    theme: &Theme,
+) -> (String, Color) {
+    if limit.is_exhausted(now) {
+        let secs = limit.resets_in(now).as_secs();
+        (
+            format!(
+                "GH: rate limited, resets in {}m{:02}s",
+                secs / 60,
+                secs % 60
+            ),
+            theme.error,
+        )
+    } else if limit.is_low(now) {
+        (
+            format!("GH: {}/{} (polling slower)", limit.remaining, limit.limit),
+            theme.warning,
+        )
+    } else {
+        (
+            format!("GH: {}/{}", limit.remaining, limit.limit),
+            theme.muted,
+        )
+    }
+}
+
+/// Warning next to the Linear status once its budget runs low; None while it's fine
+fn linear_rate_limit_label(
+    limit: &RateLimit,
+    now: chrono::DateTime<chrono::Utc>,
+    theme: &Theme,
+) -> Option<(String, Color)> {
+    if let Some(wait) = linear_rate_limit_wait(limit, now) {
+        let secs = wait.as_secs();
+        Some((
+            format!("rate limited, paused {}m{:02}s", secs / 60, secs % 60),
+            theme.error,
+        ))
+    } else if limit.is_low(now) {
+        Some((
+            format!("{}/{} requests left", limit.remaining, limit.limit),
+            theme.warning,
+        ))
+    } else {
+        None
+    }
+}
+
+fn render_header_compact(frame: &mut Frame, area: Rect, state: &AppState, theme: &Theme) {
     let title = match &state.selected_project_id {
         Some(id) => {
             // Try to find the project name in the projects list, otherwise use the id directly
@@ -165,9 +230,9 @@ fn render_header_compact(frame: &mut Frame, area: Rect, state: &AppState) {
     };
 
     let status = if state.backend_connected {
-        Span::styled(" Connected ", Style::default().fg(Color::Green))
+        Span::styled(" Connected ", Style::default().fg(theme.success))
     } else {
-        Span::styled(" Disconnected ", Style::default().fg(Color::Red))
+        Span::styled(" Disconnected ", Style::default().fg(theme.error))
     };
 
     let header = Paragraph::new(Line::from(vec![
@@ -181,13 +246,69 @@ fn render_header_compact(frame: &mut Frame, area: Rect, state: &AppState) {
     frame.render_widget(header, area);
 }
 
-pub fn render_footer(frame: &mut Frame, area: Rect, state: &AppState) {
+/// Smallest terminal the views lay out in: header, a few rows of cards and the footer
+pub const MIN_WIDTH: u16 = 40;
+pub const MIN_HEIGHT: u16 = 15;
+
+pub fn is_too_small(area: Rect) -> bool {
+    area.width < MIN_WIDTH || area.height < MIN_HEIGHT
+}
+
+/// Drawn instead of the current view while the terminal is below the minimum size
+pub fn render_too_small(frame: &mut Frame, area: Rect, theme: &Theme) {
+    let message = Paragraph::new(vec![
+        Line::from(Span::styled(
+            "terminal too small",
+            Style::default()
+                .fg(theme.warning)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!(
+                "need ≥ {}x{}, have {}x{}",
+                MIN_WIDTH, MIN_HEIGHT, area.width, area.height
+            ),
+            Style::default().fg(theme.muted),
+        )),
+    ])
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true });
+    // Vertically centered when there is room for it
+    let top = area.height.saturating_sub(2) / 2;
+    let area = Rect::new(area.x, area.y + top, area.width, area.height - top);
+    frame.render_widget(message, area);
+}
+
+/// `Board │ Worktrees │ Sessions │ Logs`, the current view's tab highlighted
+pub fn render_tab_bar(frame: &mut Frame, area: Rect, view: View, theme: &Theme) {
+    let mut spans = vec![Span::raw(" ")];
+    for (i, tab) in View::TABS.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(" │ ", Style::default().fg(theme.muted)));
+        }
+        let style = if *tab == view.tab() {
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+        } else {
+            Style::default().fg(theme.muted)
+        };
+        spans.push(Span::styled(tab.tab_label(), style));
+    }
+    spans.push(Span::styled(
+        "  Tab/Shift-Tab",
+        Style::default().fg(theme.muted),
+    ));
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+pub fn render_footer(frame: &mut Frame, area: Rect, state: &AppState, theme: &Theme) {
     // Show command bar when active (vim-like ;f)
     if let Some(ref cmd) = state.command_input {
         let cmd_line = Line::from(vec![
-            Span::styled(";", Style::default().fg(Color::Yellow)),
+            Span::styled(";", Style::default().fg(theme.warning)),
             Span::raw(cmd.as_str()),
-            Span::styled("_", Style::default().fg(Color::Yellow)), // cursor
+            Span::styled("_", Style::default().fg(theme.warning)), // cursor
         ]);
 
         let footer = Paragraph::new(cmd_line)
@@ -198,12 +319,22 @@ pub fn render_footer(frame: &mut Frame, area: Rect, state: &AppState) {
         return;
     }
 
+    // Worktree picker has its own input box; show picker hints instead
+    if state.search_active && state.view == crate::state::View::Worktrees {
+        let footer = Paragraph::new("type to filter | Ctrl-j/k: nav | Enter: jump | Esc: cancel")
+            .style(Style::default().fg(theme.muted))
+            .block(Block::default().borders(Borders::TOP));
+
+        frame.render_widget(footer, area);
+        return;
+    }
+
     // Show search bar when active
     if state.search_active {
         let search_line = Line::from(vec![
-            Span::styled("/", Style::default().fg(Color::Yellow)),
+            Span::styled("/", Style::default().fg(theme.warning)),
             Span::raw(&state.search_query),
-            Span::styled("_", Style::default().fg(Color::Yellow)), // cursor
+            Span::styled("_", Style::default().fg(theme.warning)), // cursor
         ]);
 
         let footer = Paragraph::new(search_line)
@@ -214,6 +345,16 @@ pub fn render_footer(frame: &mut Frame, area: Rect, state: &AppState) {
         return;
     }
 
+    // Transient notices (e.g. undo hint) replace the key hints while fresh
+    if let Some(notice) = state.active_notice() {
+        let footer = Paragraph::new(notice)
+            .style(Style::default().fg(theme.warning))
+            .block(Block::default().borders(Borders::TOP));
+
+        frame.render_widget(footer, area);
+        return;
+    }
+
     // Show active search filter if present
     let search_indicator = if !state.search_query.is_empty() {
         format!(" [/{}] |", state.search_query)
@@ -230,7 +371,7 @@ pub fn render_footer(frame: &mut Frame, area: Rect, state: &AppState) {
         }
         crate::state::View::Kanban => {
             format!(
-                "{}h/j/k/l: nav | Enter: details | /: search | s: session | Esc: back",
+                "{}h/j/k/l: nav | Enter: details | g: session | s: sort | /: search | Esc: back",
                 search_indicator
             )
         }
@@ -242,28 +383,32 @@ pub fn render_footer(frame: &mut Frame, area: Rect, state: &AppState) {
         }
         crate::state::View::Worktrees => {
             format!(
-                "{}j/k: nav | Enter: switch | g: session | /: search | Esc: back",
+                "{}j/k: nav | Enter: switch | g: session | /: jump | +: track | Esc: back",
                 search_indicator
             )
         }
+        crate::state::View::Sessions => format!(
+            "{}j/k: nav | Enter/a: attach | o: scrollback | r: refresh | Esc: back",
+            search_indicator
+        ),
         crate::state::View::Logs => "j/k: scroll | r: refresh | Esc: back".to_string(),
         crate::state::View::Search => "j/k/Ctrl-j/k: nav | Enter: select | Esc: cancel".to_string(),
     };
 
     let footer = Paragraph::new(hints)
-        .style(Style::default().fg(Color::DarkGray))
+        .style(Style::default().fg(theme.muted))
         .block(Block::default().borders(Borders::TOP));
 
     frame.render_widget(footer, area);
 }
 
-pub fn render_help_modal(frame: &mut Frame, area: Rect) {
+pub fn render_help_modal(frame: &mut Frame, area: Rect, theme: &Theme) {
     let help_text = vec![
         Line::from(vec![Span::styled(
             "Keyboard Shortcuts",
             Style::default()
                 .add_modifier(Modifier::BOLD)
-                .fg(Color::Yellow),
+                .fg(theme.warning),
         )]),
         Line::from(""),
         Line::from(vec![Span::styled(
@@ -273,6 +418,7 @@ pub fn render_help_modal(frame: &mut Frame, area: Rect) {
         Line::from("  h/j/k/l or arrows  Move around"),
         Line::from("  Enter              Select / Open"),
         Line::from("  Esc / q            Back / Quit"),
+        Line::from("  Tab / Shift-Tab    Next / previous view (board, worktrees, sessions, logs)"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Tasks",
@@ -280,7 +426,17 @@ pub fn render_help_modal(frame: &mut Frame, area: Rect) {
         )]),
         Line::from("  c                  Create task"),
         Line::from("  e                  Edit task (nvim)"),
-        Line::from("  d                  Delete task"),
+        Line::from("  N                  Edit private notes (local, never synced)"),
+        Line::from("  d                  Delete task (asks to confirm)"),
+        Line::from("  u                  Undo last delete"),
+        Line::from("  x                  Cancel task (asks to confirm)"),
+        Line::from("  s                  Sort backlog by age (toggle)"),
+        Line::from("  a                  Filter by Linear assignee (cycle)"),
+        Line::from("  B                  Filter by PR base branch (cycle)"),
+        Line::from("  m                  Only my PRs (hide teammates')"),
+        Line::from("  z                  Board layout: auto, compact, rows"),
+        Line::from("  D                  Show why each card is in its column"),
+        Line::from("  C                  Compact session (/compact) when context is high"),
         Line::from("  A                  Archive done tasks"),
         Line::from(""),
         Line::from(vec![Span::styled(
@@ -289,6 +445,8 @@ pub fn render_help_modal(frame: &mut Frame, area: Rect) {
         )]),
         Line::from("  w                  Show worktrees"),
         Line::from("  W                  Create worktree"),
+        Line::from("  / (in worktrees)   Jump to branch"),
+        Line::from("  + (in worktrees)   Create task for branch"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Sessions",
@@ -296,18 +454,28 @@ pub fn render_help_modal(frame: &mut Frame, area: Rect) {
         )]),
         Line::from("  g                  Gas it (launch Claude)"),
         Line::from("  G                  Gas it with prime"),
+        Line::from("  Ctrl+g             Gas it with a picked assistant"),
         Line::from("  p                  Plan it (launch in plan mode)"),
+        Line::from("  X                  Execute the plan (fresh session, plan in prompt)"),
         Line::from("  P                  Prime session (war room)"),
         Line::from("  v                  View PR"),
-        Line::from("  S                  Show sessions"),
-        Line::from("  a / Enter          Attach to session"),
-        Line::from("  K                  Kill session"),
+        Line::from("  b                  Bind to a branch (overrides PR/worktree matching)"),
+        Line::from("  D (in detail)      PR diff, without leaving vibe"),
+        Line::from("  A / F (in detail)  Approve the PR / request changes (body in $EDITOR)"),
+        Line::from("  E (in detail)      Edit plan file, then reload it"),
+        Line::from("  o                  Session scrollback"),
+        Line::from("  M                  Acknowledge a waiting session (until it changes)"),
+        Line::from("  S                  Show all sessions (incl. ad-hoc)"),
+        Line::from("  a / Enter          Attach to session (in sessions)"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Linear",
             Style::default().add_modifier(Modifier::BOLD),
         )]),
         Line::from("  L                  Sync Linear backlog"),
+        Line::from("  t                  Set Linear state"),
+        Line::from("  T                  Promote local task to a Linear issue"),
+        Line::from("  1-9 (in detail)    Open Linear attachment"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Other",
@@ -315,11 +483,12 @@ pub fn render_help_modal(frame: &mut Frame, area: Rect) {
         )]),
         Line::from("  / or ;f            Search"),
         Line::from("  r                  Refresh"),
+        Line::from("  R                  Refresh selected task's PR and session"),
         Line::from("  ?                  This help"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Press Esc to close",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )]),
     ];
 
@@ -331,15 +500,550 @@ pub fn render_help_modal(frame: &mut Frame, area: Rect) {
     let modal_area = Rect::new(x, y, modal_width, modal_height);
 
     // Clear the area behind the modal
-    let clear = Block::default().style(Style::default().bg(Color::Black));
+    let clear = Block::default().style(Style::default().bg(theme.background));
     frame.render_widget(clear, modal_area);
 
     let help = Paragraph::new(help_text).block(
         Block::default()
             .borders(Borders::ALL)
             .title(" Help ")
-            .border_style(Style::default().fg(super::ACCENT)),
+            .border_style(Style::default().fg(theme.accent)),
     );
 
     frame.render_widget(help, modal_area);
 }
+
+pub fn render_state_picker_modal(
+    frame: &mut Frame,
+    area: Rect,
+    picker: &LinearStatePicker,
+    theme: &Theme,
+) {
+    let title = format!(" {} state ", picker.identifier);
+
+    let mut lines: Vec<Line> = if picker.loading {
+        vec![Line::from(Span::styled(
+            "Loading workflow states...",
+            Style::default().fg(theme.muted),
+        ))]
+    } else if let Some(ref error) = picker.error {
+        vec![Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(theme.error),
+        ))]
+    } else {
+        picker
+            .states
+            .iter()
+            .enumerate()
+            .map(|(i, state)| {
+                let is_selected = i == picker.selected;
+                let style = if is_selected {
+                    Style::default()
+                        .fg(theme.accent)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                Line::from(vec![
+                    Span::raw(if is_selected { "> " } else { "  " }),
+                    Span::styled(state.name.clone(), style),
+                    Span::styled(
+                        format!("  {}", state.state_type),
+                        Style::default().fg(theme.muted),
+                    ),
+                ])
+            })
+            .collect()
+    };
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "j/k: move | Enter: apply | Esc: cancel",
+        Style::default().fg(theme.muted),
+    )));
+
+    let modal_width = 50.min(area.width);
+    let modal_height = (lines.len() as u16 + 2).min(area.height);
+    let x = (area.width.saturating_sub(modal_width)) / 2;
+    let y = (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(x, y, modal_width, modal_height);
+
+    // Clear the area behind the modal
+    let clear = Block::default().style(Style::default().bg(theme.background));
+    frame.render_widget(clear, modal_area);
+
+    let picker_widget = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(Style::default().fg(theme.accent)),
+    );
+
+    frame.render_widget(picker_widget, modal_area);
+}
+
+pub fn render_branch_picker_modal(
+    frame: &mut Frame,
+    area: Rect,
+    picker: &BranchPicker,
+    theme: &Theme,
+) {
+    let title = format!(" {} branch ", picker.label);
+
+    // Window of rows around the selection; borders, spacer and hint take 4 rows
+    let max_rows = (area.height.saturating_sub(4) as usize).max(1);
+    let options = std::iter::once(None).chain(picker.branches.iter().map(Some));
+    let offset = (picker.selected + 1).saturating_sub(max_rows);
+    let mut lines: Vec<Line> = options
+        .enumerate()
+        .skip(offset)
+        .take(max_rows)
+        .map(|(i, branch)| {
+            let is_selected = i == picker.selected;
+            let style = if is_selected {
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let label = match branch {
+                Some(branch) => Span::styled(branch.clone(), style),
+                None => Span::styled("(derive from title)", style.fg(theme.muted)),
+            };
+            Line::from(vec![
+                Span::raw(if is_selected { "> " } else { "  " }),
+                label,
+            ])
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "j/k: move | Enter: bind | Esc: cancel",
+        Style::default().fg(theme.muted),
+    )));
+
+    let modal_width = 60.min(area.width);
+    let modal_height = (lines.len() as u16 + 2).min(area.height);
+    let x = (area.width.saturating_sub(modal_width)) / 2;
+    let y = (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(x, y, modal_width, modal_height);
+
+    frame.render_widget(Clear, modal_area);
+    let picker_widget = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(Style::default().fg(theme.accent)),
+    );
+
+    frame.render_widget(picker_widget, modal_area);
+}
+
+pub fn render_assistant_picker_modal(
+    frame: &mut Frame,
+    area: Rect,
+    picker: &AssistantPicker,
+    theme: &Theme,
+) {
+    let mut lines: Vec<Line> = picker
+        .options
+        .iter()
+        .enumerate()
+        .map(|(i, &(assistant, available))| {
+            let is_selected = i == picker.selected && available;
+            let style = if !available {
+                Style::default().fg(theme.muted)
+            } else if is_selected {
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            Line::from(vec![
+                Span::raw(if is_selected { "> " } else { "  " }),
+                Span::styled(assistant.binary(), style),
+                Span::styled(
+                    if available { "" } else { "  not found on PATH" },
+                    Style::default().fg(theme.muted),
+                ),
+            ])
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "j/k: move | Enter: launch | Esc: cancel",
+        Style::default().fg(theme.muted),
+    )));
+
+    let modal_width = 44.min(area.width);
+    let modal_height = (lines.len() as u16 + 2).min(area.height);
+    let x = (area.width.saturating_sub(modal_width)) / 2;
+    let y = (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(x, y, modal_width, modal_height);
+
+    // Clear the area behind the modal
+    let clear = Block::default().style(Style::default().bg(theme.background));
+    frame.render_widget(clear, modal_area);
+
+    let picker_widget = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Launch with ")
+            .border_style(Style::default().fg(theme.accent)),
+    );
+
+    frame.render_widget(picker_widget, modal_area);
+}
+
+pub fn render_confirm_delete_modal(
+    frame: &mut Frame,
+    area: Rect,
+    pending: &PendingDelete,
+    theme: &Theme,
+) {
+    let lines = vec![
+        Line::from(vec![
+            Span::raw("Delete "),
+            Span::styled(
+                pending.label.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("?"),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "y/Enter: delete | n/Esc: cancel",
+            Style::default().fg(theme.muted),
+        )),
+    ];
+    render_confirm_modal(frame, area, lines, theme);
+}
+
+pub fn render_confirm_cancel_modal(
+    frame: &mut Frame,
+    area: Rect,
+    pending: &PendingCancel,
+    theme: &Theme,
+) {
+    let mut lines = vec![
+        Line::from(vec![
+            Span::raw("Cancel "),
+            Span::styled(
+                pending.label.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("?"),
+        ]),
+        Line::from(Span::styled(
+            "It moves to Done, marked cancelled",
+            Style::default().fg(theme.muted),
+        )),
+        Line::from(""),
+    ];
+    lines.push(Line::from(Span::styled(
+        "y/Enter: cancel | n/Esc: back",
+        Style::default().fg(theme.muted),
+    )));
+    if pending.linear_id.is_some() {
+        lines.push(Line::from(Span::styled(
+            "L: cancel the Linear issue too",
+            Style::default().fg(theme.muted),
+        )));
+    }
+    render_confirm_modal(frame, area, lines, theme);
+}
+
+pub fn render_confirm_compact_modal(
+    frame: &mut Frame,
+    area: Rect,
+    pending: &PendingCompact,
+    theme: &Theme,
+) {
+    let lines = vec![
+        Line::from(vec![
+            Span::raw("Send /compact to "),
+            Span::styled(
+                pending.label.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("?"),
+        ]),
+        Line::from(Span::styled(
+            format!("Context is at {:.0}%", pending.context_percentage),
+            Style::default().fg(theme.warning),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            "y/Enter: compact | n/Esc: cancel",
+            Style::default().fg(theme.muted),
+        )),
+    ];
+    render_confirm_modal(frame, area, lines, theme);
+}
+
+pub fn render_confirm_approve_modal(
+    frame: &mut Frame,
+    area: Rect,
+    pending: &PendingApprove,
+    theme: &Theme,
+) {
+    let pr = pending
+        .pr_number
+        .map_or_else(|| "the PR".to_string(), |n| format!("PR #{}", n));
+    let lines = vec![
+        Line::from(vec![
+            Span::raw(format!("Approve {} for ", pr)),
+            Span::styled(
+                pending.label.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("?"),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "y/Enter: approve | n/Esc: cancel",
+            Style::default().fg(theme.muted),
+        )),
+    ];
+    render_confirm_modal(frame, area, lines, theme);
+}
+
+/// Full-screen notice for a TUI started outside a git repository
+pub fn render_not_a_repo(frame: &mut Frame, cwd: &std::path::Path, theme: &Theme) {
+    let lines = vec![
+        Line::from(Span::styled(
+            "Not a git repository",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(cwd.display().to_string()),
+        Line::from(""),
+        Line::from("vibe keeps one board per repository, so it needs to start inside one."),
+        Line::from("cd into your project, or run `vibe --repo <path>`."),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Press any key to exit",
+            Style::default().fg(theme.muted),
+        )),
+    ];
+
+    let area = frame.area();
+    let width = 76.min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let notice_area = Rect::new(
+        area.x + (area.width.saturating_sub(width)) / 2,
+        area.y + (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    );
+
+    let notice = Paragraph::new(lines)
+        .alignment(ratatui::layout::Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent)),
+        );
+    frame.render_widget(notice, notice_area);
+}
+
+fn render_confirm_modal(frame: &mut Frame, area: Rect, lines: Vec<Line>, theme: &Theme) {
+    let modal_width = 50.min(area.width);
+    let modal_height = (lines.len() as u16 + 2).min(area.height);
+    let x = (area.width.saturating_sub(modal_width)) / 2;
+    let y = (area.height.saturating_sub(modal_height)) / 2;
+    let modal_area = Rect::new(x, y, modal_width, modal_height);
+
+    frame.render_widget(Clear, modal_area);
+
+    let confirm = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Confirm ")
+            .border_style(Style::default().fg(theme.error)),
+    );
+
+    frame.render_widget(confirm, modal_area);
+}
+
+pub fn render_scrollback_modal(
+    frame: &mut Frame,
+    area: Rect,
+    scrollback: &SessionScrollback,
+    theme: &Theme,
+) {
+    // Leave a margin around the popup
+    let modal_area = Rect::new(
+        area.x + area.width / 10,
+        area.y + area.height / 10,
+        area.width - area.width / 5,
+        area.height - area.height / 5,
+    );
+    let visible_height = modal_area.height.saturating_sub(2) as usize;
+
+    let lines: Vec<Line> = if scrollback.loading {
+        vec![Line::from(Span::styled(
+            "Capturing scrollback...",
+            Style::default().fg(theme.muted),
+        ))]
+    } else if let Some(ref error) = scrollback.error {
+        vec![Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(theme.error),
+        ))]
+    } else {
+        let max_offset = scrollback.lines.len().saturating_sub(visible_height);
+        scrollback
+            .lines
+            .iter()
+            .skip(scrollback.offset.min(max_offset))
+            .take(visible_height)
+            .map(|l| Line::from(l.as_str()))
+            .collect()
+    };
+
+    let position = if scrollback.lines.is_empty() {
+        String::new()
+    } else {
+        format!(
+            " {}/{} ",
+            (scrollback.offset + visible_height).min(scrollback.lines.len()),
+            scrollback.lines.len()
+        )
+    };
+
+    frame.render_widget(Clear, modal_area);
+
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", scrollback.session_name))
+            .title_bottom(Line::from(position).right_aligned())
+            .title_bottom(" j/k: scroll | Ctrl-u/d: page | Esc: close ")
+            .border_style(Style::default().fg(theme.accent)),
+    );
+
+    frame.render_widget(popup, modal_area);
+}
+
+pub fn render_pr_diff_modal(frame: &mut Frame, area: Rect, diff: &PrDiff, theme: &Theme) {
+    // Leave a margin around the popup
+    let modal_area = Rect::new(
+        area.x + area.width / 10,
+        area.y + area.height / 10,
+        area.width - area.width / 5,
+        area.height - area.height / 5,
+    );
+    let visible_height = modal_area.height.saturating_sub(2) as usize;
+
+    let mut lines: Vec<Line> = if diff.loading {
+        vec![Line::from(Span::styled(
+            "Fetching diff...",
+            Style::default().fg(theme.muted),
+        ))]
+    } else if let Some(ref error) = diff.error {
+        vec![Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(theme.error),
+        ))]
+    } else if diff.lines.is_empty() {
+        vec![Line::from(Span::styled(
+            "Empty diff",
+            Style::default().fg(theme.muted),
+        ))]
+    } else {
+        let max_offset = diff.lines.len().saturating_sub(visible_height);
+        diff.lines
+            .iter()
+            .skip(diff.offset.min(max_offset))
+            .take(visible_height)
+            .map(|l| {
+                let style = match DiffLineKind::of(l) {
+                    DiffLineKind::File => Style::default()
+                        .fg(theme.highlight)
+                        .add_modifier(Modifier::BOLD),
+                    DiffLineKind::Hunk => Style::default().fg(theme.info),
+                    DiffLineKind::Added => Style::default().fg(theme.success),
+                    DiffLineKind::Removed => Style::default().fg(theme.error),
+                    DiffLineKind::Context => Style::default(),
+                };
+                Line::from(Span::styled(l.as_str(), style))
+            })
+            .collect()
+    };
+    // The cut-off note takes the last row once the end is in view
+    let at_end = diff.offset + visible_height >= diff.lines.len();
+    if diff.omitted > 0 && at_end && !diff.loading {
+        if lines.len() >= visible_height {
+            lines.pop();
+        }
+        lines.push(Line::from(Span::styled(
+            format!(
+                "... {} more lines not shown (v opens the PR in the browser)",
+                diff.omitted
+            ),
+            Style::default().fg(theme.warning),
+        )));
+    }
+
+    let position = if diff.lines.is_empty() {
+        String::new()
+    } else {
+        format!(
+            " {}/{} ",
+            (diff.offset + visible_height).min(diff.lines.len()),
+            diff.lines.len()
+        )
+    };
+
+    frame.render_widget(Clear, modal_area);
+
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", diff.pr))
+            .title_bottom(Line::from(position).right_aligned())
+            .title_bottom(" j/k: scroll | Ctrl-u/d: page | Esc: close ")
+            .border_style(Style::default().fg(theme.accent)),
+    );
+
+    frame.render_widget(popup, modal_area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn test_too_small_guard() {
+        assert!(is_too_small(Rect::new(0, 0, 120, 10)));
+        assert!(is_too_small(Rect::new(0, 0, 30, 40)));
+        assert!(!is_too_small(Rect::new(0, 0, MIN_WIDTH, MIN_HEIGHT)));
+
+        // Renders even into a handful of cells
+        for (width, height) in [(20, 5), (1, 1), (0, 0)] {
+            let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+            terminal
+                .draw(|frame| render_too_small(frame, frame.area(), &Theme::default()))
+                .unwrap();
+        }
+        let mut terminal = Terminal::new(TestBackend::new(30, 6)).unwrap();
+        terminal
+            .draw(|frame| render_too_small(frame, frame.area(), &Theme::default()))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(text.contains("terminal too small"));
+        assert!(text.contains("need ≥ 40x15, have 30x6"));
+    }
+}
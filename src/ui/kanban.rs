@@ -8,8 +8,43 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState},
 };
 
-use crate::external::{ClaudeActivityState, LinearIssueStatus};
-use crate::state::{SessionsState, TaskStatus, TasksState, WorktreesState};
+use super::Theme;
+use crate::external::{ChecksStatus, ClaudeActivityState, LinearIssueStatus};
+use crate::state::{
+    SessionsState, Task, TaskStatus, TasksState, WorktreesState, assignee_initials, staleness,
+    task_age_days, task_assignee, task_pr_and_worktree,
+};
+
+/// "[stored:Backlog pr:OPEN/draft wt:yes linear:started -> In Progress by worktree]"
+fn status_debug(
+    task: &Task,
+    worktrees: &WorktreesState,
+    linear_statuses: &HashMap<String, LinearIssueStatus>,
+) -> String {
+    let (branch_pr, has_worktree) =
+        task_pr_and_worktree(task, &worktrees.branch_prs, &worktrees.worktrees);
+    let linear_status = task
+        .linear_issue_id
+        .as_ref()
+        .and_then(|id| linear_statuses.get(id));
+    let (status, source) = task.resolve_status(branch_pr, has_worktree, linear_status);
+
+    let pr = match (branch_pr, task.pr_status.as_deref()) {
+        (Some(pr), _) if pr.is_draft => format!("{}/draft", pr.state),
+        (Some(pr), _) => pr.state.clone(),
+        (None, Some(stored)) => format!("{} (stored)", stored),
+        (None, None) => "-".to_string(),
+    };
+    format!(
+        "[stored:{} pr:{} wt:{} linear:{} -> {} by {}]",
+        task.status.label(),
+        pr,
+        if has_worktree { "yes" } else { "no" },
+        linear_status.map_or("-", |l| l.state_type.as_str()),
+        status.label(),
+        source.label()
+    )
+}
 
 #[allow(clippy::too_many_arguments)]
 pub fn render_kanban_board(
@@ -21,7 +56,22 @@ pub fn render_kanban_board(
     spinner_char: char,
     linear_pending_count: usize,
     linear_statuses: &HashMap<String, LinearIssueStatus>,
+    theme: &Theme,
 ) {
+    if tasks.layout.is_compact(area.width) {
+        render_compact_board(
+            frame,
+            area,
+            tasks,
+            worktrees,
+            sessions,
+            linear_pending_count,
+            linear_statuses,
+            theme,
+        );
+        return;
+    }
+
     // Split into 4 horizontal rows (Backlog, In Progress, In Review, Done)
     let rows = Layout::default()
         .direction(Direction::Vertical)
@@ -51,6 +101,7 @@ pub fn render_kanban_board(
             spinner_char,
             pending,
             linear_statuses,
+            theme,
         );
     }
 }
@@ -67,6 +118,7 @@ fn render_row(
     spinner_char: char,
     linear_pending: usize,
     linear_statuses: &HashMap<String, LinearIssueStatus>,
+    theme: &Theme,
 ) {
     let tasks = tasks_state.tasks_in_column_with_prs(
         status,
@@ -77,23 +129,56 @@ fn render_row(
     let count = tasks.len();
     let column_index = status.column_index();
 
+    // With a WIP limit the count reads "4/3", and turns red once over it
+    let count_str = match tasks_state.wip_limits.limit(status) {
+        Some(limit) => format!("{}/{}", count, limit),
+        None => count.to_string(),
+    };
     let title = if linear_pending > 0 {
         format!(
             " {} ({}) - Linear (+{}) ",
             status.label(),
-            count,
+            count_str,
             linear_pending
         )
     } else {
-        format!(" {} ({}) ", status.label(), count)
+        format!(" {} ({}) ", status.label(), count_str)
+    };
+    let title = if status == TaskStatus::Backlog && tasks_state.backlog_by_age {
+        format!("{}- oldest first ", title)
+    } else {
+        title
+    };
+    // The assignee filter applies to every row; name it once on the top one
+    let title = match &tasks_state.assignee_filter {
+        Some(assignee) if column_index == 0 => format!("{}- {} ", title, assignee),
+        _ => title,
+    };
+    let title = match &tasks_state.base_filter {
+        Some(base) if column_index == 0 => format!("{}- → {} ", title, base),
+        _ => title,
+    };
+    let title = if tasks_state.mine_only && column_index == 0 {
+        format!("{}- my PRs ", title)
+    } else {
+        title
+    };
+    let title_style = if tasks_state.wip_limits.is_exceeded(status, count) {
+        Style::default()
+            .fg(theme.error)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
     };
 
     let border_color = if is_selected {
-        super::ACCENT
+        theme.accent
     } else {
-        Color::DarkGray
+        theme.muted
     };
 
+    let today = chrono::Local::now().date_naive();
+
     // For horizontal rows, show tasks in a single-line compact format
     let items: Vec<ListItem> = tasks
         .iter()
@@ -105,18 +190,76 @@ fn render_row(
                 spans.push(Span::styled(
                     format!("[{}] ", spinner_char),
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(theme.warning)
                         .add_modifier(Modifier::BOLD),
                 ));
             } else if task.last_attempt_failed {
                 spans.push(Span::styled(
                     "[!] ",
-                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    Style::default()
+                        .fg(theme.error)
+                        .add_modifier(Modifier::BOLD),
                 ));
             }
 
-            // Title
-            spans.push(Span::raw(task.title.clone()));
+            // Pinned: the task of the worktree vibe is running in
+            if tasks_state.is_pinned(task) {
+                spans.push(Span::styled(
+                    "* ",
+                    Style::default()
+                        .fg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+
+            // Linear priority, urgent to low
+            if let Some(marker) = task.priority.and_then(|p| priority_marker(p, theme)) {
+                spans.push(marker);
+            }
+
+            // Title - backlog cards fade toward red as they age, with the age shown once stale
+            let backlog_age = (status == TaskStatus::Backlog)
+                .then(|| task_age_days(task, today))
+                .flatten()
+                .filter(|days| staleness(*days) > 0.0);
+            // Cancelled cards share the Done row; strike them through to tell them apart
+            let cancelled = status == TaskStatus::Done && {
+                let (branch_pr, has_worktree) =
+                    task_pr_and_worktree(task, &worktrees.branch_prs, &worktrees.worktrees);
+                let linear_status = task
+                    .linear_issue_id
+                    .as_ref()
+                    .and_then(|id| linear_statuses.get(id));
+                task.effective_status_with_pr(branch_pr, has_worktree, linear_status)
+                    == TaskStatus::Cancelled
+            };
+            match backlog_age {
+                _ if cancelled => spans.push(Span::styled(
+                    task.title.clone(),
+                    Style::default()
+                        .fg(theme.muted)
+                        .add_modifier(Modifier::CROSSED_OUT),
+                )),
+                Some(days) => {
+                    let style = Style::default().fg(theme.staleness_color(staleness(days)));
+                    spans.push(Span::styled(task.title.clone(), style));
+                    spans.push(Span::styled(format!(" {}d", days), style));
+                }
+                None => spans.push(Span::raw(task.title.clone())),
+            }
+
+            // Linear estimate
+            if let Some(points) = task.estimate {
+                spans.push(Span::styled(
+                    format!(" {}pt", points),
+                    Style::default().fg(theme.muted),
+                ));
+            }
+
+            // Private notes
+            if task.notes.is_some() {
+                spans.push(Span::styled(" ✎", Style::default().fg(theme.secondary)));
+            }
 
             // Worktree/branch info - find it first so we can use it for PR lookup
             let task_slug = task.title.to_lowercase().replace(' ', "-");
@@ -126,23 +269,23 @@ fn render_row(
             });
 
             // PR status - check backend first, then local gh detection
-            let has_backend_pr = task.pr_url.is_some();
+            let has_backend_pr = task.pr_status.is_some();
             let branch_pr = matching_worktree.and_then(|wt| worktrees.pr_for_branch(&wt.branch));
 
             if has_backend_pr {
                 // Use backend PR info
                 let (pr_icon, pr_color) = match task.pr_status.as_deref() {
-                    Some("merged") => ("[M]", Color::Magenta),
-                    Some("closed") => ("[X]", Color::Red),
+                    Some("merged") => ("[M]", theme.highlight),
+                    Some("closed") => ("[X]", theme.error),
                     _ => match (
                         task.pr_review_decision.as_deref(),
                         task.pr_checks_status.as_deref(),
                     ) {
-                        (Some("APPROVED"), _) => ("[✓]", Color::Green),
-                        (Some("CHANGES_REQUESTED"), _) => ("[?]", Color::Yellow),
-                        (_, Some("FAILURE")) => ("[✗]", Color::Red),
-                        (_, Some("SUCCESS")) => ("[✓]", Color::Green),
-                        _ => ("[PR]", super::ACCENT),
+                        (Some("APPROVED"), _) => ("[✓]", theme.success),
+                        (Some("CHANGES_REQUESTED"), _) => ("[?]", theme.warning),
+                        (_, Some("FAILURE")) => ("[✗]", theme.error),
+                        (_, Some("SUCCESS")) => ("[✓]", theme.success),
+                        _ => ("[PR]", theme.accent),
                     },
                 };
                 spans.push(Span::styled(
@@ -150,16 +293,16 @@ fn render_row(
                     Style::default().fg(pr_color),
                 ));
                 if task.pr_has_conflicts == Some(true) {
-                    spans.push(Span::styled(" !", Style::default().fg(Color::Red)));
+                    spans.push(Span::styled(" !", Style::default().fg(theme.error)));
                 }
             } else if let Some(pr) = branch_pr {
                 // Use locally detected PR info from gh
                 match pr.state.as_str() {
                     "MERGED" => {
-                        spans.push(Span::styled(" [M]", Style::default().fg(Color::Magenta)));
+                        spans.push(Span::styled(" [M]", Style::default().fg(theme.highlight)));
                     }
                     "CLOSED" => {
-                        spans.push(Span::styled(" [X]", Style::default().fg(Color::Red)));
+                        spans.push(Span::styled(" [X]", Style::default().fg(theme.error)));
                     }
                     _ => {
                         // Open PR - check review/checks status
@@ -177,40 +320,41 @@ fn render_row(
                             };
                             spans.push(Span::styled(
                                 format!(" {}", icon),
-                                Style::default().fg(Color::Green),
+                                Style::default().fg(theme.success),
                             ));
                         } else if pr.review_decision.as_deref() == Some("CHANGES_REQUESTED") {
-                            spans.push(Span::styled(" [?]", Style::default().fg(Color::Yellow)));
+                            spans.push(Span::styled(" [?]", Style::default().fg(theme.warning)));
                         } else {
-                            match pr.checks_status().as_deref() {
-                                Some("FAILURE") => {
+                            match pr.checks_status() {
+                                ChecksStatus::Failure => {
                                     spans.push(Span::styled(
                                         " [✗]",
-                                        Style::default().fg(Color::Red),
+                                        Style::default().fg(theme.error),
                                     ));
                                 }
-                                Some("SUCCESS") => {
+                                ChecksStatus::Success => {
                                     spans.push(Span::styled(
                                         " [✓]",
-                                        Style::default().fg(Color::Green),
+                                        Style::default().fg(theme.success),
                                     ));
                                 }
-                                Some("PENDING") => {
+                                ChecksStatus::Pending => {
                                     spans.push(Span::styled(
                                         " [~]",
-                                        Style::default().fg(Color::Yellow),
+                                        Style::default().fg(theme.warning),
                                     ));
                                 }
-                                _ if pr.is_draft => {
+                                ChecksStatus::None if pr.is_draft => {
                                     spans.push(Span::styled(
                                         " [D]",
-                                        Style::default().fg(Color::DarkGray),
+                                        Style::default().fg(theme.muted),
                                     ));
                                 }
-                                _ => {
+                                // No CI on this PR: neutral, so it doesn't read as passing
+                                ChecksStatus::None => {
                                     spans.push(Span::styled(
                                         " [PR]",
-                                        Style::default().fg(super::ACCENT),
+                                        Style::default().fg(theme.muted),
                                     ));
                                 }
                             }
@@ -218,15 +362,44 @@ fn render_row(
                     }
                 }
                 if pr.has_conflicts() {
-                    spans.push(Span::styled(" !", Style::default().fg(Color::Red)));
+                    spans.push(Span::styled(" !", Style::default().fg(theme.error)));
                 }
             }
 
+            // Base branch, for PRs in review that don't target the default branch
+            if status == TaskStatus::Inreview
+                && let Some(base) =
+                    task_pr_and_worktree(task, &worktrees.branch_prs, &worktrees.worktrees)
+                        .0
+                        .and_then(|pr| pr.non_default_base(worktrees.default_branch.as_deref()))
+            {
+                spans.push(Span::styled(
+                    format!(" → {}", base),
+                    Style::default().fg(theme.highlight),
+                ));
+            }
+
             // Linear identifier
             if let Some(linear_id) = &task.linear_issue_id {
                 spans.push(Span::styled(
                     format!(" {}", linear_id),
-                    Style::default().fg(Color::Blue),
+                    Style::default().fg(theme.info),
+                ));
+            }
+
+            // Assignee initials, for boards shared between several people
+            if let Some(assignee) = task_assignee(task, linear_statuses) {
+                spans.push(Span::styled(
+                    format!(" {}", assignee_initials(assignee)),
+                    Style::default().fg(theme.secondary),
+                ));
+            }
+
+            // Debug overlay: what fed effective_status_with_pr and which rule won
+            if tasks_state.show_status_debug {
+                spans.push(Span::styled(
+                    format!(" {}", status_debug(task, worktrees, linear_statuses)),
+                    Style::default().fg(theme.muted),
                 ));
             }
 
@@ -237,30 +410,33 @@ fn render_row(
             {
                 // Claude activity indicator - only show when active or needs attention
                 match session.claude_activity {
+                    _ if session.attention_acknowledged() => {
+                        spans.push(Span::styled(" [✓]", Style::default().fg(theme.muted)));
+                    }
                     ClaudeActivityState::Thinking => {
                         spans.push(Span::styled(
                             format!(" [{}]", spinner_char),
-                            Style::default()
-                                .fg(Color::Blue)
-                                .add_modifier(Modifier::BOLD),
+                            Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
                         ));
                     }
                     ClaudeActivityState::WaitingForUser => {
                         spans.push(Span::styled(
                             " [?]",
                             Style::default()
-                                .fg(Color::Yellow)
+                                .fg(theme.warning)
                                 .add_modifier(Modifier::BOLD),
                         ));
                     }
                     ClaudeActivityState::Unknown if session.needs_attention => {
                         spans.push(Span::styled(
                             " [!]",
-                            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                            Style::default()
+                                .fg(theme.error)
+                                .add_modifier(Modifier::BOLD),
                         ));
                     }
                     ClaudeActivityState::Idle => {
-                        spans.push(Span::styled(" ·", Style::default().fg(Color::DarkGray)));
+                        spans.push(Span::styled(" ·", Style::default().fg(theme.muted)));
                     }
                     // Unknown without attention - don't show indicator
                     _ => {}
@@ -269,11 +445,11 @@ fn render_row(
                 // Context window percentage (always show if available, useful info)
                 if let Some(pct) = session.context_percentage {
                     let color = if pct > 90.0 {
-                        Color::Red
+                        theme.error
                     } else if pct > 70.0 {
-                        Color::Yellow
+                        theme.warning
                     } else {
-                        Color::DarkGray
+                        theme.muted
                     };
                     spans.push(Span::styled(
                         format!(" {:.0}%", pct),
@@ -290,12 +466,12 @@ fn render_row(
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(title)
+                .title(Span::styled(title, title_style))
                 .border_style(Style::default().fg(border_color)),
         )
         .highlight_style(
             Style::default()
-                .bg(Color::DarkGray)
+                .bg(theme.muted)
                 .add_modifier(Modifier::BOLD),
         );
 
@@ -306,3 +482,154 @@ fn render_row(
 
     frame.render_stateful_widget(list, area, &mut list_state);
 }
+
+/// Color of the dot marking a task's session activity, if it has any worth showing
+fn activity_color(
+    task: &Task,
+    status: TaskStatus,
+    worktrees: &WorktreesState,
+    sessions: &SessionsState,
+    theme: &Theme,
+) -> Option<Color> {
+    if task.has_in_progress_attempt {
+        return Some(theme.warning);
+    }
+    if task.last_attempt_failed {
+        return Some(theme.error);
+    }
+    if status == TaskStatus::Done {
+        return None;
+    }
+    let task_slug = task.title.to_lowercase().replace(' ', "-");
+    let session = worktrees
+        .worktrees
+        .iter()
+        .find(|w| {
+            w.branch.to_lowercase().contains(&task_slug)
+                || task_slug.contains(&w.branch.to_lowercase())
+        })
+        .and_then(|wt| sessions.session_for_branch(&wt.branch))?;
+    match session.claude_activity {
+        _ if session.attention_acknowledged() => Some(theme.muted),
+        ClaudeActivityState::Thinking => Some(theme.info),
+        ClaudeActivityState::WaitingForUser => Some(theme.warning),
+        ClaudeActivityState::Unknown if session.needs_attention => Some(theme.error),
+        ClaudeActivityState::Idle => Some(theme.muted),
+        _ => None,
+    }
+}
+
+/// Marker for a Linear priority in front of the title; none for "No priority"
+fn priority_marker(priority: u8, theme: &Theme) -> Option<Span<'static>> {
+    let (marker, color) = match priority {
+        1 => ("‼ ", theme.error),
+        2 => ("↑ ", theme.warning),
+        3 => ("→ ", theme.accent),
+        4 => ("↓ ", theme.muted),
+        _ => return None,
+    };
+    Some(Span::styled(marker, Style::default().fg(color)))
+}
+
+/// `title` cut to `width` characters, ending in an ellipsis when shortened
+fn fit_title(title: &str, width: usize) -> String {
+    if title.chars().count() <= width {
+        return title.to_string();
+    }
+    let mut fitted: String = title.chars().take(width.saturating_sub(1)).collect();
+    fitted.push('…');
+    fitted
+}
+
+/// Every visible task on one line each, in column order, for narrow terminals.
+/// Navigation is unchanged: the selected column's card is highlighted.
+#[allow(clippy::too_many_arguments)]
+fn render_compact_board(
+    frame: &mut Frame,
+    area: Rect,
+    tasks_state: &TasksState,
+    worktrees: &WorktreesState,
+    sessions: &SessionsState,
+    linear_pending: usize,
+    linear_statuses: &HashMap<String, LinearIssueStatus>,
+    theme: &Theme,
+) {
+    // Borders, "B " in front and " ●" behind the title
+    let title_width = usize::from(area.width).saturating_sub(6);
+
+    let mut items: Vec<ListItem> = Vec::new();
+    let mut selected = None;
+    for column in TaskStatus::VISIBLE {
+        let tasks = tasks_state.tasks_in_column_with_prs(
+            column,
+            &worktrees.branch_prs,
+            &worktrees.worktrees,
+            linear_statuses,
+        );
+        let column_index = column.column_index();
+        if tasks_state.selected_column == column_index && !tasks.is_empty() {
+            selected = Some(items.len() + tasks_state.selected_card_per_column[column_index]);
+        }
+        for task in tasks {
+            let (branch_pr, has_worktree) =
+                task_pr_and_worktree(task, &worktrees.branch_prs, &worktrees.worktrees);
+            let linear_status = task
+                .linear_issue_id
+                .as_ref()
+                .and_then(|id| linear_statuses.get(id));
+            let status = task.effective_status_with_pr(branch_pr, has_worktree, linear_status);
+
+            let title_style = if status == TaskStatus::Cancelled {
+                Style::default()
+                    .fg(theme.muted)
+                    .add_modifier(Modifier::CROSSED_OUT)
+            } else {
+                Style::default()
+            };
+            let mut spans = vec![
+                Span::styled(
+                    format!("{} ", status.letter()),
+                    Style::default()
+                        .fg(theme.status_color(status))
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(fit_title(&task.title, title_width), title_style),
+            ];
+            if let Some(color) = activity_color(task, column, worktrees, sessions, theme) {
+                spans.push(Span::styled(" ●", Style::default().fg(color)));
+            }
+            items.push(ListItem::new(Line::from(spans)));
+        }
+    }
+
+    let mut title = format!(" Tasks ({}) ", items.len());
+    if linear_pending > 0 {
+        title = format!("{}- Linear (+{}) ", title, linear_pending);
+    }
+    if let Some(assignee) = &tasks_state.assignee_filter {
+        title = format!("{}- {} ", title, assignee);
+    }
+    if let Some(base) = &tasks_state.base_filter {
+        title = format!("{}- → {} ", title, base);
+    }
+    if tasks_state.mine_only {
+        title = format!("{}- my PRs ", title);
+    }
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(Style::default().fg(theme.accent)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.muted)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    let mut list_state = ListState::default();
+    list_state.select(selected);
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
@@ -1,14 +1,15 @@
 use ratatui::{
     Frame,
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
 
+use super::Theme;
 use crate::state::LogsState;
 
-pub fn render_logs(frame: &mut Frame, area: Rect, logs: &LogsState) {
+pub fn render_logs(frame: &mut Frame, area: Rect, logs: &LogsState, theme: &Theme) {
     let height = area.height.saturating_sub(2) as usize; // Account for borders
 
     let lines: Vec<Line> = logs
@@ -16,15 +17,15 @@ pub fn render_logs(frame: &mut Frame, area: Rect, logs: &LogsState) {
         .map(|line| {
             // Color based on log level
             let style = if line.contains("ERROR") {
-                Style::default().fg(Color::Red)
+                Style::default().fg(theme.error)
             } else if line.contains("WARN") {
-                Style::default().fg(Color::Yellow)
+                Style::default().fg(theme.warning)
             } else if line.contains("INFO") {
-                Style::default().fg(Color::Green)
+                Style::default().fg(theme.success)
             } else if line.contains("DEBUG") {
-                Style::default().fg(Color::Blue)
+                Style::default().fg(theme.info)
             } else {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(theme.muted)
             };
 
             Line::from(Span::styled(line.clone(), style))
@@ -42,7 +43,7 @@ pub fn render_logs(frame: &mut Frame, area: Rect, logs: &LogsState) {
             Block::default()
                 .borders(Borders::ALL)
                 .title(title)
-                .border_style(Style::default().fg(super::ACCENT)),
+                .border_style(Style::default().fg(theme.accent)),
         )
         .wrap(Wrap { trim: false });
 
@@ -53,7 +54,7 @@ pub fn render_logs(frame: &mut Frame, area: Rect, logs: &LogsState) {
     let help_line = Line::from(vec![Span::styled(
         help_text,
         Style::default()
-            .fg(Color::DarkGray)
+            .fg(theme.muted)
             .add_modifier(Modifier::ITALIC),
     )]);
 
@@ -68,7 +69,7 @@ pub fn render_logs(frame: &mut Frame, area: Rect, logs: &LogsState) {
 }
 
 /// Render logs as a centered overlay on top of everything
-pub fn render_logs_overlay(frame: &mut Frame, area: Rect, logs: &LogsState) {
+pub fn render_logs_overlay(frame: &mut Frame, area: Rect, logs: &LogsState, theme: &Theme) {
     // Create a centered overlay that takes up 80% of the screen
     let overlay_width = (area.width as f32 * 0.8) as u16;
     let overlay_height = (area.height as f32 * 0.8) as u16;
@@ -92,15 +93,15 @@ pub fn render_logs_overlay(frame: &mut Frame, area: Rect, logs: &LogsState) {
         .visible_lines(height)
         .map(|line| {
             let style = if line.contains("ERROR") {
-                Style::default().fg(Color::Red)
+                Style::default().fg(theme.error)
             } else if line.contains("WARN") {
-                Style::default().fg(Color::Yellow)
+                Style::default().fg(theme.warning)
             } else if line.contains("INFO") {
-                Style::default().fg(Color::Green)
+                Style::default().fg(theme.success)
             } else if line.contains("DEBUG") {
-                Style::default().fg(Color::Blue)
+                Style::default().fg(theme.info)
             } else {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(theme.muted)
             };
             Line::from(Span::styled(line.clone(), style))
         })
@@ -117,7 +118,7 @@ pub fn render_logs_overlay(frame: &mut Frame, area: Rect, logs: &LogsState) {
             Block::default()
                 .borders(Borders::ALL)
                 .title(title)
-                .border_style(Style::default().fg(super::ACCENT)),
+                .border_style(Style::default().fg(theme.accent)),
         )
         .wrap(Wrap { trim: false });
 
@@ -128,7 +129,7 @@ pub fn render_logs_overlay(frame: &mut Frame, area: Rect, logs: &LogsState) {
     let help_line = Line::from(vec![Span::styled(
         help_text,
         Style::default()
-            .fg(Color::DarkGray)
+            .fg(theme.muted)
             .add_modifier(Modifier::ITALIC),
     )]);
 
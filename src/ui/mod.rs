@@ -2,16 +2,16 @@ mod common;
 mod kanban;
 mod logs;
 mod search;
+mod sessions;
 mod task_detail;
+mod theme;
 mod worktrees;
 
 pub use common::*;
 pub use kanban::*;
 pub use logs::*;
 pub use search::*;
+pub use sessions::*;
 pub use task_detail::*;
+pub use theme::Theme;
 pub use worktrees::*;
-
-use ratatui::style::Color;
-
-pub const ACCENT: Color = Color::Rgb(232, 145, 58);
@@ -1,14 +1,15 @@
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
 };
 
+use super::Theme;
 use crate::state::SearchState;
 
-pub fn render_search(frame: &mut Frame, area: Rect, search: &SearchState) {
+pub fn render_search(frame: &mut Frame, area: Rect, search: &SearchState, theme: &Theme) {
     // Split into search input (top) and results with preview (bottom)
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -18,18 +19,18 @@ pub fn render_search(frame: &mut Frame, area: Rect, search: &SearchState) {
         ])
         .split(area);
 
-    render_search_input(frame, chunks[0], search);
-    render_results_and_preview(frame, chunks[1], search);
+    render_search_input(frame, chunks[0], search, theme);
+    render_results_and_preview(frame, chunks[1], search, theme);
 }
 
-fn render_search_input(frame: &mut Frame, area: Rect, search: &SearchState) {
+fn render_search_input(frame: &mut Frame, area: Rect, search: &SearchState, theme: &Theme) {
     let input_line = Line::from(vec![
-        Span::styled("> ", Style::default().fg(super::ACCENT)),
+        Span::styled("> ", Style::default().fg(theme.accent)),
         Span::raw(&search.query),
         Span::styled(
             "_",
             Style::default()
-                .fg(super::ACCENT)
+                .fg(theme.accent)
                 .add_modifier(Modifier::SLOW_BLINK),
         ),
     ]);
@@ -38,13 +39,13 @@ fn render_search_input(frame: &mut Frame, area: Rect, search: &SearchState) {
         Block::default()
             .borders(Borders::ALL)
             .title(" Search ")
-            .border_style(Style::default().fg(super::ACCENT)),
+            .border_style(Style::default().fg(theme.accent)),
     );
 
     frame.render_widget(input, area);
 }
 
-fn render_results_and_preview(frame: &mut Frame, area: Rect, search: &SearchState) {
+fn render_results_and_preview(frame: &mut Frame, area: Rect, search: &SearchState, theme: &Theme) {
     // Split horizontally: results list (left) and preview (right)
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -54,11 +55,11 @@ fn render_results_and_preview(frame: &mut Frame, area: Rect, search: &SearchStat
         ])
         .split(area);
 
-    render_results_list(frame, chunks[0], search);
-    render_preview(frame, chunks[1], search);
+    render_results_list(frame, chunks[0], search, theme);
+    render_preview(frame, chunks[1], search, theme);
 }
 
-fn render_results_list(frame: &mut Frame, area: Rect, search: &SearchState) {
+fn render_results_list(frame: &mut Frame, area: Rect, search: &SearchState, theme: &Theme) {
     let items: Vec<ListItem> = search
         .results
         .iter()
@@ -77,11 +78,11 @@ fn render_results_list(frame: &mut Frame, area: Rect, search: &SearchState) {
             Block::default()
                 .borders(Borders::ALL)
                 .title(title)
-                .border_style(Style::default().fg(Color::DarkGray)),
+                .border_style(Style::default().fg(theme.muted)),
         )
         .highlight_style(
             Style::default()
-                .bg(Color::DarkGray)
+                .bg(theme.muted)
                 .add_modifier(Modifier::BOLD),
         )
         .highlight_symbol("> ");
@@ -94,11 +95,11 @@ fn render_results_list(frame: &mut Frame, area: Rect, search: &SearchState) {
     frame.render_stateful_widget(list, area, &mut list_state);
 }
 
-fn render_preview(frame: &mut Frame, area: Rect, search: &SearchState) {
+fn render_preview(frame: &mut Frame, area: Rect, search: &SearchState, theme: &Theme) {
     let content = if let Some(task) = search.selected_task() {
         let mut lines = vec![
             Line::from(vec![
-                Span::styled("Title: ", Style::default().fg(Color::Yellow)),
+                Span::styled("Title: ", Style::default().fg(theme.warning)),
                 Span::raw(&task.title),
             ]),
             Line::from(""),
@@ -106,7 +107,7 @@ fn render_preview(frame: &mut Frame, area: Rect, search: &SearchState) {
 
         // Status
         lines.push(Line::from(vec![
-            Span::styled("Status: ", Style::default().fg(Color::Yellow)),
+            Span::styled("Status: ", Style::default().fg(theme.warning)),
             Span::raw(task.status.label()),
         ]));
 
@@ -114,12 +115,12 @@ fn render_preview(frame: &mut Frame, area: Rect, search: &SearchState) {
         if let Some(ref pr_url) = task.pr_url {
             lines.push(Line::from(""));
             lines.push(Line::from(vec![
-                Span::styled("PR: ", Style::default().fg(Color::Yellow)),
-                Span::styled(pr_url, Style::default().fg(super::ACCENT)),
+                Span::styled("PR: ", Style::default().fg(theme.warning)),
+                Span::styled(pr_url, Style::default().fg(theme.accent)),
             ]));
             if let Some(ref status) = task.pr_status {
                 lines.push(Line::from(vec![
-                    Span::styled("  Status: ", Style::default().fg(Color::DarkGray)),
+                    Span::styled("  Status: ", Style::default().fg(theme.muted)),
                     Span::raw(status),
                 ]));
             }
@@ -129,8 +130,8 @@ fn render_preview(frame: &mut Frame, area: Rect, search: &SearchState) {
         if let Some(ref linear_url) = task.linear_url {
             lines.push(Line::from(""));
             lines.push(Line::from(vec![
-                Span::styled("Linear: ", Style::default().fg(Color::Yellow)),
-                Span::styled(linear_url, Style::default().fg(Color::Blue)),
+                Span::styled("Linear: ", Style::default().fg(theme.warning)),
+                Span::styled(linear_url, Style::default().fg(theme.info)),
             ]));
         }
 
@@ -141,7 +142,7 @@ fn render_preview(frame: &mut Frame, area: Rect, search: &SearchState) {
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
                 "Description:",
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(theme.warning),
             )));
             // Wrap description text
             for line in desc.lines().take(15) {
@@ -153,7 +154,7 @@ fn render_preview(frame: &mut Frame, area: Rect, search: &SearchState) {
     } else {
         vec![Line::from(Span::styled(
             "No task selected",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         ))]
     };
 
@@ -161,7 +162,7 @@ fn render_preview(frame: &mut Frame, area: Rect, search: &SearchState) {
         Block::default()
             .borders(Borders::ALL)
             .title(" Preview ")
-            .border_style(Style::default().fg(Color::DarkGray)),
+            .border_style(Style::default().fg(theme.muted)),
     );
 
     frame.render_widget(preview, area);
@@ -0,0 +1,160 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+};
+
+use std::collections::HashMap;
+
+use super::Theme;
+use crate::external::ClaudeActivityState;
+use crate::external::ZellijSession;
+use crate::state::SessionsState;
+
+pub fn render_sessions(
+    frame: &mut Frame,
+    area: Rect,
+    state: &SessionsState,
+    task_labels: &HashMap<String, String>,
+    spinner_char: char,
+    theme: &Theme,
+) {
+    if let Some(error) = &state.error {
+        let error_msg = Paragraph::new(format!("Error: {}", error))
+            .style(Style::default().fg(theme.error))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Sessions ")
+                    .border_style(Style::default().fg(theme.error)),
+            );
+        frame.render_widget(error_msg, area);
+        return;
+    }
+
+    if state.sessions.is_empty() {
+        let message = if state.loading {
+            "Loading sessions..."
+        } else {
+            "No zellij sessions running."
+        };
+        let empty = Paragraph::new(message).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Sessions ")
+                .border_style(Style::default().fg(theme.muted)),
+        );
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = state
+        .sessions
+        .iter()
+        .enumerate()
+        .map(|(i, session)| {
+            let is_selected = i == state.selected_index;
+
+            let current_marker = if session.is_current { "*" } else { " " };
+
+            let (activity, activity_style) = activity_marker(session, spinner_char, theme);
+
+            let name_style = if is_selected {
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else if session.is_dead {
+                Style::default().fg(theme.muted)
+            } else if session.is_current {
+                Style::default().fg(theme.success)
+            } else {
+                Style::default()
+            };
+
+            let mut spans = vec![
+                Span::raw(if is_selected { "> " } else { "  " }),
+                Span::styled(current_marker, Style::default().fg(theme.success)),
+                Span::styled(activity, activity_style),
+                Span::raw(" "),
+                Span::styled(&session.name, name_style),
+            ];
+
+            if let Some(pct) = session.context_percentage {
+                let color = if pct > 90.0 {
+                    theme.error
+                } else if pct > 70.0 {
+                    theme.warning
+                } else {
+                    theme.muted
+                };
+                spans.push(Span::styled(
+                    format!(" {:.0}%", pct),
+                    Style::default().fg(color),
+                ));
+            }
+
+            match task_labels.get(&session.name) {
+                Some(label) => spans.push(Span::styled(
+                    format!(" [{}]", label),
+                    Style::default().fg(theme.info),
+                )),
+                None => spans.push(Span::styled(
+                    " ad-hoc",
+                    Style::default().fg(theme.highlight),
+                )),
+            }
+
+            if session.is_dead {
+                spans.push(Span::styled(" (exited)", Style::default().fg(theme.muted)));
+            }
+
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Sessions ({}) ", state.sessions.len()))
+            .border_style(Style::default().fg(theme.accent)),
+    );
+
+    frame.render_widget(list, area);
+}
+
+/// One-character Claude activity marker for a session row: spinner while
+/// thinking, `?` waiting, `!` needs attention, `·` idle, `x` dead
+pub(super) fn activity_marker(
+    session: &ZellijSession,
+    spinner_char: char,
+    theme: &Theme,
+) -> (String, Style) {
+    if session.is_dead {
+        return ("x".to_string(), Style::default().fg(theme.muted));
+    }
+    if session.attention_acknowledged() {
+        return ("✓".to_string(), Style::default().fg(theme.muted));
+    }
+    match session.claude_activity {
+        ClaudeActivityState::Thinking => (
+            spinner_char.to_string(),
+            Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
+        ),
+        ClaudeActivityState::WaitingForUser => (
+            "?".to_string(),
+            Style::default()
+                .fg(theme.warning)
+                .add_modifier(Modifier::BOLD),
+        ),
+        ClaudeActivityState::Unknown if session.needs_attention => (
+            "!".to_string(),
+            Style::default()
+                .fg(theme.error)
+                .add_modifier(Modifier::BOLD),
+        ),
+        ClaudeActivityState::Idle => ("·".to_string(), Style::default().fg(theme.muted)),
+        ClaudeActivityState::Unknown => (" ".to_string(), Style::default().fg(theme.muted)),
+    }
+}
@@ -1,32 +1,55 @@
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
 };
 
-use crate::state::Task;
+use super::Theme;
+use crate::external::{BranchPrInfo, ChecksStatus, LinearAttachment, PlanContent};
+use crate::state::{PlanPhase, Task};
 
+/// Most recent transitions shown in the history timeline
+const HISTORY_ROWS: usize = 6;
+/// Attachments shown (and bound to keys 1-9) in the detail view
+const MAX_ATTACHMENTS: usize = 9;
+
+#[allow(clippy::too_many_arguments)]
 pub fn render_task_detail(
     frame: &mut Frame,
     area: Rect,
     task: &Task,
-    plan: Option<&str>,
+    branch_pr: Option<&BranchPrInfo>,
+    attachments: &[LinearAttachment],
+    plan: Option<&PlanContent>,
     plan_scroll_offset: usize,
     plan_line_count: usize,
+    theme: &Theme,
 ) {
     let has_linear = task.linear_url.is_some() || task.linear_issue_id.is_some();
-    let has_pr = task.pr_url.is_some();
+    let has_github = task.github_url.is_some();
+    let has_pr = task.pr_url.is_some() || branch_pr.is_some();
     let has_plan = plan.is_some();
+    let history_rows = task.status_history.len().min(HISTORY_ROWS);
 
     let mut constraints = vec![Constraint::Length(3)]; // Title with status
     if has_linear {
         constraints.push(Constraint::Length(3)); // Linear
     }
+    if !attachments.is_empty() {
+        let rows = attachments.len().min(MAX_ATTACHMENTS);
+        constraints.push(Constraint::Length(rows as u16 + 2)); // Linear attachments
+    }
+    if has_github {
+        constraints.push(Constraint::Length(3)); // GitHub issue
+    }
     if has_pr {
         constraints.push(Constraint::Length(3)); // PR
     }
+    if history_rows > 0 {
+        constraints.push(Constraint::Length(history_rows as u16 + 2)); // History
+    }
     if has_plan {
         // Plan section takes up to 50% of remaining space
         constraints.push(Constraint::Percentage(50));
@@ -41,14 +64,6 @@ pub fn render_task_detail(
     let mut chunk_idx = 0;
 
     // Title with status inlined
-    let status_color = match task.status {
-        crate::state::TaskStatus::Backlog => Color::Gray,
-        crate::state::TaskStatus::Todo => Color::Blue,
-        crate::state::TaskStatus::Inprogress => Color::Yellow,
-        crate::state::TaskStatus::Inreview => Color::Magenta,
-        crate::state::TaskStatus::Done => Color::Green,
-        crate::state::TaskStatus::Cancelled => Color::Red,
-    };
 
     let mut title_spans = vec![
         Span::styled(
@@ -58,23 +73,23 @@ pub fn render_task_detail(
         Span::raw(" "),
         Span::styled(
             format!("[{}]", task.status.label()),
-            Style::default().fg(status_color),
+            Style::default().fg(theme.status_color(task.status)),
         ),
     ];
 
     // Add attempt status inline
     if task.has_in_progress_attempt {
         title_spans.push(Span::raw(" "));
-        title_spans.push(Span::styled("Running", Style::default().fg(Color::Yellow)));
+        title_spans.push(Span::styled("Running", Style::default().fg(theme.warning)));
     } else if task.last_attempt_failed {
         title_spans.push(Span::raw(" "));
-        title_spans.push(Span::styled("Failed", Style::default().fg(Color::Red)));
+        title_spans.push(Span::styled("Failed", Style::default().fg(theme.error)));
     }
 
     let title = Paragraph::new(Line::from(title_spans)).block(
         Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(super::ACCENT)),
+            .border_style(Style::default().fg(theme.accent)),
     );
     frame.render_widget(title, chunks[chunk_idx]);
     chunk_idx += 1;
@@ -87,26 +102,103 @@ pub fn render_task_detail(
             .or(task.linear_issue_id.as_deref())
             .unwrap_or("");
 
+        let mut linear_title = " Linear ".to_string();
+        let priority = task
+            .priority
+            .filter(|p| *p > 0)
+            .map(crate::state::priority_label);
+        let estimate = task.estimate.map(|points| format!("{}pt", points));
+        let extras: Vec<String> = priority
+            .map(str::to_string)
+            .into_iter()
+            .chain(estimate)
+            .collect();
+        if !extras.is_empty() {
+            linear_title = format!(" Linear - {} ", extras.join(", "));
+        }
+
         let linear = Paragraph::new(linear_text).block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Linear ")
-                .border_style(Style::default().fg(Color::Blue)),
+                .title(linear_title)
+                .border_style(Style::default().fg(theme.info)),
         );
         frame.render_widget(linear, chunks[chunk_idx]);
         chunk_idx += 1;
     }
 
+    // Linear attachments, opened with their number key
+    if !attachments.is_empty() {
+        let lines: Vec<Line> = attachments
+            .iter()
+            .take(MAX_ATTACHMENTS)
+            .enumerate()
+            .map(|(i, attachment)| {
+                let mut spans = vec![
+                    Span::styled(format!("[{}] ", i + 1), Style::default().fg(theme.accent)),
+                    Span::raw(attachment.label().to_string()),
+                ];
+                if attachment.label() != attachment.url {
+                    spans.push(Span::styled(
+                        format!("  {}", attachment.url),
+                        Style::default().fg(theme.muted),
+                    ));
+                }
+                Line::from(spans)
+            })
+            .collect();
+        let attachments_widget = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Attachments ")
+                .border_style(Style::default().fg(theme.info)),
+        );
+        frame.render_widget(attachments_widget, chunks[chunk_idx]);
+        chunk_idx += 1;
+    }
+
+    // GitHub issue URL row
+    if let Some(ref github_url) = task.github_url {
+        let github = Paragraph::new(github_url.as_str()).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" GitHub issue ")
+                .border_style(Style::default().fg(theme.info)),
+        );
+        frame.render_widget(github, chunks[chunk_idx]);
+        chunk_idx += 1;
+    }
+
     // PR URL row
     if has_pr {
-        let pr_url = task.pr_url.as_deref().unwrap_or("");
-        let pr_status_color = match task.pr_status.as_deref() {
-            Some("merged") => Color::Magenta,
-            Some("closed") => Color::Red,
-            _ => Color::Green,
+        let pr_url = branch_pr
+            .map(|pr| pr.url.as_str())
+            .or(task.pr_url.as_deref())
+            .unwrap_or("");
+        let pr_state = task
+            .pr_status
+            .clone()
+            .or_else(|| branch_pr.map(|pr| pr.state.to_lowercase()));
+        let pr_status_color = match pr_state.as_deref() {
+            Some("merged") => theme.highlight,
+            Some("closed") => theme.error,
+            _ => theme.success,
         };
 
-        let pr = Paragraph::new(pr_url).block(
+        let mut pr_spans = vec![Span::raw(pr_url)];
+        if let Some(failing) = branch_pr.and_then(|pr| pr.first_failing_check()) {
+            pr_spans.push(Span::styled(
+                format!("  failing: {}", failing),
+                Style::default().fg(theme.error),
+            ));
+        } else if branch_pr.is_some_and(|pr| pr.checks_status() == ChecksStatus::None) {
+            pr_spans.push(Span::styled(
+                "  no checks",
+                Style::default().fg(theme.muted),
+            ));
+        }
+
+        let pr = Paragraph::new(Line::from(pr_spans)).block(
             Block::default()
                 .borders(Borders::ALL)
                 .title(" Pull Request ")
@@ -116,40 +208,109 @@ pub fn render_task_detail(
         chunk_idx += 1;
     }
 
+    // Status history timeline, most recent last
+    if history_rows > 0 {
+        let skipped = task.status_history.len() - history_rows;
+        let lines: Vec<Line> = task.status_history[skipped..]
+            .iter()
+            .map(|change| {
+                let at = change.at.with_timezone(&chrono::Local);
+                Line::from(vec![
+                    Span::styled(
+                        at.format("%Y-%m-%d %H:%M").to_string(),
+                        Style::default().fg(theme.muted),
+                    ),
+                    Span::raw("  → "),
+                    Span::styled(
+                        change.status.label(),
+                        Style::default().fg(theme.status_color(change.status)),
+                    ),
+                ])
+            })
+            .collect();
+
+        let title = if skipped > 0 {
+            format!(
+                " History (last {} of {}) ",
+                history_rows,
+                task.status_history.len()
+            )
+        } else {
+            " History ".to_string()
+        };
+        let history = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(Style::default().fg(theme.muted)),
+        );
+        frame.render_widget(history, chunks[chunk_idx]);
+        chunk_idx += 1;
+    }
+
     // Plan section with scrolling
     if let Some(plan_content) = plan {
         // Calculate visible area height (minus borders)
         let plan_area = chunks[chunk_idx];
         let visible_height = plan_area.height.saturating_sub(2) as usize;
 
-        // Get lines with offset for scrolling
-        let lines: Vec<&str> = plan_content.lines().collect();
-        let visible_lines: Vec<Line> = lines
+        // Only the visible window of the cached lines is turned into widgets
+        let visible_lines: Vec<Line> = plan_content
+            .lines
             .iter()
             .skip(plan_scroll_offset)
             .take(visible_height)
-            .map(|l| Line::from(*l))
+            .map(|l| Line::from(l.as_str()))
             .collect();
 
-        // Build title with scroll position
-        let title = if plan_line_count > visible_height {
+        // Title shows checkbox progress, or the scroll position for plans without any
+        let title = if let Some((done, total)) = plan_content.checkbox_progress {
+            format!(" Plan [{}/{} done] ", done, total)
+        } else if plan_line_count > visible_height {
             let current_pos = plan_scroll_offset + 1;
             format!(" Plan [{}/{}] ", current_pos, plan_line_count)
         } else {
             " Plan ".to_string()
         };
 
-        let plan_widget = Paragraph::new(visible_lines).block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(title)
-                .border_style(Style::default().fg(Color::Magenta)),
-        );
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(Style::default().fg(theme.highlight));
+        if let Some(phase) = task.plan_phase {
+            let phase = match phase {
+                PlanPhase::Planning => " planning ",
+                PlanPhase::Executing => " executing ",
+            };
+            block = block.title_top(
+                Line::from(Span::styled(phase, Style::default().fg(theme.muted))).right_aligned(),
+            );
+        }
+        if plan_content.truncated {
+            block = block.title_bottom(Line::from(Span::styled(
+                format!(
+                    " truncated at {} lines, P to open in editor ",
+                    plan_content.lines.len()
+                ),
+                Style::default().fg(theme.warning),
+            )));
+        }
+        let plan_widget = Paragraph::new(visible_lines).block(block);
         frame.render_widget(plan_widget, plan_area);
         chunk_idx += 1;
     }
 
-    // Description
+    // Description, with the private notes beside it when there are any
+    let (description_area, notes_area) = match task.notes {
+        Some(_) => {
+            let halves = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(chunks[chunk_idx]);
+            (halves[0], Some(halves[1]))
+        }
+        None => (chunks[chunk_idx], None),
+    };
     let description_text = task.description.as_deref().unwrap_or("No description");
 
     let description = Paragraph::new(description_text)
@@ -158,19 +319,33 @@ pub fn render_task_detail(
             Block::default()
                 .borders(Borders::ALL)
                 .title(" Description ")
-                .border_style(Style::default().fg(Color::DarkGray)),
+                .border_style(Style::default().fg(theme.muted)),
         );
 
-    frame.render_widget(description, chunks[chunk_idx]);
+    frame.render_widget(description, description_area);
+
+    if let (Some(notes), Some(area)) = (task.notes.as_deref(), notes_area) {
+        let notes_widget = Paragraph::new(notes).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Notes (local) ")
+                .border_style(Style::default().fg(theme.secondary)),
+        );
+        frame.render_widget(notes_widget, area);
+    }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn render_task_detail_with_actions(
     frame: &mut Frame,
     area: Rect,
     task: &Task,
-    plan: Option<&str>,
+    branch_pr: Option<&BranchPrInfo>,
+    attachments: &[LinearAttachment],
+    plan: Option<&PlanContent>,
     plan_scroll_offset: usize,
     plan_line_count: usize,
+    theme: &Theme,
 ) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -184,33 +359,56 @@ pub fn render_task_detail_with_actions(
         frame,
         chunks[0],
         task,
+        branch_pr,
+        attachments,
         plan,
         plan_scroll_offset,
         plan_line_count,
+        theme,
     );
 
+    // Plan editing and execution are only offered when the task has a plan
+    let (edit_plan_key, edit_plan_label) = if plan.is_some() {
+        (Style::default().fg(theme.accent), Style::default())
+    } else {
+        let disabled = Style::default().fg(theme.muted);
+        (disabled, disabled)
+    };
+
     // Actions bar
     let actions = Paragraph::new(Line::from(vec![
-        Span::styled("[g]", Style::default().fg(super::ACCENT)),
+        Span::styled("[g]", Style::default().fg(theme.accent)),
         Span::raw(" Gas  "),
-        Span::styled("[G]", Style::default().fg(super::ACCENT)),
+        Span::styled("[G]", Style::default().fg(theme.accent)),
         Span::raw(" Gas+prime  "),
-        Span::styled("[p]", Style::default().fg(super::ACCENT)),
+        Span::styled("[p]", Style::default().fg(theme.accent)),
         Span::raw(" Plan  "),
-        Span::styled("[P]", Style::default().fg(super::ACCENT)),
+        Span::styled("[P]", Style::default().fg(theme.accent)),
         Span::raw(" View Plan  "),
-        Span::styled("[v]", Style::default().fg(super::ACCENT)),
+        Span::styled("[E]", edit_plan_key),
+        Span::styled(" Edit Plan  ", edit_plan_label),
+        Span::styled("[X]", edit_plan_key),
+        Span::styled(" Execute  ", edit_plan_label),
+        Span::styled("[v]", Style::default().fg(theme.accent)),
         Span::raw(" View PR  "),
-        Span::styled("[e]", Style::default().fg(super::ACCENT)),
+        Span::styled("[D]", Style::default().fg(theme.accent)),
+        Span::raw(" Diff  "),
+        Span::styled("[A]", Style::default().fg(theme.accent)),
+        Span::raw(" Approve  "),
+        Span::styled("[F]", Style::default().fg(theme.accent)),
+        Span::raw(" Changes  "),
+        Span::styled("[e]", Style::default().fg(theme.accent)),
         Span::raw(" Edit  "),
-        Span::styled("[d]", Style::default().fg(super::ACCENT)),
+        Span::styled("[N]", Style::default().fg(theme.accent)),
+        Span::raw(" Notes  "),
+        Span::styled("[d]", Style::default().fg(theme.accent)),
         Span::raw(" Delete"),
     ]))
     .block(
         Block::default()
             .borders(Borders::ALL)
             .title(" Actions ")
-            .border_style(Style::default().fg(Color::DarkGray)),
+            .border_style(Style::default().fg(theme.muted)),
     );
 
     frame.render_widget(actions, chunks[1]);
@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use ratatui::style::Color;
+use serde::Deserialize;
+
+use crate::state::TaskStatus;
+
+/// Named color slots used by every render function
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// Selection, focused borders and highlighted values
+    pub accent: Color,
+    /// Labels and primary text drawn with an explicit color
+    pub text: Color,
+    /// Less prominent text (backlog status)
+    pub subtle: Color,
+    /// Hints, separators and unfocused borders
+    pub muted: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub error: Color,
+    /// Linear identifiers, todo status, thinking sessions
+    pub info: Color,
+    /// Merged PRs, plans, review status
+    pub highlight: Color,
+    /// Assignees and other secondary details
+    pub secondary: Color,
+    /// Fill behind modals
+    pub background: Color,
+    /// Backlog title color for a fresh card
+    pub fresh: Color,
+    /// Backlog title color once a card is fully stale
+    pub stale: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            accent: Color::Rgb(232, 145, 58),
+            text: Color::White,
+            subtle: Color::Gray,
+            muted: Color::DarkGray,
+            success: Color::Green,
+            warning: Color::Yellow,
+            error: Color::Red,
+            info: Color::Blue,
+            highlight: Color::Magenta,
+            secondary: Color::Cyan,
+            background: Color::Black,
+            fresh: Color::Rgb(200, 200, 200),
+            stale: Color::Rgb(170, 70, 70),
+        }
+    }
+}
+
+/// Contents of `~/.vibe/theme.toml`:
+///
+/// ```toml
+/// # Built-in theme to start from: "default", "high-contrast" or "light"
+/// base = "light"
+///
+/// [colors]
+/// accent = "#d75f00"
+/// muted = "gray"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ThemeFile {
+    base: Option<String>,
+    colors: HashMap<String, String>,
+}
+
+impl Theme {
+    /// Brighter colors for dark terminals where the default ones blend in
+    pub fn high_contrast() -> Self {
+        Self {
+            accent: Color::Rgb(255, 175, 0),
+            text: Color::White,
+            subtle: Color::White,
+            muted: Color::Gray,
+            success: Color::LightGreen,
+            warning: Color::LightYellow,
+            error: Color::LightRed,
+            info: Color::LightCyan,
+            highlight: Color::LightMagenta,
+            secondary: Color::LightCyan,
+            background: Color::Black,
+            fresh: Color::White,
+            stale: Color::Rgb(255, 95, 95),
+        }
+    }
+
+    /// Dark colors for terminals with a light background
+    pub fn light() -> Self {
+        Self {
+            accent: Color::Rgb(191, 87, 0),
+            text: Color::Black,
+            subtle: Color::Rgb(80, 80, 80),
+            muted: Color::Rgb(120, 120, 120),
+            success: Color::Rgb(0, 128, 0),
+            warning: Color::Rgb(150, 100, 0),
+            error: Color::Rgb(190, 0, 0),
+            info: Color::Rgb(0, 70, 180),
+            highlight: Color::Rgb(150, 0, 150),
+            secondary: Color::Rgb(0, 115, 135),
+            background: Color::White,
+            fresh: Color::Rgb(60, 60, 60),
+            stale: Color::Rgb(180, 40, 40),
+        }
+    }
+
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "default" | "dark" => Some(Self::default()),
+            "high-contrast" | "high_contrast" => Some(Self::high_contrast()),
+            "light" => Some(Self::light()),
+            _ => None,
+        }
+    }
+
+    pub fn path() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".vibe").join("theme.toml"))
+    }
+
+    /// Load the user's theme; a missing file means the default theme
+    pub fn load() -> Result<Self> {
+        match Self::path() {
+            Some(path) if path.is_file() => Self::load_from(&path),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    fn load_from(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read theme: {:?}", path))?;
+        Self::parse(&content).with_context(|| format!("Invalid theme: {:?}", path))
+    }
+
+    fn parse(content: &str) -> Result<Self> {
+        let file: ThemeFile = toml::from_str(content)?;
+        let mut theme = match file.base.as_deref() {
+            Some(base) => Self::builtin(base)
+                .ok_or_else(|| anyhow::anyhow!("unknown base theme {:?}", base))?,
+            None => Self::default(),
+        };
+        for (slot, value) in &file.colors {
+            let color = theme
+                .slot_mut(slot)
+                .ok_or_else(|| anyhow::anyhow!("unknown color slot {:?}", slot))?;
+            *color = Color::from_str(value)
+                .map_err(|_| anyhow::anyhow!("invalid color {:?} for {}", value, slot))?;
+        }
+        Ok(theme)
+    }
+
+    fn slot_mut(&mut self, name: &str) -> Option<&mut Color> {
+        Some(match name {
+            "accent" => &mut self.accent,
+            "text" => &mut self.text,
+            "subtle" => &mut self.subtle,
+            "muted" => &mut self.muted,
+            "success" => &mut self.success,
+            "warning" => &mut self.warning,
+            "error" => &mut self.error,
+            "info" => &mut self.info,
+            "highlight" => &mut self.highlight,
+            "secondary" => &mut self.secondary,
+            "background" => &mut self.background,
+            "fresh" => &mut self.fresh,
+            "stale" => &mut self.stale,
+            _ => return None,
+        })
+    }
+
+    pub fn status_color(&self, status: TaskStatus) -> Color {
+        match status {
+            TaskStatus::Backlog => self.subtle,
+            TaskStatus::Todo => self.info,
+            TaskStatus::Inprogress => self.warning,
+            TaskStatus::Inreview => self.highlight,
+            TaskStatus::Done => self.success,
+            TaskStatus::Cancelled => self.error,
+        }
+    }
+
+    /// Blend from `fresh` to `stale`; named colors can't be blended, so they switch halfway
+    pub fn staleness_color(&self, staleness: f32) -> Color {
+        match (self.fresh, self.stale) {
+            (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
+                let lerp =
+                    |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * staleness) as u8;
+                Color::Rgb(lerp(r1, r2), lerp(g1, g2), lerp(b1, b2))
+            }
+            _ if staleness < 0.5 => self.fresh,
+            _ => self.stale,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_theme() {
+        assert_eq!(Theme::parse("").unwrap(), Theme::default());
+
+        let theme = Theme::parse(
+            "base = \"light\"\n[colors]\naccent = \"#d75f00\"\nmuted = \"dark gray\"\n",
+        )
+        .unwrap();
+        assert_eq!(theme.accent, Color::Rgb(0xd7, 0x5f, 0x00));
+        assert_eq!(theme.muted, Color::DarkGray);
+        assert_eq!(theme.text, Theme::light().text);
+
+        assert!(Theme::parse("base = \"solarized\"").is_err());
+        assert!(Theme::parse("[colors]\nborder = \"red\"").is_err());
+        assert!(Theme::parse("[colors]\naccent = \"not-a-color\"").is_err());
+        assert!(Theme::parse("accent = \"red\"").is_err());
+    }
+
+    #[test]
+    fn test_staleness_color() {
+        let theme = Theme::default();
+        assert_eq!(theme.staleness_color(0.0), Color::Rgb(200, 200, 200));
+        assert_eq!(theme.staleness_color(1.0), Color::Rgb(170, 70, 70));
+
+        let named = Theme {
+            fresh: Color::White,
+            stale: Color::Red,
+            ..Theme::default()
+        };
+        assert_eq!(named.staleness_color(0.2), Color::White);
+        assert_eq!(named.staleness_color(0.8), Color::Red);
+    }
+}
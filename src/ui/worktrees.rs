@@ -1,22 +1,34 @@
 use ratatui::{
     Frame,
-    layout::Rect,
-    style::{Color, Modifier, Style},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
 };
 
-use crate::state::WorktreesState;
+use std::collections::HashSet;
 
-pub fn render_worktrees(frame: &mut Frame, area: Rect, state: &WorktreesState) {
+use super::Theme;
+use super::sessions::activity_marker;
+use crate::state::{SessionsState, WorktreePicker, WorktreesState};
+
+pub fn render_worktrees(
+    frame: &mut Frame,
+    area: Rect,
+    state: &WorktreesState,
+    untracked: &HashSet<String>,
+    sessions: &SessionsState,
+    spinner_char: char,
+    theme: &Theme,
+) {
     if let Some(error) = &state.error {
         let error_msg = Paragraph::new(format!("Error: {}", error))
-            .style(Style::default().fg(Color::Red))
+            .style(Style::default().fg(theme.error))
             .block(
                 Block::default()
                     .borders(Borders::ALL)
                     .title(" Worktrees ")
-                    .border_style(Style::default().fg(Color::Red)),
+                    .border_style(Style::default().fg(theme.error)),
             );
         frame.render_widget(error_msg, area);
         return;
@@ -27,7 +39,7 @@ pub fn render_worktrees(frame: &mut Frame, area: Rect, state: &WorktreesState) {
             Block::default()
                 .borders(Borders::ALL)
                 .title(" Worktrees ")
-                .border_style(Style::default().fg(Color::DarkGray)),
+                .border_style(Style::default().fg(theme.muted)),
         );
         frame.render_widget(loading, area);
         return;
@@ -38,7 +50,7 @@ pub fn render_worktrees(frame: &mut Frame, area: Rect, state: &WorktreesState) {
             Block::default()
                 .borders(Borders::ALL)
                 .title(" Worktrees ")
-                .border_style(Style::default().fg(Color::DarkGray)),
+                .border_style(Style::default().fg(theme.muted)),
         );
         frame.render_widget(empty, area);
         return;
@@ -56,6 +68,12 @@ pub fn render_worktrees(frame: &mut Frame, area: Rect, state: &WorktreesState) {
             let dirty_marker = if wt.is_dirty() { "!" } else { " " };
             let main_status = wt.status_symbol();
 
+            // Activity of the branch's session, whether or not a task owns the branch
+            let (activity, activity_style) = sessions
+                .session_for_branch(&wt.branch)
+                .map(|session| activity_marker(session, spinner_char, theme))
+                .unwrap_or_else(|| (" ".to_string(), Style::default()));
+
             // Ahead/behind counts
             let ahead_behind = wt
                 .main
@@ -76,38 +94,47 @@ pub fn render_worktrees(frame: &mut Frame, area: Rect, state: &WorktreesState) {
             // Style based on selection and state
             let base_style = if is_selected {
                 Style::default()
-                    .fg(super::ACCENT)
+                    .fg(theme.accent)
                     .add_modifier(Modifier::BOLD)
             } else if wt.is_current {
-                Style::default().fg(Color::Green)
+                Style::default().fg(theme.success)
             } else {
                 Style::default()
             };
 
             let status_style = if wt.is_dirty() {
-                Style::default().fg(Color::Yellow)
+                Style::default().fg(theme.warning)
             } else {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(theme.muted)
             };
 
             let main_style = match wt.main_state.as_str() {
-                "ahead" => Style::default().fg(Color::Green),
-                "behind" => Style::default().fg(Color::Red),
-                "diverged" => Style::default().fg(Color::Yellow),
-                _ => Style::default().fg(Color::DarkGray),
+                "ahead" => Style::default().fg(theme.success),
+                "behind" => Style::default().fg(theme.error),
+                "diverged" => Style::default().fg(theme.warning),
+                _ => Style::default().fg(theme.muted),
             };
 
             ListItem::new(Line::from(vec![
                 Span::raw(if is_selected { "> " } else { "  " }),
-                Span::styled(current_marker, Style::default().fg(Color::Green)),
+                Span::styled(current_marker, Style::default().fg(theme.success)),
                 Span::styled(dirty_marker, status_style),
                 Span::styled(main_status, main_style),
+                Span::styled(activity, activity_style),
                 Span::raw(" "),
                 Span::styled(&wt.branch, base_style),
                 Span::styled(ahead_behind, main_style),
                 Span::styled(
                     format!(" ({})", wt.short_commit()),
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme.muted),
+                ),
+                Span::styled(
+                    if untracked.contains(&wt.branch) {
+                        " untracked [+]"
+                    } else {
+                        ""
+                    },
+                    Style::default().fg(theme.highlight),
                 ),
             ]))
         })
@@ -117,8 +144,82 @@ pub fn render_worktrees(frame: &mut Frame, area: Rect, state: &WorktreesState) {
         Block::default()
             .borders(Borders::ALL)
             .title(format!(" Worktrees ({}) ", state.worktrees.len()))
-            .border_style(Style::default().fg(super::ACCENT)),
+            .border_style(Style::default().fg(theme.accent)),
     );
 
     frame.render_widget(list, area);
+
+    if let Some(picker) = &state.picker {
+        render_picker(frame, area, state, picker, theme);
+    }
+}
+
+fn render_picker(
+    frame: &mut Frame,
+    area: Rect,
+    state: &WorktreesState,
+    picker: &WorktreePicker,
+    theme: &Theme,
+) {
+    let width = area.width.saturating_sub(4).min(70);
+    let height = area.height.saturating_sub(2).min(16);
+    let x = area.x + (area.width.saturating_sub(width)) / 2;
+    let y = area.y + (area.height.saturating_sub(height)) / 2;
+    let picker_area = Rect::new(x, y, width, height);
+
+    frame.render_widget(Clear, picker_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(picker_area);
+
+    let input_line = Line::from(vec![
+        Span::styled("> ", Style::default().fg(theme.accent)),
+        Span::raw(&picker.query),
+        Span::styled(
+            "_",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::SLOW_BLINK),
+        ),
+    ]);
+    let input = Paragraph::new(input_line).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Jump to branch ")
+            .border_style(Style::default().fg(theme.accent)),
+    );
+    frame.render_widget(input, chunks[0]);
+
+    let items: Vec<ListItem> = picker
+        .matches
+        .iter()
+        .filter_map(|&i| state.worktrees.get(i))
+        .map(|wt| ListItem::new(Line::from(wt.branch.clone())))
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    " {}/{} ",
+                    picker.matches.len(),
+                    state.worktrees.len()
+                ))
+                .border_style(Style::default().fg(theme.muted)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.muted)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+
+    let mut list_state = ListState::default();
+    if !picker.matches.is_empty() {
+        list_state.select(Some(picker.selected));
+    }
+    frame.render_stateful_widget(list, chunks[1], &mut list_state);
 }